@@ -0,0 +1,59 @@
+//! Сравнение пропускной способности репозитория задач при создании пула соединений
+//! на каждый запрос (прежнее поведение `DbConnection::get_pool`) и при общем пуле,
+//! созданном один раз при запуске приложения.
+//!
+//! Запуск: `cargo bench --bench db_pool`.
+
+use std::time::Duration;
+use criterion::{criterion_group, criterion_main, Criterion, Throughput};
+use diesel::prelude::*;
+use diesel_migrations::{embed_migrations, EmbeddedMigrations, MigrationHarness};
+use task_api::application::repositories::task_abstract_repo::TaskRepositoryAbstract;
+use task_api::dal::db_connection::{DbConfig, DbConnection};
+use task_api::dal::repositories::db_repo_tasks::TaskRepository;
+use task_api::domain::task::task_event::EventActor;
+use task_api::domain::workspace::Workspace;
+
+const MIGRATIONS: EmbeddedMigrations = embed_migrations!("migrations");
+
+fn prepare_database() -> DbConfig {
+    let path = std::env::temp_dir().join(format!("task_api_bench_{}.db", std::process::id()));
+    let _ = std::fs::remove_file(&path);
+    let config = DbConfig::new(&path.to_string_lossy());
+
+    let mut conn = SqliteConnection::establish(&config.database_url).expect("cannot open bench database");
+    conn.run_pending_migrations(MIGRATIONS).expect("cannot run migrations");
+    diesel::sql_query("INSERT INTO tasks (title, description) VALUES ('Benchmark task', 'Read in a loop')")
+        .execute(&mut conn)
+        .expect("cannot seed bench database");
+
+    config
+}
+
+fn pool_benchmark(c: &mut Criterion) {
+    let config = prepare_database();
+    let runtime = actix_rt::Runtime::new().unwrap();
+    let mut group = c.benchmark_group("get_task_by_id");
+    group.throughput(Throughput::Elements(1));
+    // Каждый пул r2d2 запускает собственные фоновые потоки, которые завершаются не сразу
+    // после удаления пула; при большом числе итераций процесс упирается в лимит потоков.
+    group.sample_size(10);
+    group.measurement_time(Duration::from_secs(2));
+
+    group.bench_function("pool_per_request", |b| {
+        b.iter(|| {
+            let repository = TaskRepository { db_connection: DbConnection::new("bench", &config).unwrap(), workspace: Workspace::DEFAULT, actor: EventActor::system() };
+            runtime.block_on(repository.get_task_by_id(1)).unwrap()
+        })
+    });
+
+    let repository = TaskRepository { db_connection: DbConnection::new("bench", &config).unwrap(), workspace: Workspace::DEFAULT, actor: EventActor::system() };
+    group.bench_function("shared_pool", |b| {
+        b.iter(|| runtime.block_on(repository.get_task_by_id(1)).unwrap())
+    });
+
+    group.finish();
+}
+
+criterion_group!(benches, pool_benchmark);
+criterion_main!(benches);
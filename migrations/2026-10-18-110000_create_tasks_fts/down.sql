@@ -0,0 +1,4 @@
+DROP TRIGGER tasks_fts_after_update;
+DROP TRIGGER tasks_fts_after_delete;
+DROP TRIGGER tasks_fts_after_insert;
+DROP TABLE tasks_fts;
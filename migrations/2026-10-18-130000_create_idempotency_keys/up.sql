@@ -0,0 +1,12 @@
+CREATE TABLE idempotency_keys (
+    idempotency_key TEXT PRIMARY KEY NOT NULL,
+    fingerprint TEXT NOT NULL,
+    -- NULL, пока запрос с этим ключом выполняется.
+    status_code INTEGER,
+    headers TEXT,
+    body BLOB,
+    created_at BIGINT NOT NULL,
+    expires_at BIGINT NOT NULL
+);
+
+CREATE INDEX idx_idempotency_keys_expires_at ON idempotency_keys (expires_at);
@@ -0,0 +1,4 @@
+DROP INDEX idx_tasks_due_at;
+ALTER TABLE tasks DROP COLUMN due_at;
+ALTER TABLE tasks DROP COLUMN start_at;
+ALTER TABLE tasks DROP COLUMN priority;
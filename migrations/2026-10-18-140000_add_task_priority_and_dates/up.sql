@@ -0,0 +1,7 @@
+ALTER TABLE tasks ADD COLUMN priority TEXT NOT NULL DEFAULT 'medium'
+    CHECK (priority IN ('low', 'medium', 'high', 'urgent'));
+ALTER TABLE tasks ADD COLUMN start_at TIMESTAMP;
+ALTER TABLE tasks ADD COLUMN due_at TIMESTAMP
+    CHECK (due_at IS NULL OR start_at IS NULL OR start_at <= due_at);
+
+CREATE INDEX idx_tasks_due_at ON tasks (due_at);
@@ -0,0 +1,13 @@
+CREATE TABLE projects (
+    id INTEGER PRIMARY KEY AUTOINCREMENT NOT NULL,
+    name TEXT NOT NULL,
+    description TEXT,
+    -- NULL, пока проект не отправлен в архив.
+    archived_at TIMESTAMP
+);
+
+-- Задачи удаляемого проекта удаляются или отвязываются приложением явно, поэтому внешний ключ запрещает
+-- удалить проект, у которого остались задачи.
+ALTER TABLE tasks ADD COLUMN project_id INTEGER REFERENCES projects (id) ON DELETE RESTRICT;
+
+CREATE INDEX idx_tasks_project_id ON tasks (project_id);
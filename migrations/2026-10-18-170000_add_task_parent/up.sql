@@ -0,0 +1,5 @@
+-- Дочерние задачи удаляемой задачи приложение отвязывает явно, увеличивая их версию; SET NULL
+-- подстраховывает удаления, выполняемые в обход этого, например каскадное удаление задач проекта.
+ALTER TABLE tasks ADD COLUMN parent_id INTEGER REFERENCES tasks (id) ON DELETE SET NULL;
+
+CREATE INDEX idx_tasks_parent_id ON tasks (parent_id);
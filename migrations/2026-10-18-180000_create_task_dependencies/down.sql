@@ -0,0 +1,3 @@
+DROP INDEX idx_task_dependencies_blocker_id;
+DROP TABLE task_dependencies;
+ALTER TABLE tasks DROP COLUMN estimate_minutes;
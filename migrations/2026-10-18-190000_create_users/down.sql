@@ -0,0 +1,5 @@
+DROP INDEX idx_tasks_assignee_id;
+ALTER TABLE tasks DROP COLUMN assignee_id;
+DROP INDEX idx_task_assignees_user_id;
+DROP TABLE task_assignees;
+DROP TABLE users;
@@ -0,0 +1,20 @@
+CREATE TABLE users (
+    id INTEGER PRIMARY KEY AUTOINCREMENT NOT NULL,
+    name TEXT NOT NULL,
+    -- Адреса почты уникальны без учёта регистра.
+    email TEXT NOT NULL UNIQUE COLLATE NOCASE
+);
+
+-- Все исполнители задачи, включая ответственного.
+CREATE TABLE task_assignees (
+    task_id INTEGER NOT NULL REFERENCES tasks (id) ON DELETE CASCADE,
+    user_id INTEGER NOT NULL REFERENCES users (id) ON DELETE CASCADE,
+    PRIMARY KEY (task_id, user_id)
+);
+
+CREATE INDEX idx_task_assignees_user_id ON task_assignees (user_id);
+
+-- Ответственный исполнитель задачи; всегда входит в task_assignees.
+ALTER TABLE tasks ADD COLUMN assignee_id INTEGER REFERENCES users (id) ON DELETE SET NULL;
+
+CREATE INDEX idx_tasks_assignee_id ON tasks (assignee_id);
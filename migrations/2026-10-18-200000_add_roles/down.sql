@@ -0,0 +1,3 @@
+DROP INDEX idx_project_members_user_id;
+DROP TABLE project_members;
+ALTER TABLE users DROP COLUMN role;
@@ -0,0 +1,19 @@
+CREATE TEMP TABLE task_tags_backup AS SELECT task_id, tag_id FROM task_tags WHERE workspace_id = 1;
+CREATE TABLE tags_old (
+    id INTEGER PRIMARY KEY AUTOINCREMENT NOT NULL,
+    name TEXT NOT NULL UNIQUE COLLATE NOCASE
+);
+INSERT INTO tags_old (id, name) SELECT id, name FROM tags WHERE workspace_id = 1;
+DROP TABLE tags;
+ALTER TABLE tags_old RENAME TO tags;
+INSERT INTO task_tags (task_id, tag_id) SELECT task_id, tag_id FROM task_tags_backup;
+DROP TABLE task_tags_backup;
+
+DROP INDEX idx_projects_workspace_id;
+DROP INDEX idx_tasks_workspace_id;
+ALTER TABLE api_keys DROP COLUMN workspace_id;
+ALTER TABLE task_assignees DROP COLUMN workspace_id;
+ALTER TABLE task_dependencies DROP COLUMN workspace_id;
+ALTER TABLE task_tags DROP COLUMN workspace_id;
+ALTER TABLE projects DROP COLUMN workspace_id;
+ALTER TABLE tasks DROP COLUMN workspace_id;
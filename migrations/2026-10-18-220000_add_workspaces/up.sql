@@ -0,0 +1,26 @@
+-- Рабочие пространства изолируют данные команд, размещённых на одном сервере.
+-- Существующие данные попадают в пространство по умолчанию 1.
+ALTER TABLE tasks ADD COLUMN workspace_id INTEGER NOT NULL DEFAULT 1;
+ALTER TABLE projects ADD COLUMN workspace_id INTEGER NOT NULL DEFAULT 1;
+ALTER TABLE task_tags ADD COLUMN workspace_id INTEGER NOT NULL DEFAULT 1;
+ALTER TABLE task_dependencies ADD COLUMN workspace_id INTEGER NOT NULL DEFAULT 1;
+ALTER TABLE task_assignees ADD COLUMN workspace_id INTEGER NOT NULL DEFAULT 1;
+ALTER TABLE api_keys ADD COLUMN workspace_id INTEGER NOT NULL DEFAULT 1;
+
+CREATE INDEX idx_tasks_workspace_id ON tasks (workspace_id);
+CREATE INDEX idx_projects_workspace_id ON projects (workspace_id);
+
+-- Имена меток уникальны в пределах пространства, поэтому таблица пересоздаётся без прежнего ограничения UNIQUE.
+-- DROP TABLE каскадно удаляет связи задач с метками, поэтому они сохраняются и восстанавливаются.
+CREATE TEMP TABLE task_tags_backup AS SELECT task_id, tag_id, workspace_id FROM task_tags;
+CREATE TABLE tags_new (
+    id INTEGER PRIMARY KEY AUTOINCREMENT NOT NULL,
+    name TEXT NOT NULL COLLATE NOCASE,
+    workspace_id INTEGER NOT NULL DEFAULT 1,
+    UNIQUE (workspace_id, name)
+);
+INSERT INTO tags_new (id, name) SELECT id, name FROM tags;
+DROP TABLE tags;
+ALTER TABLE tags_new RENAME TO tags;
+INSERT INTO task_tags (task_id, tag_id, workspace_id) SELECT task_id, tag_id, workspace_id FROM task_tags_backup;
+DROP TABLE task_tags_backup;
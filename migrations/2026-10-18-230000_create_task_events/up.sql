@@ -0,0 +1,15 @@
+-- История изменений задач. Ссылки на tasks нет: история удалённой задачи сохраняется.
+CREATE TABLE task_events (
+    id INTEGER PRIMARY KEY AUTOINCREMENT NOT NULL,
+    task_id INTEGER NOT NULL,
+    workspace_id INTEGER NOT NULL DEFAULT 1,
+    -- created, updated или deleted.
+    action TEXT NOT NULL,
+    -- Автор изменения: user:<sub>, api_key:<id> или system.
+    actor TEXT NOT NULL,
+    occurred_at TIMESTAMP NOT NULL DEFAULT CURRENT_TIMESTAMP,
+    -- JSON-массив изменений полей [{"field", "before", "after"}].
+    changes TEXT NOT NULL
+);
+
+CREATE INDEX idx_task_events_task ON task_events (workspace_id, task_id, id);
@@ -0,0 +1,4 @@
+-- Задачи из корзины удаляются окончательно: без deleted_at они снова стали бы видны.
+DELETE FROM tasks WHERE deleted_at IS NOT NULL;
+DROP INDEX idx_tasks_deleted_at;
+ALTER TABLE tasks DROP COLUMN deleted_at;
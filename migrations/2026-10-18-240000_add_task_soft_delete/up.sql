@@ -0,0 +1,6 @@
+-- Удалённые задачи остаются в корзине до восстановления или окончательного удаления.
+-- Вместе с корзиной task_events.action, кроме created, updated и deleted, принимает значения restored
+-- (восстановление из корзины) и purged (окончательное удаление).
+ALTER TABLE tasks ADD COLUMN deleted_at TIMESTAMP;
+
+CREATE INDEX idx_tasks_deleted_at ON tasks (deleted_at);
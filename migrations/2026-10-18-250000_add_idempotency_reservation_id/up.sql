@@ -0,0 +1,3 @@
+-- Случайный идентификатор резервирования: сохранить ответ или освободить ключ может только запрос,
+-- который занял его последним, а не запрос, у которого ключ перехватили по истечении аренды.
+ALTER TABLE idempotency_keys ADD COLUMN reservation_id TEXT NOT NULL DEFAULT '';
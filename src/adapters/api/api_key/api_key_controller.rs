@@ -0,0 +1,88 @@
+use actix_web::{delete, get, http::header, post, web, HttpRequest, HttpResponse};
+use crate::adapters::api::api_key::api_key_payload::ApiKeyPayload;
+use crate::adapters::api::task::error::ErrorReponse;
+use crate::adapters::api::auth::workspace_extractor::WorkspaceConfigs;
+use crate::application::repo_requesters::abstract_request::AbstractRequest;
+use crate::application::repo_requesters::get_api_keys_request::GetApiKeysRequest;
+use crate::application::repo_requesters::mint_api_key_request::MintApiKeyRequest;
+use crate::application::repo_requesters::revoke_api_key_request::RevokeApiKeyRequest;
+use crate::application::validation::validator::Validate;
+use crate::domain::access::actor::Actor;
+
+pub fn routes(cfg: &mut web::ServiceConfig) {
+    cfg.service(get_api_keys);
+    cfg.service(mint_api_key);
+    cfg.service(revoke_api_key);
+}
+
+#[utoipa::path(
+context_path = "/api",
+responses(
+(status = 200, description = "List API keys in order of issue, including revoked ones; key values are never returned", body = [ApiKey]),
+(status = 403, description = "Only global admins can manage API keys", body = ProblemDetails, content_type = "application/problem+json"),
+(status = 500, description = "Unexpected server error", body = ProblemDetails, content_type = "application/problem+json"),
+(status = 503, description = "Storage is temporarily unavailable", body = ProblemDetails, content_type = "application/problem+json")
+)
+)]
+#[get("/api-keys")]
+async fn get_api_keys(req: HttpRequest, data: WorkspaceConfigs, actor: Actor) -> Result<HttpResponse, ErrorReponse> {
+    let get_api_keys_request = GetApiKeysRequest::new(actor, data.task_repository.as_ref(), data.api_key_repository.as_ref());
+    let api_keys = get_api_keys_request.execute().await;
+
+    api_keys
+        .map_err(|err| ErrorReponse::map_io_error(err).with_instance(req.path()))
+        .map(|api_keys| HttpResponse::Ok().json(api_keys))
+}
+
+#[utoipa::path(
+context_path = "/api",
+request_body = ApiKeyPayload,
+responses(
+(status = 201, description = "Mint a new API key; `key` is returned only in this response", body = MintedApiKey, headers(
+    ("Location" = String, description = "URL of the API key")
+)),
+(status = 403, description = "Only global admins can manage API keys", body = ProblemDetails, content_type = "application/problem+json"),
+(status = 422, description = "API key payload is invalid; `errors` lists every failing field", body = ProblemDetails, content_type = "application/problem+json"),
+(status = 500, description = "Unexpected server error", body = ProblemDetails, content_type = "application/problem+json"),
+(status = 503, description = "Storage is temporarily unavailable", body = ProblemDetails, content_type = "application/problem+json")
+)
+)]
+#[post("/api-keys")]
+async fn mint_api_key(req: HttpRequest, data: WorkspaceConfigs, actor: Actor, input: web::Json<ApiKeyPayload>) -> Result<HttpResponse, ErrorReponse> {
+    let ApiKeyPayload { name, scopes } = input.into_inner()
+        .validate()
+        .map_err(|err| ErrorReponse::map_io_error(err).with_instance(req.path()))?;
+
+    let mint_api_key_request = MintApiKeyRequest::new(name, scopes, actor, data.task_repository.as_ref(), data.api_key_repository.as_ref());
+
+    let result = mint_api_key_request.execute().await;
+
+    match result {
+        Ok(minted) => Ok(HttpResponse::Created()
+            .insert_header((header::LOCATION, format!("{}/{}", req.path(), minted.api_key.id)))
+            .json(minted)),
+        Err(err) => Err(ErrorReponse::map_io_error(err).with_instance(req.path())),
+    }
+}
+
+#[utoipa::path(
+context_path = "/api",
+responses(
+(status = 204, description = "API key is revoked; revoking it again changes nothing"),
+(status = 403, description = "Only global admins can manage API keys", body = ProblemDetails, content_type = "application/problem+json"),
+(status = 404, description = "API key not found", body = ProblemDetails, content_type = "application/problem+json"),
+(status = 500, description = "Unexpected server error", body = ProblemDetails, content_type = "application/problem+json"),
+(status = 503, description = "Storage is temporarily unavailable", body = ProblemDetails, content_type = "application/problem+json")
+),
+params(
+("id" = i32, Path, description = "API key ID")
+)
+)]
+#[delete("/api-keys/{id}")]
+async fn revoke_api_key(req: HttpRequest, data: WorkspaceConfigs, actor: Actor, key_id: web::Path<i32>) -> Result<HttpResponse, ErrorReponse> {
+    let revoke_api_key_request = RevokeApiKeyRequest::new(key_id.into_inner(), actor, data.task_repository.as_ref(), data.api_key_repository.as_ref());
+
+    revoke_api_key_request.execute().await
+        .map_err(|err| ErrorReponse::map_io_error(err).with_instance(req.path()))
+        .map(|_| HttpResponse::NoContent().finish())
+}
@@ -0,0 +1,43 @@
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+use crate::application::validation::api_key_rules::API_KEY_NAME_RULES;
+use crate::application::validation::validator::{Validate, Validator};
+use crate::domain::access::api_key::ApiKeyScope;
+use crate::domain::error::{ApiError, FieldError};
+
+#[derive(Deserialize, Serialize, ToSchema)]
+pub struct ApiKeyPayload {
+    /// Название сервисной учётной записи; пробелы в начале и в конце удаляются.
+    #[schema(min_length = 1, max_length = 100, pattern = r"^[^\u0000-\u001F\u007F-\u009F]*$", example = "ci-bot")]
+    pub name: String,
+    /// Права ключа, хотя бы одно.
+    #[schema(min_items = 1)]
+    pub scopes: Vec<ApiKeyScope>,
+}
+
+impl Validate for ApiKeyPayload {
+    fn validate(self) -> Result<Self, ApiError> {
+        let mut validator = Validator::new();
+        let name = validator.string("name", self.name, &API_KEY_NAME_RULES);
+        if self.scopes.is_empty() {
+            validator.add_error(FieldError::new("scopes", "required", "scopes must contain at least one scope"));
+        }
+
+        validator.finish(ApiKeyPayload { name, scopes: self.scopes })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_should_require_name_and_scopes() {
+        let valid = ApiKeyPayload { name: " ci-bot ".to_string(), scopes: vec![ApiKeyScope::ReadTasks] }.validate().unwrap();
+        let invalid = ApiKeyPayload { name: " ".to_string(), scopes: vec![] }.validate().err().unwrap();
+
+        assert_eq!(valid.name, "ci-bot");
+        let errors = invalid.errors.iter().map(|e| (e.field.as_str(), e.code.as_str())).collect::<Vec<_>>();
+        assert_eq!(errors, vec![("name", "required"), ("scopes", "required")]);
+    }
+}
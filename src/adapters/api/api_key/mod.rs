@@ -0,0 +1,2 @@
+pub mod api_key_controller;
+pub mod api_key_payload;
@@ -0,0 +1,75 @@
+use std::future::Future;
+use std::pin::Pin;
+use actix_web::dev::Payload;
+use actix_web::{web, FromRequest, HttpMessage, HttpRequest};
+use crate::adapters::api::task::error::ErrorReponse;
+use crate::adapters::shared::app_confs::AppConfigs;
+use crate::application::helpers::error_handler::ErrorHandlingHelper;
+use crate::application::repo_requesters::abstract_request::AbstractRequest;
+use crate::application::repo_requesters::authenticate_api_key_request::AuthenticateApiKeyRequest;
+use crate::domain::access::api_key::ApiKey;
+
+/// Заголовок, в котором сервисные учётные записи передают ключ API.
+pub const API_KEY_HEADER: &str = "X-Api-Key";
+
+/// Ключ API из заголовка `X-Api-Key`, проверенный по хранилищу.
+///
+/// Успешная проверка отмечает время использования ключа и сохраняет его в расширениях запроса, поэтому
+/// повторное извлечение в том же запросе не обращается к хранилищу. Без заголовка, с неизвестным
+/// или отозванным ключом обработчик получает 401.
+impl FromRequest for ApiKey {
+    type Error = ErrorReponse;
+    type Future = Pin<Box<dyn Future<Output = Result<Self, Self::Error>>>>;
+
+    fn from_request(req: &HttpRequest, _: &mut Payload) -> Self::Future {
+        let req = req.clone();
+
+        Box::pin(async move {
+            if let Some(api_key) = req.extensions().get::<ApiKey>().cloned() {
+                return Ok(api_key);
+            }
+            let unauthorized = || ErrorReponse::map_io_error(ErrorHandlingHelper::unauthorized_error()).with_instance(req.path());
+            let key = api_key_header(&req).ok_or_else(unauthorized)?;
+            let data = req.app_data::<web::Data<AppConfigs>>().ok_or_else(unauthorized)?;
+
+            let api_key = AuthenticateApiKeyRequest::new(key.to_string(), data.api_key_repository.as_ref())
+                .execute().await
+                .map_err(|err| ErrorReponse::map_io_error(err).with_instance(req.path()))?;
+            req.extensions_mut().insert(api_key.clone());
+
+            Ok(api_key)
+        })
+    }
+}
+
+/// Значение заголовка `X-Api-Key`, если он есть и не пуст.
+pub fn api_key_header(req: &HttpRequest) -> Option<&str> {
+    req.headers().get(API_KEY_HEADER)?.to_str().ok()
+        .map(str::trim)
+        .filter(|key| !key.is_empty())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use actix_web::test::TestRequest;
+
+    #[test]
+    fn test_should_read_api_key_header() {
+        let req = TestRequest::get().insert_header((API_KEY_HEADER, " tm_abc ")).to_http_request();
+        let empty = TestRequest::get().insert_header((API_KEY_HEADER, "")).to_http_request();
+
+        assert_eq!(api_key_header(&req), Some("tm_abc"));
+        assert_eq!(api_key_header(&empty), None);
+        assert_eq!(api_key_header(&TestRequest::get().to_http_request()), None);
+    }
+
+    #[actix_rt::test]
+    async fn test_should_reject_request_without_api_key() {
+        let req = TestRequest::get().uri("/api/tasks").to_http_request();
+
+        let error = ApiKey::extract(&req).await.unwrap_err();
+
+        assert_eq!(error.problem().status, 401);
+    }
+}
@@ -0,0 +1,17 @@
+use actix_web::{get, web, HttpResponse};
+use crate::domain::principal::Principal;
+
+pub fn routes(cfg: &mut web::ServiceConfig) {
+    cfg.service(get_current_principal);
+}
+
+#[utoipa::path(
+context_path = "/api",
+responses(
+(status = 200, description = "The client authenticated by the bearer token", body = Principal)
+)
+)]
+#[get("/me")]
+async fn get_current_principal(principal: Principal) -> HttpResponse {
+    HttpResponse::Ok().json(principal)
+}
@@ -0,0 +1,4 @@
+pub mod api_key_extractor;
+pub mod auth_controller;
+pub mod principal_extractor;
+pub mod workspace_extractor;
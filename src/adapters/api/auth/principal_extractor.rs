@@ -0,0 +1,95 @@
+use std::future::{ready, Ready};
+use actix_web::dev::Payload;
+use actix_web::{FromRequest, HttpMessage, HttpRequest};
+use crate::adapters::api::task::error::ErrorReponse;
+use crate::application::helpers::error_handler::ErrorHandlingHelper;
+use crate::domain::access::actor::Actor;
+use crate::domain::access::api_key::ApiKey;
+use crate::domain::principal::Principal;
+
+/// Клиент, которого промежуточный слой аутентификации поместил в расширения запроса.
+///
+/// Если клиента нет (аутентификация отключена или маршрут не защищён), обработчик получает 401.
+impl FromRequest for Principal {
+    type Error = ErrorReponse;
+    type Future = Ready<Result<Self, Self::Error>>;
+
+    fn from_request(req: &HttpRequest, _: &mut Payload) -> Self::Future {
+        let principal = req.extensions().get::<Principal>().cloned()
+            .ok_or_else(|| ErrorReponse::map_io_error(ErrorHandlingHelper::unauthorized_error()).with_instance(req.path()));
+        ready(principal)
+    }
+}
+
+/// От чьего имени выполняется запрос: сервисная учётная запись с ключом API, клиент из токена
+/// или, если аутентификация отключена, `Actor::System`.
+impl FromRequest for Actor {
+    type Error = ErrorReponse;
+    type Future = Ready<Result<Self, Self::Error>>;
+
+    fn from_request(req: &HttpRequest, _: &mut Payload) -> Self::Future {
+        let extensions = req.extensions();
+        let actor = match (extensions.get::<ApiKey>(), extensions.get::<Principal>()) {
+            (Some(api_key), _) => Actor::Service(api_key.scope_set()),
+            (None, Some(principal)) => Actor::from(principal),
+            (None, None) => Actor::System,
+        };
+        ready(Ok(actor))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use actix_web::test::TestRequest;
+    use crate::domain::access::api_key::ApiKeyScope;
+
+    #[actix_rt::test]
+    async fn test_should_extract_principal_from_request_extensions() {
+        let req = TestRequest::get().to_http_request();
+        req.extensions_mut().insert(Principal::new("42"));
+
+        let principal = Principal::extract(&req).await.unwrap();
+
+        assert_eq!(principal.subject, "42");
+    }
+
+    #[actix_rt::test]
+    async fn test_should_reject_request_without_principal() {
+        let req = TestRequest::get().uri("/api/me").to_http_request();
+
+        let error = Principal::extract(&req).await.unwrap_err();
+
+        assert_eq!(error.problem().status, 401);
+    }
+
+    #[actix_rt::test]
+    async fn test_should_map_principal_to_actor() {
+        let req = TestRequest::get().to_http_request();
+        assert_eq!(Actor::extract(&req).await.unwrap(), Actor::System);
+
+        req.extensions_mut().insert(Principal::new("42"));
+        assert_eq!(Actor::extract(&req).await.unwrap(), Actor::User(42));
+
+        let req = TestRequest::get().to_http_request();
+        req.extensions_mut().insert(Principal::new("ci-bot"));
+        assert_eq!(Actor::extract(&req).await.unwrap(), Actor::Guest);
+    }
+
+    #[actix_rt::test]
+    async fn test_should_map_api_key_to_service_actor() {
+        let req = TestRequest::get().to_http_request();
+        req.extensions_mut().insert(ApiKey {
+            id: 1,
+            name: "ci-bot".to_string(),
+            prefix: "tm_abcdefg".to_string(),
+            scopes: vec![ApiKeyScope::ReadTasks],
+            created_at: chrono::Utc::now(),
+            last_used_at: None,
+            revoked_at: None,
+            workspace_id: 1,
+        });
+
+        assert_eq!(Actor::extract(&req).await.unwrap(), Actor::Service([ApiKeyScope::ReadTasks].into_iter().collect()));
+    }
+}
@@ -0,0 +1,150 @@
+use std::future::{ready, Ready};
+use std::ops::Deref;
+use actix_web::dev::Payload;
+use actix_web::http::StatusCode;
+use actix_web::{web, FromRequest, HttpMessage, HttpRequest};
+use crate::adapters::api::task::error::ErrorReponse;
+use crate::adapters::shared::app_confs::AppConfigs;
+use crate::application::helpers::error_handler::ErrorHandlingHelper;
+use crate::domain::access::api_key::ApiKey;
+use crate::domain::principal::Principal;
+use crate::domain::task::task_event::EventActor;
+use crate::domain::workspace::Workspace;
+
+/// Заголовок, в котором клиент выбирает рабочее пространство.
+pub const WORKSPACE_HEADER: &str = "X-Workspace-Id";
+
+/// Рабочее пространство запроса.
+///
+/// Пространство ключа API или утверждения `workspace_id` токена закреплено за клиентом: заголовок
+/// `X-Workspace-Id` с другим значением отклоняется с 403. Клиент с токеном без `workspace_id` закреплён
+/// за пространством по умолчанию: роли пользователей общие, и выбор пространства заголовком открыл бы
+/// ему данные всех команд. Выбирать пространство заголовком можно только без аутентификации, а без
+/// заголовка используется пространство по умолчанию. Некорректный заголовок отклоняется с 400.
+impl FromRequest for Workspace {
+    type Error = ErrorReponse;
+    type Future = Ready<Result<Self, Self::Error>>;
+
+    fn from_request(req: &HttpRequest, _: &mut Payload) -> Self::Future {
+        ready(resolve_workspace(req))
+    }
+}
+
+/// Репозитории приложения, ограниченные рабочим пространством запроса; см. `Workspace`.
+/// Изменения задач записываются в их историю от имени клиента запроса.
+pub struct WorkspaceConfigs(AppConfigs);
+
+impl Deref for WorkspaceConfigs {
+    type Target = AppConfigs;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl FromRequest for WorkspaceConfigs {
+    type Error = ErrorReponse;
+    type Future = Ready<Result<Self, Self::Error>>;
+
+    fn from_request(req: &HttpRequest, _: &mut Payload) -> Self::Future {
+        let configs = resolve_workspace(req).and_then(|workspace| {
+            let data = req.app_data::<web::Data<AppConfigs>>()
+                .ok_or_else(|| ErrorReponse::new(StatusCode::INTERNAL_SERVER_ERROR, "Error: an unknown error occured").with_instance(req.path()))?;
+            let mut configs = data.in_workspace(workspace);
+            let actor = event_actor(req);
+            configs.task_repository = configs.task_repository.on_behalf_of(actor.clone());
+            configs.project_repository = configs.project_repository.on_behalf_of(actor.clone());
+            configs.user_repository = configs.user_repository.on_behalf_of(actor);
+            Ok(WorkspaceConfigs(configs))
+        });
+        ready(configs)
+    }
+}
+
+fn resolve_workspace(req: &HttpRequest) -> Result<Workspace, ErrorReponse> {
+    let requested = workspace_header(req).map_err(|error| error.with_instance(req.path()))?;
+    let extensions = req.extensions();
+    let claimed = match (extensions.get::<ApiKey>(), extensions.get::<Principal>()) {
+        (Some(api_key), _) => Some(api_key.workspace_id),
+        (None, Some(principal)) => Some(principal.workspace_id.unwrap_or(Workspace::DEFAULT.id)),
+        (None, None) => None,
+    };
+
+    Workspace::resolve(claimed, requested)
+        .ok_or_else(|| ErrorReponse::map_io_error(ErrorHandlingHelper::forbidden_error()).with_instance(req.path()))
+}
+
+/// Автор изменений запроса: ключ API, пользователь токена или, без аутентификации, система.
+pub fn event_actor(req: &HttpRequest) -> EventActor {
+    let extensions = req.extensions();
+    match (extensions.get::<ApiKey>(), extensions.get::<Principal>()) {
+        (Some(api_key), _) => EventActor::from(api_key),
+        (None, Some(principal)) => EventActor::from(principal),
+        (None, None) => EventActor::system(),
+    }
+}
+
+/// Значение заголовка `X-Workspace-Id`, если он задан.
+///
+/// # Ошибки
+/// 400, если значение не является положительным целым числом.
+fn workspace_header(req: &HttpRequest) -> Result<Option<i32>, ErrorReponse> {
+    let value = match req.headers().get(WORKSPACE_HEADER) {
+        Some(value) => value,
+        None => return Ok(None),
+    };
+
+    value.to_str().ok()
+        .and_then(|value| value.trim().parse::<i32>().ok())
+        .filter(|id| *id > 0)
+        .map(Some)
+        .ok_or_else(|| ErrorReponse::new(StatusCode::BAD_REQUEST, "X-Workspace-Id must be a positive integer"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use actix_web::test::TestRequest;
+
+    #[actix_rt::test]
+    async fn test_should_resolve_workspace_from_header_or_default() {
+        let req = TestRequest::get().to_http_request();
+        assert_eq!(Workspace::extract(&req).await.unwrap(), Workspace::DEFAULT);
+
+        let req = TestRequest::get().insert_header((WORKSPACE_HEADER, "7")).to_http_request();
+        assert_eq!(Workspace::extract(&req).await.unwrap(), Workspace::new(7));
+
+        for invalid in ["0", "-1", "seven"] {
+            let req = TestRequest::get().insert_header((WORKSPACE_HEADER, invalid)).to_http_request();
+            assert_eq!(Workspace::extract(&req).await.unwrap_err().problem().status, 400);
+        }
+    }
+
+    #[actix_rt::test]
+    async fn test_should_reject_header_other_than_claimed_workspace() {
+        let principal = Principal { workspace_id: Some(2), ..Principal::new("42") };
+
+        let req = TestRequest::get().to_http_request();
+        req.extensions_mut().insert(principal.clone());
+        assert_eq!(Workspace::extract(&req).await.unwrap(), Workspace::new(2));
+
+        let req = TestRequest::get().insert_header((WORKSPACE_HEADER, "3")).to_http_request();
+        req.extensions_mut().insert(principal);
+        assert_eq!(Workspace::extract(&req).await.unwrap_err().problem().status, 403);
+    }
+
+    #[actix_rt::test]
+    async fn test_should_bind_principal_without_workspace_claim_to_default_workspace() {
+        let req = TestRequest::get().to_http_request();
+        req.extensions_mut().insert(Principal::new("42"));
+        assert_eq!(Workspace::extract(&req).await.unwrap(), Workspace::DEFAULT);
+
+        let req = TestRequest::get().insert_header((WORKSPACE_HEADER, "1")).to_http_request();
+        req.extensions_mut().insert(Principal::new("42"));
+        assert_eq!(Workspace::extract(&req).await.unwrap(), Workspace::DEFAULT);
+
+        let req = TestRequest::get().insert_header((WORKSPACE_HEADER, "2")).to_http_request();
+        req.extensions_mut().insert(Principal::new("42"));
+        assert_eq!(Workspace::extract(&req).await.unwrap_err().problem().status, 403);
+    }
+}
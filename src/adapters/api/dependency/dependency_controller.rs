@@ -0,0 +1,122 @@
+use actix_web::{get, web, HttpRequest, HttpResponse, put, delete};
+use crate::adapters::api::dependency::dependency_query_params::BlockersParams;
+use crate::adapters::api::task::error::ErrorReponse;
+use crate::adapters::api::auth::workspace_extractor::WorkspaceConfigs;
+use crate::application::repo_requesters::abstract_request::AbstractRequest;
+use crate::application::repo_requesters::add_task_dependency_request::AddTaskDependencyRequest;
+use crate::application::repo_requesters::get_critical_path_request::GetCriticalPathRequest;
+use crate::application::repo_requesters::get_task_blockers_request::GetTaskBlockersRequest;
+use crate::application::repo_requesters::remove_task_dependency_request::RemoveTaskDependencyRequest;
+use crate::domain::access::actor::Actor;
+
+pub fn routes(cfg: &mut web::ServiceConfig) {
+    cfg.service(get_task_blockers);
+    cfg.service(add_task_dependency);
+    cfg.service(remove_task_dependency);
+    cfg.service(get_critical_path);
+}
+
+#[utoipa::path(
+context_path = "/api",
+responses(
+(status = 200, description = "List tasks blocking the task, ordered by ID", body = [Task]),
+(status = 404, description = "Task not found", body = ProblemDetails, content_type = "application/problem+json"),
+(status = 500, description = "Unexpected server error", body = ProblemDetails, content_type = "application/problem+json"),
+(status = 503, description = "Storage is temporarily unavailable", body = ProblemDetails, content_type = "application/problem+json")
+),
+params(
+("id" = i32, Path, description = "Task ID"),
+BlockersParams
+)
+)]
+#[get("/tasks/{id}/blockers")]
+async fn get_task_blockers(req: HttpRequest, data: WorkspaceConfigs, task_id: web::Path<i32>, params: web::Query<BlockersParams>) -> Result<HttpResponse, ErrorReponse> {
+    let transitive = params.transitive.unwrap_or(true);
+    let get_task_blockers_request = GetTaskBlockersRequest::new(task_id.into_inner(), transitive, data.dependency_repository.as_ref());
+    let blockers = get_task_blockers_request.execute().await;
+
+    blockers
+        .map_err(|err| ErrorReponse::map_io_error(err).with_instance(req.path()))
+        .map(|blockers| HttpResponse::Ok().json(blockers))
+}
+
+#[utoipa::path(
+context_path = "/api",
+responses(
+(status = 204, description = "The task is blocked by the blocker task; adding the dependency again changes nothing"),
+(status = 403, description = "Only editors and admins of the task's project can change its dependencies", body = ProblemDetails, content_type = "application/problem+json"),
+(status = 404, description = "Task or blocker task not found", body = ProblemDetails, content_type = "application/problem+json"),
+(status = 409, description = "The dependency would create a cycle", body = ProblemDetails, content_type = "application/problem+json"),
+(status = 500, description = "Unexpected server error", body = ProblemDetails, content_type = "application/problem+json"),
+(status = 503, description = "Storage is temporarily unavailable", body = ProblemDetails, content_type = "application/problem+json")
+),
+params(
+("id" = i32, Path, description = "Blocked task ID"),
+("blocker_id" = i32, Path, description = "Blocker task ID")
+)
+)]
+#[put("/tasks/{id}/blockers/{blocker_id}")]
+async fn add_task_dependency(req: HttpRequest, data: WorkspaceConfigs, actor: Actor, path: web::Path<(i32, i32)>) -> Result<HttpResponse, ErrorReponse> {
+    let (task_id, blocker_id) = path.into_inner();
+    let add_task_dependency_request = AddTaskDependencyRequest::new(task_id, blocker_id, actor, data.task_repository.as_ref(), data.dependency_repository.as_ref());
+
+    let result = add_task_dependency_request.execute().await;
+
+    match result {
+        Ok(_) => Ok(HttpResponse::NoContent().finish()),
+        Err(err) => Err(ErrorReponse::map_io_error(err).with_instance(req.path())),
+    }
+}
+
+#[utoipa::path(
+context_path = "/api",
+responses(
+(status = 204, description = "The task is no longer blocked by the blocker task"),
+(status = 403, description = "Only editors and admins of the task's project can change its dependencies", body = ProblemDetails, content_type = "application/problem+json"),
+(status = 404, description = "Task or blocker task not found, or the task is not blocked by it", body = ProblemDetails, content_type = "application/problem+json"),
+(status = 500, description = "Unexpected server error", body = ProblemDetails, content_type = "application/problem+json"),
+(status = 503, description = "Storage is temporarily unavailable", body = ProblemDetails, content_type = "application/problem+json")
+),
+params(
+("id" = i32, Path, description = "Blocked task ID"),
+("blocker_id" = i32, Path, description = "Blocker task ID")
+)
+)]
+#[delete("/tasks/{id}/blockers/{blocker_id}")]
+async fn remove_task_dependency(req: HttpRequest, data: WorkspaceConfigs, actor: Actor, path: web::Path<(i32, i32)>) -> Result<HttpResponse, ErrorReponse> {
+    let (task_id, blocker_id) = path.into_inner();
+    let remove_task_dependency_request = RemoveTaskDependencyRequest::new(task_id, blocker_id, actor, data.task_repository.as_ref(), data.dependency_repository.as_ref());
+
+    let result = remove_task_dependency_request.execute().await;
+
+    match result {
+        Ok(_) => Ok(HttpResponse::NoContent().finish()),
+        Err(err) => Err(ErrorReponse::map_io_error(err).with_instance(req.path())),
+    }
+}
+
+#[utoipa::path(
+context_path = "/api",
+responses(
+(status = 200, description = "The longest chain of dependent project tasks by estimate", body = CriticalPath),
+(status = 404, description = "Project not found", body = ProblemDetails, content_type = "application/problem+json"),
+(status = 500, description = "Unexpected server error", body = ProblemDetails, content_type = "application/problem+json"),
+(status = 503, description = "Storage is temporarily unavailable", body = ProblemDetails, content_type = "application/problem+json")
+),
+params(
+("id" = i32, Path, description = "Project ID")
+)
+)]
+#[get("/projects/{id}/critical-path")]
+async fn get_critical_path(req: HttpRequest, data: WorkspaceConfigs, project_id: web::Path<i32>) -> Result<HttpResponse, ErrorReponse> {
+    let get_critical_path_request = GetCriticalPathRequest::new(
+        project_id.into_inner(),
+        data.dependency_repository.as_ref(),
+        data.project_repository.as_ref(),
+    );
+    let path = get_critical_path_request.execute().await;
+
+    path
+        .map_err(|err| ErrorReponse::map_io_error(err).with_instance(req.path()))
+        .map(|path| HttpResponse::Ok().json(path))
+}
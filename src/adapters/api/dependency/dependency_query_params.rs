@@ -0,0 +1,10 @@
+use serde::Deserialize;
+use utoipa::IntoParams;
+
+#[derive(Deserialize, IntoParams)]
+#[into_params(parameter_in = Query)]
+pub struct BlockersParams {
+    /// Включать ли задачи, блокирующие задачу через цепочку зависимостей (по умолчанию `true`);
+    /// `false` - только прямые блокирующие задачи.
+    pub transitive: Option<bool>,
+}
@@ -0,0 +1,2 @@
+pub mod dependency_controller;
+pub mod dependency_query_params;
@@ -1 +1,7 @@
-pub mod task;
\ No newline at end of file
+pub mod api_key;
+pub mod auth;
+pub mod dependency;
+pub mod project;
+pub mod task;
+pub mod tag;
+pub mod user;
\ No newline at end of file
@@ -0,0 +1,3 @@
+pub mod project_controller;
+pub mod project_payload;
+pub mod project_query_params;
@@ -0,0 +1,355 @@
+use actix_web::{get, web, HttpRequest, HttpResponse, post, put, delete, http::header};
+use crate::adapters::api::project::project_payload::ProjectPayload;
+use crate::adapters::api::project::project_query_params::{ProjectDeleteParams, ProjectListParams};
+use crate::adapters::api::task::error::ErrorReponse;
+use crate::adapters::api::task::task_etag::task_etag;
+use crate::adapters::api::task::task_payload::TaskPayload;
+use crate::adapters::api::task::task_query_params::TaskListParams;
+use crate::adapters::api::user::user_payload::RolePayload;
+use crate::adapters::api::auth::workspace_extractor::WorkspaceConfigs;
+use crate::adapters::shared::app_confs::AppConfigs;
+use crate::application::repo_requesters::abstract_request::AbstractRequest;
+use crate::application::repo_requesters::archive_project_request::ArchiveProjectRequest;
+use crate::application::repo_requesters::create_project_request::CreateProjectRequest;
+use crate::application::repo_requesters::create_task_request::CreateTaskRequest;
+use crate::application::repo_requesters::delete_project_request::DeleteProjectRequest;
+use crate::application::repo_requesters::get_project_members_request::GetProjectMembersRequest;
+use crate::application::repo_requesters::get_project_request::GetProjectRequest;
+use crate::application::repo_requesters::get_project_tasks_request::GetProjectTasksRequest;
+use crate::application::repo_requesters::get_projects_request::GetProjectsRequest;
+use crate::application::repo_requesters::get_tasks_request::GetTasksRequest;
+use crate::application::repo_requesters::remove_project_member_request::RemoveProjectMemberRequest;
+use crate::application::repo_requesters::set_project_member_request::SetProjectMemberRequest;
+use crate::application::repo_requesters::update_project_request::UpdateProjectRequest;
+use crate::application::validation::validator::Validate;
+use crate::domain::access::actor::Actor;
+use crate::domain::task::task_placement::TaskPlacement;
+
+pub fn routes(cfg: &mut web::ServiceConfig) {
+    cfg.service(get_projects);
+    cfg.service(get_project);
+    cfg.service(create_project);
+    cfg.service(update_project);
+    cfg.service(delete_project);
+    cfg.service(archive_project);
+    cfg.service(unarchive_project);
+    cfg.service(get_project_tasks);
+    cfg.service(create_project_task);
+    cfg.service(get_project_members);
+    cfg.service(set_project_member);
+    cfg.service(remove_project_member);
+}
+
+#[utoipa::path(
+context_path = "/api",
+responses(
+(status = 200, description = "List projects ordered by name", body = [Project]),
+(status = 400, description = "Invalid query parameters", body = ProblemDetails, content_type = "application/problem+json"),
+(status = 500, description = "Unexpected server error", body = ProblemDetails, content_type = "application/problem+json"),
+(status = 503, description = "Storage is temporarily unavailable", body = ProblemDetails, content_type = "application/problem+json")
+),
+params(ProjectListParams)
+)]
+#[get("/projects")]
+async fn get_projects(req: HttpRequest, data: WorkspaceConfigs, params: web::Query<ProjectListParams>) -> Result<HttpResponse, ErrorReponse> {
+    let get_projects_request = GetProjectsRequest::new(params.include_archived.unwrap_or(false), data.project_repository.as_ref());
+    let projects = get_projects_request.execute().await;
+
+    projects
+        .map_err(|err| ErrorReponse::map_io_error(err).with_instance(req.path()))
+        .map(|projects| HttpResponse::Ok().json(projects))
+}
+
+#[utoipa::path(
+context_path = "/api",
+responses(
+(status = 200, description = "Get project by ID", body = Project),
+(status = 404, description = "Project not found", body = ProblemDetails, content_type = "application/problem+json"),
+(status = 500, description = "Unexpected server error", body = ProblemDetails, content_type = "application/problem+json"),
+(status = 503, description = "Storage is temporarily unavailable", body = ProblemDetails, content_type = "application/problem+json")
+),
+params(
+("id" = i32, Path, description = "Project ID")
+)
+)]
+#[get("/projects/{id}")]
+async fn get_project(req: HttpRequest, data: WorkspaceConfigs, project_id: web::Path<i32>) -> Result<HttpResponse, ErrorReponse> {
+    let get_project_request = GetProjectRequest::new(project_id.into_inner(), data.project_repository.as_ref());
+    let project = get_project_request.execute().await;
+
+    project
+        .map_err(|err| ErrorReponse::map_io_error(err).with_instance(req.path()))
+        .map(|project| HttpResponse::Ok().json(project))
+}
+
+#[utoipa::path(
+context_path = "/api",
+request_body = ProjectPayload,
+responses(
+(status = 201, description = "Create a new project", body = Project, headers(
+    ("Location" = String, description = "URL of the created project")
+)),
+(status = 403, description = "Only editors and admins can create projects", body = ProblemDetails, content_type = "application/problem+json"),
+(status = 422, description = "Project payload is invalid; `errors` lists every failing field", body = ProblemDetails, content_type = "application/problem+json"),
+(status = 500, description = "Unexpected server error", body = ProblemDetails, content_type = "application/problem+json"),
+(status = 503, description = "Storage is temporarily unavailable", body = ProblemDetails, content_type = "application/problem+json")
+)
+)]
+#[post("/projects")]
+async fn create_project(req: HttpRequest, data: WorkspaceConfigs, actor: Actor, input: web::Json<ProjectPayload>) -> Result<HttpResponse, ErrorReponse> {
+    let ProjectPayload { name, description } = input.into_inner()
+        .validate()
+        .map_err(|err| ErrorReponse::map_io_error(err).with_instance(req.path()))?;
+
+    let create_project_request = CreateProjectRequest::new(name, description, actor, data.task_repository.as_ref(), data.project_repository.as_ref());
+
+    let result = create_project_request.execute().await;
+
+    match result {
+        Ok(project) => Ok(HttpResponse::Created()
+            .insert_header((header::LOCATION, format!("{}/{}", req.path(), project.id)))
+            .json(project)),
+        Err(err) => Err(ErrorReponse::map_io_error(err).with_instance(req.path())),
+    }
+}
+
+#[utoipa::path(
+context_path = "/api",
+request_body = ProjectPayload,
+responses(
+(status = 200, description = "Replace project name and description", body = Project),
+(status = 403, description = "Only project editors and admins can change the project", body = ProblemDetails, content_type = "application/problem+json"),
+(status = 404, description = "Project not found", body = ProblemDetails, content_type = "application/problem+json"),
+(status = 422, description = "Project payload is invalid; `errors` lists every failing field", body = ProblemDetails, content_type = "application/problem+json"),
+(status = 500, description = "Unexpected server error", body = ProblemDetails, content_type = "application/problem+json"),
+(status = 503, description = "Storage is temporarily unavailable", body = ProblemDetails, content_type = "application/problem+json")
+),
+params(
+("id" = i32, Path, description = "Project ID")
+)
+)]
+#[put("/projects/{id}")]
+async fn update_project(req: HttpRequest, data: WorkspaceConfigs, actor: Actor, project_id: web::Path<i32>, input: web::Json<ProjectPayload>) -> Result<HttpResponse, ErrorReponse> {
+    let ProjectPayload { name, description } = input.into_inner()
+        .validate()
+        .map_err(|err| ErrorReponse::map_io_error(err).with_instance(req.path()))?;
+
+    let update_project_request = UpdateProjectRequest::new(project_id.into_inner(), name, description, actor, data.task_repository.as_ref(), data.project_repository.as_ref());
+
+    let result = update_project_request.execute().await;
+
+    match result {
+        Ok(project) => Ok(HttpResponse::Ok().json(project)),
+        Err(err) => Err(ErrorReponse::map_io_error(err).with_instance(req.path())),
+    }
+}
+
+#[utoipa::path(
+context_path = "/api",
+responses(
+(status = 204, description = "Delete a project; its tasks are handled according to `on_tasks`"),
+(status = 400, description = "Invalid `on_tasks` value", body = ProblemDetails, content_type = "application/problem+json"),
+(status = 403, description = "Only project or global admins can delete projects", body = ProblemDetails, content_type = "application/problem+json"),
+(status = 404, description = "Project not found", body = ProblemDetails, content_type = "application/problem+json"),
+(status = 409, description = "`on_tasks=restrict` and the project still has tasks", body = ProblemDetails, content_type = "application/problem+json"),
+(status = 500, description = "Unexpected server error", body = ProblemDetails, content_type = "application/problem+json"),
+(status = 503, description = "Storage is temporarily unavailable", body = ProblemDetails, content_type = "application/problem+json")
+),
+params(
+("id" = i32, Path, description = "Project ID"),
+ProjectDeleteParams
+)
+)]
+#[delete("/projects/{id}")]
+async fn delete_project(req: HttpRequest, data: WorkspaceConfigs, actor: Actor, project_id: web::Path<i32>, params: web::Query<ProjectDeleteParams>) -> Result<HttpResponse, ErrorReponse> {
+    let policy = params.on_tasks.unwrap_or_default();
+    let delete_project_request = DeleteProjectRequest::new(project_id.into_inner(), policy, actor, data.task_repository.as_ref(), data.project_repository.as_ref());
+
+    let result = delete_project_request.execute().await;
+
+    match result {
+        Ok(_) => Ok(HttpResponse::NoContent().finish()),
+        Err(err) => Err(ErrorReponse::map_io_error(err).with_instance(req.path())),
+    }
+}
+
+#[utoipa::path(
+context_path = "/api",
+responses(
+(status = 200, description = "Project is archived; new tasks cannot be added to it. Archiving it again keeps the original `archived_at`", body = Project),
+(status = 403, description = "Only project or global admins can archive projects", body = ProblemDetails, content_type = "application/problem+json"),
+(status = 404, description = "Project not found", body = ProblemDetails, content_type = "application/problem+json"),
+(status = 500, description = "Unexpected server error", body = ProblemDetails, content_type = "application/problem+json"),
+(status = 503, description = "Storage is temporarily unavailable", body = ProblemDetails, content_type = "application/problem+json")
+),
+params(
+("id" = i32, Path, description = "Project ID")
+)
+)]
+#[post("/projects/{id}/archive")]
+async fn archive_project(req: HttpRequest, data: WorkspaceConfigs, actor: Actor, project_id: web::Path<i32>) -> Result<HttpResponse, ErrorReponse> {
+    set_archived(&req, &data, actor, project_id.into_inner(), true).await
+}
+
+#[utoipa::path(
+context_path = "/api",
+responses(
+(status = 200, description = "Project is restored from the archive", body = Project),
+(status = 403, description = "Only project or global admins can restore projects from the archive", body = ProblemDetails, content_type = "application/problem+json"),
+(status = 404, description = "Project not found", body = ProblemDetails, content_type = "application/problem+json"),
+(status = 500, description = "Unexpected server error", body = ProblemDetails, content_type = "application/problem+json"),
+(status = 503, description = "Storage is temporarily unavailable", body = ProblemDetails, content_type = "application/problem+json")
+),
+params(
+("id" = i32, Path, description = "Project ID")
+)
+)]
+#[post("/projects/{id}/unarchive")]
+async fn unarchive_project(req: HttpRequest, data: WorkspaceConfigs, actor: Actor, project_id: web::Path<i32>) -> Result<HttpResponse, ErrorReponse> {
+    set_archived(&req, &data, actor, project_id.into_inner(), false).await
+}
+
+async fn set_archived(req: &HttpRequest, data: &AppConfigs, actor: Actor, project_id: i32, archived: bool) -> Result<HttpResponse, ErrorReponse> {
+    let archive_project_request = ArchiveProjectRequest::new(project_id, archived, actor, data.task_repository.as_ref(), data.project_repository.as_ref());
+
+    archive_project_request.execute().await
+        .map_err(|err| ErrorReponse::map_io_error(err).with_instance(req.path()))
+        .map(|project| HttpResponse::Ok().json(project))
+}
+
+#[utoipa::path(
+context_path = "/api",
+responses(
+(status = 200, description = "List tasks of the project page by page", body = TaskPage),
+(status = 400, description = "Invalid paging, sorting or filter parameters", body = ProblemDetails, content_type = "application/problem+json"),
+(status = 404, description = "Project not found", body = ProblemDetails, content_type = "application/problem+json"),
+(status = 500, description = "Unexpected server error", body = ProblemDetails, content_type = "application/problem+json"),
+(status = 503, description = "Storage is temporarily unavailable", body = ProblemDetails, content_type = "application/problem+json")
+),
+params(
+("id" = i32, Path, description = "Project ID"),
+TaskListParams
+)
+)]
+#[get("/projects/{id}/tasks")]
+async fn get_project_tasks(req: HttpRequest, data: WorkspaceConfigs, actor: Actor, project_id: web::Path<i32>, params: web::Query<TaskListParams>) -> Result<HttpResponse, ErrorReponse> {
+    let params = params.into_inner();
+    let get_tasks_request = GetTasksRequest::new(params.filter(), params.sort_by, params.order, params.limit, params.offset, params.cursor, actor, data.task_repository.as_ref());
+    let get_project_tasks_request = GetProjectTasksRequest::new(project_id.into_inner(), get_tasks_request, data.project_repository.as_ref());
+    let page = get_project_tasks_request.execute().await;
+
+    page
+        .map_err(|err| ErrorReponse::map_io_error(err).with_instance(req.path()))
+        .map(|page| HttpResponse::Ok().json(page))
+}
+
+#[utoipa::path(
+context_path = "/api",
+request_body = TaskPayload,
+responses(
+(status = 201, description = "Create a new task in the project", body = Task, headers(
+    ("Location" = String, description = "URL of the created task"),
+    ("ETag" = String, description = "Version of the created task")
+)),
+(status = 404, description = "Project not found", body = ProblemDetails, content_type = "application/problem+json"),
+(status = 409, description = "Project is archived", body = ProblemDetails, content_type = "application/problem+json"),
+(status = 422, description = "Task payload is invalid; `errors` lists every failing field", body = ProblemDetails, content_type = "application/problem+json"),
+(status = 500, description = "Unexpected server error", body = ProblemDetails, content_type = "application/problem+json"),
+(status = 503, description = "Storage is temporarily unavailable", body = ProblemDetails, content_type = "application/problem+json")
+),
+params(
+("id" = i32, Path, description = "Project ID")
+)
+)]
+#[post("/projects/{id}/tasks")]
+async fn create_project_task(req: HttpRequest, data: WorkspaceConfigs, actor: Actor, project_id: web::Path<i32>, input: web::Json<TaskPayload>) -> Result<HttpResponse, ErrorReponse> {
+    let payload = input.into_inner()
+        .validate()
+        .map_err(|err| ErrorReponse::map_io_error(err).with_instance(req.path()))?;
+    let schedule = payload.schedule();
+    let TaskPayload { title, description, status, .. } = payload;
+
+    let create_task = CreateTaskRequest::new(title, description, status.unwrap_or_default(), schedule, TaskPlacement::in_project(project_id.into_inner()), actor, data.task_repository.as_ref());
+
+    let result = create_task.execute().await;
+
+    match result {
+        Ok(task) => Ok(HttpResponse::Created()
+            .insert_header((header::LOCATION, format!("/api/tasks/{}", task.id)))
+            .insert_header(task_etag(&task))
+            .json(task)),
+        Err(err) => Err(ErrorReponse::map_io_error(err).with_instance(req.path())),
+    }
+}
+
+#[utoipa::path(
+context_path = "/api",
+responses(
+(status = 200, description = "List members of the project and their roles", body = [ProjectMember]),
+(status = 403, description = "The caller is not allowed to view the project", body = ProblemDetails, content_type = "application/problem+json"),
+(status = 404, description = "Project not found", body = ProblemDetails, content_type = "application/problem+json"),
+(status = 500, description = "Unexpected server error", body = ProblemDetails, content_type = "application/problem+json"),
+(status = 503, description = "Storage is temporarily unavailable", body = ProblemDetails, content_type = "application/problem+json")
+),
+params(
+("id" = i32, Path, description = "Project ID")
+)
+)]
+#[get("/projects/{id}/members")]
+async fn get_project_members(req: HttpRequest, data: WorkspaceConfigs, actor: Actor, project_id: web::Path<i32>) -> Result<HttpResponse, ErrorReponse> {
+    let get_project_members_request = GetProjectMembersRequest::new(project_id.into_inner(), actor, data.task_repository.as_ref(), data.project_repository.as_ref());
+    let members = get_project_members_request.execute().await;
+
+    members
+        .map_err(|err| ErrorReponse::map_io_error(err).with_instance(req.path()))
+        .map(|members| HttpResponse::Ok().json(members))
+}
+
+#[utoipa::path(
+context_path = "/api",
+request_body = RolePayload,
+responses(
+(status = 200, description = "User is a member of the project with the given role", body = ProjectMember),
+(status = 403, description = "Only project or global admins can manage members", body = ProblemDetails, content_type = "application/problem+json"),
+(status = 404, description = "Project or user not found", body = ProblemDetails, content_type = "application/problem+json"),
+(status = 500, description = "Unexpected server error", body = ProblemDetails, content_type = "application/problem+json"),
+(status = 503, description = "Storage is temporarily unavailable", body = ProblemDetails, content_type = "application/problem+json")
+),
+params(
+("id" = i32, Path, description = "Project ID"),
+("user_id" = i32, Path, description = "User ID")
+)
+)]
+#[put("/projects/{id}/members/{user_id}")]
+async fn set_project_member(req: HttpRequest, data: WorkspaceConfigs, actor: Actor, path: web::Path<(i32, i32)>, input: web::Json<RolePayload>) -> Result<HttpResponse, ErrorReponse> {
+    let (project_id, user_id) = path.into_inner();
+    let set_project_member_request = SetProjectMemberRequest::new(project_id, user_id, input.role, actor, data.task_repository.as_ref(), data.project_repository.as_ref());
+
+    set_project_member_request.execute().await
+        .map_err(|err| ErrorReponse::map_io_error(err).with_instance(req.path()))
+        .map(|member| HttpResponse::Ok().json(member))
+}
+
+#[utoipa::path(
+context_path = "/api",
+responses(
+(status = 204, description = "User is no longer a member of the project"),
+(status = 403, description = "Only project or global admins can manage members", body = ProblemDetails, content_type = "application/problem+json"),
+(status = 404, description = "Project not found or the user is not its member", body = ProblemDetails, content_type = "application/problem+json"),
+(status = 500, description = "Unexpected server error", body = ProblemDetails, content_type = "application/problem+json"),
+(status = 503, description = "Storage is temporarily unavailable", body = ProblemDetails, content_type = "application/problem+json")
+),
+params(
+("id" = i32, Path, description = "Project ID"),
+("user_id" = i32, Path, description = "User ID")
+)
+)]
+#[delete("/projects/{id}/members/{user_id}")]
+async fn remove_project_member(req: HttpRequest, data: WorkspaceConfigs, actor: Actor, path: web::Path<(i32, i32)>) -> Result<HttpResponse, ErrorReponse> {
+    let (project_id, user_id) = path.into_inner();
+    let remove_project_member_request = RemoveProjectMemberRequest::new(project_id, user_id, actor, data.task_repository.as_ref(), data.project_repository.as_ref());
+
+    remove_project_member_request.execute().await
+        .map_err(|err| ErrorReponse::map_io_error(err).with_instance(req.path()))
+        .map(|_| HttpResponse::NoContent().finish())
+}
@@ -0,0 +1,42 @@
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+use crate::application::validation::project_rules::PROJECT_NAME_RULES;
+use crate::application::validation::task_rules::DESCRIPTION_RULES;
+use crate::application::validation::validator::{Validate, Validator};
+use crate::domain::error::ApiError;
+
+#[derive(Deserialize, Serialize, ToSchema)]
+pub struct ProjectPayload {
+    /// Название проекта; пробелы в начале и в конце удаляются.
+    #[schema(min_length = 1, max_length = 100, pattern = r"^[^\u0000-\u001F\u007F-\u009F]*$", example = "Backend")]
+    pub name: String,
+    /// Описание проекта; пустое описание сохраняется как отсутствующее.
+    #[schema(max_length = 10000, pattern = r"^[^\u0000-\u0008\u000B\u000C\u000E-\u001F\u007F-\u009F]*$")]
+    pub description: Option<String>,
+}
+
+impl Validate for ProjectPayload {
+    fn validate(self) -> Result<Self, ApiError> {
+        let mut validator = Validator::new();
+        let name = validator.string("name", self.name, &PROJECT_NAME_RULES);
+        let description = validator.optional_string("description", self.description, &DESCRIPTION_RULES);
+
+        validator.finish(ProjectPayload { name, description })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_should_normalize_and_reject_invalid_fields() {
+        let valid = ProjectPayload { name: " Backend ".to_string(), description: Some("  ".to_string()) }.validate().unwrap();
+        let invalid = ProjectPayload { name: "".to_string(), description: Some("\u{0}".to_string()) }.validate().err().unwrap();
+
+        assert_eq!(valid.name, "Backend");
+        assert_eq!(valid.description, None);
+        let fields = invalid.errors.iter().map(|e| e.field.as_str()).collect::<Vec<_>>();
+        assert_eq!(fields, vec!["name", "description"]);
+    }
+}
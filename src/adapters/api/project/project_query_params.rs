@@ -0,0 +1,18 @@
+use serde::Deserialize;
+use utoipa::IntoParams;
+use crate::domain::project::project::ProjectTasksPolicy;
+
+#[derive(Deserialize, IntoParams)]
+#[into_params(parameter_in = Query)]
+pub struct ProjectListParams {
+    /// Включать ли проекты из архива (по умолчанию `false`).
+    pub include_archived: Option<bool>,
+}
+
+#[derive(Deserialize, IntoParams)]
+#[into_params(parameter_in = Query)]
+pub struct ProjectDeleteParams {
+    /// Что сделать с задачами проекта: `restrict` - отказать, если они есть (по умолчанию),
+    /// `cascade` - переместить их в корзину, `detach` - оставить их без проекта.
+    pub on_tasks: Option<ProjectTasksPolicy>,
+}
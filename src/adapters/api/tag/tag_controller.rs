@@ -0,0 +1,231 @@
+use actix_web::{get, web, HttpRequest, HttpResponse, post, put, delete, http::header};
+use crate::adapters::api::tag::tag_payload::TagPayload;
+use crate::adapters::api::task::error::ErrorReponse;
+use crate::adapters::api::auth::workspace_extractor::WorkspaceConfigs;
+use crate::application::repo_requesters::abstract_request::AbstractRequest;
+use crate::application::repo_requesters::attach_tag_request::AttachTagRequest;
+use crate::application::repo_requesters::create_tag_request::CreateTagRequest;
+use crate::application::repo_requesters::delete_tag_request::DeleteTagRequest;
+use crate::application::repo_requesters::detach_tag_request::DetachTagRequest;
+use crate::application::repo_requesters::get_tag_request::GetTagRequest;
+use crate::application::repo_requesters::get_tags_request::GetTagsRequest;
+use crate::application::repo_requesters::get_task_tags_request::GetTaskTagsRequest;
+use crate::application::repo_requesters::update_tag_request::UpdateTagRequest;
+use crate::application::validation::validator::Validate;
+use crate::domain::access::actor::Actor;
+
+pub fn routes(cfg: &mut web::ServiceConfig) {
+    cfg.service(get_tags);
+    cfg.service(get_tag);
+    cfg.service(create_tag);
+    cfg.service(update_tag);
+    cfg.service(delete_tag);
+    cfg.service(get_task_tags);
+    cfg.service(attach_tag);
+    cfg.service(detach_tag);
+}
+
+#[utoipa::path(
+context_path = "/api",
+responses(
+(status = 200, description = "List all tags ordered by name", body = [Tag]),
+(status = 500, description = "Unexpected server error", body = ProblemDetails, content_type = "application/problem+json"),
+(status = 503, description = "Storage is temporarily unavailable", body = ProblemDetails, content_type = "application/problem+json")
+)
+)]
+#[get("/tags")]
+async fn get_tags(req: HttpRequest, data: WorkspaceConfigs) -> Result<HttpResponse, ErrorReponse> {
+    let get_tags_request = GetTagsRequest::new(data.tag_repository.as_ref());
+    let tags = get_tags_request.execute().await;
+
+    tags
+        .map_err(|err| ErrorReponse::map_io_error(err).with_instance(req.path()))
+        .map(|tags| HttpResponse::Ok().json(tags))
+}
+
+#[utoipa::path(
+context_path = "/api",
+responses(
+(status = 200, description = "Get tag by ID", body = Tag),
+(status = 404, description = "Tag not found", body = ProblemDetails, content_type = "application/problem+json"),
+(status = 500, description = "Unexpected server error", body = ProblemDetails, content_type = "application/problem+json"),
+(status = 503, description = "Storage is temporarily unavailable", body = ProblemDetails, content_type = "application/problem+json")
+),
+params(
+("id" = i32, Path, description = "Tag ID")
+)
+)]
+#[get("/tags/{id}")]
+async fn get_tag(req: HttpRequest, data: WorkspaceConfigs, tag_id: web::Path<i32>) -> Result<HttpResponse, ErrorReponse> {
+    let get_tag_request = GetTagRequest::new(tag_id.into_inner(), data.tag_repository.as_ref());
+    let tag = get_tag_request.execute().await;
+
+    tag
+        .map_err(|err| ErrorReponse::map_io_error(err).with_instance(req.path()))
+        .map(|tag| HttpResponse::Ok().json(tag))
+}
+
+#[utoipa::path(
+context_path = "/api",
+request_body = TagPayload,
+responses(
+(status = 201, description = "Create a new tag", body = Tag, headers(
+    ("Location" = String, description = "URL of the created tag")
+)),
+(status = 403, description = "Only editors and admins can create tags", body = ProblemDetails, content_type = "application/problem+json"),
+(status = 409, description = "A tag with this name already exists", body = ProblemDetails, content_type = "application/problem+json"),
+(status = 422, description = "Tag payload is invalid; `errors` lists every failing field", body = ProblemDetails, content_type = "application/problem+json"),
+(status = 500, description = "Unexpected server error", body = ProblemDetails, content_type = "application/problem+json"),
+(status = 503, description = "Storage is temporarily unavailable", body = ProblemDetails, content_type = "application/problem+json")
+)
+)]
+#[post("/tags")]
+async fn create_tag(req: HttpRequest, data: WorkspaceConfigs, actor: Actor, input: web::Json<TagPayload>) -> Result<HttpResponse, ErrorReponse> {
+    let TagPayload { name } = input.into_inner()
+        .validate()
+        .map_err(|err| ErrorReponse::map_io_error(err).with_instance(req.path()))?;
+
+    let create_tag_request = CreateTagRequest::new(name, actor, data.task_repository.as_ref(), data.tag_repository.as_ref());
+
+    let result = create_tag_request.execute().await;
+
+    match result {
+        Ok(tag) => Ok(HttpResponse::Created()
+            .insert_header((header::LOCATION, format!("{}/{}", req.path(), tag.id)))
+            .json(tag)),
+        Err(err) => Err(ErrorReponse::map_io_error(err).with_instance(req.path())),
+    }
+}
+
+#[utoipa::path(
+context_path = "/api",
+request_body = TagPayload,
+responses(
+(status = 200, description = "Rename a tag", body = Tag),
+(status = 403, description = "Only editors and admins can rename tags", body = ProblemDetails, content_type = "application/problem+json"),
+(status = 404, description = "Tag not found", body = ProblemDetails, content_type = "application/problem+json"),
+(status = 409, description = "Another tag already has this name", body = ProblemDetails, content_type = "application/problem+json"),
+(status = 422, description = "Tag payload is invalid; `errors` lists every failing field", body = ProblemDetails, content_type = "application/problem+json"),
+(status = 500, description = "Unexpected server error", body = ProblemDetails, content_type = "application/problem+json"),
+(status = 503, description = "Storage is temporarily unavailable", body = ProblemDetails, content_type = "application/problem+json")
+),
+params(
+("id" = i32, Path, description = "Tag ID")
+)
+)]
+#[put("/tags/{id}")]
+async fn update_tag(req: HttpRequest, data: WorkspaceConfigs, actor: Actor, tag_id: web::Path<i32>, input: web::Json<TagPayload>) -> Result<HttpResponse, ErrorReponse> {
+    let TagPayload { name } = input.into_inner()
+        .validate()
+        .map_err(|err| ErrorReponse::map_io_error(err).with_instance(req.path()))?;
+
+    let update_tag_request = UpdateTagRequest::new(tag_id.into_inner(), name, actor, data.task_repository.as_ref(), data.tag_repository.as_ref());
+
+    let result = update_tag_request.execute().await;
+
+    match result {
+        Ok(tag) => Ok(HttpResponse::Ok().json(tag)),
+        Err(err) => Err(ErrorReponse::map_io_error(err).with_instance(req.path())),
+    }
+}
+
+#[utoipa::path(
+context_path = "/api",
+responses(
+(status = 204, description = "Delete a tag and remove it from all tasks"),
+(status = 403, description = "Only admins can delete tags", body = ProblemDetails, content_type = "application/problem+json"),
+(status = 404, description = "Tag not found", body = ProblemDetails, content_type = "application/problem+json"),
+(status = 500, description = "Unexpected server error", body = ProblemDetails, content_type = "application/problem+json"),
+(status = 503, description = "Storage is temporarily unavailable", body = ProblemDetails, content_type = "application/problem+json")
+),
+params(
+("id" = i32, Path, description = "Tag ID")
+)
+)]
+#[delete("/tags/{id}")]
+async fn delete_tag(req: HttpRequest, data: WorkspaceConfigs, actor: Actor, tag_id: web::Path<i32>) -> Result<HttpResponse, ErrorReponse> {
+    let delete_tag_request = DeleteTagRequest::new(tag_id.into_inner(), actor, data.task_repository.as_ref(), data.tag_repository.as_ref());
+
+    let result = delete_tag_request.execute().await;
+
+    match result {
+        Ok(_) => Ok(HttpResponse::NoContent().finish()),
+        Err(err) => Err(ErrorReponse::map_io_error(err).with_instance(req.path())),
+    }
+}
+
+#[utoipa::path(
+context_path = "/api",
+responses(
+(status = 200, description = "Tags attached to the task, ordered by name", body = [Tag]),
+(status = 404, description = "Task not found", body = ProblemDetails, content_type = "application/problem+json"),
+(status = 500, description = "Unexpected server error", body = ProblemDetails, content_type = "application/problem+json"),
+(status = 503, description = "Storage is temporarily unavailable", body = ProblemDetails, content_type = "application/problem+json")
+),
+params(
+("id" = i32, Path, description = "Task ID")
+)
+)]
+#[get("/tasks/{id}/tags")]
+async fn get_task_tags(req: HttpRequest, data: WorkspaceConfigs, task_id: web::Path<i32>) -> Result<HttpResponse, ErrorReponse> {
+    let get_task_tags_request = GetTaskTagsRequest::new(task_id.into_inner(), data.tag_repository.as_ref());
+    let tags = get_task_tags_request.execute().await;
+
+    tags
+        .map_err(|err| ErrorReponse::map_io_error(err).with_instance(req.path()))
+        .map(|tags| HttpResponse::Ok().json(tags))
+}
+
+#[utoipa::path(
+context_path = "/api",
+responses(
+(status = 204, description = "Tag is attached to the task; attaching it again changes nothing"),
+(status = 403, description = "Only editors and admins of the task's project can change its tags", body = ProblemDetails, content_type = "application/problem+json"),
+(status = 404, description = "Task or tag not found", body = ProblemDetails, content_type = "application/problem+json"),
+(status = 500, description = "Unexpected server error", body = ProblemDetails, content_type = "application/problem+json"),
+(status = 503, description = "Storage is temporarily unavailable", body = ProblemDetails, content_type = "application/problem+json")
+),
+params(
+("id" = i32, Path, description = "Task ID"),
+("tag_id" = i32, Path, description = "Tag ID")
+)
+)]
+#[put("/tasks/{id}/tags/{tag_id}")]
+async fn attach_tag(req: HttpRequest, data: WorkspaceConfigs, actor: Actor, path: web::Path<(i32, i32)>) -> Result<HttpResponse, ErrorReponse> {
+    let (task_id, tag_id) = path.into_inner();
+    let attach_tag_request = AttachTagRequest::new(task_id, tag_id, actor, data.task_repository.as_ref(), data.tag_repository.as_ref());
+
+    let result = attach_tag_request.execute().await;
+
+    match result {
+        Ok(_) => Ok(HttpResponse::NoContent().finish()),
+        Err(err) => Err(ErrorReponse::map_io_error(err).with_instance(req.path())),
+    }
+}
+
+#[utoipa::path(
+context_path = "/api",
+responses(
+(status = 204, description = "Tag is removed from the task"),
+(status = 403, description = "Only editors and admins of the task's project can change its tags", body = ProblemDetails, content_type = "application/problem+json"),
+(status = 404, description = "Task or tag not found, or the task is not tagged with it", body = ProblemDetails, content_type = "application/problem+json"),
+(status = 500, description = "Unexpected server error", body = ProblemDetails, content_type = "application/problem+json"),
+(status = 503, description = "Storage is temporarily unavailable", body = ProblemDetails, content_type = "application/problem+json")
+),
+params(
+("id" = i32, Path, description = "Task ID"),
+("tag_id" = i32, Path, description = "Tag ID")
+)
+)]
+#[delete("/tasks/{id}/tags/{tag_id}")]
+async fn detach_tag(req: HttpRequest, data: WorkspaceConfigs, actor: Actor, path: web::Path<(i32, i32)>) -> Result<HttpResponse, ErrorReponse> {
+    let (task_id, tag_id) = path.into_inner();
+    let detach_tag_request = DetachTagRequest::new(task_id, tag_id, actor, data.task_repository.as_ref(), data.tag_repository.as_ref());
+
+    let result = detach_tag_request.execute().await;
+
+    match result {
+        Ok(_) => Ok(HttpResponse::NoContent().finish()),
+        Err(err) => Err(ErrorReponse::map_io_error(err).with_instance(req.path())),
+    }
+}
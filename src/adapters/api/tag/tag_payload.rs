@@ -0,0 +1,37 @@
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+use crate::application::validation::tag_rules::TAG_NAME_RULES;
+use crate::application::validation::validator::{Validate, Validator};
+use crate::domain::error::ApiError;
+
+#[derive(Deserialize, Serialize, ToSchema)]
+pub struct TagPayload {
+    /// Имя метки; пробелы в начале и в конце удаляются, запятая недопустима.
+    #[schema(min_length = 1, max_length = 50, pattern = r"^[^,\u0000-\u001F\u007F-\u009F]*$", example = "backend")]
+    pub name: String,
+}
+
+impl Validate for TagPayload {
+    fn validate(self) -> Result<Self, ApiError> {
+        let mut validator = Validator::new();
+        let name = validator.string("name", self.name, &TAG_NAME_RULES);
+
+        validator.finish(TagPayload { name })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_should_trim_and_reject_invalid_names() {
+        let valid = TagPayload { name: "  backend ".to_string() }.validate();
+        let with_comma = TagPayload { name: "a,b".to_string() }.validate();
+        let blank = TagPayload { name: "  ".to_string() }.validate();
+
+        assert_eq!(valid.unwrap().name, "backend");
+        assert_eq!(with_comma.err().unwrap().errors[0].code, "forbidden_character");
+        assert_eq!(blank.err().unwrap().errors[0].code, "required");
+    }
+}
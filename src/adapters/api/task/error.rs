@@ -1,15 +1,38 @@
-use crate::domain::error::ApiError;
-use actix_web::{error::ResponseError, http::StatusCode, HttpResponse};
+use crate::domain::error::{ApiError, FieldError};
+use actix_web::{error::{JsonPayloadError, PathError, QueryPayloadError, ResponseError}, http::{header, StatusCode}, HttpRequest, HttpResponse};
 use derive_more::Display;
 use serde::Deserialize;
 use serde::Serialize;
 use thiserror::Error;
+use utoipa::ToSchema;
 
-#[derive(Serialize, Deserialize, Debug)]
-pub struct ErrorPresenter {
-    pub code: u16,
-    pub error: String,
-    pub message: String,
+pub const PROBLEM_JSON: &str = "application/problem+json";
+/// Форматы документов, принимаемые `PATCH`; возвращаются в `Accept-Patch` при ответе 415.
+const ACCEPT_PATCH: &str = "application/merge-patch+json, application/json-patch+json";
+
+/// Описание ошибки в формате RFC 7807 (`application/problem+json`).
+#[derive(Serialize, Deserialize, Debug, ToSchema)]
+pub struct ProblemDetails {
+    /// URI-ссылка, определяющая тип проблемы.
+    #[serde(rename = "type")]
+    #[schema(example = "/problems/not-found")]
+    pub problem_type: String,
+    /// Краткое описание типа проблемы, одинаковое для всех её экземпляров.
+    #[schema(example = "Not Found")]
+    pub title: String,
+    /// HTTP-код ответа.
+    #[schema(example = 404)]
+    pub status: u16,
+    /// Описание конкретного случая.
+    #[schema(example = "Task 42 not found")]
+    pub detail: String,
+    /// Путь запроса, при обработке которого возникла ошибка.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[schema(example = "/api/tasks/42")]
+    pub instance: Option<String>,
+    /// Ошибки отдельных полей; присутствует только для ошибок валидации.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub errors: Vec<FieldError>,
 }
 
 #[derive(Error, Debug, Display)]
@@ -17,6 +40,8 @@ pub struct ErrorPresenter {
 pub struct ErrorReponse {
     status_code: StatusCode,
     error: String,
+    instance: Option<String>,
+    errors: Vec<FieldError>,
 }
 
 impl ResponseError for ErrorReponse {
@@ -26,34 +51,136 @@ impl ResponseError for ErrorReponse {
 
     fn error_response(&self) -> HttpResponse {
         let status_code = self.status_code();
-        let error_response = ErrorPresenter {
-            code: status_code.as_u16(),
-            message: status_code.to_string(),
-            error: self.error.clone(),
-        };
-        HttpResponse::build(status_code).json(error_response)
+        let problem = self.problem();
+        let mut response = HttpResponse::build(status_code);
+        if status_code == StatusCode::SERVICE_UNAVAILABLE {
+            response.insert_header((header::RETRY_AFTER, "1"));
+        }
+        if status_code == StatusCode::UNAUTHORIZED {
+            response.insert_header((header::WWW_AUTHENTICATE, "Bearer"));
+        }
+        if status_code == StatusCode::UNSUPPORTED_MEDIA_TYPE {
+            response.insert_header(("Accept-Patch", ACCEPT_PATCH));
+        }
+        response
+            .content_type(PROBLEM_JSON)
+            .json(problem)
     }
 }
 
 impl ErrorReponse {
     pub fn map_io_error(e: ApiError) -> ErrorReponse {
-        match e.get_error_code() {
-            400 => ErrorReponse {
-                status_code: StatusCode::BAD_REQUEST,
-                error: e.get_error_message(),
-            },
-            401 => ErrorReponse {
-                status_code: StatusCode::UNAUTHORIZED,
-                error: e.get_error_message(),
-            },
-            403 => ErrorReponse {
-                status_code: StatusCode::FORBIDDEN,
-                error: e.get_error_message(),
-            },
-            _ => ErrorReponse {
-                status_code: StatusCode::INTERNAL_SERVER_ERROR,
-                error: String::from("Error: an unknown error occured"),
-            },
+        let status_code = match e.get_error_code() {
+            400 => StatusCode::BAD_REQUEST,
+            401 => StatusCode::UNAUTHORIZED,
+            403 => StatusCode::FORBIDDEN,
+            404 => StatusCode::NOT_FOUND,
+            409 => StatusCode::CONFLICT,
+            412 => StatusCode::PRECONDITION_FAILED,
+            422 => StatusCode::UNPROCESSABLE_ENTITY,
+            503 => StatusCode::SERVICE_UNAVAILABLE,
+            _ => return ErrorReponse::new(StatusCode::INTERNAL_SERVER_ERROR, "Error: an unknown error occured"),
+        };
+
+        ErrorReponse {
+            status_code,
+            error: e.get_error_message(),
+            instance: None,
+            errors: e.errors,
         }
     }
+
+    pub fn new(status_code: StatusCode, error: &str) -> ErrorReponse {
+        ErrorReponse { status_code, error: error.to_string(), instance: None, errors: vec![] }
+    }
+
+    /// Описание ошибки в формате RFC 7807, отправляемое клиенту.
+    pub fn problem(&self) -> ProblemDetails {
+        ProblemDetails {
+            problem_type: format!("/problems/{}", problem_type(self.status_code)),
+            title: self.status_code.canonical_reason().unwrap_or("Error").to_string(),
+            status: self.status_code.as_u16(),
+            detail: self.error.clone(),
+            instance: self.instance.clone(),
+            errors: self.errors.clone(),
+        }
+    }
+
+    /// Указывает путь запроса, к которому относится ошибка (поле `instance`).
+    pub fn with_instance(mut self, path: &str) -> ErrorReponse {
+        self.instance = Some(path.to_string());
+        self
+    }
+}
+
+/// Последний сегмент URI типа проблемы для HTTP-кода.
+fn problem_type(status_code: StatusCode) -> &'static str {
+    match status_code {
+        StatusCode::BAD_REQUEST => "bad-request",
+        StatusCode::UNAUTHORIZED => "unauthorized",
+        StatusCode::FORBIDDEN => "forbidden",
+        StatusCode::NOT_FOUND => "not-found",
+        StatusCode::CONFLICT => "conflict",
+        StatusCode::PRECONDITION_FAILED => "precondition-failed",
+        StatusCode::UNSUPPORTED_MEDIA_TYPE => "unsupported-media-type",
+        StatusCode::UNPROCESSABLE_ENTITY => "validation-failed",
+        StatusCode::SERVICE_UNAVAILABLE => "service-unavailable",
+        _ => "internal-error",
+    }
+}
+
+/// Обработчик ошибок разбора JSON-тела, возвращающий их в формате `application/problem+json`.
+pub fn json_error_handler(err: JsonPayloadError, req: &HttpRequest) -> actix_web::Error {
+    ErrorReponse::new(err.status_code(), &err.to_string()).with_instance(req.path()).into()
+}
+
+/// Обработчик ошибок разбора строки запроса, возвращающий их в формате `application/problem+json`.
+pub fn query_error_handler(err: QueryPayloadError, req: &HttpRequest) -> actix_web::Error {
+    ErrorReponse::new(StatusCode::BAD_REQUEST, &err.to_string()).with_instance(req.path()).into()
+}
+
+/// Обработчик ошибок разбора параметров пути, возвращающий их в формате `application/problem+json`.
+pub fn path_error_handler(err: PathError, req: &HttpRequest) -> actix_web::Error {
+    ErrorReponse::new(StatusCode::NOT_FOUND, &err.to_string()).with_instance(req.path()).into()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use actix_web::body::to_bytes;
+
+    #[actix_rt::test]
+    async fn test_should_render_problem_document_with_field_errors() {
+        let error = ApiError {
+            code: 422,
+            message: "Validation failed".to_string(),
+            error: None,
+            errors: vec![FieldError::new("title", "required", "title must not be empty")],
+        };
+
+        let response = ErrorReponse::map_io_error(error).with_instance("/api/tasks").error_response();
+
+        assert_eq!(response.status(), StatusCode::UNPROCESSABLE_ENTITY);
+        assert_eq!(response.headers().get(header::CONTENT_TYPE).unwrap(), PROBLEM_JSON);
+        let body: serde_json::Value = serde_json::from_slice(&to_bytes(response.into_body()).await.unwrap()).unwrap();
+        assert_eq!(body["type"], "/problems/validation-failed");
+        assert_eq!(body["title"], "Unprocessable Entity");
+        assert_eq!(body["status"], 422);
+        assert_eq!(body["detail"], "Validation failed");
+        assert_eq!(body["instance"], "/api/tasks");
+        assert_eq!(body["errors"][0]["field"], "title");
+    }
+
+    #[actix_rt::test]
+    async fn test_should_hide_details_of_internal_errors() {
+        let error = ApiError { code: 500, message: "disk I/O error".to_string(), error: None, errors: vec![] };
+
+        let response = ErrorReponse::map_io_error(error).error_response();
+
+        assert_eq!(response.status(), StatusCode::INTERNAL_SERVER_ERROR);
+        let body: serde_json::Value = serde_json::from_slice(&to_bytes(response.into_body()).await.unwrap()).unwrap();
+        assert_eq!(body["detail"], "Error: an unknown error occured");
+        assert!(body.get("instance").is_none());
+        assert!(body.get("errors").is_none());
+    }
 }
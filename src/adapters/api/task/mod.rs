@@ -1,3 +1,5 @@
 pub mod task_controller;
 pub mod error;
-pub mod task_payload;
\ No newline at end of file
+pub mod task_payload;
+pub mod task_query_params;
+pub mod task_etag;pub mod task_bulk_response;
@@ -0,0 +1,66 @@
+use actix_web::http::StatusCode;
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+use crate::adapters::api::task::error::{ErrorReponse, ProblemDetails};
+use crate::application::repo_requesters::bulk_tasks_request::BulkItemResult;
+use crate::domain::task::task::Task;
+use crate::domain::task::task_bulk::BulkOutcome;
+
+/// Ответ `POST /api/tasks/bulk`.
+#[derive(Serialize, Deserialize, Debug, ToSchema)]
+pub struct BulkResponse {
+    /// Результаты операций в порядке их следования в запросе.
+    pub results: Vec<BulkItemResponse>,
+}
+
+/// Результат одной операции пакета.
+#[derive(Serialize, Deserialize, Debug, ToSchema)]
+pub struct BulkItemResponse {
+    /// Порядковый номер операции в запросе, начиная с 0.
+    pub index: usize,
+    /// HTTP-код, который вернул бы соответствующий одиночный запрос.
+    #[schema(example = 201)]
+    pub status: u16,
+    /// Идентификатор задачи, над которой выполнена операция.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub id: Option<i32>,
+    /// Задача после создания или обновления.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub task: Option<Task>,
+    /// Описание ошибки неудачной операции.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub error: Option<ProblemDetails>,
+}
+
+impl From<BulkItemResult> for BulkItemResponse {
+    fn from(item: BulkItemResult) -> Self {
+        let index = item.index;
+        match item.result {
+            Ok(BulkOutcome::Created(task)) => BulkItemResponse::task(index, StatusCode::CREATED, task),
+            Ok(BulkOutcome::Updated(task)) => BulkItemResponse::task(index, StatusCode::OK, task),
+            Ok(BulkOutcome::Deleted(task_id)) => BulkItemResponse {
+                index,
+                status: StatusCode::NO_CONTENT.as_u16(),
+                id: Some(task_id),
+                task: None,
+                error: None,
+            },
+            Err(error) => {
+                let problem = ErrorReponse::map_io_error(error).problem();
+                BulkItemResponse { index, status: problem.status, id: None, task: None, error: Some(problem) }
+            },
+        }
+    }
+}
+
+impl BulkItemResponse {
+    fn task(index: usize, status: StatusCode, task: Task) -> Self {
+        BulkItemResponse { index, status: status.as_u16(), id: Some(task.id), task: Some(task), error: None }
+    }
+}
+
+impl From<Vec<BulkItemResult>> for BulkResponse {
+    fn from(results: Vec<BulkItemResult>) -> Self {
+        BulkResponse { results: results.into_iter().map(BulkItemResponse::from).collect() }
+    }
+}
@@ -1,124 +1,544 @@
-use actix_web::{get, web, HttpResponse, post, put, delete};
+use chrono::Utc;
+use actix_web::{get, web, HttpRequest, HttpResponse, post, put, patch, delete, http::{header, StatusCode}};
 use crate::adapters::api::task::error::ErrorReponse;
-use crate::adapters::api::task::task_payload::TaskPayload;
+use crate::adapters::api::task::task_bulk_response::BulkResponse;
+use crate::adapters::api::task::task_etag::{if_match_version, is_not_modified, task_etag};
+use crate::adapters::api::task::task_payload::{BulkPayload, MovePayload, TaskPayload, TransitionPayload, JSON_PATCH_JSON, MERGE_PATCH_JSON};
+use crate::adapters::api::task::task_query_params::{TaskDueParams, TaskDueSoonParams, TaskHistoryParams, TaskListParams, TaskSearchParams, TrashParams};
+use crate::adapters::api::auth::workspace_extractor::WorkspaceConfigs;
 use crate::adapters::shared::app_confs::AppConfigs;
 use crate::application::repo_requesters::abstract_request::AbstractRequest;
+use crate::application::repo_requesters::bulk_tasks_request::BulkTasksRequest;
 use crate::application::repo_requesters::create_task_request::CreateTaskRequest;
 use crate::application::repo_requesters::delete_task_request::DeleteTaskRequest;
+use crate::application::repo_requesters::get_due_tasks_request::GetDueTasksRequest;
+use crate::application::repo_requesters::get_task_history_request::GetTaskHistoryRequest;
 use crate::application::repo_requesters::get_task_request::GetTaskRequest;
-use crate::application::repo_requesters::get_tasks_request::{GetAllTasksRequest};
+use crate::application::repo_requesters::get_task_tree_request::GetTaskTreeRequest;
+use crate::application::repo_requesters::get_tasks_request::GetTasksRequest;
+use crate::application::repo_requesters::get_trash_request::GetTrashRequest;
+use crate::application::repo_requesters::move_task_request::MoveTaskRequest;
+use crate::application::repo_requesters::patch_task_request::{PatchTaskRequest, TaskPatchDocument};
+use crate::application::repo_requesters::restore_task_request::RestoreTaskRequest;
+use crate::application::repo_requesters::search_tasks_request::SearchTasksRequest;
+use crate::application::repo_requesters::transition_task_request::TransitionTaskRequest;
 use crate::application::repo_requesters::update_task_request::UpdateTaskRequest;
+use crate::application::validation::validator::Validate;
+use crate::domain::access::actor::Actor;
 use crate::domain::error::ApiError;
-use crate::domain::task::task::Task;
+use crate::domain::task::task_due::{TaskDueView, DEFAULT_DUE_WITHIN_DAYS};
+use crate::domain::task::task_placement::TaskPlacement;
+use crate::domain::task::task_query::TaskPage;
 
 pub fn routes(cfg: &mut web::ServiceConfig) {
     cfg.service(get_tasks);
+    cfg.service(search_tasks);
+    cfg.service(get_overdue_tasks);
+    cfg.service(get_tasks_due_soon);
+    cfg.service(get_urgent_tasks);
     cfg.service(get_task);
     cfg.service(create_task);
+    cfg.service(bulk_tasks);
     cfg.service(update_task);
+    cfg.service(patch_task);
     cfg.service(delete_task);
+    cfg.service(restore_task);
+    cfg.service(get_trash);
+    cfg.service(transition_task);
+    cfg.service(get_task_tree);
+    cfg.service(get_task_history);
+    cfg.service(create_subtask);
+    cfg.service(move_task);
 
 }
 
 #[utoipa::path(
 context_path = "/api",
 responses(
-(status = 200, description = "List all tasks", body = [Task])
-)
+(status = 200, description = "List tasks page by page", body = TaskPage),
+(status = 400, description = "Invalid paging, sorting or filter parameters", body = ProblemDetails, content_type = "application/problem+json"),
+(status = 500, description = "Unexpected server error", body = ProblemDetails, content_type = "application/problem+json"),
+(status = 503, description = "Storage is temporarily unavailable", body = ProblemDetails, content_type = "application/problem+json")
+),
+params(TaskListParams)
 )]
 #[get("/tasks")]
-async fn get_tasks(data: web::Data<AppConfigs>) -> Result<HttpResponse, ErrorReponse> {
-    let get_all_tasks_request = GetAllTasksRequest::new(&data.task_repository);
-    let tasks: Result<Vec<Task>, ApiError> = get_all_tasks_request.execute().await;
+async fn get_tasks(req: HttpRequest, data: WorkspaceConfigs, actor: Actor, params: web::Query<TaskListParams>) -> Result<HttpResponse, ErrorReponse> {
+    let params = params.into_inner();
+    let get_tasks_request = GetTasksRequest::new(params.filter(), params.sort_by, params.order, params.limit, params.offset, params.cursor, actor, data.task_repository.as_ref());
+    let page: Result<TaskPage, ApiError> = get_tasks_request.execute().await;
+
+    page
+        .map_err(|err| ErrorReponse::map_io_error(err).with_instance(req.path()))
+        .map(|page| HttpResponse::Ok().json(page))
+
+}
+
+#[utoipa::path(
+context_path = "/api",
+responses(
+(status = 200, description = "Search tasks by title and description, best matches first", body = [TaskSearchHit]),
+(status = 400, description = "Empty search query or invalid limit", body = ProblemDetails, content_type = "application/problem+json"),
+(status = 500, description = "Unexpected server error", body = ProblemDetails, content_type = "application/problem+json"),
+(status = 503, description = "Storage is temporarily unavailable", body = ProblemDetails, content_type = "application/problem+json")
+),
+params(TaskSearchParams)
+)]
+#[get("/tasks/search")]
+async fn search_tasks(req: HttpRequest, data: WorkspaceConfigs, actor: Actor, params: web::Query<TaskSearchParams>) -> Result<HttpResponse, ErrorReponse> {
+    let TaskSearchParams { q, limit } = params.into_inner();
+    let search_tasks_request = SearchTasksRequest::new(q, limit, actor, data.task_repository.as_ref());
+    let hits = search_tasks_request.execute().await;
+
+    hits
+        .map_err(|err| ErrorReponse::map_io_error(err).with_instance(req.path()))
+        .map(|hits| HttpResponse::Ok().json(hits))
+}
+
+#[utoipa::path(
+context_path = "/api",
+responses(
+(status = 200, description = "Open tasks past their due date, most overdue first", body = [Task]),
+(status = 400, description = "Invalid limit", body = ProblemDetails, content_type = "application/problem+json"),
+(status = 500, description = "Unexpected server error", body = ProblemDetails, content_type = "application/problem+json"),
+(status = 503, description = "Storage is temporarily unavailable", body = ProblemDetails, content_type = "application/problem+json")
+),
+params(TaskDueParams)
+)]
+#[get("/tasks/overdue")]
+async fn get_overdue_tasks(req: HttpRequest, data: WorkspaceConfigs, actor: Actor, params: web::Query<TaskDueParams>) -> Result<HttpResponse, ErrorReponse> {
+    due_tasks(&req, &data, actor, TaskDueView::Overdue, params.limit).await
+}
+
+#[utoipa::path(
+context_path = "/api",
+responses(
+(status = 200, description = "Open tasks due within the next `days` days, nearest first", body = [Task]),
+(status = 400, description = "Invalid days or limit", body = ProblemDetails, content_type = "application/problem+json"),
+(status = 500, description = "Unexpected server error", body = ProblemDetails, content_type = "application/problem+json"),
+(status = 503, description = "Storage is temporarily unavailable", body = ProblemDetails, content_type = "application/problem+json")
+),
+params(TaskDueSoonParams)
+)]
+#[get("/tasks/due-soon")]
+async fn get_tasks_due_soon(req: HttpRequest, data: WorkspaceConfigs, actor: Actor, params: web::Query<TaskDueSoonParams>) -> Result<HttpResponse, ErrorReponse> {
+    let view = TaskDueView::DueWithin { days: params.days.unwrap_or(DEFAULT_DUE_WITHIN_DAYS) };
+    due_tasks(&req, &data, actor, view, params.limit).await
+}
+
+#[utoipa::path(
+context_path = "/api",
+responses(
+(status = 200, description = "Open tasks by urgency: overdue first, then by priority and due date", body = [Task]),
+(status = 400, description = "Invalid limit", body = ProblemDetails, content_type = "application/problem+json"),
+(status = 500, description = "Unexpected server error", body = ProblemDetails, content_type = "application/problem+json"),
+(status = 503, description = "Storage is temporarily unavailable", body = ProblemDetails, content_type = "application/problem+json")
+),
+params(TaskDueParams)
+)]
+#[get("/tasks/urgent")]
+async fn get_urgent_tasks(req: HttpRequest, data: WorkspaceConfigs, actor: Actor, params: web::Query<TaskDueParams>) -> Result<HttpResponse, ErrorReponse> {
+    due_tasks(&req, &data, actor, TaskDueView::ByUrgency, params.limit).await
+}
+
+/// Выполняет выборку задач по срокам относительно текущего момента.
+async fn due_tasks(req: &HttpRequest, data: &AppConfigs, actor: Actor, view: TaskDueView, limit: Option<i64>) -> Result<HttpResponse, ErrorReponse> {
+    let get_due_tasks_request = GetDueTasksRequest::new(view, limit, Utc::now(), actor, data.task_repository.as_ref());
+    let tasks = get_due_tasks_request.execute().await;
 
     tasks
-        .map_err(ErrorReponse::map_io_error)
+        .map_err(|err| ErrorReponse::map_io_error(err).with_instance(req.path()))
         .map(|tasks| HttpResponse::Ok().json(tasks))
-
 }
 
 #[utoipa::path(
 context_path = "/api",
 responses(
-(status = 200, description = "Get task by ID", body = Task)
+(status = 200, description = "Get task by ID", body = Task, headers(("ETag" = String, description = "Current version of the task"))),
+(status = 304, description = "Task has not changed since the version in `If-None-Match`", headers(("ETag" = String, description = "Current version of the task"))),
+(status = 404, description = "Task not found", body = ProblemDetails, content_type = "application/problem+json"),
+(status = 500, description = "Unexpected server error", body = ProblemDetails, content_type = "application/problem+json"),
+(status = 503, description = "Storage is temporarily unavailable", body = ProblemDetails, content_type = "application/problem+json")
 ),
 params(
-("id" = i32, Path, description = "Task ID")
+("id" = i32, Path, description = "Task ID"),
+("If-None-Match" = Option<String>, Header, description = "ETags already known to the client; 304 if one of them is current")
 )
 )]
 #[get("/tasks/{id}")]
-async fn get_task(data: web::Data<AppConfigs>, task_id: web::Path<i32>) -> Result<HttpResponse, ErrorReponse> {
+async fn get_task(req: HttpRequest, data: WorkspaceConfigs, actor: Actor, task_id: web::Path<i32>) -> Result<HttpResponse, ErrorReponse> {
     let task_id = task_id.into_inner();
-    let get_task_by_id = GetTaskRequest::new(&task_id, &data.task_repository);
+    let get_task_by_id = GetTaskRequest::new(&task_id, actor, data.task_repository.as_ref());
     let task = get_task_by_id.execute().await;
 
     task
-        .map_err(ErrorReponse::map_io_error)
-        .map(|task| HttpResponse::Ok().json(task))
+        .map_err(|err| ErrorReponse::map_io_error(err).with_instance(req.path()))
+        .map(|task| match is_not_modified(&req, &task) {
+            true => HttpResponse::NotModified().insert_header(task_etag(&task)).finish(),
+            false => HttpResponse::Ok().insert_header(task_etag(&task)).json(task),
+        })
 }
 
 #[utoipa::path(
 context_path = "/api",
 request_body = TaskPayload,
 responses(
-(status = 200, description = "Create a new task", body = Task)
+(status = 201, description = "Create a new task", body = Task, headers(
+    ("Location" = String, description = "URL of the created task"),
+    ("ETag" = String, description = "Current version of the task")
+)),
+(status = 422, description = "Task payload is invalid; `errors` lists every failing field", body = ProblemDetails, content_type = "application/problem+json"),
+(status = 500, description = "Unexpected server error", body = ProblemDetails, content_type = "application/problem+json"),
+(status = 503, description = "Storage is temporarily unavailable", body = ProblemDetails, content_type = "application/problem+json")
 )
 )]
 #[post("/tasks")]
-async fn create_task(data: web::Data<AppConfigs>, input: web::Json<TaskPayload>) -> Result<HttpResponse, ErrorReponse> {
-    let TaskPayload { title, description } = input.into_inner();
+async fn create_task(req: HttpRequest, data: WorkspaceConfigs, actor: Actor, input: web::Json<TaskPayload>) -> Result<HttpResponse, ErrorReponse> {
+    let payload = input.into_inner()
+        .validate()
+        .map_err(|err| ErrorReponse::map_io_error(err).with_instance(req.path()))?;
+    let schedule = payload.schedule();
+    let TaskPayload { title, description, status, .. } = payload;
 
-    let create_task = CreateTaskRequest::new(title, description, &data.task_repository);
+    let create_task = CreateTaskRequest::new(title, description, status.unwrap_or_default(), schedule, TaskPlacement::default(), actor, data.task_repository.as_ref());
 
     let result = create_task.execute().await;
 
     match result {
-        Ok(task) => Ok(HttpResponse::Ok().json(task)),
-        Err(err) => Err(ErrorReponse::map_io_error(err)),
+        Ok(task) => Ok(HttpResponse::Created()
+            .insert_header((header::LOCATION, format!("{}/{}", req.path(), task.id)))
+            .insert_header(task_etag(&task))
+            .json(task)),
+        Err(err) => Err(ErrorReponse::map_io_error(err).with_instance(req.path())),
     }
 }
 
+#[utoipa::path(
+context_path = "/api",
+request_body = BulkPayload,
+responses(
+(status = 200, description = "Operations executed; `results` holds the outcome of every operation in request order", body = BulkResponse),
+(status = 404, description = "`all_or_nothing`: an operation refers to a missing task, nothing was saved", body = ProblemDetails, content_type = "application/problem+json"),
+(status = 409, description = "`all_or_nothing`: an operation requests a forbidden status transition, nothing was saved", body = ProblemDetails, content_type = "application/problem+json"),
+(status = 412, description = "`all_or_nothing`: an operation expects another version of the task, nothing was saved", body = ProblemDetails, content_type = "application/problem+json"),
+(status = 422, description = "The list of operations is empty or too long, or, in `all_or_nothing` mode, some operations are invalid", body = ProblemDetails, content_type = "application/problem+json"),
+(status = 500, description = "Unexpected server error", body = ProblemDetails, content_type = "application/problem+json"),
+(status = 503, description = "Storage is temporarily unavailable", body = ProblemDetails, content_type = "application/problem+json")
+)
+)]
+#[post("/tasks/bulk")]
+async fn bulk_tasks(req: HttpRequest, data: WorkspaceConfigs, actor: Actor, input: web::Json<BulkPayload>) -> Result<HttpResponse, ErrorReponse> {
+    let BulkPayload { mode, operations } = input.into_inner();
+    let operations = operations.into_iter().map(Into::into).collect();
+    let bulk_tasks = BulkTasksRequest::new(operations, mode, actor, data.task_repository.as_ref());
+
+    bulk_tasks.execute().await
+        .map_err(|err| ErrorReponse::map_io_error(err).with_instance(req.path()))
+        .map(|results| HttpResponse::Ok().json(BulkResponse::from(results)))
+}
+
 #[utoipa::path(
 context_path = "/api",
 request_body = TaskPayload,
 responses(
-(status = 200, description = "Update a task", body = Task)
+(status = 200, description = "Update a task", body = Task, headers(("ETag" = String, description = "Current version of the task"))),
+(status = 404, description = "Task not found", body = ProblemDetails, content_type = "application/problem+json"),
+(status = 409, description = "Status transition is not allowed", body = ProblemDetails, content_type = "application/problem+json"),
+(status = 412, description = "`If-Match` does not match the current version of the task", body = ProblemDetails, content_type = "application/problem+json"),
+(status = 422, description = "Task payload is invalid; `errors` lists every failing field", body = ProblemDetails, content_type = "application/problem+json"),
+(status = 500, description = "Unexpected server error", body = ProblemDetails, content_type = "application/problem+json"),
+(status = 503, description = "Storage is temporarily unavailable", body = ProblemDetails, content_type = "application/problem+json")
 ),
 params(
-("id" = i32, Path, description = "Task ID")
+("id" = i32, Path, description = "Task ID"),
+("If-Match" = Option<String>, Header, description = "ETag of the version the client expects; 412 if the task has changed")
 )
 )]
 #[put("/tasks/{id}")]
-async fn update_task(data: web::Data<AppConfigs>, task_id: web::Path<i32>, input: web::Json<TaskPayload>) -> Result<HttpResponse, ErrorReponse> {
-    let TaskPayload { title, description } = input.into_inner();
+async fn update_task(req: HttpRequest, data: WorkspaceConfigs, actor: Actor, task_id: web::Path<i32>, input: web::Json<TaskPayload>) -> Result<HttpResponse, ErrorReponse> {
+    let if_match = if_match_version(&req).map_err(|err| err.with_instance(req.path()))?;
+    let payload = input.into_inner()
+        .validate()
+        .map_err(|err| ErrorReponse::map_io_error(err).with_instance(req.path()))?;
+    let schedule = payload.schedule();
+    let TaskPayload { title, description, status, .. } = payload;
 
-    let update_task_request = UpdateTaskRequest::new(task_id.into_inner(), title, description, &data.task_repository);
+    let update_task_request = UpdateTaskRequest::new(task_id.into_inner(), title, description, status, schedule, if_match, actor, data.task_repository.as_ref());
 
     let result = update_task_request.execute().await;
 
     match result {
-        Ok(task) => Ok(HttpResponse::Ok().json(task)),
-        Err(err) => Err(ErrorReponse::map_io_error(err)),
+        Ok(task) => Ok(HttpResponse::Ok().insert_header(task_etag(&task)).json(task)),
+        Err(err) => Err(ErrorReponse::map_io_error(err).with_instance(req.path())),
     }
 }
 #[utoipa::path(
 context_path = "/api",
+request_body(content = TaskMergePatch, content_type = "application/merge-patch+json", description = "JSON Merge Patch (RFC 7396) or JSON Patch (RFC 6902) document"),
 responses(
-(status = 200, description = "Delete a task")
+(status = 200, description = "Partially update a task; only the touched fields are written", body = Task, headers(("ETag" = String, description = "Current version of the task"))),
+(status = 400, description = "Malformed patch document", body = ProblemDetails, content_type = "application/problem+json"),
+(status = 404, description = "Task not found", body = ProblemDetails, content_type = "application/problem+json"),
+(status = 409, description = "A `test` operation failed or the status transition is not allowed", body = ProblemDetails, content_type = "application/problem+json"),
+(status = 412, description = "`If-Match` does not match the current version of the task", body = ProblemDetails, content_type = "application/problem+json"),
+(status = 415, description = "Unsupported patch format; `Accept-Patch` lists the supported ones", body = ProblemDetails, content_type = "application/problem+json"),
+(status = 422, description = "Patched task is invalid; `errors` lists every failing field", body = ProblemDetails, content_type = "application/problem+json"),
+(status = 500, description = "Unexpected server error", body = ProblemDetails, content_type = "application/problem+json"),
+(status = 503, description = "Storage is temporarily unavailable", body = ProblemDetails, content_type = "application/problem+json")
 ),
 params(
-("id" = i32, Path, description = "Task ID")
+("id" = i32, Path, description = "Task ID"),
+("If-Match" = Option<String>, Header, description = "ETag of the version the client expects; 412 if the task has changed")
+)
+)]
+#[patch("/tasks/{id}")]
+async fn patch_task(req: HttpRequest, data: WorkspaceConfigs, actor: Actor, task_id: web::Path<i32>, body: web::Bytes) -> Result<HttpResponse, ErrorReponse> {
+    let if_match = if_match_version(&req).map_err(|err| err.with_instance(req.path()))?;
+    let document = patch_document(&req, &body).map_err(|err| err.with_instance(req.path()))?;
+
+    let patch_task_request = PatchTaskRequest::new(task_id.into_inner(), document, if_match, actor, data.task_repository.as_ref());
+
+    let result = patch_task_request.execute().await;
+
+    match result {
+        Ok(task) => Ok(HttpResponse::Ok().insert_header(task_etag(&task)).json(task)),
+        Err(err) => Err(ErrorReponse::map_io_error(err).with_instance(req.path())),
+    }
+}
+
+/// Разбирает тело `PATCH`-запроса в соответствии с его `Content-Type`.
+fn patch_document(req: &HttpRequest, body: &[u8]) -> Result<TaskPatchDocument, ErrorReponse> {
+    let content_type = req.headers().get(header::CONTENT_TYPE)
+        .and_then(|value| value.to_str().ok())
+        .and_then(|value| value.split(';').next())
+        .map(|value| value.trim().to_ascii_lowercase());
+    let invalid_document = |e: serde_json::Error| ErrorReponse::new(StatusCode::BAD_REQUEST, &format!("Invalid patch document: {}", e));
+
+    match content_type.as_deref() {
+        Some(MERGE_PATCH_JSON) => serde_json::from_slice(body).map(TaskPatchDocument::MergePatch).map_err(invalid_document),
+        Some(JSON_PATCH_JSON) => serde_json::from_slice(body).map(TaskPatchDocument::JsonPatch).map_err(invalid_document),
+        _ => Err(ErrorReponse::new(StatusCode::UNSUPPORTED_MEDIA_TYPE, &format!("PATCH accepts {} or {} documents", MERGE_PATCH_JSON, JSON_PATCH_JSON))),
+    }
+}
+
+#[utoipa::path(
+context_path = "/api",
+responses(
+(status = 200, description = "Move a task to the trash; it can be restored until the trash is purged"),
+(status = 404, description = "Task not found", body = ProblemDetails, content_type = "application/problem+json"),
+(status = 412, description = "`If-Match` does not match the current version of the task", body = ProblemDetails, content_type = "application/problem+json"),
+(status = 500, description = "Unexpected server error", body = ProblemDetails, content_type = "application/problem+json"),
+(status = 503, description = "Storage is temporarily unavailable", body = ProblemDetails, content_type = "application/problem+json")
+),
+params(
+("id" = i32, Path, description = "Task ID"),
+("If-Match" = Option<String>, Header, description = "ETag of the version the client expects; 412 if the task has changed")
 )
 )]
 #[delete("/tasks/{id}")]
-async fn delete_task(data: web::Data<AppConfigs>, task_id: web::Path<i32>) -> Result<HttpResponse, ErrorReponse> {
-    let delete_task_request = DeleteTaskRequest::new(task_id.into_inner(), &data.task_repository);
+async fn delete_task(req: HttpRequest, data: WorkspaceConfigs, actor: Actor, task_id: web::Path<i32>) -> Result<HttpResponse, ErrorReponse> {
+    let if_match = if_match_version(&req).map_err(|err| err.with_instance(req.path()))?;
+    let delete_task_request = DeleteTaskRequest::new(task_id.into_inner(), if_match, actor, data.task_repository.as_ref());
 
     let result = delete_task_request.execute().await;
 
     match result {
         Ok(_) => Ok(HttpResponse::Ok().body("Task deleted successfully")),
-        Err(err) => Err(ErrorReponse::map_io_error(err)),
+        Err(err) => Err(ErrorReponse::map_io_error(err).with_instance(req.path())),
+    }
+}
+
+#[utoipa::path(
+context_path = "/api",
+responses(
+(status = 200, description = "Restore a task from the trash; it returns under its parent unless the parent is deleted", body = Task, headers(("ETag" = String, description = "Current version of the task"))),
+(status = 404, description = "Task is not in the trash", body = ProblemDetails, content_type = "application/problem+json"),
+(status = 409, description = "Project of the task is archived", body = ProblemDetails, content_type = "application/problem+json"),
+(status = 412, description = "`If-Match` does not match the current version of the task", body = ProblemDetails, content_type = "application/problem+json"),
+(status = 500, description = "Unexpected server error", body = ProblemDetails, content_type = "application/problem+json"),
+(status = 503, description = "Storage is temporarily unavailable", body = ProblemDetails, content_type = "application/problem+json")
+),
+params(
+("id" = i32, Path, description = "Task ID"),
+("If-Match" = Option<String>, Header, description = "ETag of the version the client expects; 412 if the task has changed")
+)
+)]
+#[post("/tasks/{id}/restore")]
+async fn restore_task(req: HttpRequest, data: WorkspaceConfigs, actor: Actor, task_id: web::Path<i32>) -> Result<HttpResponse, ErrorReponse> {
+    let if_match = if_match_version(&req).map_err(|err| err.with_instance(req.path()))?;
+    let restore_task_request = RestoreTaskRequest::new(task_id.into_inner(), if_match, actor, data.task_repository.as_ref());
+
+    let result = restore_task_request.execute().await;
+
+    match result {
+        Ok(task) => Ok(HttpResponse::Ok().insert_header(task_etag(&task)).json(task)),
+        Err(err) => Err(ErrorReponse::map_io_error(err).with_instance(req.path())),
+    }
+}
+
+#[utoipa::path(
+context_path = "/api",
+responses(
+(status = 200, description = "Deleted tasks, most recently deleted first", body = TrashPage),
+(status = 400, description = "Invalid paging parameters", body = ProblemDetails, content_type = "application/problem+json"),
+(status = 500, description = "Unexpected server error", body = ProblemDetails, content_type = "application/problem+json"),
+(status = 503, description = "Storage is temporarily unavailable", body = ProblemDetails, content_type = "application/problem+json")
+),
+params(TrashParams)
+)]
+#[get("/trash")]
+async fn get_trash(req: HttpRequest, data: WorkspaceConfigs, actor: Actor, params: web::Query<TrashParams>) -> Result<HttpResponse, ErrorReponse> {
+    let TrashParams { limit, offset } = params.into_inner();
+    let get_trash_request = GetTrashRequest::new(limit, offset, actor, data.task_repository.as_ref());
+    let page = get_trash_request.execute().await;
+
+    page
+        .map_err(|err| ErrorReponse::map_io_error(err).with_instance(req.path()))
+        .map(|page| HttpResponse::Ok().json(page))
+}
+
+#[utoipa::path(
+context_path = "/api",
+request_body = TransitionPayload,
+responses(
+(status = 200, description = "Move a task to another status", body = Task, headers(("ETag" = String, description = "Current version of the task"))),
+(status = 404, description = "Task not found", body = ProblemDetails, content_type = "application/problem+json"),
+(status = 409, description = "Status transition is not allowed", body = ProblemDetails, content_type = "application/problem+json"),
+(status = 500, description = "Unexpected server error", body = ProblemDetails, content_type = "application/problem+json"),
+(status = 503, description = "Storage is temporarily unavailable", body = ProblemDetails, content_type = "application/problem+json")
+),
+params(
+("id" = i32, Path, description = "Task ID")
+)
+)]
+#[post("/tasks/{id}/transition")]
+async fn transition_task(req: HttpRequest, data: WorkspaceConfigs, actor: Actor, task_id: web::Path<i32>, input: web::Json<TransitionPayload>) -> Result<HttpResponse, ErrorReponse> {
+    let TransitionPayload { status } = input.into_inner();
+
+    let transition_task_request = TransitionTaskRequest::new(task_id.into_inner(), status, actor, data.task_repository.as_ref());
+
+    let result = transition_task_request.execute().await;
+
+    match result {
+        Ok(task) => Ok(HttpResponse::Ok().insert_header(task_etag(&task)).json(task)),
+        Err(err) => Err(ErrorReponse::map_io_error(err).with_instance(req.path())),
     }
 }
 
+#[utoipa::path(
+context_path = "/api",
+responses(
+(status = 200, description = "Task with its whole subtree and completion rolled up from subtasks", body = TaskTree),
+(status = 404, description = "Task not found", body = ProblemDetails, content_type = "application/problem+json"),
+(status = 500, description = "Unexpected server error", body = ProblemDetails, content_type = "application/problem+json"),
+(status = 503, description = "Storage is temporarily unavailable", body = ProblemDetails, content_type = "application/problem+json")
+),
+params(
+("id" = i32, Path, description = "Task ID")
+)
+)]
+#[get("/tasks/{id}/tree")]
+async fn get_task_tree(req: HttpRequest, data: WorkspaceConfigs, actor: Actor, task_id: web::Path<i32>) -> Result<HttpResponse, ErrorReponse> {
+    let get_task_tree_request = GetTaskTreeRequest::new(task_id.into_inner(), actor, data.task_repository.as_ref());
+    let tree = get_task_tree_request.execute().await;
+
+    tree
+        .map_err(|err| ErrorReponse::map_io_error(err).with_instance(req.path()))
+        .map(|tree| HttpResponse::Ok().json(tree))
+}
+
+#[utoipa::path(
+context_path = "/api",
+responses(
+(status = 200, description = "Change history of the task, oldest first; kept after the task is deleted", body = TaskEventPage),
+(status = 400, description = "Invalid paging parameters", body = ProblemDetails, content_type = "application/problem+json"),
+(status = 404, description = "Task not found and has no history", body = ProblemDetails, content_type = "application/problem+json"),
+(status = 500, description = "Unexpected server error", body = ProblemDetails, content_type = "application/problem+json"),
+(status = 503, description = "Storage is temporarily unavailable", body = ProblemDetails, content_type = "application/problem+json")
+),
+params(
+("id" = i32, Path, description = "Task ID"),
+TaskHistoryParams
+)
+)]
+#[get("/tasks/{id}/history")]
+async fn get_task_history(req: HttpRequest, data: WorkspaceConfigs, actor: Actor, task_id: web::Path<i32>, params: web::Query<TaskHistoryParams>) -> Result<HttpResponse, ErrorReponse> {
+    let TaskHistoryParams { limit, offset } = params.into_inner();
+    let get_task_history_request = GetTaskHistoryRequest::new(task_id.into_inner(), limit, offset, actor, data.task_repository.as_ref());
+    let page = get_task_history_request.execute().await;
+
+    page
+        .map_err(|err| ErrorReponse::map_io_error(err).with_instance(req.path()))
+        .map(|page| HttpResponse::Ok().json(page))
+}
+
+#[utoipa::path(
+context_path = "/api",
+request_body = TaskPayload,
+responses(
+(status = 201, description = "Create a subtask; it joins the project of its parent", body = Task, headers(
+    ("Location" = String, description = "URL of the created task"),
+    ("ETag" = String, description = "Version of the created task")
+)),
+(status = 404, description = "Parent task not found", body = ProblemDetails, content_type = "application/problem+json"),
+(status = 409, description = "The project of the parent task is archived", body = ProblemDetails, content_type = "application/problem+json"),
+(status = 422, description = "Task payload is invalid; `errors` lists every failing field", body = ProblemDetails, content_type = "application/problem+json"),
+(status = 500, description = "Unexpected server error", body = ProblemDetails, content_type = "application/problem+json"),
+(status = 503, description = "Storage is temporarily unavailable", body = ProblemDetails, content_type = "application/problem+json")
+),
+params(
+("id" = i32, Path, description = "Parent task ID")
+)
+)]
+#[post("/tasks/{id}/subtasks")]
+async fn create_subtask(req: HttpRequest, data: WorkspaceConfigs, actor: Actor, parent_id: web::Path<i32>, input: web::Json<TaskPayload>) -> Result<HttpResponse, ErrorReponse> {
+    let payload = input.into_inner()
+        .validate()
+        .map_err(|err| ErrorReponse::map_io_error(err).with_instance(req.path()))?;
+    let schedule = payload.schedule();
+    let TaskPayload { title, description, status, .. } = payload;
+
+    let create_subtask_request = CreateTaskRequest::new(title, description, status.unwrap_or_default(), schedule, TaskPlacement::under(parent_id.into_inner()), actor, data.task_repository.as_ref());
+
+    let result = create_subtask_request.execute().await;
+
+    match result {
+        Ok(task) => Ok(HttpResponse::Created()
+            .insert_header((header::LOCATION, format!("/api/tasks/{}", task.id)))
+            .insert_header(task_etag(&task))
+            .json(task)),
+        Err(err) => Err(ErrorReponse::map_io_error(err).with_instance(req.path())),
+    }
+}
+
+#[utoipa::path(
+context_path = "/api",
+request_body = MovePayload,
+responses(
+(status = 200, description = "Move a task together with its subtasks under another parent or to the root", body = Task, headers(("ETag" = String, description = "Current version of the task"))),
+(status = 400, description = "`If-Match` header is malformed", body = ProblemDetails, content_type = "application/problem+json"),
+(status = 404, description = "Task or new parent not found", body = ProblemDetails, content_type = "application/problem+json"),
+(status = 409, description = "The new parent is the task itself or one of its descendants", body = ProblemDetails, content_type = "application/problem+json"),
+(status = 412, description = "`If-Match` does not match the current version of the task", body = ProblemDetails, content_type = "application/problem+json"),
+(status = 500, description = "Unexpected server error", body = ProblemDetails, content_type = "application/problem+json"),
+(status = 503, description = "Storage is temporarily unavailable", body = ProblemDetails, content_type = "application/problem+json")
+),
+params(
+("id" = i32, Path, description = "Task ID"),
+("If-Match" = Option<String>, Header, description = "ETag of the version the client expects; 412 if the task has changed")
+)
+)]
+#[post("/tasks/{id}/move")]
+async fn move_task(req: HttpRequest, data: WorkspaceConfigs, actor: Actor, task_id: web::Path<i32>, input: web::Json<MovePayload>) -> Result<HttpResponse, ErrorReponse> {
+    let if_match = if_match_version(&req).map_err(|err| err.with_instance(req.path()))?;
+    let MovePayload { parent_id } = input.into_inner();
+
+    let move_task_request = MoveTaskRequest::new(task_id.into_inner(), parent_id, if_match, actor, data.task_repository.as_ref());
+
+    let result = move_task_request.execute().await;
+
+    match result {
+        Ok(task) => Ok(HttpResponse::Ok().insert_header(task_etag(&task)).json(task)),
+        Err(err) => Err(ErrorReponse::map_io_error(err).with_instance(req.path())),
+    }
+}
@@ -0,0 +1,93 @@
+use actix_web::http::header::{EntityTag, ETag, Header, IfMatch, IfNoneMatch};
+use actix_web::http::StatusCode;
+use actix_web::HttpRequest;
+use crate::adapters::api::task::error::ErrorReponse;
+use crate::domain::task::task::Task;
+
+/// Сильный `ETag` задачи, построенный по её версии.
+pub fn task_etag(task: &Task) -> ETag {
+    ETag(EntityTag::new_strong(task.version.to_string()))
+}
+
+/// Извлекает из заголовка `If-Match` версию задачи, которую ожидает клиент.
+///
+/// # Возвращает
+/// - `Ok(None)` если заголовка нет или он равен `*`.
+/// - `Ok(Some(version))` если заголовок содержит один сильный `ETag`, выданный этим сервисом.
+///
+/// # Ошибки
+/// - 400, если заголовок некорректен или содержит несколько `ETag`.
+/// - 412, если `ETag` слабый или не является версией задачи: такой заголовок не может совпасть ни с одной версией.
+pub fn if_match_version(req: &HttpRequest) -> Result<Option<i32>, ErrorReponse> {
+    if !req.headers().contains_key(IfMatch::name()) {
+        return Ok(None);
+    }
+
+    let tags = match IfMatch::parse(req) {
+        Ok(IfMatch::Any) => return Ok(None),
+        Ok(IfMatch::Items(tags)) => tags,
+        Err(_) => return Err(ErrorReponse::new(StatusCode::BAD_REQUEST, "If-Match header is malformed")),
+    };
+    let tag = match tags.as_slice() {
+        [tag] => tag,
+        _ => return Err(ErrorReponse::new(StatusCode::BAD_REQUEST, "If-Match must contain a single entity tag or *")),
+    };
+
+    tag.tag().parse::<i32>().ok()
+        .filter(|_| !tag.weak)
+        .map(Some)
+        .ok_or_else(|| ErrorReponse::new(StatusCode::PRECONDITION_FAILED, "If-Match does not match the current version of the task"))
+}
+
+/// Возвращает `true`, если `If-None-Match` совпадает с текущей версией задачи и можно ответить 304.
+///
+/// Согласно RFC 7232 для `If-None-Match` используется слабое сравнение.
+pub fn is_not_modified(req: &HttpRequest, task: &Task) -> bool {
+    let etag = task_etag(task);
+    match IfNoneMatch::parse(req) {
+        Ok(IfNoneMatch::Any) => true,
+        Ok(IfNoneMatch::Items(tags)) => tags.iter().any(|tag| tag.weak_eq(&etag)),
+        Err(_) => false,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use actix_web::test::TestRequest;
+    use actix_web::ResponseError;
+
+    #[test]
+    fn test_should_read_version_from_if_match() {
+        let req = TestRequest::default().insert_header(("If-Match", "\"3\"")).to_http_request();
+        let any = TestRequest::default().insert_header(("If-Match", "*")).to_http_request();
+        let absent = TestRequest::default().to_http_request();
+
+        assert_eq!(if_match_version(&req).unwrap(), Some(3));
+        assert_eq!(if_match_version(&any).unwrap(), None);
+        assert_eq!(if_match_version(&absent).unwrap(), None);
+    }
+
+    #[test]
+    fn test_should_reject_if_match_that_cannot_match() {
+        let weak = TestRequest::default().insert_header(("If-Match", "W/\"3\"")).to_http_request();
+        let foreign = TestRequest::default().insert_header(("If-Match", "\"abc\"")).to_http_request();
+        let several = TestRequest::default().insert_header(("If-Match", "\"1\", \"2\"")).to_http_request();
+
+        assert_eq!(if_match_version(&weak).unwrap_err().status_code(), StatusCode::PRECONDITION_FAILED);
+        assert_eq!(if_match_version(&foreign).unwrap_err().status_code(), StatusCode::PRECONDITION_FAILED);
+        assert_eq!(if_match_version(&several).unwrap_err().status_code(), StatusCode::BAD_REQUEST);
+    }
+
+    #[test]
+    fn test_should_detect_not_modified_with_weak_comparison() {
+        let mut task = Task::new(1, "Task".to_string(), None);
+        task.version = 2;
+        let matching = TestRequest::default().insert_header(("If-None-Match", "W/\"1\", W/\"2\"")).to_http_request();
+        let stale = TestRequest::default().insert_header(("If-None-Match", "\"1\"")).to_http_request();
+
+        assert!(is_not_modified(&matching, &task));
+        assert!(!is_not_modified(&stale, &task));
+        assert!(!is_not_modified(&TestRequest::default().to_http_request(), &task));
+    }
+}
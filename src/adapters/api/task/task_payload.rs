@@ -1,7 +1,251 @@
+use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
 use utoipa::ToSchema;
+use crate::application::validation::task_rules::{check_estimate, check_schedule_dates, DESCRIPTION_RULES, TITLE_RULES};
+use crate::application::validation::validator::{Validate, Validator};
+use crate::domain::error::ApiError;
+use crate::domain::task::task_bulk::{BulkMode, BulkOperation};
+use crate::domain::task::task_priority::TaskPriority;
+use crate::domain::task::task_schedule::TaskSchedule;
+use crate::domain::task::task_status::TaskStatus;
+
 #[derive(Deserialize,Serialize, ToSchema)]
 pub struct TaskPayload {
+    /// Заголовок задачи; пробелы в начале и в конце удаляются.
+    #[schema(min_length = 1, max_length = 200, pattern = r"^[^\u0000-\u001F\u007F-\u009F]*$")]
     pub title: String,
+    /// Описание задачи; пустое описание сохраняется как отсутствующее.
+    #[schema(max_length = 10000, pattern = r"^[^\u0000-\u0008\u000B\u000C\u000E-\u001F\u007F-\u009F]*$")]
     pub description: Option<String>,
-}
\ No newline at end of file
+    pub status: Option<TaskStatus>,
+    /// Приоритет задачи; при создании по умолчанию `medium`, при замене отсутствие поля оставляет приоритет без изменений.
+    pub priority: Option<TaskPriority>,
+    /// Дата начала работы над задачей (RFC 3339).
+    pub start_at: Option<DateTime<Utc>>,
+    /// Срок выполнения задачи (RFC 3339); не может быть раньше `start_at`.
+    pub due_at: Option<DateTime<Utc>>,
+    /// Оценка трудоёмкости в минутах.
+    #[schema(minimum = 0, maximum = 525600, example = 90)]
+    pub estimate_minutes: Option<i32>,
+}
+
+impl TaskPayload {
+    pub fn schedule(&self) -> TaskSchedule {
+        TaskSchedule { priority: self.priority, start_at: self.start_at, due_at: self.due_at, estimate_minutes: self.estimate_minutes }
+    }
+}
+
+impl Validate for TaskPayload {
+    fn validate(self) -> Result<Self, ApiError> {
+        let mut validator = Validator::new();
+        let title = validator.string("title", self.title, &TITLE_RULES);
+        let description = validator.optional_string("description", self.description, &DESCRIPTION_RULES);
+        check_schedule_dates(&mut validator, self.start_at, self.due_at);
+        check_estimate(&mut validator, self.estimate_minutes);
+
+        validator.finish(TaskPayload { title, description, ..self })
+    }
+}
+
+#[derive(Deserialize,Serialize, ToSchema)]
+pub struct TransitionPayload {
+    pub status: TaskStatus,
+}
+
+/// Тело `POST /api/tasks/{id}/move`.
+#[derive(Deserialize,Serialize, ToSchema)]
+pub struct MovePayload {
+    /// Новая родительская задача; `null` или отсутствие поля делает задачу корнем.
+    #[schema(nullable, example = 1)]
+    pub parent_id: Option<i32>,
+}
+
+/// Тело `POST /api/tasks/bulk`.
+#[derive(Deserialize,Serialize, ToSchema)]
+pub struct BulkPayload {
+    #[serde(default)]
+    pub mode: BulkMode,
+    /// Операции в порядке выполнения.
+    pub operations: Vec<BulkOperationPayload>,
+}
+
+/// Операция пакета; вид операции задаётся полем `op`.
+#[derive(Deserialize,Serialize, ToSchema)]
+#[serde(tag = "op", rename_all = "snake_case")]
+pub enum BulkOperationPayload {
+    Create {
+        title: String,
+        description: Option<String>,
+        status: Option<TaskStatus>,
+        priority: Option<TaskPriority>,
+        start_at: Option<DateTime<Utc>>,
+        due_at: Option<DateTime<Utc>>,
+        estimate_minutes: Option<i32>,
+    },
+    Update {
+        id: i32,
+        title: String,
+        description: Option<String>,
+        status: Option<TaskStatus>,
+        priority: Option<TaskPriority>,
+        start_at: Option<DateTime<Utc>>,
+        due_at: Option<DateTime<Utc>>,
+        estimate_minutes: Option<i32>,
+        /// Ожидаемая версия задачи, как в `If-Match`.
+        version: Option<i32>,
+    },
+    Delete {
+        id: i32,
+        /// Ожидаемая версия задачи, как в `If-Match`.
+        version: Option<i32>,
+    },
+}
+
+impl From<BulkOperationPayload> for BulkOperation {
+    fn from(payload: BulkOperationPayload) -> Self {
+        match payload {
+            BulkOperationPayload::Create { title, description, status, priority, start_at, due_at, estimate_minutes } => BulkOperation::Create {
+                title,
+                description,
+                status: status.unwrap_or_default(),
+                schedule: TaskSchedule { priority, start_at, due_at, estimate_minutes },
+            },
+            BulkOperationPayload::Update { id, title, description, status, priority, start_at, due_at, estimate_minutes, version } => BulkOperation::Update {
+                task_id: id,
+                title,
+                description,
+                status,
+                schedule: TaskSchedule { priority, start_at, due_at, estimate_minutes },
+                expected_version: version,
+            },
+            BulkOperationPayload::Delete { id, version } => BulkOperation::Delete { task_id: id, expected_version: version },
+        }
+    }
+}
+
+pub const MERGE_PATCH_JSON: &str = "application/merge-patch+json";
+pub const JSON_PATCH_JSON: &str = "application/json-patch+json";
+
+/// Документ JSON Merge Patch (RFC 7396) для `PATCH /api/tasks/{id}`.
+///
+/// Используется только для описания API: отсутствующее поле не меняется, `null` в `description`,
+/// `start_at`, `due_at`, `project_id` или `estimate_minutes` очищает значение.
+#[derive(Deserialize,Serialize, ToSchema)]
+pub struct TaskMergePatch {
+    #[schema(min_length = 1, max_length = 200)]
+    pub title: Option<String>,
+    #[schema(max_length = 10000, nullable)]
+    pub description: Option<String>,
+    pub status: Option<TaskStatus>,
+    pub priority: Option<TaskPriority>,
+    #[schema(nullable)]
+    pub start_at: Option<DateTime<Utc>>,
+    #[schema(nullable)]
+    pub due_at: Option<DateTime<Utc>>,
+    /// Проект, в который переносится задача; проект должен существовать и не находиться в архиве.
+    #[schema(nullable)]
+    pub project_id: Option<i32>,
+    #[schema(minimum = 0, maximum = 525600, nullable)]
+    pub estimate_minutes: Option<i32>,
+}
+
+/// Операция JSON Patch (RFC 6902) над документом задачи с полями `/title`, `/description`, `/status`,
+/// `/priority`, `/start_at`, `/due_at`, `/project_id` и `/estimate_minutes`.
+///
+/// Используется только для описания API, разбор выполняет `json_patch::Patch`.
+#[derive(Deserialize,Serialize, ToSchema)]
+pub struct JsonPatchOperation {
+    #[schema(example = "replace")]
+    pub op: JsonPatchOp,
+    #[schema(example = "/title")]
+    pub path: String,
+    /// Источник для операций `move` и `copy`.
+    pub from: Option<String>,
+    /// Значение для операций `add`, `replace` и `test`.
+    pub value: Option<serde_json::Value>,
+}
+
+#[derive(Deserialize,Serialize, ToSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum JsonPatchOp {
+    Add,
+    Remove,
+    Replace,
+    Move,
+    Copy,
+    Test,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use utoipa::openapi::{RefOr, Schema};
+    use crate::domain::task::task_schedule::MAX_ESTIMATE_MINUTES;
+
+    fn payload(title: &str, description: Option<&str>) -> TaskPayload {
+        TaskPayload {
+            title: title.to_string(),
+            description: description.map(str::to_string),
+            status: None,
+            priority: None,
+            start_at: None,
+            due_at: None,
+            estimate_minutes: None,
+        }
+    }
+
+    #[test]
+    fn test_should_normalize_valid_payload() {
+        let result = payload("  Write report ", Some("   ")).validate();
+
+        let payload = result.unwrap();
+        assert_eq!(payload.title, "Write report");
+        assert!(payload.description.is_none());
+    }
+
+    #[test]
+    fn test_should_return_errors_for_all_invalid_fields() {
+        let long_description = "a".repeat(10_001);
+        let result = payload("\u{0}", Some(&long_description)).validate();
+
+        let error = result.err().unwrap();
+        assert_eq!(error.code, 422);
+        let failures = error.errors.iter().map(|e| (e.field.as_str(), e.code.as_str())).collect::<Vec<_>>();
+        assert_eq!(failures, vec![("title", "control_characters"), ("description", "too_long")]);
+    }
+
+    #[test]
+    fn test_should_reject_due_date_before_start_date() {
+        let start_at = "2026-10-20T09:00:00Z".parse().unwrap();
+        let due_at = "2026-10-19T18:00:00Z".parse().unwrap();
+        let result = TaskPayload { start_at: Some(start_at), due_at: Some(due_at), ..payload("Plan", None) }.validate();
+
+        let error = result.err().unwrap();
+        assert_eq!(error.errors[0].field, "due_at");
+        assert_eq!(error.errors[0].code, "before_start");
+    }
+
+    #[test]
+    fn test_should_reject_negative_estimate() {
+        let result = TaskPayload { estimate_minutes: Some(-5), ..payload("Plan", None) }.validate();
+
+        let error = result.err().unwrap();
+        assert_eq!(error.errors[0].field, "estimate_minutes");
+        assert_eq!(error.errors[0].code, "out_of_range");
+    }
+
+    #[test]
+    fn test_should_keep_schema_constraints_in_sync_with_rules() {
+        let (_, schema) = <TaskPayload as ToSchema>::schema();
+        let RefOr::T(Schema::Object(schema)) = schema else { panic!("TaskPayload must be an object schema") };
+        let max_length = |field: &str| match &schema.properties[field] {
+            RefOr::T(Schema::Object(property)) => property.max_length,
+            _ => None,
+        };
+
+        assert_eq!(max_length("title"), TITLE_RULES.max_length);
+        assert_eq!(max_length("description"), DESCRIPTION_RULES.max_length);
+        let RefOr::T(Schema::Object(estimate)) = &schema.properties["estimate_minutes"] else { panic!("estimate_minutes must be inline") };
+        assert_eq!(estimate.maximum, Some(MAX_ESTIMATE_MINUTES as f64));
+    }
+}
@@ -0,0 +1,92 @@
+use serde::Deserialize;
+use utoipa::IntoParams;
+use crate::domain::task::task_query::{SortOrder, TagMatch, TaskFilter, TaskSortField};
+
+#[derive(Deserialize, IntoParams)]
+#[into_params(parameter_in = Query)]
+pub struct TaskListParams {
+    /// Количество задач на странице (1..=100, по умолчанию 20).
+    pub limit: Option<i64>,
+    /// Смещение от начала выборки. Нельзя использовать вместе с `cursor`.
+    pub offset: Option<i64>,
+    /// Курсор `next_cursor` или `prev_cursor` из предыдущего ответа.
+    pub cursor: Option<String>,
+    /// Поле сортировки (по умолчанию `id`); при равных значениях задачи упорядочиваются по `id`.
+    pub sort_by: Option<TaskSortField>,
+    pub order: Option<SortOrder>,
+    /// Подстрока, которую должен содержать заголовок задачи.
+    pub title_contains: Option<String>,
+    /// `true` - только задачи с описанием, `false` - только без описания.
+    pub has_description: Option<bool>,
+    /// Минимальный идентификатор задачи (включительно).
+    pub id_from: Option<i32>,
+    /// Максимальный идентификатор задачи (включительно).
+    pub id_to: Option<i32>,
+    /// Имена меток через запятую, например `backend,urgent`.
+    pub tag: Option<String>,
+    /// `all` - задача отмечена всеми метками из `tag` (по умолчанию), `any` - хотя бы одной.
+    pub tag_match: Option<TagMatch>,
+}
+
+impl TaskListParams {
+    pub fn filter(&self) -> TaskFilter {
+        TaskFilter {
+            title_contains: self.title_contains.clone(),
+            has_description: self.has_description,
+            id_from: self.id_from,
+            id_to: self.id_to,
+            tags: self.tag.iter()
+                .flat_map(|tags| tags.split(','))
+                .map(str::trim)
+                .filter(|tag| !tag.is_empty())
+                .map(str::to_string)
+                .collect(),
+            tag_match: self.tag_match.unwrap_or_default(),
+            project_id: None,
+            assignee_id: None,
+        }
+    }
+}
+
+#[derive(Deserialize, IntoParams)]
+#[into_params(parameter_in = Query)]
+pub struct TaskSearchParams {
+    /// Строка поиска по заголовку и описанию задачи.
+    pub q: String,
+    /// Максимальное количество результатов (1..=100, по умолчанию 20).
+    pub limit: Option<i64>,
+}
+
+#[derive(Deserialize, IntoParams)]
+#[into_params(parameter_in = Query)]
+pub struct TaskDueParams {
+    /// Максимальное количество задач (1..=100, по умолчанию 20).
+    pub limit: Option<i64>,
+}
+
+#[derive(Deserialize, IntoParams)]
+#[into_params(parameter_in = Query)]
+pub struct TaskDueSoonParams {
+    /// Горизонт в днях от текущего момента (1..=365, по умолчанию 7).
+    pub days: Option<i64>,
+    /// Максимальное количество задач (1..=100, по умолчанию 20).
+    pub limit: Option<i64>,
+}
+
+#[derive(Deserialize, IntoParams)]
+#[into_params(parameter_in = Query)]
+pub struct TaskHistoryParams {
+    /// Количество записей истории на странице (1..=100, по умолчанию 20).
+    pub limit: Option<i64>,
+    /// Смещение от самой ранней записи истории.
+    pub offset: Option<i64>,
+}
+
+#[derive(Deserialize, IntoParams)]
+#[into_params(parameter_in = Query)]
+pub struct TrashParams {
+    /// Количество задач на странице (1..=100, по умолчанию 20).
+    pub limit: Option<i64>,
+    /// Смещение от задачи, удалённой последней.
+    pub offset: Option<i64>,
+}
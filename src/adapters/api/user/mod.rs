@@ -0,0 +1,3 @@
+pub mod user_controller;
+pub mod user_payload;
+pub mod user_query_params;
@@ -0,0 +1,236 @@
+use actix_web::{get, web, HttpRequest, HttpResponse, post, put, delete, http::header};
+use crate::adapters::api::task::error::ErrorReponse;
+use crate::adapters::api::task::task_etag::{if_match_version, task_etag};
+use crate::adapters::api::task::task_query_params::TaskListParams;
+use crate::adapters::api::user::user_payload::{RolePayload, UserPayload};
+use crate::adapters::api::user::user_query_params::AssignParams;
+use crate::adapters::api::auth::workspace_extractor::WorkspaceConfigs;
+use crate::application::repo_requesters::abstract_request::AbstractRequest;
+use crate::application::repo_requesters::assign_task_request::AssignTaskRequest;
+use crate::application::repo_requesters::create_user_request::CreateUserRequest;
+use crate::application::repo_requesters::get_task_assignees_request::GetTaskAssigneesRequest;
+use crate::application::repo_requesters::get_tasks_request::GetTasksRequest;
+use crate::application::repo_requesters::get_user_request::GetUserRequest;
+use crate::application::repo_requesters::get_user_tasks_request::GetUserTasksRequest;
+use crate::application::repo_requesters::get_users_request::GetUsersRequest;
+use crate::application::repo_requesters::set_user_role_request::SetUserRoleRequest;
+use crate::application::repo_requesters::unassign_task_request::UnassignTaskRequest;
+use crate::application::validation::validator::Validate;
+use crate::domain::access::actor::Actor;
+
+pub fn routes(cfg: &mut web::ServiceConfig) {
+    cfg.service(get_users);
+    cfg.service(get_user);
+    cfg.service(create_user);
+    cfg.service(set_user_role);
+    cfg.service(get_user_tasks);
+    cfg.service(get_task_assignees);
+    cfg.service(assign_task);
+    cfg.service(unassign_task);
+}
+
+#[utoipa::path(
+context_path = "/api",
+responses(
+(status = 200, description = "List all users ordered by name", body = [User]),
+(status = 500, description = "Unexpected server error", body = ProblemDetails, content_type = "application/problem+json"),
+(status = 503, description = "Storage is temporarily unavailable", body = ProblemDetails, content_type = "application/problem+json")
+)
+)]
+#[get("/users")]
+async fn get_users(req: HttpRequest, data: WorkspaceConfigs) -> Result<HttpResponse, ErrorReponse> {
+    let get_users_request = GetUsersRequest::new(data.user_repository.as_ref());
+    let users = get_users_request.execute().await;
+
+    users
+        .map_err(|err| ErrorReponse::map_io_error(err).with_instance(req.path()))
+        .map(|users| HttpResponse::Ok().json(users))
+}
+
+#[utoipa::path(
+context_path = "/api",
+responses(
+(status = 200, description = "Get user by ID", body = User),
+(status = 404, description = "User not found", body = ProblemDetails, content_type = "application/problem+json"),
+(status = 500, description = "Unexpected server error", body = ProblemDetails, content_type = "application/problem+json"),
+(status = 503, description = "Storage is temporarily unavailable", body = ProblemDetails, content_type = "application/problem+json")
+),
+params(
+("id" = i32, Path, description = "User ID")
+)
+)]
+#[get("/users/{id}")]
+async fn get_user(req: HttpRequest, data: WorkspaceConfigs, user_id: web::Path<i32>) -> Result<HttpResponse, ErrorReponse> {
+    let get_user_request = GetUserRequest::new(user_id.into_inner(), data.user_repository.as_ref());
+    let user = get_user_request.execute().await;
+
+    user
+        .map_err(|err| ErrorReponse::map_io_error(err).with_instance(req.path()))
+        .map(|user| HttpResponse::Ok().json(user))
+}
+
+#[utoipa::path(
+context_path = "/api",
+request_body = UserPayload,
+responses(
+(status = 201, description = "Create a new user", body = User, headers(
+    ("Location" = String, description = "URL of the created user")
+)),
+(status = 403, description = "Only global admins can create users", body = ProblemDetails, content_type = "application/problem+json"),
+(status = 409, description = "A user with this email already exists", body = ProblemDetails, content_type = "application/problem+json"),
+(status = 422, description = "User payload is invalid; `errors` lists every failing field", body = ProblemDetails, content_type = "application/problem+json"),
+(status = 500, description = "Unexpected server error", body = ProblemDetails, content_type = "application/problem+json"),
+(status = 503, description = "Storage is temporarily unavailable", body = ProblemDetails, content_type = "application/problem+json")
+)
+)]
+#[post("/users")]
+async fn create_user(req: HttpRequest, data: WorkspaceConfigs, actor: Actor, input: web::Json<UserPayload>) -> Result<HttpResponse, ErrorReponse> {
+    let UserPayload { name, email } = input.into_inner()
+        .validate()
+        .map_err(|err| ErrorReponse::map_io_error(err).with_instance(req.path()))?;
+
+    let create_user_request = CreateUserRequest::new(name, email, actor, data.task_repository.as_ref(), data.user_repository.as_ref());
+
+    let result = create_user_request.execute().await;
+
+    match result {
+        Ok(user) => Ok(HttpResponse::Created()
+            .insert_header((header::LOCATION, format!("{}/{}", req.path(), user.id)))
+            .json(user)),
+        Err(err) => Err(ErrorReponse::map_io_error(err).with_instance(req.path())),
+    }
+}
+
+#[utoipa::path(
+context_path = "/api",
+request_body = RolePayload,
+responses(
+(status = 200, description = "Change the global role of the user", body = User),
+(status = 403, description = "Only global admins can change roles", body = ProblemDetails, content_type = "application/problem+json"),
+(status = 404, description = "User not found", body = ProblemDetails, content_type = "application/problem+json"),
+(status = 500, description = "Unexpected server error", body = ProblemDetails, content_type = "application/problem+json"),
+(status = 503, description = "Storage is temporarily unavailable", body = ProblemDetails, content_type = "application/problem+json")
+),
+params(
+("id" = i32, Path, description = "User ID")
+)
+)]
+#[put("/users/{id}/role")]
+async fn set_user_role(req: HttpRequest, data: WorkspaceConfigs, actor: Actor, user_id: web::Path<i32>, input: web::Json<RolePayload>) -> Result<HttpResponse, ErrorReponse> {
+    let set_user_role_request = SetUserRoleRequest::new(user_id.into_inner(), input.role, actor, data.task_repository.as_ref(), data.user_repository.as_ref());
+
+    set_user_role_request.execute().await
+        .map_err(|err| ErrorReponse::map_io_error(err).with_instance(req.path()))
+        .map(|user| HttpResponse::Ok().json(user))
+}
+
+#[utoipa::path(
+context_path = "/api",
+responses(
+(status = 200, description = "List tasks assigned to the user page by page", body = TaskPage),
+(status = 400, description = "Invalid paging, sorting or filter parameters", body = ProblemDetails, content_type = "application/problem+json"),
+(status = 404, description = "User not found", body = ProblemDetails, content_type = "application/problem+json"),
+(status = 500, description = "Unexpected server error", body = ProblemDetails, content_type = "application/problem+json"),
+(status = 503, description = "Storage is temporarily unavailable", body = ProblemDetails, content_type = "application/problem+json")
+),
+params(
+("id" = i32, Path, description = "User ID"),
+TaskListParams
+)
+)]
+#[get("/users/{id}/tasks")]
+async fn get_user_tasks(req: HttpRequest, data: WorkspaceConfigs, actor: Actor, user_id: web::Path<i32>, params: web::Query<TaskListParams>) -> Result<HttpResponse, ErrorReponse> {
+    let params = params.into_inner();
+    let get_tasks_request = GetTasksRequest::new(params.filter(), params.sort_by, params.order, params.limit, params.offset, params.cursor, actor, data.task_repository.as_ref());
+    let get_user_tasks_request = GetUserTasksRequest::new(user_id.into_inner(), get_tasks_request, data.user_repository.as_ref());
+    let page = get_user_tasks_request.execute().await;
+
+    page
+        .map_err(|err| ErrorReponse::map_io_error(err).with_instance(req.path()))
+        .map(|page| HttpResponse::Ok().json(page))
+}
+
+#[utoipa::path(
+context_path = "/api",
+responses(
+(status = 200, description = "List users assigned to the task ordered by name", body = [User]),
+(status = 404, description = "Task not found", body = ProblemDetails, content_type = "application/problem+json"),
+(status = 500, description = "Unexpected server error", body = ProblemDetails, content_type = "application/problem+json"),
+(status = 503, description = "Storage is temporarily unavailable", body = ProblemDetails, content_type = "application/problem+json")
+),
+params(
+("id" = i32, Path, description = "Task ID")
+)
+)]
+#[get("/tasks/{id}/assignees")]
+async fn get_task_assignees(req: HttpRequest, data: WorkspaceConfigs, task_id: web::Path<i32>) -> Result<HttpResponse, ErrorReponse> {
+    let get_task_assignees_request = GetTaskAssigneesRequest::new(task_id.into_inner(), data.user_repository.as_ref());
+    let users = get_task_assignees_request.execute().await;
+
+    users
+        .map_err(|err| ErrorReponse::map_io_error(err).with_instance(req.path()))
+        .map(|users| HttpResponse::Ok().json(users))
+}
+
+#[utoipa::path(
+context_path = "/api",
+responses(
+(status = 200, description = "User is assigned to the task; assigning them again changes nothing", body = Task, headers(("ETag" = String, description = "Current version of the task"))),
+(status = 400, description = "`If-Match` header is malformed", body = ProblemDetails, content_type = "application/problem+json"),
+(status = 403, description = "Only editors and admins of the task's project can change its assignees", body = ProblemDetails, content_type = "application/problem+json"),
+(status = 404, description = "Task or user not found", body = ProblemDetails, content_type = "application/problem+json"),
+(status = 412, description = "`If-Match` does not match the current version of the task", body = ProblemDetails, content_type = "application/problem+json"),
+(status = 500, description = "Unexpected server error", body = ProblemDetails, content_type = "application/problem+json"),
+(status = 503, description = "Storage is temporarily unavailable", body = ProblemDetails, content_type = "application/problem+json")
+),
+params(
+("id" = i32, Path, description = "Task ID"),
+("user_id" = i32, Path, description = "User ID"),
+AssignParams,
+("If-Match" = Option<String>, Header, description = "ETag of the version the client expects; 412 if the task has changed")
+)
+)]
+#[put("/tasks/{id}/assignees/{user_id}")]
+async fn assign_task(req: HttpRequest, data: WorkspaceConfigs, actor: Actor, path: web::Path<(i32, i32)>, params: web::Query<AssignParams>) -> Result<HttpResponse, ErrorReponse> {
+    let if_match = if_match_version(&req).map_err(|err| err.with_instance(req.path()))?;
+    let (task_id, user_id) = path.into_inner();
+    let assign_task_request = AssignTaskRequest::new(task_id, user_id, params.primary.unwrap_or(false), if_match, actor, data.task_repository.as_ref(), data.user_repository.as_ref());
+
+    let result = assign_task_request.execute().await;
+
+    match result {
+        Ok(task) => Ok(HttpResponse::Ok().insert_header(task_etag(&task)).json(task)),
+        Err(err) => Err(ErrorReponse::map_io_error(err).with_instance(req.path())),
+    }
+}
+
+#[utoipa::path(
+context_path = "/api",
+responses(
+(status = 200, description = "User is no longer assigned to the task", body = Task, headers(("ETag" = String, description = "Current version of the task"))),
+(status = 400, description = "`If-Match` header is malformed", body = ProblemDetails, content_type = "application/problem+json"),
+(status = 403, description = "Only editors and admins of the task's project can change its assignees", body = ProblemDetails, content_type = "application/problem+json"),
+(status = 404, description = "Task or user not found, or the user is not assigned to the task", body = ProblemDetails, content_type = "application/problem+json"),
+(status = 412, description = "`If-Match` does not match the current version of the task", body = ProblemDetails, content_type = "application/problem+json"),
+(status = 500, description = "Unexpected server error", body = ProblemDetails, content_type = "application/problem+json"),
+(status = 503, description = "Storage is temporarily unavailable", body = ProblemDetails, content_type = "application/problem+json")
+),
+params(
+("id" = i32, Path, description = "Task ID"),
+("user_id" = i32, Path, description = "User ID"),
+("If-Match" = Option<String>, Header, description = "ETag of the version the client expects; 412 if the task has changed")
+)
+)]
+#[delete("/tasks/{id}/assignees/{user_id}")]
+async fn unassign_task(req: HttpRequest, data: WorkspaceConfigs, actor: Actor, path: web::Path<(i32, i32)>) -> Result<HttpResponse, ErrorReponse> {
+    let if_match = if_match_version(&req).map_err(|err| err.with_instance(req.path()))?;
+    let (task_id, user_id) = path.into_inner();
+    let unassign_task_request = UnassignTaskRequest::new(task_id, user_id, if_match, actor, data.task_repository.as_ref(), data.user_repository.as_ref());
+
+    let result = unassign_task_request.execute().await;
+
+    match result {
+        Ok(task) => Ok(HttpResponse::Ok().insert_header(task_etag(&task)).json(task)),
+        Err(err) => Err(ErrorReponse::map_io_error(err).with_instance(req.path())),
+    }
+}
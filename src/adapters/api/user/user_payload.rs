@@ -0,0 +1,57 @@
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+use crate::application::validation::user_rules::{check_email, USER_EMAIL_RULES, USER_NAME_RULES};
+use crate::application::validation::validator::{Validate, Validator};
+use crate::domain::access::role::Role;
+use crate::domain::error::ApiError;
+
+#[derive(Deserialize, Serialize, ToSchema)]
+pub struct UserPayload {
+    /// Имя пользователя; пробелы в начале и в конце удаляются.
+    #[schema(min_length = 1, max_length = 100, pattern = r"^[^\u0000-\u001F\u007F-\u009F]*$", example = "Ada Lovelace")]
+    pub name: String,
+    /// Адрес электронной почты, уникальный без учёта регистра.
+    #[schema(min_length = 3, max_length = 254, example = "ada@example.com")]
+    pub email: String,
+}
+
+/// Роль пользователя: глобальная или в проекте.
+#[derive(Deserialize, Serialize, ToSchema)]
+pub struct RolePayload {
+    pub role: Role,
+}
+
+impl Validate for UserPayload {
+    fn validate(self) -> Result<Self, ApiError> {
+        let mut validator = Validator::new();
+        let name = validator.string("name", self.name, &USER_NAME_RULES);
+        let email = validator.string("email", self.email, &USER_EMAIL_RULES);
+        check_email(&mut validator, &email);
+
+        validator.finish(UserPayload { name, email })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_should_normalize_and_reject_invalid_fields() {
+        let valid = UserPayload { name: " Ada ".to_string(), email: " ada@example.com ".to_string() }.validate().unwrap();
+        let invalid = UserPayload { name: "".to_string(), email: "ada@localhost".to_string() }.validate().err().unwrap();
+
+        assert_eq!(valid.name, "Ada");
+        assert_eq!(valid.email, "ada@example.com");
+        let errors = invalid.errors.iter().map(|e| (e.field.as_str(), e.code.as_str())).collect::<Vec<_>>();
+        assert_eq!(errors, vec![("name", "required"), ("email", "invalid_format")]);
+    }
+
+    #[test]
+    fn test_should_reject_malformed_emails() {
+        for email in ["ada", "@example.com", "ada@", "ada@@example.com", "ada@example..com", "a da@example.com"] {
+            let payload = UserPayload { name: "Ada".to_string(), email: email.to_string() };
+            assert!(payload.validate().is_err(), "{} must be rejected", email);
+        }
+    }
+}
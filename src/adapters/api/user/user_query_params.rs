@@ -0,0 +1,10 @@
+use serde::Deserialize;
+use utoipa::IntoParams;
+
+#[derive(Deserialize, IntoParams)]
+#[into_params(parameter_in = Query)]
+pub struct AssignParams {
+    /// Сделать пользователя ответственным исполнителем задачи (по умолчанию `false`);
+    /// первый назначенный пользователь становится ответственным в любом случае.
+    pub primary: Option<bool>,
+}
@@ -1,7 +1,20 @@
 use actix_web::web;
+use crate::adapters::api::api_key::api_key_controller;
+use crate::adapters::api::auth::auth_controller;
+use crate::adapters::api::dependency::dependency_controller;
+use crate::adapters::api::project::project_controller;
+use crate::adapters::api::tag::tag_controller;
 use crate::adapters::api::task::task_controller;
+use crate::adapters::api::user::user_controller;
 
 pub fn routes(config: &mut web::ServiceConfig) {
     config
-        .service(web::scope("/api").configure(task_controller::routes));
+        .service(web::scope("/api")
+            .configure(task_controller::routes)
+            .configure(tag_controller::routes)
+            .configure(project_controller::routes)
+            .configure(dependency_controller::routes)
+            .configure(user_controller::routes)
+            .configure(api_key_controller::routes)
+            .configure(auth_controller::routes));
 }
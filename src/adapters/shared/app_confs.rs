@@ -1,5 +1,31 @@
-use crate::dal::repositories::db_repo_tasks::TaskRepository;
+use std::sync::Arc;
+use crate::application::repositories::api_key_abstract_repo::ApiKeyRepositoryAbstract;
+use crate::application::repositories::dependency_abstract_repo::DependencyRepositoryAbstract;
+use crate::application::repositories::project_abstract_repo::ProjectRepositoryAbstract;
+use crate::application::repositories::tag_abstract_repo::TagRepositoryAbstract;
+use crate::application::repositories::task_abstract_repo::TaskRepositoryAbstract;
+use crate::application::repositories::user_abstract_repo::UserRepositoryAbstract;
+use crate::domain::workspace::Workspace;
 
 pub struct AppConfigs {
-    pub task_repository: TaskRepository
+    pub task_repository: Arc<dyn TaskRepositoryAbstract>,
+    pub tag_repository: Arc<dyn TagRepositoryAbstract>,
+    pub project_repository: Arc<dyn ProjectRepositoryAbstract>,
+    pub dependency_repository: Arc<dyn DependencyRepositoryAbstract>,
+    pub user_repository: Arc<dyn UserRepositoryAbstract>,
+    pub api_key_repository: Arc<dyn ApiKeyRepositoryAbstract>
+}
+
+impl AppConfigs {
+    /// Те же репозитории, ограниченные рабочим пространством `workspace`.
+    pub fn in_workspace(&self, workspace: Workspace) -> AppConfigs {
+        AppConfigs {
+            task_repository: self.task_repository.in_workspace(workspace),
+            tag_repository: self.tag_repository.in_workspace(workspace),
+            project_repository: self.project_repository.in_workspace(workspace),
+            dependency_repository: self.dependency_repository.in_workspace(workspace),
+            user_repository: self.user_repository.in_workspace(workspace),
+            api_key_repository: self.api_key_repository.in_workspace(workspace),
+        }
+    }
 }
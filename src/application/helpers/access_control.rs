@@ -0,0 +1,90 @@
+use crate::application::helpers::error_handler::ErrorHandlingHelper;
+use crate::application::repositories::task_abstract_repo::TaskRepositoryAbstract;
+use crate::domain::access::actor::{AccessScope, Actor};
+use crate::domain::access::role::Permission;
+use crate::domain::error::ApiError;
+
+pub struct AccessControl {}
+
+impl AccessControl {
+    /// Проверяет, что `actor` может выполнить действие `permission` в области `scope`.
+    ///
+    /// `Actor::System` разрешено всё, `Actor::Guest` - ничего, сервисной учётной записи - то, что разрешают
+    /// права её ключа в любой области; для пользователя роль определяет репозиторий.
+    /// Отказ возвращается как 403, отсутствующая задача в области - как 404.
+    pub async fn authorize(actor: Actor, scope: AccessScope, permission: Permission, repository: &dyn TaskRepositoryAbstract) -> Result<(), ApiError> {
+        let user_id = match actor {
+            Actor::System => return Ok(()),
+            Actor::Guest => return Err(ErrorHandlingHelper::forbidden_error()),
+            Actor::Service(scopes) if scopes.grants(permission) => return Ok(()),
+            Actor::Service(_) => return Err(ErrorHandlingHelper::forbidden_error()),
+            Actor::User(user_id) => user_id,
+        };
+
+        let role = repository.get_role(user_id, scope).await
+            .map_err(|e| ErrorHandlingHelper::domain_error("Cannot check access", e))?;
+        match role {
+            Some(role) if role.grants(permission) => Ok(()),
+            _ => Err(ErrorHandlingHelper::forbidden_error()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use mockall::predicate::eq;
+    use crate::application::repositories::task_abstract_repo::MockTaskRepositoryAbstract;
+    use crate::domain::access::api_key::ApiKeyScope;
+    use crate::domain::access::role::Role;
+    use crate::domain::task::task::Task;
+
+    #[actix_rt::test]
+    async fn test_should_allow_system_and_deny_guest_without_repository() {
+        let mut task_repo = MockTaskRepositoryAbstract::new();
+        task_repo.expect_get_role().times(0);
+
+        assert!(AccessControl::authorize(Actor::System, AccessScope::Global, Permission::Manage, &task_repo).await.is_ok());
+        let error = AccessControl::authorize(Actor::Guest, AccessScope::Global, Permission::Read, &task_repo).await.unwrap_err();
+        assert_eq!(error.code, 403);
+    }
+
+    #[actix_rt::test]
+    async fn test_should_check_permission_against_api_key_scopes() {
+        let mut task_repo = MockTaskRepositoryAbstract::new();
+        task_repo.expect_get_role().times(0);
+        let reader = Actor::Service([ApiKeyScope::ReadTasks].into_iter().collect());
+
+        assert!(AccessControl::authorize(reader, AccessScope::Project(1), Permission::Read, &task_repo).await.is_ok());
+        let error = AccessControl::authorize(reader, AccessScope::Task(7), Permission::Write, &task_repo).await.unwrap_err();
+        assert_eq!(error.code, 403);
+    }
+
+    #[actix_rt::test]
+    async fn test_should_check_permission_against_user_role() {
+        let mut task_repo = MockTaskRepositoryAbstract::new();
+        task_repo.expect_get_role()
+            .with(eq(3), eq(AccessScope::Task(7)))
+            .returning(|_, _| Ok(Some(Role::Editor)));
+        task_repo.expect_get_role()
+            .with(eq(4), eq(AccessScope::Task(7)))
+            .returning(|_, _| Ok(None));
+
+        assert!(AccessControl::authorize(Actor::User(3), AccessScope::Task(7), Permission::Write, &task_repo).await.is_ok());
+        let denied = AccessControl::authorize(Actor::User(3), AccessScope::Task(7), Permission::Delete, &task_repo).await.unwrap_err();
+        assert_eq!(denied.code, 403);
+        let unknown = AccessControl::authorize(Actor::User(4), AccessScope::Task(7), Permission::Read, &task_repo).await.unwrap_err();
+        assert_eq!(unknown.code, 403);
+    }
+
+    #[actix_rt::test]
+    async fn test_should_report_missing_task_as_not_found() {
+        let mut task_repo = MockTaskRepositoryAbstract::new();
+        task_repo.expect_get_role()
+            .returning(|_, _| Err(Task::not_found(9)));
+
+        let error = AccessControl::authorize(Actor::User(3), AccessScope::Task(9), Permission::Read, &task_repo).await.unwrap_err();
+
+        assert_eq!(error.code, 404);
+    }
+}
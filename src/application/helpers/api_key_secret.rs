@@ -0,0 +1,55 @@
+use base64::engine::general_purpose::URL_SAFE_NO_PAD;
+use base64::Engine;
+use sha2::{Digest, Sha256};
+
+/// Начало каждого ключа, по которому его легко узнать в конфигурации и логах.
+pub const API_KEY_PREFIX: &str = "tm_";
+/// Сколько первых символов ключа хранится открыто для отображения в списке.
+const DISPLAY_PREFIX_LENGTH: usize = 10;
+const SECRET_BYTES: usize = 32;
+
+/// Выпуск и хеширование ключей API.
+pub struct ApiKeySecret {}
+
+impl ApiKeySecret {
+    /// Новый ключ из 32 случайных байт операционной системы и его открытое начало.
+    ///
+    /// # Ошибки
+    /// Возвращает ошибку `getrandom`, если источник случайных чисел недоступен.
+    pub fn generate() -> Result<(String, String), getrandom::Error> {
+        let mut secret = [0u8; SECRET_BYTES];
+        getrandom::getrandom(&mut secret)?;
+        let key = format!("{}{}", API_KEY_PREFIX, URL_SAFE_NO_PAD.encode(secret));
+        let prefix = key[..DISPLAY_PREFIX_LENGTH].to_string();
+
+        Ok((key, prefix))
+    }
+
+    /// SHA-256 ключа в шестнадцатеричном виде; по нему ключ ищется в хранилище.
+    ///
+    /// У ключа 256 бит энтропии, поэтому медленная хеш-функция с солью, как для паролей, не нужна.
+    pub fn hash(key: &str) -> String {
+        Sha256::digest(key.as_bytes()).iter().map(|byte| format!("{:02x}", byte)).collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_should_generate_distinct_prefixed_keys() {
+        let (key, prefix) = ApiKeySecret::generate().unwrap();
+        let (other, _) = ApiKeySecret::generate().unwrap();
+
+        assert!(key.starts_with(API_KEY_PREFIX));
+        assert!(key.starts_with(&prefix));
+        assert_eq!(key.len(), API_KEY_PREFIX.len() + 43);
+        assert_ne!(key, other);
+    }
+
+    #[test]
+    fn test_should_hash_key_with_sha256() {
+        assert_eq!(ApiKeySecret::hash("abc"), "ba7816bf8f01cfea414140de5dae2223b00361a396177a9cb410ff61f20015ad");
+    }
+}
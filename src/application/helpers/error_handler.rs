@@ -1,4 +1,4 @@
-use crate::domain::error::ApiError;
+use crate::domain::error::{ApiError, DomainError, FieldError};
 use std::error::Error;
 
 pub struct ErrorHandlingHelper {}
@@ -10,6 +10,54 @@ impl ErrorHandlingHelper {
             code: 400,
             message: String::from(error_message),
             error,
+            errors: vec![],
+        }
+    }
+    /// Преобразует ошибку репозитория в `ApiError` с HTTP-кодом, соответствующим её виду.
+    ///
+    /// Для `NotFound`, `Conflict` и `Validation` клиенту возвращается сообщение доменной ошибки,
+    /// для `Internal` - только `error_message`, чтобы не раскрывать подробности хранилища.
+    pub fn domain_error(error_message: &str, error: DomainError) -> ApiError {
+        let (code, message) = match &error {
+            DomainError::NotFound(message) => (404, message.clone()),
+            DomainError::Conflict(message) => (409, message.clone()),
+            DomainError::PreconditionFailed(message) => (412, message.clone()),
+            DomainError::Validation(message) => (422, message.clone()),
+            DomainError::Unavailable(_) => return ErrorHandlingHelper::service_unavailable_error(Some(Box::new(error))),
+            DomainError::Internal(_) => (500, String::from(error_message)),
+        };
+        let error: Option<Box<dyn Error>> = Some(Box::new(error));
+        ErrorHandlingHelper::log_error(error_message, &error);
+        ApiError { code, message, error, errors: vec![] }
+    }
+    /// Ошибка проверки входных данных: 422 со списком ошибок всех некорректных полей.
+    pub fn validation_error(errors: Vec<FieldError>) -> ApiError {
+        let validation_message = "Validation failed";
+        ErrorHandlingHelper::log_error(validation_message, &None);
+        ApiError {
+            code: 422,
+            message: String::from(validation_message),
+            error: None,
+            errors,
+        }
+    }
+    pub fn conflict_error(error_message: &str, error: Option<Box<dyn Error>>) -> ApiError {
+        ErrorHandlingHelper::log_error(error_message, &error);
+        ApiError {
+            code: 409,
+            message: String::from(error_message),
+            error,
+            errors: vec![],
+        }
+    }
+    pub fn service_unavailable_error(error: Option<Box<dyn Error>>) -> ApiError {
+        let unavailable_message = "Error: service is temporarily unavailable, please retry later";
+        ErrorHandlingHelper::log_error(unavailable_message, &error);
+        ApiError {
+            code: 503,
+            message: String::from(unavailable_message),
+            error,
+            errors: vec![],
         }
     }
     pub fn unauthorized_error() -> ApiError {
@@ -19,6 +67,7 @@ impl ErrorHandlingHelper {
             code: 401,
             message: String::from(unauthorized_message),
             error: None,
+            errors: vec![],
         }
     }
     pub fn forbidden_error() -> ApiError {
@@ -28,13 +77,14 @@ impl ErrorHandlingHelper {
             code: 403,
             message: String::from(forbdden_message),
             error: None,
+            errors: vec![],
         }
     }
 
     fn log_error(message: &str, err: &Option<Box<dyn Error>>) {
         println!("Error: {}", message);
         if let Some(error) = err {
-            println!("Stack: {}", error.to_string());
+            println!("Stack: {}", error);
         }
     }
 }
@@ -1 +1,3 @@
-pub mod error_handler;
\ No newline at end of file
+pub mod error_handler;
+pub mod access_control;
+pub mod api_key_secret;
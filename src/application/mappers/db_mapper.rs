@@ -1,7 +1,9 @@
+use crate::domain::error::DomainError;
+
 pub trait DbMapper<Entity, DbModel> {
     // Map an Entity to a DbModel
     fn to_db(entity: Entity) -> DbModel;
 
-    // Map a DbModel to an Entity
-    fn to_entity(model: DbModel) -> Entity;
+    // Map a DbModel to an Entity; fails with `DomainError::Internal` if the stored data is corrupted
+    fn to_entity(model: DbModel) -> Result<Entity, DomainError>;
 }
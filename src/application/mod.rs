@@ -2,4 +2,5 @@ pub mod repositories;
 
 pub mod repo_requesters;
 pub mod helpers;
-pub mod mappers;
\ No newline at end of file
+pub mod mappers;
+pub mod validation;
\ No newline at end of file
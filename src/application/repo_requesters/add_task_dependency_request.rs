@@ -0,0 +1,100 @@
+use async_trait::async_trait;
+use crate::application::helpers::access_control::AccessControl;
+use crate::application::helpers::error_handler::ErrorHandlingHelper;
+use crate::application::repo_requesters::abstract_request::AbstractRequest;
+use crate::application::repositories::dependency_abstract_repo::DependencyRepositoryAbstract;
+use crate::application::repositories::task_abstract_repo::TaskRepositoryAbstract;
+use crate::domain::access::actor::{AccessScope, Actor};
+use crate::domain::access::role::Permission;
+use crate::domain::error::ApiError;
+use crate::domain::task::task_dependency::check_new_dependency;
+
+/// Делает одну задачу блокирующей для другой, отклоняя зависимости, образующие цикл.
+pub struct AddTaskDependencyRequest<'a> {
+    task_id: i32,
+    blocker_id: i32,
+    actor: Actor,
+    access_repository: &'a dyn TaskRepositoryAbstract,
+    repository: &'a dyn DependencyRepositoryAbstract,
+}
+
+impl<'a> AddTaskDependencyRequest<'a> {
+    pub fn new(task_id: i32, blocker_id: i32, actor: Actor, access_repository: &'a dyn TaskRepositoryAbstract, repository: &'a dyn DependencyRepositoryAbstract) -> Self {
+        AddTaskDependencyRequest { task_id, blocker_id, actor, access_repository, repository }
+    }
+}
+
+#[async_trait(?Send)]
+impl<'a> AbstractRequest<()> for AddTaskDependencyRequest<'a> {
+    async fn execute(&self) -> Result<(), ApiError> {
+        AccessControl::authorize(self.actor, AccessScope::Task(self.task_id), Permission::Write, self.access_repository).await?;
+        let blocker_blockers = self.repository.get_blocker_ids(self.blocker_id).await
+            .map_err(|e| ErrorHandlingHelper::domain_error("Cannot add task dependency", e))?;
+        check_new_dependency(self.task_id, self.blocker_id, &blocker_blockers)
+            .map_err(|e| ErrorHandlingHelper::domain_error("Cannot add task dependency", e))?;
+
+        let result = self.repository.add_dependency(self.task_id, self.blocker_id).await;
+
+        match result {
+            Ok(()) => Ok(()),
+            Err(e) => Err(ErrorHandlingHelper::domain_error("Cannot add task dependency", e)),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use mockall::predicate::eq;
+    use crate::application::repositories::dependency_abstract_repo::MockDependencyRepositoryAbstract;
+    use crate::application::repositories::task_abstract_repo::MockTaskRepositoryAbstract;
+    use crate::domain::access::role::Role;
+
+    #[actix_rt::test]
+    async fn test_should_add_dependency() {
+        let mut dependency_repo = MockDependencyRepositoryAbstract::new();
+        dependency_repo.expect_get_blocker_ids()
+            .with(eq(2))
+            .times(1)
+            .returning(|_| Ok(vec![1]));
+        dependency_repo.expect_add_dependency()
+            .with(eq(3), eq(2))
+            .times(1)
+            .returning(|_, _| Ok(()));
+
+        let result = AddTaskDependencyRequest::new(3, 2, Actor::System, &MockTaskRepositoryAbstract::new(), &dependency_repo).execute().await;
+
+        assert!(result.is_ok());
+    }
+
+    #[actix_rt::test]
+    async fn test_should_reject_dependency_creating_cycle() {
+        let mut dependency_repo = MockDependencyRepositoryAbstract::new();
+        dependency_repo.expect_get_blocker_ids()
+            .with(eq(2))
+            .times(1)
+            .returning(|_| Ok(vec![1, 3]));
+        dependency_repo.expect_add_dependency().times(0);
+
+        let error = AddTaskDependencyRequest::new(3, 2, Actor::System, &MockTaskRepositoryAbstract::new(), &dependency_repo).execute().await.unwrap_err();
+
+        assert_eq!(409, error.code);
+        assert_eq!("Task 3 cannot be blocked by task 2: task 3 already blocks it, directly or transitively", error.message);
+    }
+
+    #[actix_rt::test]
+    async fn test_should_forbid_adding_dependency_for_viewer_and_guest() {
+        let mut task_repo = MockTaskRepositoryAbstract::new();
+        task_repo.expect_get_role()
+            .with(eq(3), eq(AccessScope::Task(3)))
+            .times(1)
+            .returning(|_, _| Ok(Some(Role::Viewer)));
+        let mut dependency_repo = MockDependencyRepositoryAbstract::new();
+        dependency_repo.expect_add_dependency().times(0);
+
+        for actor in [Actor::User(3), Actor::Guest] {
+            let error = AddTaskDependencyRequest::new(3, 2, actor, &task_repo, &dependency_repo).execute().await.unwrap_err();
+            assert_eq!(403, error.code);
+        }
+    }
+}
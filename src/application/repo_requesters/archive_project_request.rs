@@ -0,0 +1,92 @@
+use async_trait::async_trait;
+use chrono::Utc;
+use crate::application::helpers::access_control::AccessControl;
+use crate::application::helpers::error_handler::ErrorHandlingHelper;
+use crate::application::repo_requesters::abstract_request::AbstractRequest;
+use crate::application::repositories::project_abstract_repo::ProjectRepositoryAbstract;
+use crate::application::repositories::task_abstract_repo::TaskRepositoryAbstract;
+use crate::domain::access::actor::{AccessScope, Actor};
+use crate::domain::access::role::Permission;
+use crate::domain::error::ApiError;
+use crate::domain::project::project::Project;
+
+/// Отправляет проект в архив (`archived = true`) или возвращает его из архива; доступно администраторам проекта.
+pub struct ArchiveProjectRequest<'a> {
+    project_id: i32,
+    archived: bool,
+    actor: Actor,
+    access_repository: &'a dyn TaskRepositoryAbstract,
+    repository: &'a dyn ProjectRepositoryAbstract,
+}
+
+impl<'a> ArchiveProjectRequest<'a> {
+    pub fn new(project_id: i32, archived: bool, actor: Actor, access_repository: &'a dyn TaskRepositoryAbstract, repository: &'a dyn ProjectRepositoryAbstract) -> Self {
+        ArchiveProjectRequest { project_id, archived, actor, access_repository, repository }
+    }
+}
+
+#[async_trait(?Send)]
+impl<'a> AbstractRequest<Project> for ArchiveProjectRequest<'a> {
+    async fn execute(&self) -> Result<Project, ApiError> {
+        AccessControl::authorize(self.actor, AccessScope::Project(self.project_id), Permission::Manage, self.access_repository).await?;
+        let archived_at = self.archived.then(Utc::now);
+        let project = self.repository.set_project_archived(self.project_id, archived_at).await;
+
+        match project {
+            Ok(project) => Ok(project),
+            Err(e) => Err(ErrorHandlingHelper::domain_error("Cannot change project archive state", e)),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::{DateTime, Utc};
+    use mockall::predicate::{eq, function};
+    use crate::application::repositories::project_abstract_repo::MockProjectRepositoryAbstract;
+    use crate::application::repositories::task_abstract_repo::MockTaskRepositoryAbstract;
+    use crate::domain::access::role::Role;
+
+    #[actix_rt::test]
+    async fn test_should_archive_project_at_current_time() {
+        let mut project_repo = MockProjectRepositoryAbstract::new();
+        project_repo.expect_set_project_archived()
+            .with(eq(1), function(|archived_at: &Option<DateTime<Utc>>| archived_at.is_some()))
+            .times(1)
+            .returning(|id, archived_at| Ok(Project { archived_at, ..Project::new(id, "Backend".to_string(), None) }));
+
+        let project = ArchiveProjectRequest::new(1, true, Actor::System, &MockTaskRepositoryAbstract::new(), &project_repo).execute().await.unwrap();
+
+        assert!(project.is_archived());
+    }
+
+    #[actix_rt::test]
+    async fn test_should_unarchive_project() {
+        let mut project_repo = MockProjectRepositoryAbstract::new();
+        project_repo.expect_set_project_archived()
+            .with(eq(1), eq(None))
+            .times(1)
+            .returning(|id, _| Ok(Project::new(id, "Backend".to_string(), None)));
+
+        let project = ArchiveProjectRequest::new(1, false, Actor::System, &MockTaskRepositoryAbstract::new(), &project_repo).execute().await.unwrap();
+
+        assert!(!project.is_archived());
+    }
+
+    #[actix_rt::test]
+    async fn test_should_forbid_archiving_for_viewer_and_guest() {
+        let mut task_repo = MockTaskRepositoryAbstract::new();
+        task_repo.expect_get_role()
+            .with(eq(3), eq(AccessScope::Project(1)))
+            .times(1)
+            .returning(|_, _| Ok(Some(Role::Viewer)));
+        let mut project_repo = MockProjectRepositoryAbstract::new();
+        project_repo.expect_set_project_archived().times(0);
+
+        for actor in [Actor::User(3), Actor::Guest] {
+            let error = ArchiveProjectRequest::new(1, true, actor, &task_repo, &project_repo).execute().await.unwrap_err();
+            assert_eq!(403, error.code);
+        }
+    }
+}
@@ -0,0 +1,92 @@
+use async_trait::async_trait;
+use crate::application::helpers::access_control::AccessControl;
+use crate::application::helpers::error_handler::ErrorHandlingHelper;
+use crate::application::repo_requesters::abstract_request::AbstractRequest;
+use crate::application::repositories::user_abstract_repo::UserRepositoryAbstract;
+use crate::application::repositories::task_abstract_repo::TaskRepositoryAbstract;
+use crate::domain::access::actor::{AccessScope, Actor};
+use crate::domain::access::role::Permission;
+use crate::domain::error::ApiError;
+use crate::domain::task::task::Task;
+
+/// Назначает пользователя исполнителем задачи, при необходимости - ответственным.
+pub struct AssignTaskRequest<'a> {
+    task_id: i32,
+    user_id: i32,
+    primary: bool,
+    expected_version: Option<i32>,
+    actor: Actor,
+    access_repository: &'a dyn TaskRepositoryAbstract,
+    repository: &'a dyn UserRepositoryAbstract,
+}
+
+impl<'a> AssignTaskRequest<'a> {
+    pub fn new(task_id: i32, user_id: i32, primary: bool, expected_version: Option<i32>, actor: Actor, access_repository: &'a dyn TaskRepositoryAbstract, repository: &'a dyn UserRepositoryAbstract) -> Self {
+        AssignTaskRequest { task_id, user_id, primary, expected_version, actor, access_repository, repository }
+    }
+}
+
+#[async_trait(?Send)]
+impl<'a> AbstractRequest<Task> for AssignTaskRequest<'a> {
+    async fn execute(&self) -> Result<Task, ApiError> {
+        AccessControl::authorize(self.actor, AccessScope::Task(self.task_id), Permission::Write, self.access_repository).await?;
+        let task = self.repository.assign_user(self.task_id, self.user_id, self.primary, self.expected_version).await;
+
+        match task {
+            Ok(task) => Ok(task),
+            Err(e) => Err(ErrorHandlingHelper::domain_error("Cannot assign task", e)),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use mockall::predicate::eq;
+    use crate::application::repositories::user_abstract_repo::MockUserRepositoryAbstract;
+    use crate::application::repositories::task_abstract_repo::MockTaskRepositoryAbstract;
+    use crate::domain::access::role::Role;
+    use crate::domain::user::user::User;
+
+    #[actix_rt::test]
+    async fn test_should_assign_user_to_task() {
+        let mut user_repo = MockUserRepositoryAbstract::new();
+        user_repo.expect_assign_user()
+            .with(eq(5), eq(2), eq(true), eq(Some(3)))
+            .times(1)
+            .returning(|task_id, user_id, _, _| Ok(Task { assignee_id: Some(user_id), ..Task::new(task_id, "Review".to_string(), None) }));
+
+        let task = AssignTaskRequest::new(5, 2, true, Some(3), Actor::System, &MockTaskRepositoryAbstract::new(), &user_repo).execute().await.unwrap();
+
+        assert_eq!(task.assignee_id, Some(2));
+    }
+
+    #[actix_rt::test]
+    async fn test_should_return_not_found_for_unknown_user() {
+        let mut user_repo = MockUserRepositoryAbstract::new();
+        user_repo.expect_assign_user()
+            .times(1)
+            .returning(|_, user_id, _, _| Err(User::not_found(user_id)));
+
+        let error = AssignTaskRequest::new(5, 9, false, None, Actor::System, &MockTaskRepositoryAbstract::new(), &user_repo).execute().await.unwrap_err();
+
+        assert_eq!(404, error.code);
+        assert_eq!("User 9 not found", error.message);
+    }
+
+    #[actix_rt::test]
+    async fn test_should_forbid_assignment_for_viewer_and_guest() {
+        let mut task_repo = MockTaskRepositoryAbstract::new();
+        task_repo.expect_get_role()
+            .with(eq(3), eq(AccessScope::Task(5)))
+            .times(1)
+            .returning(|_, _| Ok(Some(Role::Viewer)));
+        let mut user_repo = MockUserRepositoryAbstract::new();
+        user_repo.expect_assign_user().times(0);
+
+        for actor in [Actor::User(3), Actor::Guest] {
+            let error = AssignTaskRequest::new(5, 2, false, None, actor, &task_repo, &user_repo).execute().await.unwrap_err();
+            assert_eq!(403, error.code);
+        }
+    }
+}
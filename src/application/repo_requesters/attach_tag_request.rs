@@ -0,0 +1,88 @@
+use async_trait::async_trait;
+use crate::application::helpers::access_control::AccessControl;
+use crate::application::helpers::error_handler::ErrorHandlingHelper;
+use crate::application::repo_requesters::abstract_request::AbstractRequest;
+use crate::application::repositories::tag_abstract_repo::TagRepositoryAbstract;
+use crate::application::repositories::task_abstract_repo::TaskRepositoryAbstract;
+use crate::domain::access::actor::{AccessScope, Actor};
+use crate::domain::access::role::Permission;
+use crate::domain::error::ApiError;
+
+pub struct AttachTagRequest<'a> {
+    task_id: i32,
+    tag_id: i32,
+    actor: Actor,
+    access_repository: &'a dyn TaskRepositoryAbstract,
+    repository: &'a dyn TagRepositoryAbstract,
+}
+
+impl<'a> AttachTagRequest<'a> {
+    pub fn new(task_id: i32, tag_id: i32, actor: Actor, access_repository: &'a dyn TaskRepositoryAbstract, repository: &'a dyn TagRepositoryAbstract) -> Self {
+        AttachTagRequest { task_id, tag_id, actor, access_repository, repository }
+    }
+}
+
+#[async_trait(?Send)]
+impl<'a> AbstractRequest<()> for AttachTagRequest<'a> {
+    async fn execute(&self) -> Result<(), ApiError> {
+        AccessControl::authorize(self.actor, AccessScope::Task(self.task_id), Permission::Write, self.access_repository).await?;
+        let result = self.repository.attach_tag(self.task_id, self.tag_id).await;
+
+        match result {
+            Ok(()) => Ok(()),
+            Err(e) => Err(ErrorHandlingHelper::domain_error("Cannot attach tag", e)),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use mockall::predicate::eq;
+    use crate::application::repositories::tag_abstract_repo::MockTagRepositoryAbstract;
+    use crate::application::repositories::task_abstract_repo::MockTaskRepositoryAbstract;
+    use crate::domain::access::role::Role;
+    use crate::domain::tag::tag::Tag;
+
+    #[actix_rt::test]
+    async fn test_should_attach_tag_to_task() {
+        let mut tag_repo = MockTagRepositoryAbstract::new();
+        tag_repo.expect_attach_tag()
+            .with(eq(5), eq(1))
+            .times(1)
+            .returning(|_, _| Ok(()));
+
+        let result = AttachTagRequest::new(5, 1, Actor::System, &MockTaskRepositoryAbstract::new(), &tag_repo).execute().await;
+
+        assert!(result.is_ok());
+    }
+
+    #[actix_rt::test]
+    async fn test_should_return_not_found_when_tag_does_not_exist() {
+        let mut tag_repo = MockTagRepositoryAbstract::new();
+        tag_repo.expect_attach_tag()
+            .times(1)
+            .returning(|_, tag_id| Err(Tag::not_found(tag_id)));
+
+        let error = AttachTagRequest::new(5, 9, Actor::System, &MockTaskRepositoryAbstract::new(), &tag_repo).execute().await.unwrap_err();
+
+        assert_eq!(404, error.code);
+        assert_eq!("Tag 9 not found", error.message);
+    }
+
+    #[actix_rt::test]
+    async fn test_should_forbid_attaching_tag_for_viewer_and_guest() {
+        let mut task_repo = MockTaskRepositoryAbstract::new();
+        task_repo.expect_get_role()
+            .with(eq(3), eq(AccessScope::Task(5)))
+            .times(1)
+            .returning(|_, _| Ok(Some(Role::Viewer)));
+        let mut tag_repo = MockTagRepositoryAbstract::new();
+        tag_repo.expect_attach_tag().times(0);
+
+        for actor in [Actor::User(3), Actor::Guest] {
+            let error = AttachTagRequest::new(5, 1, actor, &task_repo, &tag_repo).execute().await.unwrap_err();
+            assert_eq!(403, error.code);
+        }
+    }
+}
@@ -0,0 +1,68 @@
+use async_trait::async_trait;
+use chrono::Utc;
+use crate::application::helpers::api_key_secret::ApiKeySecret;
+use crate::application::helpers::error_handler::ErrorHandlingHelper;
+use crate::application::repo_requesters::abstract_request::AbstractRequest;
+use crate::application::repositories::api_key_abstract_repo::ApiKeyRepositoryAbstract;
+use crate::domain::access::api_key::ApiKey;
+use crate::domain::error::ApiError;
+
+/// Проверяет предъявленный ключ API и отмечает время его использования.
+///
+/// Неизвестный или отозванный ключ - 401.
+pub struct AuthenticateApiKeyRequest<'a> {
+    key: String,
+    repository: &'a dyn ApiKeyRepositoryAbstract,
+}
+
+impl<'a> AuthenticateApiKeyRequest<'a> {
+    pub fn new(key: String, repository: &'a dyn ApiKeyRepositoryAbstract) -> Self {
+        AuthenticateApiKeyRequest { key, repository }
+    }
+}
+
+#[async_trait(?Send)]
+impl<'a> AbstractRequest<ApiKey> for AuthenticateApiKeyRequest<'a> {
+    async fn execute(&self) -> Result<ApiKey, ApiError> {
+        let api_key = self.repository.use_api_key(ApiKeySecret::hash(&self.key), Utc::now()).await;
+
+        match api_key {
+            Ok(Some(api_key)) => Ok(api_key),
+            Ok(None) => Err(ErrorHandlingHelper::unauthorized_error()),
+            Err(e) => Err(ErrorHandlingHelper::domain_error("Cannot check API key", e)),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use mockall::predicate::{always, eq};
+    use crate::application::repositories::api_key_abstract_repo::MockApiKeyRepositoryAbstract;
+    use crate::domain::access::api_key::ApiKeyScope;
+
+    #[actix_rt::test]
+    async fn test_should_look_up_key_by_hash() {
+        let mut api_key_repo = MockApiKeyRepositoryAbstract::new();
+        api_key_repo.expect_use_api_key()
+            .with(eq(ApiKeySecret::hash("tm_valid")), always())
+            .returning(|_, used_at| Ok(Some(ApiKey {
+                id: 1,
+                name: "ci-bot".to_string(),
+                prefix: "tm_valid".to_string(),
+                scopes: vec![ApiKeyScope::ReadTasks],
+                created_at: used_at,
+                last_used_at: Some(used_at),
+                revoked_at: None,
+                workspace_id: 1,
+            })));
+        api_key_repo.expect_use_api_key()
+            .returning(|_, _| Ok(None));
+
+        let api_key = AuthenticateApiKeyRequest::new("tm_valid".to_string(), &api_key_repo).execute().await.unwrap();
+        let error = AuthenticateApiKeyRequest::new("tm_revoked".to_string(), &api_key_repo).execute().await.unwrap_err();
+
+        assert_eq!(api_key.name, "ci-bot");
+        assert_eq!(401, error.code);
+    }
+}
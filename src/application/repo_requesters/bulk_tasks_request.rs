@@ -0,0 +1,237 @@
+use async_trait::async_trait;
+use crate::application::helpers::access_control::AccessControl;
+use crate::application::helpers::error_handler::ErrorHandlingHelper;
+use crate::application::repo_requesters::abstract_request::AbstractRequest;
+use crate::application::repositories::task_abstract_repo::TaskRepositoryAbstract;
+use crate::application::validation::task_rules::{check_estimate, check_schedule_dates, DESCRIPTION_RULES, TITLE_RULES};
+use crate::application::validation::validator::Validator;
+use crate::domain::access::actor::{AccessScope, Actor};
+use crate::domain::access::role::Permission;
+use crate::domain::error::{ApiError, DomainError, FieldError};
+use crate::domain::task::task_bulk::{BulkMode, BulkOperation, BulkOutcome, MAX_BULK_OPERATIONS};
+
+/// Результат операции пакета с её порядковым номером в запросе.
+#[derive(Debug)]
+pub struct BulkItemResult {
+    pub index: usize,
+    pub result: Result<BulkOutcome, ApiError>,
+}
+
+pub struct BulkTasksRequest<'a> {
+    operations: Vec<BulkOperation>,
+    mode: BulkMode,
+    actor: Actor,
+    repository: &'a dyn TaskRepositoryAbstract,
+}
+
+impl<'a> BulkTasksRequest<'a> {
+    pub fn new(operations: Vec<BulkOperation>, mode: BulkMode, actor: Actor, repository: &'a dyn TaskRepositoryAbstract) -> Self {
+        BulkTasksRequest { operations, mode, actor, repository }
+    }
+
+    /// Проверяет и нормализует заголовок, описание, сроки и оценку операции по тем же правилам, что и для одиночных запросов.
+    fn validate_operation(operation: BulkOperation) -> Result<BulkOperation, ApiError> {
+        let mut validator = Validator::new();
+        let operation = match operation {
+            BulkOperation::Create { title, description, status, schedule } => BulkOperation::Create {
+                title: validator.string("title", title, &TITLE_RULES),
+                description: validator.optional_string("description", description, &DESCRIPTION_RULES),
+                status,
+                schedule,
+            },
+            BulkOperation::Update { task_id, title, description, status, schedule, expected_version } => BulkOperation::Update {
+                task_id,
+                title: validator.string("title", title, &TITLE_RULES),
+                description: validator.optional_string("description", description, &DESCRIPTION_RULES),
+                status,
+                schedule,
+                expected_version,
+            },
+            delete @ BulkOperation::Delete { .. } => delete,
+        };
+        if let BulkOperation::Create { schedule, .. } | BulkOperation::Update { schedule, .. } = &operation {
+            check_schedule_dates(&mut validator, schedule.start_at, schedule.due_at);
+            check_estimate(&mut validator, schedule.estimate_minutes);
+        }
+
+        validator.finish(operation)
+    }
+
+    /// Ошибка операции `index`, отменившей пакет в режиме `AllOrNothing`.
+    fn operation_error(index: usize, error: ApiError) -> ApiError {
+        let message = format!("Operation {} failed: {}", index, error.message);
+        ApiError {
+            errors: vec![FieldError::new(&format!("/operations/{}", index), "operation_failed", &error.message)],
+            message,
+            ..error
+        }
+    }
+}
+
+#[async_trait(?Send)]
+impl<'a> AbstractRequest<Vec<BulkItemResult>> for BulkTasksRequest<'a> {
+    async fn execute(&self) -> Result<Vec<BulkItemResult>, ApiError> {
+        if self.operations.is_empty() {
+            return Err(ErrorHandlingHelper::validation_error(vec![
+                FieldError::new("operations", "required", "at least one operation is required"),
+            ]));
+        }
+        if self.operations.len() > MAX_BULK_OPERATIONS {
+            return Err(ErrorHandlingHelper::validation_error(vec![
+                FieldError::new("operations", "too_many", &format!("at most {} operations are allowed", MAX_BULK_OPERATIONS)),
+            ]));
+        }
+
+        let mut checked = self.operations.iter().cloned()
+            .map(BulkTasksRequest::validate_operation)
+            .collect::<Vec<_>>();
+
+        if self.mode == BulkMode::AllOrNothing && checked.iter().any(|operation| operation.is_err()) {
+            let errors = checked.into_iter().enumerate()
+                .filter_map(|(index, operation)| operation.err().map(|error| (index, error)))
+                .flat_map(|(index, error)| error.errors.into_iter().map(move |field_error| FieldError {
+                    field: format!("/operations/{}/{}", index, field_error.field),
+                    ..field_error
+                }))
+                .collect();
+            return Err(ErrorHandlingHelper::validation_error(errors));
+        }
+
+        for (index, operation) in checked.iter_mut().enumerate() {
+            let (scope, permission) = match operation {
+                Ok(BulkOperation::Create { .. }) => (AccessScope::Global, Permission::Write),
+                Ok(BulkOperation::Update { task_id, .. }) => (AccessScope::Task(*task_id), Permission::Write),
+                Ok(BulkOperation::Delete { task_id, .. }) => (AccessScope::Task(*task_id), Permission::Delete),
+                Err(_) => continue,
+            };
+            if let Err(error) = AccessControl::authorize(self.actor, scope, permission, self.repository).await {
+                if self.mode == BulkMode::AllOrNothing {
+                    return Err(BulkTasksRequest::operation_error(index, error));
+                }
+                *operation = Err(error);
+            }
+        }
+
+        let (valid_indices, valid_operations): (Vec<usize>, Vec<BulkOperation>) = checked.iter().enumerate()
+            .filter_map(|(index, operation)| operation.as_ref().ok().map(|operation| (index, operation.clone())))
+            .unzip();
+        let written = self.repository.bulk_write(valid_operations, self.mode).await
+            .map_err(|e| ErrorHandlingHelper::domain_error("Cannot execute bulk operations", e))?;
+
+        let mut written = valid_indices.into_iter().zip(written);
+        let mut results = Vec::with_capacity(checked.len());
+        for (index, operation) in checked.into_iter().enumerate() {
+            let result = match operation {
+                Err(error) => Err(error),
+                Ok(_) => match written.next() {
+                    Some((_, Ok(outcome))) => Ok(outcome),
+                    Some((_, Err(e))) if self.mode == BulkMode::AllOrNothing => {
+                        let error = ErrorHandlingHelper::domain_error("Cannot execute bulk operations", e);
+                        return Err(BulkTasksRequest::operation_error(index, error));
+                    },
+                    Some((_, Err(e))) => Err(ErrorHandlingHelper::domain_error("Cannot execute bulk operation", e)),
+                    None => Err(ErrorHandlingHelper::domain_error(
+                        "Cannot execute bulk operations",
+                        DomainError::Internal(format!("Operation {} was not executed", index)),
+                    )),
+                },
+            };
+            results.push(BulkItemResult { index, result });
+        }
+
+        Ok(results)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use mockall::predicate::*;
+    use crate::application::repositories::task_abstract_repo::MockTaskRepositoryAbstract;
+    use crate::domain::task::task::Task;
+    use crate::domain::task::task_schedule::TaskSchedule;
+    use crate::domain::task::task_status::TaskStatus;
+
+    fn create(title: &str) -> BulkOperation {
+        BulkOperation::Create { title: title.to_string(), description: None, status: TaskStatus::Todo, schedule: TaskSchedule::default() }
+    }
+
+    #[actix_rt::test]
+    async fn test_should_execute_normalized_operations_in_order() {
+        let mut task_repo = MockTaskRepositoryAbstract::new();
+        let expected = vec![create("First"), BulkOperation::Delete { task_id: 7, expected_version: Some(2) }];
+        task_repo.expect_bulk_write()
+            .with(eq(expected), eq(BulkMode::AllOrNothing))
+            .times(1)
+            .returning(|_, _| Ok(vec![
+                Ok(BulkOutcome::Created(Task::new(1, "First".to_string(), None))),
+                Ok(BulkOutcome::Deleted(7)),
+            ]));
+
+        let operations = vec![create("  First "), BulkOperation::Delete { task_id: 7, expected_version: Some(2) }];
+        let results = BulkTasksRequest::new(operations, BulkMode::AllOrNothing, Actor::System, &task_repo).execute().await.unwrap();
+
+        assert_eq!(results.iter().map(|item| item.index).collect::<Vec<_>>(), vec![0, 1]);
+        assert_eq!(results[1].result.as_ref().unwrap(), &BulkOutcome::Deleted(7));
+    }
+
+    #[actix_rt::test]
+    async fn test_should_reject_whole_batch_with_invalid_operation_in_all_or_nothing_mode() {
+        let mut task_repo = MockTaskRepositoryAbstract::new();
+        task_repo.expect_bulk_write().times(0);
+
+        let operations = vec![create("Valid"), create(" "), create("\u{7}")];
+        let error = BulkTasksRequest::new(operations, BulkMode::AllOrNothing, Actor::System, &task_repo).execute().await.unwrap_err();
+
+        assert_eq!(error.code, 422);
+        let fields = error.errors.iter().map(|e| e.field.as_str()).collect::<Vec<_>>();
+        assert_eq!(fields, vec!["/operations/1/title", "/operations/2/title"]);
+    }
+
+    #[actix_rt::test]
+    async fn test_should_report_failed_operation_in_all_or_nothing_mode() {
+        let mut task_repo = MockTaskRepositoryAbstract::new();
+        task_repo.expect_bulk_write()
+            .times(1)
+            .returning(|_, _| Ok(vec![
+                Ok(BulkOutcome::Created(Task::new(1, "First".to_string(), None))),
+                Err(DomainError::NotFound("Task 9 not found".to_string())),
+            ]));
+
+        let operations = vec![create("First"), BulkOperation::Delete { task_id: 9, expected_version: None }, create("Last")];
+        let error = BulkTasksRequest::new(operations, BulkMode::AllOrNothing, Actor::System, &task_repo).execute().await.unwrap_err();
+
+        assert_eq!(error.code, 404);
+        assert_eq!(error.message, "Operation 1 failed: Task 9 not found");
+        assert_eq!(error.errors[0].field, "/operations/1");
+    }
+
+    #[actix_rt::test]
+    async fn test_should_skip_invalid_and_failed_operations_in_best_effort_mode() {
+        let mut task_repo = MockTaskRepositoryAbstract::new();
+        task_repo.expect_bulk_write()
+            .with(eq(vec![create("First"), create("Third")]), eq(BulkMode::BestEffort))
+            .times(1)
+            .returning(|_, _| Ok(vec![
+                Ok(BulkOutcome::Created(Task::new(1, "First".to_string(), None))),
+                Err(DomainError::Conflict("duplicate".to_string())),
+            ]));
+
+        let operations = vec![create("First"), create(""), create("Third")];
+        let results = BulkTasksRequest::new(operations, BulkMode::BestEffort, Actor::System, &task_repo).execute().await.unwrap();
+
+        let codes = results.iter().map(|item| item.result.as_ref().map(|_| 200).unwrap_or_else(|e| e.code)).collect::<Vec<_>>();
+        assert_eq!(codes, vec![200, 422, 409]);
+    }
+
+    #[actix_rt::test]
+    async fn test_should_reject_empty_batch() {
+        let mut task_repo = MockTaskRepositoryAbstract::new();
+        task_repo.expect_bulk_write().times(0);
+
+        let error = BulkTasksRequest::new(vec![], BulkMode::BestEffort, Actor::System, &task_repo).execute().await.unwrap_err();
+
+        assert_eq!(error.code, 422);
+        assert_eq!(error.errors[0].field, "operations");
+    }
+}
@@ -0,0 +1,89 @@
+use async_trait::async_trait;
+use crate::application::helpers::access_control::AccessControl;
+use crate::application::helpers::error_handler::ErrorHandlingHelper;
+use crate::application::repo_requesters::abstract_request::AbstractRequest;
+use crate::application::repositories::project_abstract_repo::ProjectRepositoryAbstract;
+use crate::application::repositories::task_abstract_repo::TaskRepositoryAbstract;
+use crate::domain::access::actor::{AccessScope, Actor};
+use crate::domain::access::role::Permission;
+use crate::domain::error::ApiError;
+use crate::domain::project::project::Project;
+
+pub struct CreateProjectRequest<'a> {
+    name: String,
+    description: Option<String>,
+    actor: Actor,
+    access_repository: &'a dyn TaskRepositoryAbstract,
+    repository: &'a dyn ProjectRepositoryAbstract,
+}
+
+impl<'a> CreateProjectRequest<'a> {
+    pub fn new(name: String, description: Option<String>, actor: Actor, access_repository: &'a dyn TaskRepositoryAbstract, repository: &'a dyn ProjectRepositoryAbstract) -> Self {
+        CreateProjectRequest { name, description, actor, access_repository, repository }
+    }
+}
+
+#[async_trait(?Send)]
+impl<'a> AbstractRequest<Project> for CreateProjectRequest<'a> {
+    async fn execute(&self) -> Result<Project, ApiError> {
+        AccessControl::authorize(self.actor, AccessScope::Global, Permission::Write, self.access_repository).await?;
+        let project = self.repository.create_project(self.name.clone(), self.description.clone()).await;
+
+        match project {
+            Ok(project) => Ok(project),
+            Err(e) => Err(ErrorHandlingHelper::domain_error("Cannot create project", e)),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use mockall::predicate::eq;
+    use crate::application::repositories::project_abstract_repo::MockProjectRepositoryAbstract;
+    use crate::application::repositories::task_abstract_repo::MockTaskRepositoryAbstract;
+    use crate::domain::access::role::Role;
+    use crate::domain::error::DomainError;
+
+    #[actix_rt::test]
+    async fn test_should_create_project_successfully() {
+        let mut project_repo = MockProjectRepositoryAbstract::new();
+        project_repo.expect_create_project()
+            .with(eq("Backend".to_string()), eq(None))
+            .times(1)
+            .returning(|name, description| Ok(Project::new(1, name, description)));
+
+        let project = CreateProjectRequest::new("Backend".to_string(), None, Actor::System, &MockTaskRepositoryAbstract::new(), &project_repo).execute().await.unwrap();
+
+        assert_eq!(project.id, 1);
+        assert!(!project.is_archived());
+    }
+
+    #[actix_rt::test]
+    async fn test_should_return_error_when_repo_fails() {
+        let mut project_repo = MockProjectRepositoryAbstract::new();
+        project_repo.expect_create_project()
+            .times(1)
+            .returning(|_, _| Err(DomainError::Internal("disk I/O error".to_string())));
+
+        let error = CreateProjectRequest::new("Backend".to_string(), None, Actor::System, &MockTaskRepositoryAbstract::new(), &project_repo).execute().await.unwrap_err();
+
+        assert_eq!(500, error.code);
+    }
+
+    #[actix_rt::test]
+    async fn test_should_forbid_project_creation_for_viewer_and_guest() {
+        let mut task_repo = MockTaskRepositoryAbstract::new();
+        task_repo.expect_get_role()
+            .with(eq(3), eq(AccessScope::Global))
+            .times(1)
+            .returning(|_, _| Ok(Some(Role::Viewer)));
+        let mut project_repo = MockProjectRepositoryAbstract::new();
+        project_repo.expect_create_project().times(0);
+
+        for actor in [Actor::User(3), Actor::Guest] {
+            let error = CreateProjectRequest::new("Backend".to_string(), None, actor, &task_repo, &project_repo).execute().await.unwrap_err();
+            assert_eq!(403, error.code);
+        }
+    }
+}
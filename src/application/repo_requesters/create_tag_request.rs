@@ -0,0 +1,89 @@
+use async_trait::async_trait;
+use crate::application::helpers::access_control::AccessControl;
+use crate::application::helpers::error_handler::ErrorHandlingHelper;
+use crate::application::repo_requesters::abstract_request::AbstractRequest;
+use crate::application::repositories::tag_abstract_repo::TagRepositoryAbstract;
+use crate::application::repositories::task_abstract_repo::TaskRepositoryAbstract;
+use crate::domain::access::actor::{AccessScope, Actor};
+use crate::domain::access::role::Permission;
+use crate::domain::error::ApiError;
+use crate::domain::tag::tag::Tag;
+
+pub struct CreateTagRequest<'a> {
+    name: String,
+    actor: Actor,
+    access_repository: &'a dyn TaskRepositoryAbstract,
+    repository: &'a dyn TagRepositoryAbstract,
+}
+
+impl<'a> CreateTagRequest<'a> {
+    pub fn new(name: String, actor: Actor, access_repository: &'a dyn TaskRepositoryAbstract, repository: &'a dyn TagRepositoryAbstract) -> Self {
+        CreateTagRequest { name, actor, access_repository, repository }
+    }
+}
+
+#[async_trait(?Send)]
+impl<'a> AbstractRequest<Tag> for CreateTagRequest<'a> {
+    async fn execute(&self) -> Result<Tag, ApiError> {
+        AccessControl::authorize(self.actor, AccessScope::Global, Permission::Write, self.access_repository).await?;
+        let tag = self.repository.create_tag(self.name.clone()).await;
+
+        match tag {
+            Ok(tag) => Ok(tag),
+            Err(e) => Err(ErrorHandlingHelper::domain_error("Cannot create tag", e)),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use mockall::predicate::eq;
+    use crate::application::repositories::tag_abstract_repo::MockTagRepositoryAbstract;
+    use crate::application::repositories::task_abstract_repo::MockTaskRepositoryAbstract;
+    use crate::domain::access::role::Role;
+    use crate::domain::error::DomainError;
+
+    #[actix_rt::test]
+    async fn test_should_create_tag_successfully() {
+        let mut tag_repo = MockTagRepositoryAbstract::new();
+        tag_repo.expect_create_tag()
+            .with(eq("backend".to_string()))
+            .times(1)
+            .returning(|name| Ok(Tag::new(1, name)));
+
+        let tag = CreateTagRequest::new("backend".to_string(), Actor::System, &MockTaskRepositoryAbstract::new(), &tag_repo).execute().await.unwrap();
+
+        assert_eq!(tag.id, 1);
+        assert_eq!(tag.name, "backend");
+    }
+
+    #[actix_rt::test]
+    async fn test_should_return_conflict_when_name_is_taken() {
+        let mut tag_repo = MockTagRepositoryAbstract::new();
+        tag_repo.expect_create_tag()
+            .times(1)
+            .returning(|name| Err(DomainError::Conflict(format!("Tag '{}' already exists", name))));
+
+        let error = CreateTagRequest::new("Backend".to_string(), Actor::System, &MockTaskRepositoryAbstract::new(), &tag_repo).execute().await.unwrap_err();
+
+        assert_eq!(409, error.code);
+        assert_eq!("Tag 'Backend' already exists", error.message);
+    }
+
+    #[actix_rt::test]
+    async fn test_should_forbid_tag_creation_for_viewer_and_guest() {
+        let mut task_repo = MockTaskRepositoryAbstract::new();
+        task_repo.expect_get_role()
+            .with(eq(3), eq(AccessScope::Global))
+            .times(1)
+            .returning(|_, _| Ok(Some(Role::Viewer)));
+        let mut tag_repo = MockTagRepositoryAbstract::new();
+        tag_repo.expect_create_tag().times(0);
+
+        for actor in [Actor::User(3), Actor::Guest] {
+            let error = CreateTagRequest::new("backend".to_string(), actor, &task_repo, &tag_repo).execute().await.unwrap_err();
+            assert_eq!(403, error.code);
+        }
+    }
+}
@@ -1,30 +1,41 @@
 use async_trait::async_trait;
+use crate::application::helpers::access_control::AccessControl;
 use crate::application::helpers::error_handler::ErrorHandlingHelper;
 use crate::application::repo_requesters::abstract_request::AbstractRequest;
 use crate::application::repositories::task_abstract_repo::TaskRepositoryAbstract;
+use crate::domain::access::actor::{AccessScope, Actor};
+use crate::domain::access::role::Permission;
 use crate::domain::error::ApiError;
 use crate::domain::task::task::Task;
+use crate::domain::task::task_placement::TaskPlacement;
+use crate::domain::task::task_schedule::TaskSchedule;
+use crate::domain::task::task_status::TaskStatus;
 
 pub struct CreateTaskRequest<'a> {
     title: String,
     description: Option<String>,
+    status: TaskStatus,
+    schedule: TaskSchedule,
+    placement: TaskPlacement,
+    actor: Actor,
     repository: &'a dyn TaskRepositoryAbstract,
 }
 
 impl<'a> CreateTaskRequest<'a> {
-    pub fn new(title: String, description: Option<String>, repository: &'a dyn TaskRepositoryAbstract) -> Self {
-        CreateTaskRequest { title, description, repository }
+    pub fn new(title: String, description: Option<String>, status: TaskStatus, schedule: TaskSchedule, placement: TaskPlacement, actor: Actor, repository: &'a dyn TaskRepositoryAbstract) -> Self {
+        CreateTaskRequest { title, description, status, schedule, placement, actor, repository }
     }
 }
 
 #[async_trait(?Send)]
 impl<'a> AbstractRequest<Task> for CreateTaskRequest<'a> {
     async fn execute(&self) -> Result<Task, ApiError> {
-        let task = self.repository.create_task(self.title.clone(), self.description.clone()).await;
+        AccessControl::authorize(self.actor, AccessScope::for_placement(self.placement), Permission::Write, self.repository).await?;
+        let task = self.repository.create_task(self.title.clone(), self.description.clone(), self.status, self.schedule, self.placement).await;
 
         match task {
             Ok(task) => Ok(task),
-            Err(e) => Err(ErrorHandlingHelper::application_error("Cannot create task", Some(e))),
+            Err(e) => Err(ErrorHandlingHelper::domain_error("Cannot create task", e)),
         }
     }
 }
@@ -33,7 +44,7 @@ impl<'a> AbstractRequest<Task> for CreateTaskRequest<'a> {
 mod tests {
     use super::*;
     use mockall::predicate::*;
-    use std::io::{Error, ErrorKind};
+    use crate::domain::error::DomainError;
     use crate::{application::repositories::task_abstract_repo::MockTaskRepositoryAbstract, domain::task::task::Task};
 
     #[actix_rt::test]
@@ -43,11 +54,11 @@ mod tests {
         let description = Some("This is a new task".to_string());
         let expected_task = Task::new(1, title.clone(), description.clone());
         task_repo.expect_create_task()
-            .with(eq(title.clone()), eq(description.clone()))
+            .with(eq(title.clone()), eq(description.clone()), eq(TaskStatus::Todo), eq(TaskSchedule::default()), eq(TaskPlacement::default()))
             .times(1)
-            .returning(move |_, _| Ok(expected_task.clone()));
+            .returning(move |_, _, _, _, _| Ok(expected_task.clone()));
 
-        let create_task_request = CreateTaskRequest::new(title, description, &task_repo);
+        let create_task_request = CreateTaskRequest::new(title, description, TaskStatus::Todo, TaskSchedule::default(), TaskPlacement::default(), Actor::System, &task_repo);
         let result = create_task_request.execute().await;
 
         assert!(result.is_ok());
@@ -55,6 +66,7 @@ mod tests {
         assert_eq!(task.id, 1);
         assert_eq!(task.title, "New Task");
         assert_eq!(task.description, Some("This is a new task".to_string()));
+        assert_eq!(task.status, TaskStatus::Todo);
     }
 
     #[actix_rt::test]
@@ -63,15 +75,16 @@ mod tests {
         let title = "Failed Task".to_string();
         let description = Some("This task should fail".to_string());
         task_repo.expect_create_task()
-            .with(eq(title.clone()), eq(description.clone()))
+            .with(eq(title.clone()), eq(description.clone()), eq(TaskStatus::Todo), eq(TaskSchedule::default()), eq(TaskPlacement::default()))
             .times(1)
-            .returning(|_, _| Err(Box::new(Error::new(ErrorKind::Other, "Database error"))));
+            .returning(|_, _, _, _, _| Err(DomainError::Internal("Database error".to_string())));
 
-        let create_task_request = CreateTaskRequest::new(title, description, &task_repo);
+        let create_task_request = CreateTaskRequest::new(title, description, TaskStatus::Todo, TaskSchedule::default(), TaskPlacement::default(), Actor::System, &task_repo);
         let result = create_task_request.execute().await;
 
         assert!(result.is_err());
         let error = result.unwrap_err();
+        assert_eq!(500, error.code);
         assert_eq!("Cannot create task", error.message);
     }
 }
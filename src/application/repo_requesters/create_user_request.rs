@@ -0,0 +1,90 @@
+use async_trait::async_trait;
+use crate::application::helpers::access_control::AccessControl;
+use crate::application::helpers::error_handler::ErrorHandlingHelper;
+use crate::application::repo_requesters::abstract_request::AbstractRequest;
+use crate::application::repositories::user_abstract_repo::UserRepositoryAbstract;
+use crate::application::repositories::task_abstract_repo::TaskRepositoryAbstract;
+use crate::domain::access::actor::{AccessScope, Actor};
+use crate::domain::access::role::Permission;
+use crate::domain::error::ApiError;
+use crate::domain::user::user::User;
+
+/// Регистрирует пользователя; доступно глобальным администраторам.
+pub struct CreateUserRequest<'a> {
+    name: String,
+    email: String,
+    actor: Actor,
+    access_repository: &'a dyn TaskRepositoryAbstract,
+    repository: &'a dyn UserRepositoryAbstract,
+}
+
+impl<'a> CreateUserRequest<'a> {
+    pub fn new(name: String, email: String, actor: Actor, access_repository: &'a dyn TaskRepositoryAbstract, repository: &'a dyn UserRepositoryAbstract) -> Self {
+        CreateUserRequest { name, email, actor, access_repository, repository }
+    }
+}
+
+#[async_trait(?Send)]
+impl<'a> AbstractRequest<User> for CreateUserRequest<'a> {
+    async fn execute(&self) -> Result<User, ApiError> {
+        AccessControl::authorize(self.actor, AccessScope::Global, Permission::Manage, self.access_repository).await?;
+        let user = self.repository.create_user(self.name.clone(), self.email.clone()).await;
+
+        match user {
+            Ok(user) => Ok(user),
+            Err(e) => Err(ErrorHandlingHelper::domain_error("Cannot create user", e)),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use mockall::predicate::eq;
+    use crate::application::repositories::user_abstract_repo::MockUserRepositoryAbstract;
+    use crate::application::repositories::task_abstract_repo::MockTaskRepositoryAbstract;
+    use crate::domain::access::role::Role;
+    use crate::domain::error::DomainError;
+
+    #[actix_rt::test]
+    async fn test_should_create_user_successfully() {
+        let mut user_repo = MockUserRepositoryAbstract::new();
+        user_repo.expect_create_user()
+            .with(eq("Ada".to_string()), eq("ada@example.com".to_string()))
+            .times(1)
+            .returning(|name, email| Ok(User::new(1, name, email)));
+
+        let user = CreateUserRequest::new("Ada".to_string(), "ada@example.com".to_string(), Actor::System, &MockTaskRepositoryAbstract::new(), &user_repo).execute().await.unwrap();
+
+        assert_eq!(user, User::new(1, "Ada".to_string(), "ada@example.com".to_string()));
+    }
+
+    #[actix_rt::test]
+    async fn test_should_return_conflict_when_email_is_taken() {
+        let mut user_repo = MockUserRepositoryAbstract::new();
+        user_repo.expect_create_user()
+            .times(1)
+            .returning(|_, email| Err(DomainError::Conflict(format!("User with email '{}' already exists", email))));
+
+        let error = CreateUserRequest::new("Ada".to_string(), "ADA@example.com".to_string(), Actor::System, &MockTaskRepositoryAbstract::new(), &user_repo).execute().await.unwrap_err();
+
+        assert_eq!(409, error.code);
+        assert_eq!("User with email 'ADA@example.com' already exists", error.message);
+    }
+
+    #[actix_rt::test]
+    async fn test_should_forbid_user_creation_for_viewer_and_guest() {
+        let mut task_repo = MockTaskRepositoryAbstract::new();
+        task_repo.expect_get_role()
+            .with(eq(3), eq(AccessScope::Global))
+            .times(1)
+            .returning(|_, _| Ok(Some(Role::Viewer)));
+        let mut user_repo = MockUserRepositoryAbstract::new();
+        user_repo.expect_create_user().times(0);
+
+        for actor in [Actor::User(3), Actor::Guest] {
+            let error = CreateUserRequest::new("Ada".to_string(), "ada@example.com".to_string(), actor, &task_repo, &user_repo).execute().await.unwrap_err();
+            assert_eq!(403, error.code);
+        }
+    }
+}
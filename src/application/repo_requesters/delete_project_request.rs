@@ -0,0 +1,89 @@
+use async_trait::async_trait;
+use crate::application::helpers::access_control::AccessControl;
+use crate::application::helpers::error_handler::ErrorHandlingHelper;
+use crate::application::repo_requesters::abstract_request::AbstractRequest;
+use crate::application::repositories::project_abstract_repo::ProjectRepositoryAbstract;
+use crate::application::repositories::task_abstract_repo::TaskRepositoryAbstract;
+use crate::domain::access::actor::{AccessScope, Actor};
+use crate::domain::access::role::Permission;
+use crate::domain::error::ApiError;
+use crate::domain::project::project::ProjectTasksPolicy;
+
+/// Удаляет проект, поступая с его задачами по `policy`; доступно администраторам проекта.
+pub struct DeleteProjectRequest<'a> {
+    project_id: i32,
+    policy: ProjectTasksPolicy,
+    actor: Actor,
+    access_repository: &'a dyn TaskRepositoryAbstract,
+    repository: &'a dyn ProjectRepositoryAbstract,
+}
+
+impl<'a> DeleteProjectRequest<'a> {
+    pub fn new(project_id: i32, policy: ProjectTasksPolicy, actor: Actor, access_repository: &'a dyn TaskRepositoryAbstract, repository: &'a dyn ProjectRepositoryAbstract) -> Self {
+        DeleteProjectRequest { project_id, policy, actor, access_repository, repository }
+    }
+}
+
+#[async_trait(?Send)]
+impl<'a> AbstractRequest<()> for DeleteProjectRequest<'a> {
+    async fn execute(&self) -> Result<(), ApiError> {
+        AccessControl::authorize(self.actor, AccessScope::Project(self.project_id), Permission::Delete, self.access_repository).await?;
+        let result = self.repository.delete_project(self.project_id, self.policy).await;
+
+        match result {
+            Ok(()) => Ok(()),
+            Err(e) => Err(ErrorHandlingHelper::domain_error("Cannot delete project", e)),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use mockall::predicate::eq;
+    use crate::application::repositories::project_abstract_repo::MockProjectRepositoryAbstract;
+    use crate::application::repositories::task_abstract_repo::MockTaskRepositoryAbstract;
+    use crate::domain::access::role::Role;
+    use crate::domain::error::DomainError;
+
+    #[actix_rt::test]
+    async fn test_should_delete_project_with_requested_policy() {
+        let mut project_repo = MockProjectRepositoryAbstract::new();
+        project_repo.expect_delete_project()
+            .with(eq(1), eq(ProjectTasksPolicy::Cascade))
+            .times(1)
+            .returning(|_, _| Ok(()));
+
+        let result = DeleteProjectRequest::new(1, ProjectTasksPolicy::Cascade, Actor::System, &MockTaskRepositoryAbstract::new(), &project_repo).execute().await;
+
+        assert!(result.is_ok());
+    }
+
+    #[actix_rt::test]
+    async fn test_should_return_conflict_when_project_has_tasks() {
+        let mut project_repo = MockProjectRepositoryAbstract::new();
+        project_repo.expect_delete_project()
+            .times(1)
+            .returning(|id, _| Err(DomainError::Conflict(format!("Project {} still has 2 tasks", id))));
+
+        let error = DeleteProjectRequest::new(1, ProjectTasksPolicy::Restrict, Actor::System, &MockTaskRepositoryAbstract::new(), &project_repo).execute().await.unwrap_err();
+
+        assert_eq!(409, error.code);
+    }
+
+    #[actix_rt::test]
+    async fn test_should_forbid_project_deletion_for_viewer_and_guest() {
+        let mut task_repo = MockTaskRepositoryAbstract::new();
+        task_repo.expect_get_role()
+            .with(eq(3), eq(AccessScope::Project(1)))
+            .times(1)
+            .returning(|_, _| Ok(Some(Role::Viewer)));
+        let mut project_repo = MockProjectRepositoryAbstract::new();
+        project_repo.expect_delete_project().times(0);
+
+        for actor in [Actor::User(3), Actor::Guest] {
+            let error = DeleteProjectRequest::new(1, ProjectTasksPolicy::Cascade, actor, &task_repo, &project_repo).execute().await.unwrap_err();
+            assert_eq!(403, error.code);
+        }
+    }
+}
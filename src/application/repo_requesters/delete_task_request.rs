@@ -1,28 +1,34 @@
 use async_trait::async_trait;
+use crate::application::helpers::access_control::AccessControl;
 use crate::application::helpers::error_handler::ErrorHandlingHelper;
 use crate::application::repo_requesters::abstract_request::AbstractRequest;
 use crate::application::repositories::task_abstract_repo::TaskRepositoryAbstract;
+use crate::domain::access::actor::{AccessScope, Actor};
+use crate::domain::access::role::Permission;
 use crate::domain::error::ApiError;
 
 pub struct DeleteTaskRequest<'a> {
     task_id: i32,
+    if_match: Option<i32>,
+    actor: Actor,
     repository: &'a dyn TaskRepositoryAbstract,
 }
 
 impl<'a> DeleteTaskRequest<'a> {
-    pub fn new(task_id: i32, repository: &'a dyn TaskRepositoryAbstract) -> Self {
-        DeleteTaskRequest { task_id, repository }
+    pub fn new(task_id: i32, if_match: Option<i32>, actor: Actor, repository: &'a dyn TaskRepositoryAbstract) -> Self {
+        DeleteTaskRequest { task_id, if_match, actor, repository }
     }
 }
 
 #[async_trait(?Send)]
 impl<'a> AbstractRequest<()> for DeleteTaskRequest<'a> {
     async fn execute(&self) -> Result<(), ApiError> {
-        let result = self.repository.delete_task(self.task_id).await;
+        AccessControl::authorize(self.actor, AccessScope::Task(self.task_id), Permission::Delete, self.repository).await?;
+        let result = self.repository.delete_task(self.task_id, self.if_match).await;
 
         match result {
             Ok(_) => Ok(()),
-            Err(e) => Err(ErrorHandlingHelper::application_error("task not found", Some(e))),
+            Err(e) => Err(ErrorHandlingHelper::domain_error("Cannot delete task", e)),
         }
     }
 }
@@ -31,19 +37,20 @@ impl<'a> AbstractRequest<()> for DeleteTaskRequest<'a> {
 mod tests {
     use super::*;
     use mockall::predicate::*;
-    use std::io::{Error, ErrorKind};
-    use crate::{application::repositories::task_abstract_repo::MockTaskRepositoryAbstract, domain::error::ApiError};
+    use crate::domain::access::role::Role;
+    use crate::domain::error::DomainError;
+    use crate::application::repositories::task_abstract_repo::MockTaskRepositoryAbstract;
 
     #[actix_rt::test]
     async fn test_should_delete_task_successfully() {
         let mut task_repo = MockTaskRepositoryAbstract::new();
         let task_id = 1;
         task_repo.expect_delete_task()
-            .with(eq(task_id))
+            .with(eq(task_id), eq(None))
             .times(1)
-            .returning(move |_| Ok(()));
+            .returning(move |_, _| Ok(()));
 
-        let delete_task_request = DeleteTaskRequest::new(task_id, &task_repo);
+        let delete_task_request = DeleteTaskRequest::new(task_id, None, Actor::System, &task_repo);
         let result = delete_task_request.execute().await;
 
         assert!(result.is_ok());
@@ -54,17 +61,49 @@ mod tests {
         let mut task_repo = MockTaskRepositoryAbstract::new();
         let task_id = 2;
         task_repo.expect_delete_task()
-            .with(eq(task_id))
+            .with(eq(task_id), eq(None))
             .times(1)
-            .returning(|_| Err(Box::new(Error::new(ErrorKind::NotFound, "Task not found"))));
+            .returning(|_, _| Err(DomainError::NotFound("Task 2 not found".to_string())));
 
-        let delete_task_request = DeleteTaskRequest::new(task_id, &task_repo);
+        let delete_task_request = DeleteTaskRequest::new(task_id, None, Actor::System, &task_repo);
         let result = delete_task_request.execute().await;
 
         assert!(result.is_err());
         let error = result.unwrap_err();
-        assert_eq!("task not found", error.message);
+        assert_eq!(404, error.code);
+        assert_eq!("Task 2 not found", error.message);
         assert!(error.error.is_some());
     }
-}
 
+    #[actix_rt::test]
+    async fn test_should_forbid_deletion_without_admin_role() {
+        let mut task_repo = MockTaskRepositoryAbstract::new();
+        task_repo.expect_get_role()
+            .with(eq(3), eq(AccessScope::Task(1)))
+            .times(1)
+            .returning(|_, _| Ok(Some(Role::Editor)));
+        task_repo.expect_delete_task().times(0);
+
+        let error = DeleteTaskRequest::new(1, None, Actor::User(3), &task_repo).execute().await.unwrap_err();
+
+        assert_eq!(403, error.code);
+        assert_eq!("Error: resource not allowed", error.message);
+    }
+
+    #[actix_rt::test]
+    async fn test_should_delete_task_for_project_admin() {
+        let mut task_repo = MockTaskRepositoryAbstract::new();
+        task_repo.expect_get_role()
+            .with(eq(3), eq(AccessScope::Task(1)))
+            .times(1)
+            .returning(|_, _| Ok(Some(Role::Admin)));
+        task_repo.expect_delete_task()
+            .with(eq(1), eq(Some(4)))
+            .times(1)
+            .returning(|_, _| Ok(()));
+
+        let result = DeleteTaskRequest::new(1, Some(4), Actor::User(3), &task_repo).execute().await;
+
+        assert!(result.is_ok());
+    }
+}
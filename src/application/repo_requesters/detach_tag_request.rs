@@ -0,0 +1,87 @@
+use async_trait::async_trait;
+use crate::application::helpers::access_control::AccessControl;
+use crate::application::helpers::error_handler::ErrorHandlingHelper;
+use crate::application::repo_requesters::abstract_request::AbstractRequest;
+use crate::application::repositories::tag_abstract_repo::TagRepositoryAbstract;
+use crate::application::repositories::task_abstract_repo::TaskRepositoryAbstract;
+use crate::domain::access::actor::{AccessScope, Actor};
+use crate::domain::access::role::Permission;
+use crate::domain::error::ApiError;
+
+pub struct DetachTagRequest<'a> {
+    task_id: i32,
+    tag_id: i32,
+    actor: Actor,
+    access_repository: &'a dyn TaskRepositoryAbstract,
+    repository: &'a dyn TagRepositoryAbstract,
+}
+
+impl<'a> DetachTagRequest<'a> {
+    pub fn new(task_id: i32, tag_id: i32, actor: Actor, access_repository: &'a dyn TaskRepositoryAbstract, repository: &'a dyn TagRepositoryAbstract) -> Self {
+        DetachTagRequest { task_id, tag_id, actor, access_repository, repository }
+    }
+}
+
+#[async_trait(?Send)]
+impl<'a> AbstractRequest<()> for DetachTagRequest<'a> {
+    async fn execute(&self) -> Result<(), ApiError> {
+        AccessControl::authorize(self.actor, AccessScope::Task(self.task_id), Permission::Write, self.access_repository).await?;
+        let result = self.repository.detach_tag(self.task_id, self.tag_id).await;
+
+        match result {
+            Ok(()) => Ok(()),
+            Err(e) => Err(ErrorHandlingHelper::domain_error("Cannot detach tag", e)),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use mockall::predicate::eq;
+    use crate::application::repositories::tag_abstract_repo::MockTagRepositoryAbstract;
+    use crate::application::repositories::task_abstract_repo::MockTaskRepositoryAbstract;
+    use crate::domain::access::role::Role;
+    use crate::domain::error::DomainError;
+
+    #[actix_rt::test]
+    async fn test_should_detach_tag_from_task() {
+        let mut tag_repo = MockTagRepositoryAbstract::new();
+        tag_repo.expect_detach_tag()
+            .with(eq(5), eq(1))
+            .times(1)
+            .returning(|_, _| Ok(()));
+
+        let result = DetachTagRequest::new(5, 1, Actor::System, &MockTaskRepositoryAbstract::new(), &tag_repo).execute().await;
+
+        assert!(result.is_ok());
+    }
+
+    #[actix_rt::test]
+    async fn test_should_return_not_found_when_task_is_not_tagged() {
+        let mut tag_repo = MockTagRepositoryAbstract::new();
+        tag_repo.expect_detach_tag()
+            .times(1)
+            .returning(|task_id, tag_id| Err(DomainError::NotFound(format!("Task {} is not tagged with tag {}", task_id, tag_id))));
+
+        let error = DetachTagRequest::new(5, 1, Actor::System, &MockTaskRepositoryAbstract::new(), &tag_repo).execute().await.unwrap_err();
+
+        assert_eq!(404, error.code);
+    }
+
+    #[actix_rt::test]
+    async fn test_should_forbid_detaching_tag_for_viewer_and_guest() {
+        let mut task_repo = MockTaskRepositoryAbstract::new();
+        task_repo.expect_get_role()
+            .with(eq(3), eq(AccessScope::Task(5)))
+            .times(1)
+            .returning(|_, _| Ok(Some(Role::Viewer)));
+        let mut tag_repo = MockTagRepositoryAbstract::new();
+        tag_repo.expect_detach_tag().times(0);
+
+        for actor in [Actor::User(3), Actor::Guest] {
+            let error = DetachTagRequest::new(5, 1, actor, &task_repo, &tag_repo).execute().await.unwrap_err();
+            assert_eq!(403, error.code);
+        }
+    }
+}
@@ -0,0 +1,62 @@
+use async_trait::async_trait;
+use crate::application::helpers::access_control::AccessControl;
+use crate::application::helpers::error_handler::ErrorHandlingHelper;
+use crate::application::repo_requesters::abstract_request::AbstractRequest;
+use crate::application::repositories::api_key_abstract_repo::ApiKeyRepositoryAbstract;
+use crate::application::repositories::task_abstract_repo::TaskRepositoryAbstract;
+use crate::domain::access::actor::{AccessScope, Actor};
+use crate::domain::access::api_key::ApiKey;
+use crate::domain::access::role::Permission;
+use crate::domain::error::ApiError;
+
+/// Список ключей API без их значений; доступен глобальным администраторам.
+pub struct GetApiKeysRequest<'a> {
+    actor: Actor,
+    access_repository: &'a dyn TaskRepositoryAbstract,
+    repository: &'a dyn ApiKeyRepositoryAbstract,
+}
+
+impl<'a> GetApiKeysRequest<'a> {
+    pub fn new(actor: Actor, access_repository: &'a dyn TaskRepositoryAbstract, repository: &'a dyn ApiKeyRepositoryAbstract) -> Self {
+        GetApiKeysRequest { actor, access_repository, repository }
+    }
+}
+
+#[async_trait(?Send)]
+impl<'a> AbstractRequest<Vec<ApiKey>> for GetApiKeysRequest<'a> {
+    async fn execute(&self) -> Result<Vec<ApiKey>, ApiError> {
+        AccessControl::authorize(self.actor, AccessScope::Global, Permission::Manage, self.access_repository).await?;
+        let api_keys = self.repository.get_api_keys().await;
+
+        match api_keys {
+            Ok(api_keys) => Ok(api_keys),
+            Err(e) => Err(ErrorHandlingHelper::domain_error("Cannot get API keys", e)),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use mockall::predicate::eq;
+    use crate::application::repositories::api_key_abstract_repo::MockApiKeyRepositoryAbstract;
+    use crate::application::repositories::task_abstract_repo::MockTaskRepositoryAbstract;
+    use crate::domain::access::api_key::ApiKeyScope;
+    use crate::domain::access::role::Role;
+
+    #[actix_rt::test]
+    async fn test_should_forbid_editors_and_services_to_list_keys() {
+        let mut task_repo = MockTaskRepositoryAbstract::new();
+        task_repo.expect_get_role()
+            .with(eq(2), eq(AccessScope::Global))
+            .returning(|_, _| Ok(Some(Role::Editor)));
+        let mut api_key_repo = MockApiKeyRepositoryAbstract::new();
+        api_key_repo.expect_get_api_keys().times(0);
+        let service = Actor::Service([ApiKeyScope::ReadTasks, ApiKeyScope::WriteTasks].into_iter().collect());
+
+        for actor in [Actor::User(2), service] {
+            let error = GetApiKeysRequest::new(actor, &task_repo, &api_key_repo).execute().await.unwrap_err();
+            assert_eq!(403, error.code);
+        }
+    }
+}
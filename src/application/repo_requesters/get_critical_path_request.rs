@@ -0,0 +1,85 @@
+use async_trait::async_trait;
+use crate::application::helpers::error_handler::ErrorHandlingHelper;
+use crate::application::repo_requesters::abstract_request::AbstractRequest;
+use crate::application::repositories::dependency_abstract_repo::DependencyRepositoryAbstract;
+use crate::application::repositories::project_abstract_repo::ProjectRepositoryAbstract;
+use crate::domain::error::ApiError;
+use crate::domain::task::task_dependency::CriticalPath;
+
+/// Критический путь по графу зависимостей задач проекта с учётом их оценок.
+pub struct GetCriticalPathRequest<'a> {
+    project_id: i32,
+    repository: &'a dyn DependencyRepositoryAbstract,
+    project_repository: &'a dyn ProjectRepositoryAbstract,
+}
+
+impl<'a> GetCriticalPathRequest<'a> {
+    pub fn new(project_id: i32, repository: &'a dyn DependencyRepositoryAbstract, project_repository: &'a dyn ProjectRepositoryAbstract) -> Self {
+        GetCriticalPathRequest { project_id, repository, project_repository }
+    }
+}
+
+#[async_trait(?Send)]
+impl<'a> AbstractRequest<CriticalPath> for GetCriticalPathRequest<'a> {
+    async fn execute(&self) -> Result<CriticalPath, ApiError> {
+        self.project_repository.get_project_by_id(self.project_id).await
+            .map_err(|e| ErrorHandlingHelper::domain_error("Cannot get critical path", e))?;
+
+        let (tasks, dependencies) = self.repository.get_project_dependency_graph(self.project_id).await
+            .map_err(|e| ErrorHandlingHelper::domain_error("Cannot get critical path", e))?;
+
+        CriticalPath::compute(tasks, &dependencies)
+            .map_err(|e| ErrorHandlingHelper::domain_error("Cannot get critical path", e))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use mockall::predicate::eq;
+    use crate::application::repositories::dependency_abstract_repo::MockDependencyRepositoryAbstract;
+    use crate::application::repositories::project_abstract_repo::MockProjectRepositoryAbstract;
+    use crate::domain::project::project::Project;
+    use crate::domain::task::task::Task;
+    use crate::domain::task::task_dependency::TaskDependency;
+
+    fn estimated(id: i32, estimate_minutes: i32) -> Task {
+        Task { estimate_minutes: Some(estimate_minutes), ..Task::new(id, format!("Task {}", id), None) }
+    }
+
+    #[actix_rt::test]
+    async fn test_should_compute_critical_path_of_project() {
+        let mut project_repo = MockProjectRepositoryAbstract::new();
+        project_repo.expect_get_project_by_id()
+            .with(eq(5))
+            .times(1)
+            .returning(|id| Ok(Project::new(id, "Backend".to_string(), None)));
+        let mut dependency_repo = MockDependencyRepositoryAbstract::new();
+        dependency_repo.expect_get_project_dependency_graph()
+            .with(eq(5))
+            .times(1)
+            .returning(|_| Ok((
+                vec![estimated(1, 60), estimated(2, 30), estimated(3, 120)],
+                vec![TaskDependency { task_id: 3, blocker_id: 1 }, TaskDependency { task_id: 3, blocker_id: 2 }],
+            )));
+
+        let path = GetCriticalPathRequest::new(5, &dependency_repo, &project_repo).execute().await.unwrap();
+
+        assert_eq!(path.tasks.iter().map(|task| task.id).collect::<Vec<_>>(), vec![1, 3]);
+        assert_eq!(path.total_estimate_minutes, 180);
+    }
+
+    #[actix_rt::test]
+    async fn test_should_return_not_found_for_unknown_project() {
+        let mut project_repo = MockProjectRepositoryAbstract::new();
+        project_repo.expect_get_project_by_id()
+            .times(1)
+            .returning(|id| Err(Project::not_found(id)));
+        let mut dependency_repo = MockDependencyRepositoryAbstract::new();
+        dependency_repo.expect_get_project_dependency_graph().times(0);
+
+        let error = GetCriticalPathRequest::new(5, &dependency_repo, &project_repo).execute().await.unwrap_err();
+
+        assert_eq!(404, error.code);
+    }
+}
@@ -0,0 +1,118 @@
+use async_trait::async_trait;
+use chrono::{DateTime, Duration, Utc};
+use crate::application::helpers::access_control::AccessControl;
+use crate::application::helpers::error_handler::ErrorHandlingHelper;
+use crate::application::repo_requesters::abstract_request::AbstractRequest;
+use crate::application::repo_requesters::get_tasks_request::{DEFAULT_PAGE_LIMIT, MAX_PAGE_LIMIT};
+use crate::application::repositories::task_abstract_repo::TaskRepositoryAbstract;
+use crate::domain::access::actor::{AccessScope, Actor};
+use crate::domain::access::role::Permission;
+use crate::domain::error::ApiError;
+use crate::domain::task::task::Task;
+use crate::domain::task::task_due::{TaskDueView, MAX_DUE_WITHIN_DAYS};
+
+pub struct GetDueTasksRequest<'a> {
+    view: TaskDueView,
+    limit: Option<i64>,
+    now: DateTime<Utc>,
+    actor: Actor,
+    repository: &'a dyn TaskRepositoryAbstract,
+}
+
+impl<'a> GetDueTasksRequest<'a> {
+    /// Создаёт запрос выборки задач по срокам относительно момента `now`.
+    pub fn new(view: TaskDueView, limit: Option<i64>, now: DateTime<Utc>, actor: Actor, repository: &'a dyn TaskRepositoryAbstract) -> Self {
+        GetDueTasksRequest { view, limit, now, actor, repository }
+    }
+}
+
+#[async_trait(?Send)]
+impl<'a> AbstractRequest<Vec<Task>> for GetDueTasksRequest<'a> {
+    async fn execute(&self) -> Result<Vec<Task>, ApiError> {
+        let limit = self.limit.unwrap_or(DEFAULT_PAGE_LIMIT);
+        if !(1..=MAX_PAGE_LIMIT).contains(&limit) {
+            return Err(ErrorHandlingHelper::application_error(&format!("limit must be between 1 and {}", MAX_PAGE_LIMIT), None));
+        }
+
+        AccessControl::authorize(self.actor, AccessScope::Global, Permission::Read, self.repository).await?;
+        let tasks = match self.view {
+            TaskDueView::Overdue => self.repository.get_overdue_tasks(self.now, limit).await,
+            TaskDueView::DueWithin { days } => {
+                if !(1..=MAX_DUE_WITHIN_DAYS).contains(&days) {
+                    return Err(ErrorHandlingHelper::application_error(&format!("days must be between 1 and {}", MAX_DUE_WITHIN_DAYS), None));
+                }
+                self.repository.get_tasks_due_within(self.now, self.now + Duration::days(days), limit).await
+            },
+            TaskDueView::ByUrgency => self.repository.get_tasks_by_urgency(self.now, limit).await,
+        };
+
+        match tasks {
+            Ok(tasks) => Ok(tasks),
+            Err(e) => Err(ErrorHandlingHelper::domain_error("Cannot get tasks by due date", e)),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use mockall::predicate::*;
+    use crate::application::repositories::task_abstract_repo::MockTaskRepositoryAbstract;
+    use crate::domain::error::DomainError;
+
+    fn now() -> DateTime<Utc> {
+        "2026-10-18T12:00:00Z".parse().unwrap()
+    }
+
+    #[actix_rt::test]
+    async fn test_should_get_overdue_tasks_with_default_limit() {
+        let mut task_repo = MockTaskRepositoryAbstract::new();
+        task_repo.expect_get_overdue_tasks()
+            .with(eq(now()), eq(DEFAULT_PAGE_LIMIT))
+            .times(1)
+            .returning(|_, _| Ok(vec![Task::new(1, "Late".to_string(), None)]));
+
+        let result = GetDueTasksRequest::new(TaskDueView::Overdue, None, now(), Actor::System, &task_repo).execute().await;
+
+        assert_eq!(result.unwrap()[0].title, "Late");
+    }
+
+    #[actix_rt::test]
+    async fn test_should_query_tasks_due_within_given_days() {
+        let mut task_repo = MockTaskRepositoryAbstract::new();
+        let until = "2026-10-25T12:00:00Z".parse::<DateTime<Utc>>().unwrap();
+        task_repo.expect_get_tasks_due_within()
+            .with(eq(now()), eq(until), eq(10))
+            .times(1)
+            .returning(|_, _, _| Ok(vec![]));
+
+        let result = GetDueTasksRequest::new(TaskDueView::DueWithin { days: 7 }, Some(10), now(), Actor::System, &task_repo).execute().await;
+
+        assert!(result.unwrap().is_empty());
+    }
+
+    #[actix_rt::test]
+    async fn test_should_reject_out_of_range_days_and_limit() {
+        let mut task_repo = MockTaskRepositoryAbstract::new();
+        task_repo.expect_get_tasks_due_within().times(0);
+        task_repo.expect_get_tasks_by_urgency().times(0);
+
+        let days = GetDueTasksRequest::new(TaskDueView::DueWithin { days: 0 }, None, now(), Actor::System, &task_repo).execute().await;
+        let limit = GetDueTasksRequest::new(TaskDueView::ByUrgency, Some(MAX_PAGE_LIMIT + 1), now(), Actor::System, &task_repo).execute().await;
+
+        assert_eq!(days.unwrap_err().code, 400);
+        assert_eq!(limit.unwrap_err().code, 400);
+    }
+
+    #[actix_rt::test]
+    async fn test_should_return_error_when_repository_fails() {
+        let mut task_repo = MockTaskRepositoryAbstract::new();
+        task_repo.expect_get_tasks_by_urgency()
+            .times(1)
+            .returning(|_, _| Err(DomainError::Internal("Database error".to_string())));
+
+        let result = GetDueTasksRequest::new(TaskDueView::ByUrgency, None, now(), Actor::System, &task_repo).execute().await;
+
+        assert_eq!(result.unwrap_err().code, 500);
+    }
+}
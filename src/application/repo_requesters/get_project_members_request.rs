@@ -0,0 +1,73 @@
+use async_trait::async_trait;
+use crate::application::helpers::access_control::AccessControl;
+use crate::application::helpers::error_handler::ErrorHandlingHelper;
+use crate::application::repo_requesters::abstract_request::AbstractRequest;
+use crate::application::repositories::project_abstract_repo::ProjectRepositoryAbstract;
+use crate::application::repositories::task_abstract_repo::TaskRepositoryAbstract;
+use crate::domain::access::actor::{AccessScope, Actor};
+use crate::domain::access::role::Permission;
+use crate::domain::error::ApiError;
+use crate::domain::project::project_member::ProjectMember;
+
+pub struct GetProjectMembersRequest<'a> {
+    project_id: i32,
+    actor: Actor,
+    access_repository: &'a dyn TaskRepositoryAbstract,
+    repository: &'a dyn ProjectRepositoryAbstract,
+}
+
+impl<'a> GetProjectMembersRequest<'a> {
+    pub fn new(project_id: i32, actor: Actor, access_repository: &'a dyn TaskRepositoryAbstract, repository: &'a dyn ProjectRepositoryAbstract) -> Self {
+        GetProjectMembersRequest { project_id, actor, access_repository, repository }
+    }
+}
+
+#[async_trait(?Send)]
+impl<'a> AbstractRequest<Vec<ProjectMember>> for GetProjectMembersRequest<'a> {
+    async fn execute(&self) -> Result<Vec<ProjectMember>, ApiError> {
+        AccessControl::authorize(self.actor, AccessScope::Project(self.project_id), Permission::Read, self.access_repository).await?;
+        let members = self.repository.get_project_members(self.project_id).await;
+
+        match members {
+            Ok(members) => Ok(members),
+            Err(e) => Err(ErrorHandlingHelper::domain_error("Cannot get project members", e)),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use mockall::predicate::eq;
+    use crate::application::repositories::project_abstract_repo::MockProjectRepositoryAbstract;
+    use crate::application::repositories::task_abstract_repo::MockTaskRepositoryAbstract;
+    use crate::domain::access::role::Role;
+    use crate::domain::project::project::Project;
+
+    #[actix_rt::test]
+    async fn test_should_list_project_members() {
+        let task_repo = MockTaskRepositoryAbstract::new();
+        let mut project_repo = MockProjectRepositoryAbstract::new();
+        project_repo.expect_get_project_members()
+            .with(eq(5))
+            .times(1)
+            .returning(|project_id| Ok(vec![ProjectMember::new(project_id, 2, Role::Editor)]));
+
+        let members = GetProjectMembersRequest::new(5, Actor::System, &task_repo, &project_repo).execute().await.unwrap();
+
+        assert_eq!(members, vec![ProjectMember::new(5, 2, Role::Editor)]);
+    }
+
+    #[actix_rt::test]
+    async fn test_should_return_not_found_for_unknown_project() {
+        let task_repo = MockTaskRepositoryAbstract::new();
+        let mut project_repo = MockProjectRepositoryAbstract::new();
+        project_repo.expect_get_project_members()
+            .times(1)
+            .returning(|project_id| Err(Project::not_found(project_id)));
+
+        let error = GetProjectMembersRequest::new(6, Actor::System, &task_repo, &project_repo).execute().await.unwrap_err();
+
+        assert_eq!(404, error.code);
+    }
+}
@@ -0,0 +1,63 @@
+use async_trait::async_trait;
+use crate::application::helpers::error_handler::ErrorHandlingHelper;
+use crate::application::repo_requesters::abstract_request::AbstractRequest;
+use crate::application::repositories::project_abstract_repo::ProjectRepositoryAbstract;
+use crate::domain::error::ApiError;
+use crate::domain::project::project::Project;
+
+pub struct GetProjectRequest<'a> {
+    project_id: i32,
+    repository: &'a dyn ProjectRepositoryAbstract,
+}
+
+impl<'a> GetProjectRequest<'a> {
+    pub fn new(project_id: i32, repository: &'a dyn ProjectRepositoryAbstract) -> Self {
+        GetProjectRequest { project_id, repository }
+    }
+}
+
+#[async_trait(?Send)]
+impl<'a> AbstractRequest<Project> for GetProjectRequest<'a> {
+    async fn execute(&self) -> Result<Project, ApiError> {
+        let project = self.repository.get_project_by_id(self.project_id).await;
+
+        match project {
+            Ok(project) => Ok(project),
+            Err(e) => Err(ErrorHandlingHelper::domain_error("Cannot get project", e)),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use mockall::predicate::eq;
+    use crate::application::repositories::project_abstract_repo::MockProjectRepositoryAbstract;
+
+    #[actix_rt::test]
+    async fn test_should_return_project_when_it_exists() {
+        let mut project_repo = MockProjectRepositoryAbstract::new();
+        project_repo.expect_get_project_by_id()
+            .with(eq(3))
+            .times(1)
+            .returning(|id| Ok(Project::new(id, "Backend".to_string(), Some("API".to_string()))));
+
+        let project = GetProjectRequest::new(3, &project_repo).execute().await.unwrap();
+
+        assert_eq!(project.id, 3);
+        assert_eq!(project.description.as_deref(), Some("API"));
+    }
+
+    #[actix_rt::test]
+    async fn test_should_return_not_found_when_project_does_not_exist() {
+        let mut project_repo = MockProjectRepositoryAbstract::new();
+        project_repo.expect_get_project_by_id()
+            .times(1)
+            .returning(|id| Err(Project::not_found(id)));
+
+        let error = GetProjectRequest::new(4, &project_repo).execute().await.unwrap_err();
+
+        assert_eq!(404, error.code);
+        assert_eq!("Project 4 not found", error.message);
+    }
+}
@@ -0,0 +1,76 @@
+use async_trait::async_trait;
+use crate::application::helpers::error_handler::ErrorHandlingHelper;
+use crate::application::repo_requesters::abstract_request::AbstractRequest;
+use crate::application::repo_requesters::get_tasks_request::GetTasksRequest;
+use crate::application::repositories::project_abstract_repo::ProjectRepositoryAbstract;
+use crate::domain::error::ApiError;
+use crate::domain::task::task_query::TaskPage;
+
+/// Страница задач проекта; для несуществующего проекта возвращает 404, а не пустую страницу.
+pub struct GetProjectTasksRequest<'a> {
+    project_id: i32,
+    tasks_request: GetTasksRequest<'a>,
+    repository: &'a dyn ProjectRepositoryAbstract,
+}
+
+impl<'a> GetProjectTasksRequest<'a> {
+    pub fn new(project_id: i32, tasks_request: GetTasksRequest<'a>, repository: &'a dyn ProjectRepositoryAbstract) -> Self {
+        GetProjectTasksRequest { project_id, tasks_request: tasks_request.in_project(project_id), repository }
+    }
+}
+
+#[async_trait(?Send)]
+impl<'a> AbstractRequest<TaskPage> for GetProjectTasksRequest<'a> {
+    async fn execute(&self) -> Result<TaskPage, ApiError> {
+        self.repository.get_project_by_id(self.project_id).await
+            .map_err(|e| ErrorHandlingHelper::domain_error("Cannot get project tasks", e))?;
+
+        self.tasks_request.execute().await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::access::actor::Actor;
+    use mockall::predicate::{eq, function};
+    use crate::application::repositories::project_abstract_repo::MockProjectRepositoryAbstract;
+    use crate::application::repositories::task_abstract_repo::MockTaskRepositoryAbstract;
+    use crate::domain::project::project::Project;
+    use crate::domain::task::task::Task;
+    use crate::domain::task::task_query::{TaskFilter, TaskQuery};
+
+    #[actix_rt::test]
+    async fn test_should_list_only_tasks_of_the_project() {
+        let mut project_repo = MockProjectRepositoryAbstract::new();
+        project_repo.expect_get_project_by_id()
+            .with(eq(5))
+            .times(1)
+            .returning(|id| Ok(Project::new(id, "Backend".to_string(), None)));
+        let mut task_repo = MockTaskRepositoryAbstract::new();
+        task_repo.expect_get_tasks()
+            .with(function(|query: &TaskQuery| query.filter.project_id == Some(5)))
+            .times(1)
+            .returning(|query| Ok(TaskPage::new(vec![Task::new(1, "Design API".to_string(), None)], 1, &query, false, false)));
+
+        let tasks_request = GetTasksRequest::new(TaskFilter::default(), None, None, None, None, None, Actor::System, &task_repo);
+        let page = GetProjectTasksRequest::new(5, tasks_request, &project_repo).execute().await.unwrap();
+
+        assert_eq!(page.items.len(), 1);
+    }
+
+    #[actix_rt::test]
+    async fn test_should_return_not_found_without_listing_tasks() {
+        let mut project_repo = MockProjectRepositoryAbstract::new();
+        project_repo.expect_get_project_by_id()
+            .times(1)
+            .returning(|id| Err(Project::not_found(id)));
+        let mut task_repo = MockTaskRepositoryAbstract::new();
+        task_repo.expect_get_tasks().times(0);
+
+        let tasks_request = GetTasksRequest::new(TaskFilter::default(), None, None, None, None, None, Actor::System, &task_repo);
+        let error = GetProjectTasksRequest::new(6, tasks_request, &project_repo).execute().await.unwrap_err();
+
+        assert_eq!(404, error.code);
+    }
+}
@@ -0,0 +1,63 @@
+use async_trait::async_trait;
+use crate::application::helpers::error_handler::ErrorHandlingHelper;
+use crate::application::repo_requesters::abstract_request::AbstractRequest;
+use crate::application::repositories::project_abstract_repo::ProjectRepositoryAbstract;
+use crate::domain::error::ApiError;
+use crate::domain::project::project::Project;
+
+pub struct GetProjectsRequest<'a> {
+    include_archived: bool,
+    repository: &'a dyn ProjectRepositoryAbstract,
+}
+
+impl<'a> GetProjectsRequest<'a> {
+    pub fn new(include_archived: bool, repository: &'a dyn ProjectRepositoryAbstract) -> Self {
+        GetProjectsRequest { include_archived, repository }
+    }
+}
+
+#[async_trait(?Send)]
+impl<'a> AbstractRequest<Vec<Project>> for GetProjectsRequest<'a> {
+    async fn execute(&self) -> Result<Vec<Project>, ApiError> {
+        let projects = self.repository.get_projects(self.include_archived).await;
+
+        match projects {
+            Ok(projects) => Ok(projects),
+            Err(e) => Err(ErrorHandlingHelper::domain_error("Cannot get projects", e)),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use mockall::predicate::eq;
+    use crate::application::repositories::project_abstract_repo::MockProjectRepositoryAbstract;
+    use crate::domain::error::DomainError;
+
+    #[actix_rt::test]
+    async fn test_should_return_active_projects_by_default() {
+        let mut project_repo = MockProjectRepositoryAbstract::new();
+        project_repo.expect_get_projects()
+            .with(eq(false))
+            .times(1)
+            .returning(|_| Ok(vec![Project::new(1, "Backend".to_string(), None)]));
+
+        let projects = GetProjectsRequest::new(false, &project_repo).execute().await.unwrap();
+
+        assert_eq!(projects.len(), 1);
+        assert_eq!(projects[0].name, "Backend");
+    }
+
+    #[actix_rt::test]
+    async fn test_should_return_error_when_repo_fails() {
+        let mut project_repo = MockProjectRepositoryAbstract::new();
+        project_repo.expect_get_projects()
+            .times(1)
+            .returning(|_| Err(DomainError::Unavailable("database is locked".to_string())));
+
+        let error = GetProjectsRequest::new(true, &project_repo).execute().await.unwrap_err();
+
+        assert_eq!(503, error.code);
+    }
+}
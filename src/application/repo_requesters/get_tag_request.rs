@@ -0,0 +1,62 @@
+use async_trait::async_trait;
+use crate::application::helpers::error_handler::ErrorHandlingHelper;
+use crate::application::repo_requesters::abstract_request::AbstractRequest;
+use crate::application::repositories::tag_abstract_repo::TagRepositoryAbstract;
+use crate::domain::error::ApiError;
+use crate::domain::tag::tag::Tag;
+
+pub struct GetTagRequest<'a> {
+    tag_id: i32,
+    repository: &'a dyn TagRepositoryAbstract,
+}
+
+impl<'a> GetTagRequest<'a> {
+    pub fn new(tag_id: i32, repository: &'a dyn TagRepositoryAbstract) -> Self {
+        GetTagRequest { tag_id, repository }
+    }
+}
+
+#[async_trait(?Send)]
+impl<'a> AbstractRequest<Tag> for GetTagRequest<'a> {
+    async fn execute(&self) -> Result<Tag, ApiError> {
+        let tag = self.repository.get_tag_by_id(self.tag_id).await;
+
+        match tag {
+            Ok(tag) => Ok(tag),
+            Err(e) => Err(ErrorHandlingHelper::domain_error("Cannot get tag", e)),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use mockall::predicate::eq;
+    use crate::application::repositories::tag_abstract_repo::MockTagRepositoryAbstract;
+
+    #[actix_rt::test]
+    async fn test_should_return_tag_when_repo_returns_success() {
+        let mut tag_repo = MockTagRepositoryAbstract::new();
+        tag_repo.expect_get_tag_by_id()
+            .with(eq(1))
+            .times(1)
+            .returning(|tag_id| Ok(Tag::new(tag_id, "backend".to_string())));
+
+        let tag = GetTagRequest::new(1, &tag_repo).execute().await.unwrap();
+
+        assert_eq!(tag, Tag::new(1, "backend".to_string()));
+    }
+
+    #[actix_rt::test]
+    async fn test_should_return_not_found_when_tag_does_not_exist() {
+        let mut tag_repo = MockTagRepositoryAbstract::new();
+        tag_repo.expect_get_tag_by_id()
+            .times(1)
+            .returning(|tag_id| Err(Tag::not_found(tag_id)));
+
+        let error = GetTagRequest::new(7, &tag_repo).execute().await.unwrap_err();
+
+        assert_eq!(404, error.code);
+        assert_eq!("Tag 7 not found", error.message);
+    }
+}
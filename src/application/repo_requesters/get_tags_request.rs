@@ -0,0 +1,60 @@
+use async_trait::async_trait;
+use crate::application::helpers::error_handler::ErrorHandlingHelper;
+use crate::application::repo_requesters::abstract_request::AbstractRequest;
+use crate::application::repositories::tag_abstract_repo::TagRepositoryAbstract;
+use crate::domain::error::ApiError;
+use crate::domain::tag::tag::Tag;
+
+pub struct GetTagsRequest<'a> {
+    repository: &'a dyn TagRepositoryAbstract,
+}
+
+impl<'a> GetTagsRequest<'a> {
+    pub fn new(repository: &'a dyn TagRepositoryAbstract) -> Self {
+        GetTagsRequest { repository }
+    }
+}
+
+#[async_trait(?Send)]
+impl<'a> AbstractRequest<Vec<Tag>> for GetTagsRequest<'a> {
+    async fn execute(&self) -> Result<Vec<Tag>, ApiError> {
+        let tags = self.repository.get_tags().await;
+
+        match tags {
+            Ok(tags) => Ok(tags),
+            Err(e) => Err(ErrorHandlingHelper::domain_error("Cannot get tags", e)),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::application::repositories::tag_abstract_repo::MockTagRepositoryAbstract;
+    use crate::domain::error::DomainError;
+
+    #[actix_rt::test]
+    async fn test_should_return_all_tags() {
+        let mut tag_repo = MockTagRepositoryAbstract::new();
+        tag_repo.expect_get_tags()
+            .times(1)
+            .returning(|| Ok(vec![Tag::new(1, "backend".to_string()), Tag::new(2, "bug".to_string())]));
+
+        let result = GetTagsRequest::new(&tag_repo).execute().await;
+
+        assert_eq!(result.unwrap().len(), 2);
+    }
+
+    #[actix_rt::test]
+    async fn test_should_return_error_when_repo_returns_error() {
+        let mut tag_repo = MockTagRepositoryAbstract::new();
+        tag_repo.expect_get_tags()
+            .times(1)
+            .returning(|| Err(DomainError::Internal("Database error".to_string())));
+
+        let error = GetTagsRequest::new(&tag_repo).execute().await.unwrap_err();
+
+        assert_eq!(500, error.code);
+        assert_eq!("Cannot get tags", error.message);
+    }
+}
@@ -0,0 +1,63 @@
+use async_trait::async_trait;
+use crate::application::helpers::error_handler::ErrorHandlingHelper;
+use crate::application::repo_requesters::abstract_request::AbstractRequest;
+use crate::application::repositories::user_abstract_repo::UserRepositoryAbstract;
+use crate::domain::error::ApiError;
+use crate::domain::user::user::User;
+
+pub struct GetTaskAssigneesRequest<'a> {
+    task_id: i32,
+    repository: &'a dyn UserRepositoryAbstract,
+}
+
+impl<'a> GetTaskAssigneesRequest<'a> {
+    pub fn new(task_id: i32, repository: &'a dyn UserRepositoryAbstract) -> Self {
+        GetTaskAssigneesRequest { task_id, repository }
+    }
+}
+
+#[async_trait(?Send)]
+impl<'a> AbstractRequest<Vec<User>> for GetTaskAssigneesRequest<'a> {
+    async fn execute(&self) -> Result<Vec<User>, ApiError> {
+        let users = self.repository.get_task_assignees(self.task_id).await;
+
+        match users {
+            Ok(users) => Ok(users),
+            Err(e) => Err(ErrorHandlingHelper::domain_error("Cannot get task assignees", e)),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use mockall::predicate::eq;
+    use crate::application::repositories::user_abstract_repo::MockUserRepositoryAbstract;
+    use crate::domain::task::task::Task;
+
+    #[actix_rt::test]
+    async fn test_should_return_assignees_of_task() {
+        let mut user_repo = MockUserRepositoryAbstract::new();
+        user_repo.expect_get_task_assignees()
+            .with(eq(5))
+            .times(1)
+            .returning(|_| Ok(vec![User::new(1, "Ada".to_string(), "ada@example.com".to_string())]));
+
+        let users = GetTaskAssigneesRequest::new(5, &user_repo).execute().await.unwrap();
+
+        assert_eq!(users.iter().map(|user| user.id).collect::<Vec<_>>(), vec![1]);
+    }
+
+    #[actix_rt::test]
+    async fn test_should_return_not_found_when_task_does_not_exist() {
+        let mut user_repo = MockUserRepositoryAbstract::new();
+        user_repo.expect_get_task_assignees()
+            .times(1)
+            .returning(|task_id| Err(Task::not_found(task_id)));
+
+        let error = GetTaskAssigneesRequest::new(5, &user_repo).execute().await.unwrap_err();
+
+        assert_eq!(404, error.code);
+        assert_eq!("Task 5 not found", error.message);
+    }
+}
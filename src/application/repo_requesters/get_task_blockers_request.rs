@@ -0,0 +1,64 @@
+use async_trait::async_trait;
+use crate::application::helpers::error_handler::ErrorHandlingHelper;
+use crate::application::repo_requesters::abstract_request::AbstractRequest;
+use crate::application::repositories::dependency_abstract_repo::DependencyRepositoryAbstract;
+use crate::domain::error::ApiError;
+use crate::domain::task::task::Task;
+
+/// Задачи, блокирующие задачу: только прямые или вместе с блокирующими их задачами.
+pub struct GetTaskBlockersRequest<'a> {
+    task_id: i32,
+    transitive: bool,
+    repository: &'a dyn DependencyRepositoryAbstract,
+}
+
+impl<'a> GetTaskBlockersRequest<'a> {
+    pub fn new(task_id: i32, transitive: bool, repository: &'a dyn DependencyRepositoryAbstract) -> Self {
+        GetTaskBlockersRequest { task_id, transitive, repository }
+    }
+}
+
+#[async_trait(?Send)]
+impl<'a> AbstractRequest<Vec<Task>> for GetTaskBlockersRequest<'a> {
+    async fn execute(&self) -> Result<Vec<Task>, ApiError> {
+        let blockers = self.repository.get_blockers(self.task_id, self.transitive).await;
+
+        match blockers {
+            Ok(blockers) => Ok(blockers),
+            Err(e) => Err(ErrorHandlingHelper::domain_error("Cannot get task blockers", e)),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use mockall::predicate::eq;
+    use crate::application::repositories::dependency_abstract_repo::MockDependencyRepositoryAbstract;
+    use crate::domain::error::DomainError;
+
+    #[actix_rt::test]
+    async fn test_should_return_transitive_blockers() {
+        let mut dependency_repo = MockDependencyRepositoryAbstract::new();
+        dependency_repo.expect_get_blockers()
+            .with(eq(3), eq(true))
+            .times(1)
+            .returning(|_, _| Ok(vec![Task::new(1, "Design".to_string(), None), Task::new(2, "Build".to_string(), None)]));
+
+        let blockers = GetTaskBlockersRequest::new(3, true, &dependency_repo).execute().await.unwrap();
+
+        assert_eq!(blockers.iter().map(|task| task.id).collect::<Vec<_>>(), vec![1, 2]);
+    }
+
+    #[actix_rt::test]
+    async fn test_should_return_not_found_for_unknown_task() {
+        let mut dependency_repo = MockDependencyRepositoryAbstract::new();
+        dependency_repo.expect_get_blockers()
+            .times(1)
+            .returning(|task_id, _| Err(DomainError::NotFound(format!("Task with id {} not found", task_id))));
+
+        let error = GetTaskBlockersRequest::new(3, false, &dependency_repo).execute().await.unwrap_err();
+
+        assert_eq!(404, error.code);
+    }
+}
@@ -0,0 +1,122 @@
+use async_trait::async_trait;
+use crate::application::helpers::access_control::AccessControl;
+use crate::application::helpers::error_handler::ErrorHandlingHelper;
+use crate::application::repo_requesters::abstract_request::AbstractRequest;
+use crate::application::repo_requesters::get_tasks_request::{DEFAULT_PAGE_LIMIT, MAX_PAGE_LIMIT};
+use crate::application::repositories::task_abstract_repo::TaskRepositoryAbstract;
+use crate::domain::access::actor::{AccessScope, Actor};
+use crate::domain::access::role::Permission;
+use crate::domain::error::ApiError;
+use crate::domain::task::task_event::TaskEventPage;
+
+pub struct GetTaskHistoryRequest<'a> {
+    task_id: i32,
+    limit: Option<i64>,
+    offset: Option<i64>,
+    actor: Actor,
+    repository: &'a dyn TaskRepositoryAbstract,
+}
+
+impl<'a> GetTaskHistoryRequest<'a> {
+    pub fn new(task_id: i32, limit: Option<i64>, offset: Option<i64>, actor: Actor, repository: &'a dyn TaskRepositoryAbstract) -> Self {
+        GetTaskHistoryRequest { task_id, limit, offset, actor, repository }
+    }
+}
+
+#[async_trait(?Send)]
+impl<'a> AbstractRequest<TaskEventPage> for GetTaskHistoryRequest<'a> {
+    async fn execute(&self) -> Result<TaskEventPage, ApiError> {
+        let limit = self.limit.unwrap_or(DEFAULT_PAGE_LIMIT);
+        if !(1..=MAX_PAGE_LIMIT).contains(&limit) {
+            return Err(ErrorHandlingHelper::application_error(&format!("limit must be between 1 and {}", MAX_PAGE_LIMIT), None));
+        }
+        let offset = self.offset.unwrap_or(0);
+        if offset < 0 {
+            return Err(ErrorHandlingHelper::application_error("offset must not be negative", None));
+        }
+
+        AccessControl::authorize(self.actor, AccessScope::Task(self.task_id), Permission::Read, self.repository).await?;
+        let page = self.repository.get_task_events(self.task_id, limit, offset).await;
+
+        match page {
+            Ok(page) => Ok(page),
+            Err(e) => Err(ErrorHandlingHelper::domain_error("Cannot get task history", e)),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::Utc;
+    use mockall::predicate::eq;
+    use crate::application::repositories::task_abstract_repo::MockTaskRepositoryAbstract;
+    use crate::domain::error::DomainError;
+    use crate::domain::task::task::Task;
+    use crate::domain::task::task_event::{TaskEvent, TaskEventAction};
+
+    #[actix_rt::test]
+    async fn test_should_return_history_page_with_default_limit() {
+        let mut task_repo = MockTaskRepositoryAbstract::new();
+        task_repo
+            .expect_get_task_events()
+            .with(eq(1), eq(DEFAULT_PAGE_LIMIT), eq(0))
+            .times(1)
+            .returning(|task_id, limit, offset| {
+                let task = Task::new(task_id, "Complete the project".to_string(), None);
+                let created = TaskEvent {
+                    id: 1,
+                    task_id,
+                    action: TaskEventAction::Created,
+                    actor: "system".to_string(),
+                    occurred_at: Utc::now(),
+                    changes: TaskEvent::diff(None, Some(&task)),
+                };
+                Ok(TaskEventPage { items: vec![created], total: 1, limit, offset })
+            });
+
+        let page = GetTaskHistoryRequest::new(1, None, None, Actor::System, &task_repo).execute().await.unwrap();
+
+        assert_eq!(page.total, 1);
+        assert_eq!(page.items[0].action, TaskEventAction::Created);
+        assert_eq!(page.limit, DEFAULT_PAGE_LIMIT);
+    }
+
+    #[actix_rt::test]
+    async fn test_should_reject_invalid_pagination() {
+        let task_repo = MockTaskRepositoryAbstract::new();
+
+        let limit = GetTaskHistoryRequest::new(1, Some(MAX_PAGE_LIMIT + 1), None, Actor::System, &task_repo).execute().await;
+        assert_eq!(limit.unwrap_err().code, 400);
+
+        let offset = GetTaskHistoryRequest::new(1, None, Some(-1), Actor::System, &task_repo).execute().await;
+        assert_eq!(offset.unwrap_err().code, 400);
+    }
+
+    #[actix_rt::test]
+    async fn test_should_return_not_found_when_task_has_no_history() {
+        let mut task_repo = MockTaskRepositoryAbstract::new();
+        task_repo
+            .expect_get_task_events()
+            .times(1)
+            .returning(|task_id, _, _| Err(Task::not_found(task_id)));
+
+        let error = GetTaskHistoryRequest::new(9, None, None, Actor::System, &task_repo).execute().await.unwrap_err();
+
+        assert_eq!(error.code, 404);
+    }
+
+    #[actix_rt::test]
+    async fn test_should_return_generic_message_when_unexpected_repo_error() {
+        let mut task_repo = MockTaskRepositoryAbstract::new();
+        task_repo
+            .expect_get_task_events()
+            .times(1)
+            .returning(|_, _, _| Err(DomainError::Internal("oh no!".to_string())));
+
+        let error = GetTaskHistoryRequest::new(1, None, None, Actor::System, &task_repo).execute().await.unwrap_err();
+
+        assert_eq!(error.code, 500);
+        assert_eq!(error.message, "Cannot get task history");
+    }
+}
@@ -1,29 +1,34 @@
 use async_trait::async_trait;
+use crate::application::helpers::access_control::AccessControl;
 use crate::application::helpers::error_handler::ErrorHandlingHelper;
 use crate::application::repo_requesters::abstract_request::AbstractRequest;
 use crate::application::repositories::task_abstract_repo::TaskRepositoryAbstract;
+use crate::domain::access::actor::{AccessScope, Actor};
+use crate::domain::access::role::Permission;
 use crate::domain::error::ApiError;
 use crate::domain::task::task::Task;
 
 pub struct GetTaskRequest<'a> {
     task_id: &'a i32,
+    actor: Actor,
     repository: &'a dyn TaskRepositoryAbstract,
 }
 
 impl<'a> GetTaskRequest<'a> {
-    pub fn new(task_id: &'a i32, repository: &'a dyn TaskRepositoryAbstract) -> Self {
-        GetTaskRequest { task_id, repository }
+    pub fn new(task_id: &'a i32, actor: Actor, repository: &'a dyn TaskRepositoryAbstract) -> Self {
+        GetTaskRequest { task_id, actor, repository }
     }
 }
 
 #[async_trait(?Send)]
 impl<'a> AbstractRequest<Task> for GetTaskRequest<'a> {
     async fn execute(&self) -> Result<Task, ApiError> {
+        AccessControl::authorize(self.actor, AccessScope::Task(*self.task_id), Permission::Read, self.repository).await?;
         let task = self.repository.get_task_by_id(*self.task_id).await;
 
         match task {
             Ok(task) => Ok(task),
-            Err(e) => Err(ErrorHandlingHelper::application_error("Cannot get task", Some(e))),
+            Err(e) => Err(ErrorHandlingHelper::domain_error("Cannot get task", e)),
         }
     }
 }
@@ -32,8 +37,8 @@ impl<'a> AbstractRequest<Task> for GetTaskRequest<'a> {
 mod tests {
     use super::*;
     use mockall::predicate::eq;
-    use std::io::{Error, ErrorKind};
     use crate::{application::repositories::task_abstract_repo::MockTaskRepositoryAbstract, domain::task::task::Task};
+    use crate::domain::error::DomainError;
 
     #[actix_rt::test]
     async fn test_should_return_task_when_repo_returns_success() {
@@ -45,7 +50,7 @@ mod tests {
             .times(1)
             .returning(move |_| Ok(expected_task.clone()));
 
-        let get_one_task_by_id = GetTaskRequest::new(&1, &task_repo);
+        let get_one_task_by_id = GetTaskRequest::new(&1, Actor::System, &task_repo);
         let result = get_one_task_by_id.execute().await;
 
         assert!(result.is_ok());
@@ -62,13 +67,14 @@ mod tests {
             .expect_get_task_by_id()
             .with(eq(-1))
             .times(1)
-            .returning(|_| Err(Box::new(Error::new(ErrorKind::InvalidInput, "Invalid ID"))));
+            .returning(|_| Err(DomainError::Validation("Invalid ID provided".to_string())));
 
-        let get_one_task_by_id = GetTaskRequest::new(&-1, &task_repo);
+        let get_one_task_by_id = GetTaskRequest::new(&-1, Actor::System, &task_repo);
         let result = get_one_task_by_id.execute().await;
 
         assert!(result.is_err());
         let error = result.unwrap_err();
+        assert_eq!(422, error.code);
         assert_eq!(error.message, "Invalid ID provided");
     }
 
@@ -79,15 +85,30 @@ mod tests {
             .expect_get_task_by_id()
             .with(eq(1))
             .times(1)
-            .returning(|_| Err(Box::new(Error::new(ErrorKind::Other, "oh no!"))));
+            .returning(|_| Err(DomainError::Internal("oh no!".to_string())));
 
-        let get_one_task_by_id = GetTaskRequest::new(&1, &task_repo);
+        let get_one_task_by_id = GetTaskRequest::new(&1, Actor::System, &task_repo);
         let data = get_one_task_by_id.execute().await;
 
         assert!(data.is_err());
         let result = data.unwrap_err();
+        assert_eq!(500, result.code);
         assert_eq!("Cannot get task", result.message);
     }
-}
 
+    #[actix_rt::test]
+    async fn test_should_return_service_unavailable_when_storage_is_unavailable() {
+        let mut task_repo = MockTaskRepositoryAbstract::new();
+        task_repo
+            .expect_get_task_by_id()
+            .with(eq(1))
+            .times(1)
+            .returning(|_| Err(DomainError::Unavailable("timed out waiting for connection".to_string())));
+
+        let get_one_task_by_id = GetTaskRequest::new(&1, Actor::System, &task_repo);
+        let result = get_one_task_by_id.execute().await;
 
+        let error = result.unwrap_err();
+        assert_eq!(503, error.code);
+    }
+}
@@ -0,0 +1,63 @@
+use async_trait::async_trait;
+use crate::application::helpers::error_handler::ErrorHandlingHelper;
+use crate::application::repo_requesters::abstract_request::AbstractRequest;
+use crate::application::repositories::tag_abstract_repo::TagRepositoryAbstract;
+use crate::domain::error::ApiError;
+use crate::domain::tag::tag::Tag;
+
+pub struct GetTaskTagsRequest<'a> {
+    task_id: i32,
+    repository: &'a dyn TagRepositoryAbstract,
+}
+
+impl<'a> GetTaskTagsRequest<'a> {
+    pub fn new(task_id: i32, repository: &'a dyn TagRepositoryAbstract) -> Self {
+        GetTaskTagsRequest { task_id, repository }
+    }
+}
+
+#[async_trait(?Send)]
+impl<'a> AbstractRequest<Vec<Tag>> for GetTaskTagsRequest<'a> {
+    async fn execute(&self) -> Result<Vec<Tag>, ApiError> {
+        let tags = self.repository.get_task_tags(self.task_id).await;
+
+        match tags {
+            Ok(tags) => Ok(tags),
+            Err(e) => Err(ErrorHandlingHelper::domain_error("Cannot get task tags", e)),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use mockall::predicate::eq;
+    use crate::application::repositories::tag_abstract_repo::MockTagRepositoryAbstract;
+    use crate::domain::task::task::Task;
+
+    #[actix_rt::test]
+    async fn test_should_return_tags_of_task() {
+        let mut tag_repo = MockTagRepositoryAbstract::new();
+        tag_repo.expect_get_task_tags()
+            .with(eq(5))
+            .times(1)
+            .returning(|_| Ok(vec![Tag::new(1, "backend".to_string())]));
+
+        let tags = GetTaskTagsRequest::new(5, &tag_repo).execute().await.unwrap();
+
+        assert_eq!(tags, vec![Tag::new(1, "backend".to_string())]);
+    }
+
+    #[actix_rt::test]
+    async fn test_should_return_not_found_when_task_does_not_exist() {
+        let mut tag_repo = MockTagRepositoryAbstract::new();
+        tag_repo.expect_get_task_tags()
+            .times(1)
+            .returning(|task_id| Err(Task::not_found(task_id)));
+
+        let error = GetTaskTagsRequest::new(5, &tag_repo).execute().await.unwrap_err();
+
+        assert_eq!(404, error.code);
+        assert_eq!("Task 5 not found", error.message);
+    }
+}
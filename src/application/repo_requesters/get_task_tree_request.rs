@@ -0,0 +1,73 @@
+use async_trait::async_trait;
+use crate::application::helpers::access_control::AccessControl;
+use crate::application::helpers::error_handler::ErrorHandlingHelper;
+use crate::application::repo_requesters::abstract_request::AbstractRequest;
+use crate::application::repositories::task_abstract_repo::TaskRepositoryAbstract;
+use crate::domain::access::actor::{AccessScope, Actor};
+use crate::domain::access::role::Permission;
+use crate::domain::error::ApiError;
+use crate::domain::task::task::Task;
+use crate::domain::task::task_tree::TaskTree;
+
+pub struct GetTaskTreeRequest<'a> {
+    task_id: i32,
+    actor: Actor,
+    repository: &'a dyn TaskRepositoryAbstract,
+}
+
+impl<'a> GetTaskTreeRequest<'a> {
+    pub fn new(task_id: i32, actor: Actor, repository: &'a dyn TaskRepositoryAbstract) -> Self {
+        GetTaskTreeRequest { task_id, actor, repository }
+    }
+}
+
+#[async_trait(?Send)]
+impl<'a> AbstractRequest<TaskTree> for GetTaskTreeRequest<'a> {
+    async fn execute(&self) -> Result<TaskTree, ApiError> {
+        AccessControl::authorize(self.actor, AccessScope::Task(self.task_id), Permission::Read, self.repository).await?;
+        let subtree = self.repository.get_task_subtree(self.task_id).await
+            .map_err(|e| ErrorHandlingHelper::domain_error("Cannot get task tree", e))?;
+
+        TaskTree::build(self.task_id, subtree)
+            .ok_or_else(|| ErrorHandlingHelper::domain_error("Cannot get task tree", Task::not_found(self.task_id)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use mockall::predicate::eq;
+    use crate::application::repositories::task_abstract_repo::MockTaskRepositoryAbstract;
+    use crate::domain::task::task_status::TaskStatus;
+
+    #[actix_rt::test]
+    async fn test_should_build_tree_from_subtree() {
+        let mut task_repo = MockTaskRepositoryAbstract::new();
+        task_repo.expect_get_task_subtree()
+            .with(eq(1))
+            .times(1)
+            .returning(|_| Ok(vec![
+                Task::new(1, "Epic".to_string(), None),
+                Task { parent_id: Some(1), status: TaskStatus::Done, ..Task::new(2, "Done part".to_string(), None) },
+                Task { parent_id: Some(1), ..Task::new(3, "Open part".to_string(), None) },
+            ]));
+
+        let tree = GetTaskTreeRequest::new(1, Actor::System, &task_repo).execute().await.unwrap();
+
+        assert_eq!(tree.task.id, 1);
+        assert_eq!(tree.children.len(), 2);
+        assert_eq!(tree.completion, 50.0);
+    }
+
+    #[actix_rt::test]
+    async fn test_should_return_not_found_when_task_does_not_exist() {
+        let mut task_repo = MockTaskRepositoryAbstract::new();
+        task_repo.expect_get_task_subtree()
+            .times(1)
+            .returning(|task_id| Err(Task::not_found(task_id)));
+
+        let error = GetTaskTreeRequest::new(7, Actor::System, &task_repo).execute().await.unwrap_err();
+
+        assert_eq!(404, error.code);
+    }
+}
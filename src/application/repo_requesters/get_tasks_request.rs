@@ -1,28 +1,103 @@
 use async_trait::async_trait;
+use crate::application::helpers::access_control::AccessControl;
 use crate::application::helpers::error_handler::ErrorHandlingHelper;
 use crate::application::repo_requesters::abstract_request::AbstractRequest;
 use crate::application::repositories::task_abstract_repo::TaskRepositoryAbstract;
+use crate::domain::access::actor::{AccessScope, Actor};
+use crate::domain::access::role::Permission;
 use crate::domain::error::ApiError;
-use crate::domain::task::task::Task;
-
-pub struct GetAllTasksRequest<'a> {
+use crate::domain::task::task_query::{Pagination, SortOrder, TaskCursor, TaskFilter, TaskPage, TaskQuery, TaskSortField};
+
+pub const DEFAULT_PAGE_LIMIT: i64 = 20;
+pub const MAX_PAGE_LIMIT: i64 = 100;
+/// Наибольшее количество меток в фильтре списка задач.
+pub const MAX_TAG_FILTERS: usize = 20;
+
+pub struct GetTasksRequest<'a> {
+    filter: TaskFilter,
+    sort_by: Option<TaskSortField>,
+    order: Option<SortOrder>,
+    limit: Option<i64>,
+    offset: Option<i64>,
+    cursor: Option<String>,
+    actor: Actor,
     repository: &'a dyn TaskRepositoryAbstract,
 }
 
-impl<'a> GetAllTasksRequest<'a> {
-    pub fn new(repository: &'a dyn TaskRepositoryAbstract) -> Self {
-        GetAllTasksRequest { repository }
+impl<'a> GetTasksRequest<'a> {
+    #[allow(clippy::too_many_arguments)]
+    pub fn new(filter: TaskFilter, sort_by: Option<TaskSortField>, order: Option<SortOrder>, limit: Option<i64>, offset: Option<i64>, cursor: Option<String>, actor: Actor, repository: &'a dyn TaskRepositoryAbstract) -> Self {
+        GetTasksRequest { filter, sort_by, order, limit, offset, cursor, actor, repository }
+    }
+
+    /// Ограничивает выборку задачами проекта `project_id`.
+    pub fn in_project(mut self, project_id: i32) -> Self {
+        self.filter.project_id = Some(project_id);
+        self
+    }
+
+    /// Ограничивает выборку задачами, на которые назначен пользователь `user_id`.
+    pub fn assigned_to(mut self, user_id: i32) -> Self {
+        self.filter.assignee_id = Some(user_id);
+        self
+    }
+
+    /// Проверяет параметры запроса и собирает из них `TaskQuery`.
+    ///
+    /// Курсор несёт в себе сортировку, для которой был выдан, поэтому при передаче курсора
+    /// явно указанная сортировка должна с ним совпадать.
+    fn build_query(&self) -> Result<TaskQuery, ApiError> {
+        let limit = self.limit.unwrap_or(DEFAULT_PAGE_LIMIT);
+        if !(1..=MAX_PAGE_LIMIT).contains(&limit) {
+            return Err(ErrorHandlingHelper::application_error(&format!("limit must be between 1 and {}", MAX_PAGE_LIMIT), None));
+        }
+
+        let (sort_by, order, pagination) = match (&self.cursor, self.offset) {
+            (Some(_), Some(_)) => {
+                return Err(ErrorHandlingHelper::application_error("cursor and offset cannot be used together", None));
+            },
+            (Some(token), None) => {
+                let cursor = TaskCursor::decode(token)
+                    .map_err(|e| ErrorHandlingHelper::application_error(&e.to_string(), Some(Box::new(e))))?;
+                let sort_matches = self.sort_by.is_none_or(|sort_by| sort_by == cursor.sort_by)
+                    && self.order.is_none_or(|order| order == cursor.order);
+                if !sort_matches {
+                    return Err(ErrorHandlingHelper::application_error("cursor was issued for a different sort order", None));
+                }
+                (cursor.sort_by, cursor.order, Pagination::Cursor(cursor))
+            },
+            (None, offset) => {
+                let offset = offset.unwrap_or(0);
+                if offset < 0 {
+                    return Err(ErrorHandlingHelper::application_error("offset must not be negative", None));
+                }
+                (self.sort_by.unwrap_or_default(), self.order.unwrap_or_default(), Pagination::Offset(offset))
+            },
+        };
+
+        if let (Some(from), Some(to)) = (self.filter.id_from, self.filter.id_to) {
+            if from > to {
+                return Err(ErrorHandlingHelper::application_error("id_from must not be greater than id_to", None));
+            }
+        }
+        if self.filter.tags.len() > MAX_TAG_FILTERS {
+            return Err(ErrorHandlingHelper::application_error(&format!("at most {} tags can be used in a filter", MAX_TAG_FILTERS), None));
+        }
+
+        Ok(TaskQuery { filter: self.filter.clone(), sort_by, order, pagination, limit })
     }
 }
 
 #[async_trait(?Send)]
-impl<'a> AbstractRequest<Vec<Task>> for GetAllTasksRequest<'a> {
-    async fn execute(&self) -> Result<Vec<Task>, ApiError> {
-        let tasks = self.repository.get_all_tasks().await;
-
-        match tasks {
-            Ok(tasks) => Ok(tasks),
-            Err(e) => Err(ErrorHandlingHelper::application_error("Cannot get all tasks", Some(e))),
+impl<'a> AbstractRequest<TaskPage> for GetTasksRequest<'a> {
+    async fn execute(&self) -> Result<TaskPage, ApiError> {
+        AccessControl::authorize(self.actor, AccessScope::for_project(self.filter.project_id), Permission::Read, self.repository).await?;
+        let query = self.build_query()?;
+        let page = self.repository.get_tasks(query).await;
+
+        match page {
+            Ok(page) => Ok(page),
+            Err(e) => Err(ErrorHandlingHelper::domain_error("Cannot get all tasks", e)),
         }
     }
 }
@@ -31,8 +106,19 @@ impl<'a> AbstractRequest<Vec<Task>> for GetAllTasksRequest<'a> {
 mod tests {
     use super::*;
     use mockall::predicate::*;
-    use std::io::{Error, ErrorKind};
+    use crate::domain::error::DomainError;
     use crate::{application::repositories::task_abstract_repo::MockTaskRepositoryAbstract, domain::task::task::Task};
+    use crate::domain::task::task_query::CursorDirection;
+
+    fn default_query() -> TaskQuery {
+        TaskQuery {
+            filter: TaskFilter::default(),
+            sort_by: TaskSortField::Id,
+            order: SortOrder::Asc,
+            pagination: Pagination::Offset(0),
+            limit: DEFAULT_PAGE_LIMIT,
+        }
+    }
 
     #[actix_rt::test]
     async fn test_should_return_all_tasks_when_repo_returns_success() {
@@ -41,15 +127,18 @@ mod tests {
             Task::new(1, "Complete the project".to_string(), None),
             Task::new(2, "Start the new module".to_string(), Some("Important step".to_string())),
         ];
-        task_repo.expect_get_all_tasks()
+        task_repo.expect_get_tasks()
+            .with(eq(default_query()))
             .times(1)
-            .returning(move || Ok(expected_tasks.clone()));
+            .returning(move |query| Ok(TaskPage::new(expected_tasks.clone(), 2, &query, false, false)));
 
-        let get_all_tasks_request = GetAllTasksRequest::new(&task_repo);
-        let result = get_all_tasks_request.execute().await;
+        let get_tasks_request = GetTasksRequest::new(TaskFilter::default(), None, None, None, None, None, Actor::System, &task_repo);
+        let result = get_tasks_request.execute().await;
 
         assert!(result.is_ok());
-        let tasks = result.unwrap();
+        let page = result.unwrap();
+        let tasks = page.items;
+        assert_eq!(page.total, 2);
         assert_eq!(tasks.len(), 2);
         assert_eq!(tasks[0].id, 1);
         assert_eq!(tasks[0].title, "Complete the project");
@@ -62,20 +151,75 @@ mod tests {
     #[actix_rt::test]
     async fn test_should_return_error_when_repo_returns_error() {
         let mut task_repo = MockTaskRepositoryAbstract::new();
-        task_repo.expect_get_all_tasks()
+        task_repo.expect_get_tasks()
             .times(1)
-            .returning(|| Err(Box::new(Error::new(ErrorKind::Other, "Database error"))));
+            .returning(|_| Err(DomainError::Internal("Database error".to_string())));
 
-        let get_all_tasks_request = GetAllTasksRequest::new(&task_repo);
-        let result = get_all_tasks_request.execute().await;
+        let get_tasks_request = GetTasksRequest::new(TaskFilter::default(), None, None, None, None, None, Actor::System, &task_repo);
+        let result = get_tasks_request.execute().await;
 
         assert!(result.is_err());
         let error = result.unwrap_err();
+        assert_eq!(500, error.code);
         assert_eq!("Cannot get all tasks", error.message);
     }
-}
 
+    #[actix_rt::test]
+    async fn test_should_take_sort_order_from_cursor() {
+        let mut task_repo = MockTaskRepositoryAbstract::new();
+        let task = Task::new(5, "Write report".to_string(), None);
+        let cursor = TaskCursor::for_task(&task, CursorDirection::Next, TaskSortField::Title, SortOrder::Desc);
+        let mut expected_query = default_query();
+        expected_query.sort_by = TaskSortField::Title;
+        expected_query.order = SortOrder::Desc;
+        expected_query.pagination = Pagination::Cursor(cursor.clone());
+        task_repo.expect_get_tasks()
+            .with(eq(expected_query))
+            .times(1)
+            .returning(|query| Ok(TaskPage::new(vec![], 0, &query, true, false)));
+
+        let get_tasks_request = GetTasksRequest::new(TaskFilter::default(), None, None, None, None, Some(cursor.encode()), Actor::System, &task_repo);
+        let result = get_tasks_request.execute().await;
+
+        assert!(result.is_ok());
+    }
+
+    #[actix_rt::test]
+    async fn test_should_reject_cursor_issued_for_different_sort() {
+        let mut task_repo = MockTaskRepositoryAbstract::new();
+        let task = Task::new(5, "Write report".to_string(), None);
+        let cursor = TaskCursor::for_task(&task, CursorDirection::Next, TaskSortField::Title, SortOrder::Asc);
+        task_repo.expect_get_tasks().times(0);
+
+        let get_tasks_request = GetTasksRequest::new(TaskFilter::default(), Some(TaskSortField::Id), None, None, None, Some(cursor.encode()), Actor::System, &task_repo);
+        let result = get_tasks_request.execute().await;
 
+        assert_eq!("cursor was issued for a different sort order", result.unwrap_err().message);
+    }
 
+    #[actix_rt::test]
+    async fn test_should_reject_invalid_paging_parameters() {
+        let task_repo = MockTaskRepositoryAbstract::new();
 
+        let too_large = GetTasksRequest::new(TaskFilter::default(), None, None, Some(MAX_PAGE_LIMIT + 1), None, None, Actor::System, &task_repo);
+        let both = GetTasksRequest::new(TaskFilter::default(), None, None, None, Some(10), Some("abc".to_string()), Actor::System, &task_repo);
+        let garbage = GetTasksRequest::new(TaskFilter::default(), None, None, None, None, Some("abc".to_string()), Actor::System, &task_repo);
 
+        assert_eq!("limit must be between 1 and 100", too_large.execute().await.unwrap_err().message);
+        assert_eq!("cursor and offset cannot be used together", both.execute().await.unwrap_err().message);
+        assert_eq!("Invalid pagination cursor", garbage.execute().await.unwrap_err().message);
+    }
+
+    #[actix_rt::test]
+    async fn test_should_reject_too_many_tags_in_filter() {
+        let task_repo = MockTaskRepositoryAbstract::new();
+        let filter = TaskFilter {
+            tags: (0..=MAX_TAG_FILTERS).map(|i| format!("tag{}", i)).collect(),
+            ..TaskFilter::default()
+        };
+
+        let result = GetTasksRequest::new(filter, None, None, None, None, None, Actor::System, &task_repo).execute().await;
+
+        assert_eq!("at most 20 tags can be used in a filter", result.unwrap_err().message);
+    }
+}
@@ -0,0 +1,98 @@
+use async_trait::async_trait;
+use crate::application::helpers::access_control::AccessControl;
+use crate::application::helpers::error_handler::ErrorHandlingHelper;
+use crate::application::repo_requesters::abstract_request::AbstractRequest;
+use crate::application::repo_requesters::get_tasks_request::{DEFAULT_PAGE_LIMIT, MAX_PAGE_LIMIT};
+use crate::application::repositories::task_abstract_repo::TaskRepositoryAbstract;
+use crate::domain::access::actor::{AccessScope, Actor};
+use crate::domain::access::role::Permission;
+use crate::domain::error::ApiError;
+use crate::domain::task::task_trash::TrashPage;
+
+pub struct GetTrashRequest<'a> {
+    limit: Option<i64>,
+    offset: Option<i64>,
+    actor: Actor,
+    repository: &'a dyn TaskRepositoryAbstract,
+}
+
+impl<'a> GetTrashRequest<'a> {
+    pub fn new(limit: Option<i64>, offset: Option<i64>, actor: Actor, repository: &'a dyn TaskRepositoryAbstract) -> Self {
+        GetTrashRequest { limit, offset, actor, repository }
+    }
+}
+
+#[async_trait(?Send)]
+impl<'a> AbstractRequest<TrashPage> for GetTrashRequest<'a> {
+    async fn execute(&self) -> Result<TrashPage, ApiError> {
+        let limit = self.limit.unwrap_or(DEFAULT_PAGE_LIMIT);
+        if !(1..=MAX_PAGE_LIMIT).contains(&limit) {
+            return Err(ErrorHandlingHelper::application_error(&format!("limit must be between 1 and {}", MAX_PAGE_LIMIT), None));
+        }
+        let offset = self.offset.unwrap_or(0);
+        if offset < 0 {
+            return Err(ErrorHandlingHelper::application_error("offset must not be negative", None));
+        }
+
+        AccessControl::authorize(self.actor, AccessScope::Global, Permission::Read, self.repository).await?;
+        let page = self.repository.get_trash(limit, offset).await;
+
+        match page {
+            Ok(page) => Ok(page),
+            Err(e) => Err(ErrorHandlingHelper::domain_error("Cannot get trash", e)),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::Utc;
+    use mockall::predicate::eq;
+    use crate::application::repositories::task_abstract_repo::MockTaskRepositoryAbstract;
+    use crate::domain::error::DomainError;
+    use crate::domain::task::task::Task;
+
+    #[actix_rt::test]
+    async fn test_should_return_trash_page_with_default_limit() {
+        let mut task_repo = MockTaskRepositoryAbstract::new();
+        task_repo
+            .expect_get_trash()
+            .with(eq(DEFAULT_PAGE_LIMIT), eq(0))
+            .times(1)
+            .returning(|limit, offset| {
+                let trashed = Task { deleted_at: Some(Utc::now()), ..Task::new(1, "Deleted by mistake".to_string(), None) };
+                Ok(TrashPage { items: vec![trashed], total: 1, limit, offset })
+            });
+
+        let page = GetTrashRequest::new(None, None, Actor::System, &task_repo).execute().await.unwrap();
+
+        assert_eq!(page.total, 1);
+        assert!(page.items[0].deleted_at.is_some());
+    }
+
+    #[actix_rt::test]
+    async fn test_should_reject_invalid_pagination() {
+        let task_repo = MockTaskRepositoryAbstract::new();
+
+        let limit = GetTrashRequest::new(Some(0), None, Actor::System, &task_repo).execute().await;
+        assert_eq!(limit.unwrap_err().code, 400);
+
+        let offset = GetTrashRequest::new(None, Some(-5), Actor::System, &task_repo).execute().await;
+        assert_eq!(offset.unwrap_err().code, 400);
+    }
+
+    #[actix_rt::test]
+    async fn test_should_return_generic_message_when_unexpected_repo_error() {
+        let mut task_repo = MockTaskRepositoryAbstract::new();
+        task_repo
+            .expect_get_trash()
+            .times(1)
+            .returning(|_, _| Err(DomainError::Internal("oh no!".to_string())));
+
+        let error = GetTrashRequest::new(None, None, Actor::System, &task_repo).execute().await.unwrap_err();
+
+        assert_eq!(error.code, 500);
+        assert_eq!(error.message, "Cannot get trash");
+    }
+}
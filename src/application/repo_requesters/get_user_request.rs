@@ -0,0 +1,62 @@
+use async_trait::async_trait;
+use crate::application::helpers::error_handler::ErrorHandlingHelper;
+use crate::application::repo_requesters::abstract_request::AbstractRequest;
+use crate::application::repositories::user_abstract_repo::UserRepositoryAbstract;
+use crate::domain::error::ApiError;
+use crate::domain::user::user::User;
+
+pub struct GetUserRequest<'a> {
+    user_id: i32,
+    repository: &'a dyn UserRepositoryAbstract,
+}
+
+impl<'a> GetUserRequest<'a> {
+    pub fn new(user_id: i32, repository: &'a dyn UserRepositoryAbstract) -> Self {
+        GetUserRequest { user_id, repository }
+    }
+}
+
+#[async_trait(?Send)]
+impl<'a> AbstractRequest<User> for GetUserRequest<'a> {
+    async fn execute(&self) -> Result<User, ApiError> {
+        let user = self.repository.get_user_by_id(self.user_id).await;
+
+        match user {
+            Ok(user) => Ok(user),
+            Err(e) => Err(ErrorHandlingHelper::domain_error("Cannot get user", e)),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use mockall::predicate::eq;
+    use crate::application::repositories::user_abstract_repo::MockUserRepositoryAbstract;
+
+    #[actix_rt::test]
+    async fn test_should_return_user_when_repo_returns_success() {
+        let mut user_repo = MockUserRepositoryAbstract::new();
+        user_repo.expect_get_user_by_id()
+            .with(eq(1))
+            .times(1)
+            .returning(|user_id| Ok(User::new(user_id, "Ada".to_string(), "ada@example.com".to_string())));
+
+        let user = GetUserRequest::new(1, &user_repo).execute().await.unwrap();
+
+        assert_eq!(user.email, "ada@example.com");
+    }
+
+    #[actix_rt::test]
+    async fn test_should_return_not_found_when_user_does_not_exist() {
+        let mut user_repo = MockUserRepositoryAbstract::new();
+        user_repo.expect_get_user_by_id()
+            .times(1)
+            .returning(|user_id| Err(User::not_found(user_id)));
+
+        let error = GetUserRequest::new(7, &user_repo).execute().await.unwrap_err();
+
+        assert_eq!(404, error.code);
+        assert_eq!("User 7 not found", error.message);
+    }
+}
@@ -0,0 +1,77 @@
+use async_trait::async_trait;
+use crate::application::helpers::error_handler::ErrorHandlingHelper;
+use crate::application::repo_requesters::abstract_request::AbstractRequest;
+use crate::application::repo_requesters::get_tasks_request::GetTasksRequest;
+use crate::application::repositories::user_abstract_repo::UserRepositoryAbstract;
+use crate::domain::error::ApiError;
+use crate::domain::task::task_query::TaskPage;
+
+/// Страница задач, на которые назначен пользователь; для несуществующего пользователя возвращает 404.
+pub struct GetUserTasksRequest<'a> {
+    user_id: i32,
+    tasks_request: GetTasksRequest<'a>,
+    repository: &'a dyn UserRepositoryAbstract,
+}
+
+impl<'a> GetUserTasksRequest<'a> {
+    pub fn new(user_id: i32, tasks_request: GetTasksRequest<'a>, repository: &'a dyn UserRepositoryAbstract) -> Self {
+        GetUserTasksRequest { user_id, tasks_request: tasks_request.assigned_to(user_id), repository }
+    }
+}
+
+#[async_trait(?Send)]
+impl<'a> AbstractRequest<TaskPage> for GetUserTasksRequest<'a> {
+    async fn execute(&self) -> Result<TaskPage, ApiError> {
+        self.repository.get_user_by_id(self.user_id).await
+            .map_err(|e| ErrorHandlingHelper::domain_error("Cannot get user tasks", e))?;
+
+        self.tasks_request.execute().await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::access::actor::Actor;
+    use mockall::predicate::{eq, function};
+    use crate::application::repositories::task_abstract_repo::MockTaskRepositoryAbstract;
+    use crate::application::repositories::user_abstract_repo::MockUserRepositoryAbstract;
+    use crate::domain::task::task::Task;
+    use crate::domain::task::task_query::{TaskFilter, TaskQuery};
+    use crate::domain::user::user::User;
+
+    #[actix_rt::test]
+    async fn test_should_list_only_tasks_assigned_to_user() {
+        let mut user_repo = MockUserRepositoryAbstract::new();
+        user_repo.expect_get_user_by_id()
+            .with(eq(3))
+            .times(1)
+            .returning(|id| Ok(User::new(id, "Ada".to_string(), "ada@example.com".to_string())));
+        let mut task_repo = MockTaskRepositoryAbstract::new();
+        task_repo.expect_get_tasks()
+            .with(function(|query: &TaskQuery| query.filter.assignee_id == Some(3) && query.filter.title_contains.as_deref() == Some("API")))
+            .times(1)
+            .returning(|query| Ok(TaskPage::new(vec![Task::new(1, "Design API".to_string(), None)], 1, &query, false, false)));
+
+        let filter = TaskFilter { title_contains: Some("API".to_string()), ..TaskFilter::default() };
+        let tasks_request = GetTasksRequest::new(filter, None, None, None, None, None, Actor::System, &task_repo);
+        let page = GetUserTasksRequest::new(3, tasks_request, &user_repo).execute().await.unwrap();
+
+        assert_eq!(page.items.len(), 1);
+    }
+
+    #[actix_rt::test]
+    async fn test_should_return_not_found_without_listing_tasks() {
+        let mut user_repo = MockUserRepositoryAbstract::new();
+        user_repo.expect_get_user_by_id()
+            .times(1)
+            .returning(|id| Err(User::not_found(id)));
+        let mut task_repo = MockTaskRepositoryAbstract::new();
+        task_repo.expect_get_tasks().times(0);
+
+        let tasks_request = GetTasksRequest::new(TaskFilter::default(), None, None, None, None, None, Actor::System, &task_repo);
+        let error = GetUserTasksRequest::new(6, tasks_request, &user_repo).execute().await.unwrap_err();
+
+        assert_eq!(404, error.code);
+    }
+}
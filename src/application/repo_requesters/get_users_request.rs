@@ -0,0 +1,60 @@
+use async_trait::async_trait;
+use crate::application::helpers::error_handler::ErrorHandlingHelper;
+use crate::application::repo_requesters::abstract_request::AbstractRequest;
+use crate::application::repositories::user_abstract_repo::UserRepositoryAbstract;
+use crate::domain::error::ApiError;
+use crate::domain::user::user::User;
+
+pub struct GetUsersRequest<'a> {
+    repository: &'a dyn UserRepositoryAbstract,
+}
+
+impl<'a> GetUsersRequest<'a> {
+    pub fn new(repository: &'a dyn UserRepositoryAbstract) -> Self {
+        GetUsersRequest { repository }
+    }
+}
+
+#[async_trait(?Send)]
+impl<'a> AbstractRequest<Vec<User>> for GetUsersRequest<'a> {
+    async fn execute(&self) -> Result<Vec<User>, ApiError> {
+        let users = self.repository.get_users().await;
+
+        match users {
+            Ok(users) => Ok(users),
+            Err(e) => Err(ErrorHandlingHelper::domain_error("Cannot get users", e)),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::application::repositories::user_abstract_repo::MockUserRepositoryAbstract;
+    use crate::domain::error::DomainError;
+
+    #[actix_rt::test]
+    async fn test_should_return_all_users() {
+        let mut user_repo = MockUserRepositoryAbstract::new();
+        user_repo.expect_get_users()
+            .times(1)
+            .returning(|| Ok(vec![User::new(1, "Ada".to_string(), "ada@example.com".to_string())]));
+
+        let result = GetUsersRequest::new(&user_repo).execute().await;
+
+        assert_eq!(result.unwrap().len(), 1);
+    }
+
+    #[actix_rt::test]
+    async fn test_should_return_error_when_repo_returns_error() {
+        let mut user_repo = MockUserRepositoryAbstract::new();
+        user_repo.expect_get_users()
+            .times(1)
+            .returning(|| Err(DomainError::Internal("Database error".to_string())));
+
+        let error = GetUsersRequest::new(&user_repo).execute().await.unwrap_err();
+
+        assert_eq!(500, error.code);
+        assert_eq!("Cannot get users", error.message);
+    }
+}
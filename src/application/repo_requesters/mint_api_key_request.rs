@@ -0,0 +1,84 @@
+use async_trait::async_trait;
+use crate::application::helpers::access_control::AccessControl;
+use crate::application::helpers::api_key_secret::ApiKeySecret;
+use crate::application::helpers::error_handler::ErrorHandlingHelper;
+use crate::application::repo_requesters::abstract_request::AbstractRequest;
+use crate::application::repositories::api_key_abstract_repo::ApiKeyRepositoryAbstract;
+use crate::application::repositories::task_abstract_repo::TaskRepositoryAbstract;
+use crate::domain::access::actor::{AccessScope, Actor};
+use crate::domain::access::api_key::{ApiKeyScope, MintedApiKey};
+use crate::domain::access::role::Permission;
+use crate::domain::error::{ApiError, DomainError};
+
+/// Выпускает ключ API для сервисной учётной записи; доступно глобальным администраторам.
+///
+/// Значение ключа возвращается только в ответе на этот запрос, в хранилище попадает его хеш.
+pub struct MintApiKeyRequest<'a> {
+    name: String,
+    scopes: Vec<ApiKeyScope>,
+    actor: Actor,
+    access_repository: &'a dyn TaskRepositoryAbstract,
+    repository: &'a dyn ApiKeyRepositoryAbstract,
+}
+
+impl<'a> MintApiKeyRequest<'a> {
+    pub fn new(name: String, scopes: Vec<ApiKeyScope>, actor: Actor, access_repository: &'a dyn TaskRepositoryAbstract, repository: &'a dyn ApiKeyRepositoryAbstract) -> Self {
+        MintApiKeyRequest { name, scopes, actor, access_repository, repository }
+    }
+}
+
+#[async_trait(?Send)]
+impl<'a> AbstractRequest<MintedApiKey> for MintApiKeyRequest<'a> {
+    async fn execute(&self) -> Result<MintedApiKey, ApiError> {
+        AccessControl::authorize(self.actor, AccessScope::Global, Permission::Manage, self.access_repository).await?;
+        let (key, prefix) = ApiKeySecret::generate()
+            .map_err(|e| ErrorHandlingHelper::domain_error("Cannot mint API key", DomainError::Internal(e.to_string())))?;
+
+        let mut scopes = self.scopes.clone();
+        scopes.sort();
+        scopes.dedup();
+        let api_key = self.repository.create_api_key(self.name.clone(), prefix, ApiKeySecret::hash(&key), scopes).await;
+
+        match api_key {
+            Ok(api_key) => Ok(MintedApiKey { api_key, key }),
+            Err(e) => Err(ErrorHandlingHelper::domain_error("Cannot mint API key", e)),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::Utc;
+    use mockall::predicate::{always, eq, function};
+    use crate::application::repositories::api_key_abstract_repo::MockApiKeyRepositoryAbstract;
+    use crate::application::repositories::task_abstract_repo::MockTaskRepositoryAbstract;
+    use crate::domain::access::api_key::ApiKey;
+
+    #[actix_rt::test]
+    async fn test_should_store_only_hash_of_minted_key() {
+        let task_repo = MockTaskRepositoryAbstract::new();
+        let mut api_key_repo = MockApiKeyRepositoryAbstract::new();
+        api_key_repo.expect_create_api_key()
+            .with(eq("ci-bot".to_string()), always(), function(|hash: &String| hash.len() == 64), eq(vec![ApiKeyScope::ReadTasks, ApiKeyScope::WriteTasks]))
+            .times(1)
+            .returning(|name, prefix, _, scopes| Ok(ApiKey { id: 1, name, prefix, scopes, created_at: Utc::now(), last_used_at: None, revoked_at: None, workspace_id: 1 }));
+        let scopes = vec![ApiKeyScope::WriteTasks, ApiKeyScope::ReadTasks, ApiKeyScope::WriteTasks];
+
+        let minted = MintApiKeyRequest::new("ci-bot".to_string(), scopes, Actor::System, &task_repo, &api_key_repo).execute().await.unwrap();
+
+        assert!(minted.key.starts_with(&minted.api_key.prefix));
+        assert_ne!(ApiKeySecret::hash(&minted.key), minted.key);
+    }
+
+    #[actix_rt::test]
+    async fn test_should_forbid_guest_to_mint_keys() {
+        let task_repo = MockTaskRepositoryAbstract::new();
+        let mut api_key_repo = MockApiKeyRepositoryAbstract::new();
+        api_key_repo.expect_create_api_key().times(0);
+
+        let error = MintApiKeyRequest::new("ci-bot".to_string(), vec![ApiKeyScope::ReadTasks], Actor::Guest, &task_repo, &api_key_repo).execute().await.unwrap_err();
+
+        assert_eq!(403, error.code);
+    }
+}
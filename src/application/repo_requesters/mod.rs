@@ -3,4 +3,49 @@ pub mod abstract_request;
 pub mod get_task_request;
 pub mod create_task_request;
 pub mod update_task_request;
-pub mod delete_task_request;
\ No newline at end of file
+pub mod delete_task_request;
+pub mod restore_task_request;
+pub mod get_trash_request;
+pub mod purge_trash_request;
+pub mod transition_task_request;
+pub mod search_tasks_request;
+pub mod patch_task_request;
+pub mod bulk_tasks_request;
+pub mod get_due_tasks_request;
+pub mod get_tags_request;
+pub mod get_tag_request;
+pub mod create_tag_request;
+pub mod update_tag_request;
+pub mod delete_tag_request;
+pub mod get_task_tags_request;
+pub mod attach_tag_request;
+pub mod detach_tag_request;
+pub mod get_projects_request;
+pub mod get_project_request;
+pub mod create_project_request;
+pub mod update_project_request;
+pub mod archive_project_request;
+pub mod delete_project_request;
+pub mod get_project_tasks_request;
+pub mod get_task_tree_request;
+pub mod get_task_history_request;
+pub mod move_task_request;
+pub mod get_task_blockers_request;
+pub mod add_task_dependency_request;
+pub mod remove_task_dependency_request;
+pub mod get_critical_path_request;
+pub mod get_users_request;
+pub mod get_user_request;
+pub mod create_user_request;
+pub mod get_task_assignees_request;
+pub mod assign_task_request;
+pub mod unassign_task_request;
+pub mod get_user_tasks_request;
+pub mod get_project_members_request;
+pub mod set_project_member_request;
+pub mod remove_project_member_request;
+pub mod set_user_role_request;
+pub mod get_api_keys_request;
+pub mod mint_api_key_request;
+pub mod revoke_api_key_request;
+pub mod authenticate_api_key_request;
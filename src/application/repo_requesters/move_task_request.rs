@@ -0,0 +1,98 @@
+use async_trait::async_trait;
+use crate::application::helpers::access_control::AccessControl;
+use crate::application::helpers::error_handler::ErrorHandlingHelper;
+use crate::application::repo_requesters::abstract_request::AbstractRequest;
+use crate::application::repositories::task_abstract_repo::TaskRepositoryAbstract;
+use crate::domain::access::actor::{AccessScope, Actor};
+use crate::domain::access::role::Permission;
+use crate::domain::error::ApiError;
+use crate::domain::task::task::Task;
+use crate::domain::task::task_tree::check_new_parent;
+
+/// Перемещает задачу вместе с её подзадачами под другого родителя или в корень.
+pub struct MoveTaskRequest<'a> {
+    task_id: i32,
+    parent_id: Option<i32>,
+    expected_version: Option<i32>,
+    actor: Actor,
+    repository: &'a dyn TaskRepositoryAbstract,
+}
+
+impl<'a> MoveTaskRequest<'a> {
+    pub fn new(task_id: i32, parent_id: Option<i32>, expected_version: Option<i32>, actor: Actor, repository: &'a dyn TaskRepositoryAbstract) -> Self {
+        MoveTaskRequest { task_id, parent_id, expected_version, actor, repository }
+    }
+}
+
+#[async_trait(?Send)]
+impl<'a> AbstractRequest<Task> for MoveTaskRequest<'a> {
+    async fn execute(&self) -> Result<Task, ApiError> {
+        AccessControl::authorize(self.actor, AccessScope::Task(self.task_id), Permission::Write, self.repository).await?;
+        if let Some(parent_id) = self.parent_id {
+            AccessControl::authorize(self.actor, AccessScope::Task(parent_id), Permission::Write, self.repository).await?;
+            let lineage = self.repository.get_task_lineage(parent_id).await
+                .map_err(|e| ErrorHandlingHelper::domain_error("Cannot move task", e))?;
+            check_new_parent(self.task_id, &lineage)
+                .map_err(|e| ErrorHandlingHelper::domain_error("Cannot move task", e))?;
+        }
+
+        let task = self.repository.move_task(self.task_id, self.parent_id, self.expected_version).await;
+
+        match task {
+            Ok(task) => Ok(task),
+            Err(e) => Err(ErrorHandlingHelper::domain_error("Cannot move task", e)),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use mockall::predicate::eq;
+    use crate::application::repositories::task_abstract_repo::MockTaskRepositoryAbstract;
+
+    #[actix_rt::test]
+    async fn test_should_move_task_under_new_parent() {
+        let mut task_repo = MockTaskRepositoryAbstract::new();
+        task_repo.expect_get_task_lineage()
+            .with(eq(4))
+            .times(1)
+            .returning(|_| Ok(vec![4, 1]));
+        task_repo.expect_move_task()
+            .with(eq(2), eq(Some(4)), eq(Some(3)))
+            .times(1)
+            .returning(|task_id, parent_id, _| Ok(Task { parent_id, ..Task::new(task_id, "Subtask".to_string(), None) }));
+
+        let task = MoveTaskRequest::new(2, Some(4), Some(3), Actor::System, &task_repo).execute().await.unwrap();
+
+        assert_eq!(task.parent_id, Some(4));
+    }
+
+    #[actix_rt::test]
+    async fn test_should_reject_move_under_own_descendant() {
+        let mut task_repo = MockTaskRepositoryAbstract::new();
+        task_repo.expect_get_task_lineage()
+            .times(1)
+            .returning(|_| Ok(vec![5, 2, 1]));
+        task_repo.expect_move_task().times(0);
+
+        let error = MoveTaskRequest::new(2, Some(5), None, Actor::System, &task_repo).execute().await.unwrap_err();
+
+        assert_eq!(409, error.code);
+        assert_eq!("Task 2 cannot be moved under task 5: a task cannot become its own descendant", error.message);
+    }
+
+    #[actix_rt::test]
+    async fn test_should_move_task_to_root_without_lineage_check() {
+        let mut task_repo = MockTaskRepositoryAbstract::new();
+        task_repo.expect_get_task_lineage().times(0);
+        task_repo.expect_move_task()
+            .with(eq(2), eq(None), eq(None))
+            .times(1)
+            .returning(|task_id, _, _| Ok(Task::new(task_id, "Subtask".to_string(), None)));
+
+        let task = MoveTaskRequest::new(2, None, None, Actor::System, &task_repo).execute().await.unwrap();
+
+        assert_eq!(task.parent_id, None);
+    }
+}
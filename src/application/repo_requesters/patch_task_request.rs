@@ -0,0 +1,360 @@
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use json_patch::{Patch, PatchErrorKind};
+use serde_json::{json, Map, Value};
+use crate::application::helpers::access_control::AccessControl;
+use crate::application::helpers::error_handler::ErrorHandlingHelper;
+use crate::application::repo_requesters::abstract_request::AbstractRequest;
+use crate::application::repositories::task_abstract_repo::TaskRepositoryAbstract;
+use crate::application::validation::task_rules::{check_estimate, check_schedule_dates, DESCRIPTION_RULES, TITLE_RULES};
+use crate::application::validation::validator::Validator;
+use crate::domain::access::actor::{AccessScope, Actor};
+use crate::domain::access::role::Permission;
+use crate::domain::error::{ApiError, FieldError};
+use crate::domain::task::task::Task;
+use crate::domain::task::task_patch::TaskPatch;
+use crate::domain::task::task_priority::TaskPriority;
+use crate::domain::task::task_status::TaskStatus;
+
+/// Документ частичного изменения задачи.
+#[derive(Debug, Clone, PartialEq)]
+pub enum TaskPatchDocument {
+    /// JSON Merge Patch (RFC 7396): отсутствующее поле не меняется, `null` очищает его.
+    MergePatch(Value),
+    /// JSON Patch (RFC 6902): последовательность операций над документом задачи.
+    JsonPatch(Patch),
+}
+
+pub struct PatchTaskRequest<'a> {
+    task_id: i32,
+    document: TaskPatchDocument,
+    if_match: Option<i32>,
+    actor: Actor,
+    repository: &'a dyn TaskRepositoryAbstract,
+}
+
+impl<'a> PatchTaskRequest<'a> {
+    pub fn new(task_id: i32, document: TaskPatchDocument, if_match: Option<i32>, actor: Actor, repository: &'a dyn TaskRepositoryAbstract) -> Self {
+        PatchTaskRequest { task_id, document, if_match, actor, repository }
+    }
+
+    /// Применяет документ изменения к редактируемым полям задачи `task`.
+    ///
+    /// Операции JSON Patch выполняются атомарно: при ошибке любой из них документ не меняется.
+    fn apply_document(&self, task: &Task) -> Result<Value, ApiError> {
+        let mut document = json!({
+            "title": task.title,
+            "description": task.description,
+            "status": task.status,
+            "priority": task.priority,
+            "start_at": task.start_at,
+            "due_at": task.due_at,
+            "project_id": task.project_id,
+            "estimate_minutes": task.estimate_minutes,
+        });
+
+        match &self.document {
+            TaskPatchDocument::MergePatch(merge_patch) => {
+                if !merge_patch.is_object() {
+                    return Err(ErrorHandlingHelper::application_error("Merge patch document must be a JSON object", None));
+                }
+                json_patch::merge(&mut document, merge_patch);
+            },
+            TaskPatchDocument::JsonPatch(operations) => {
+                json_patch::patch(&mut document, operations).map_err(|e| match e.kind {
+                    PatchErrorKind::TestFailed => ErrorHandlingHelper::conflict_error(&format!("Patch test failed at {}", e.path), None),
+                    _ => ErrorHandlingHelper::validation_error(vec![
+                        FieldError::new(&format!("/{}", e.operation), "invalid_operation", &e.to_string()),
+                    ]),
+                })?;
+            },
+        }
+
+        Ok(document)
+    }
+
+    /// Проверяет документ после изменения и собирает `TaskPatch` из полей, отличающихся от `task`.
+    fn build_patch(task: &Task, document: Value) -> Result<TaskPatch, ApiError> {
+        let mut validator = Validator::new();
+        let mut fields = match document {
+            Value::Object(fields) => fields,
+            _ => Map::new(),
+        };
+
+        let title = match fields.remove("title") {
+            Some(Value::String(title)) => validator.string("title", title, &TITLE_RULES),
+            Some(Value::Null) | None => {
+                validator.add_error(FieldError::new("title", "required", "title must not be empty"));
+                task.title.clone()
+            },
+            Some(_) => {
+                validator.add_error(FieldError::new("title", "invalid_type", "title must be a string"));
+                task.title.clone()
+            },
+        };
+        let description = match fields.remove("description") {
+            Some(Value::String(description)) => validator.optional_string("description", Some(description), &DESCRIPTION_RULES),
+            Some(Value::Null) | None => None,
+            Some(_) => {
+                validator.add_error(FieldError::new("description", "invalid_type", "description must be a string or null"));
+                task.description.clone()
+            },
+        };
+        let status = match fields.remove("status").map(serde_json::from_value::<TaskStatus>) {
+            Some(Ok(status)) => status,
+            _ => {
+                validator.add_error(FieldError::new("status", "invalid_value", "status must be one of todo, in_progress, blocked, done"));
+                task.status
+            },
+        };
+        let priority = match fields.remove("priority").map(serde_json::from_value::<TaskPriority>) {
+            Some(Ok(priority)) => priority,
+            _ => {
+                validator.add_error(FieldError::new("priority", "invalid_value", "priority must be one of low, medium, high, urgent"));
+                task.priority
+            },
+        };
+        let start_at = PatchTaskRequest::date(&mut validator, "start_at", fields.remove("start_at"), task.start_at);
+        let due_at = PatchTaskRequest::date(&mut validator, "due_at", fields.remove("due_at"), task.due_at);
+        check_schedule_dates(&mut validator, start_at, due_at);
+        let project_id = match fields.remove("project_id") {
+            Some(Value::Null) | None => None,
+            Some(value) => match value.as_i64().and_then(|value| i32::try_from(value).ok()) {
+                Some(project_id) => Some(project_id),
+                None => {
+                    validator.add_error(FieldError::new("project_id", "invalid_type", "project_id must be an integer or null"));
+                    task.project_id
+                },
+            },
+        };
+        let estimate_minutes = match fields.remove("estimate_minutes") {
+            Some(Value::Null) | None => None,
+            Some(value) => match value.as_i64().and_then(|value| i32::try_from(value).ok()) {
+                Some(estimate_minutes) => Some(estimate_minutes),
+                None => {
+                    validator.add_error(FieldError::new("estimate_minutes", "invalid_type", "estimate_minutes must be an integer or null"));
+                    task.estimate_minutes
+                },
+            },
+        };
+        check_estimate(&mut validator, estimate_minutes);
+        for field in fields.keys() {
+            validator.add_error(FieldError::new(field, "unknown_field", &format!("{} cannot be changed", field)));
+        }
+
+        validator.finish(TaskPatch {
+            title: Some(title).filter(|title| *title != task.title),
+            description: Some(description).filter(|description| *description != task.description),
+            status: Some(status).filter(|status| *status != task.status),
+            priority: Some(priority).filter(|priority| *priority != task.priority),
+            start_at: Some(start_at).filter(|start_at| *start_at != task.start_at),
+            due_at: Some(due_at).filter(|due_at| *due_at != task.due_at),
+            project_id: Some(project_id).filter(|project_id| *project_id != task.project_id),
+            estimate_minutes: Some(estimate_minutes).filter(|estimate_minutes| *estimate_minutes != task.estimate_minutes),
+        })
+    }
+
+    /// Разбирает необязательную дату `field` из документа; отсутствие поля или `null` очищает дату.
+    fn date(validator: &mut Validator, field: &str, value: Option<Value>, current: Option<DateTime<Utc>>) -> Option<DateTime<Utc>> {
+        match value.map(serde_json::from_value::<Option<DateTime<Utc>>>) {
+            Some(Ok(date)) => date,
+            None => None,
+            Some(Err(_)) => {
+                validator.add_error(FieldError::new(field, "invalid_value", &format!("{} must be an RFC 3339 timestamp or null", field)));
+                current
+            },
+        }
+    }
+}
+
+#[async_trait(?Send)]
+impl<'a> AbstractRequest<Task> for PatchTaskRequest<'a> {
+    async fn execute(&self) -> Result<Task, ApiError> {
+        AccessControl::authorize(self.actor, AccessScope::Task(self.task_id), Permission::Write, self.repository).await?;
+        let current = self.repository.get_task_by_id(self.task_id).await
+            .map_err(|e| ErrorHandlingHelper::domain_error("Cannot patch task", e))?;
+        current.ensure_version(self.if_match)
+            .map_err(|e| ErrorHandlingHelper::domain_error("Cannot patch task", e))?;
+
+        let document = self.apply_document(&current)?;
+        let patch = PatchTaskRequest::build_patch(&current, document)?;
+        if let Some(Some(project_id)) = patch.project_id {
+            AccessControl::authorize(self.actor, AccessScope::Project(project_id), Permission::Write, self.repository).await?;
+        }
+        if let Some(status) = patch.status {
+            current.status.transition_to(status)
+                .map_err(|e| ErrorHandlingHelper::conflict_error(&e.to_string(), Some(Box::new(e))))?;
+        }
+        if patch.is_empty() {
+            return Ok(current);
+        }
+
+        // Изменение вычислено относительно прочитанной версии, поэтому записывается только поверх неё.
+        let task = self.repository.patch_task(self.task_id, patch, Some(current.version)).await;
+
+        match task {
+            Ok(task) => Ok(task),
+            Err(e) if self.if_match.is_none() => Err(ErrorHandlingHelper::domain_error("Cannot patch task", e.precondition_as_conflict())),
+            Err(e) => Err(ErrorHandlingHelper::domain_error("Cannot patch task", e)),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use mockall::predicate::*;
+    use crate::application::repositories::task_abstract_repo::MockTaskRepositoryAbstract;
+    use crate::domain::error::DomainError;
+
+    fn current_task() -> Task {
+        Task::new(1, "Write report".to_string(), Some("Quarterly".to_string()))
+    }
+
+    fn repo_with_current_task() -> MockTaskRepositoryAbstract {
+        let mut task_repo = MockTaskRepositoryAbstract::new();
+        task_repo.expect_get_task_by_id()
+            .with(eq(1))
+            .times(1)
+            .returning(|_| Ok(current_task()));
+        task_repo
+    }
+
+    #[actix_rt::test]
+    async fn test_should_only_touch_fields_present_in_merge_patch() {
+        let mut task_repo = repo_with_current_task();
+        let expected_patch = TaskPatch { description: Some(None), ..TaskPatch::default() };
+        task_repo.expect_patch_task()
+            .with(eq(1), eq(expected_patch), eq(Some(1)))
+            .times(1)
+            .returning(|_, _, _| Ok(Task::new(1, "Write report".to_string(), None)));
+
+        let document = TaskPatchDocument::MergePatch(json!({ "description": null }));
+        let result = PatchTaskRequest::new(1, document, None, Actor::System, &task_repo).execute().await;
+
+        assert!(result.unwrap().description.is_none());
+    }
+
+    #[actix_rt::test]
+    async fn test_should_apply_json_patch_operations() {
+        let mut task_repo = repo_with_current_task();
+        let expected_patch = TaskPatch {
+            title: Some("Write annual report".to_string()),
+            status: Some(TaskStatus::InProgress),
+            ..TaskPatch::default()
+        };
+        task_repo.expect_patch_task()
+            .with(eq(1), eq(expected_patch), eq(Some(1)))
+            .times(1)
+            .returning(|_, _, _| Ok(current_task()));
+
+        let operations = serde_json::from_value(json!([
+            { "op": "test", "path": "/status", "value": "todo" },
+            { "op": "replace", "path": "/title", "value": "  Write annual report " },
+            { "op": "replace", "path": "/status", "value": "in_progress" },
+        ])).unwrap();
+        let result = PatchTaskRequest::new(1, TaskPatchDocument::JsonPatch(operations), None, Actor::System, &task_repo).execute().await;
+
+        assert!(result.is_ok());
+    }
+
+    #[actix_rt::test]
+    async fn test_should_return_conflict_when_json_patch_test_fails() {
+        let mut task_repo = repo_with_current_task();
+        task_repo.expect_patch_task().times(0);
+
+        let operations = serde_json::from_value(json!([
+            { "op": "test", "path": "/title", "value": "Something else" },
+            { "op": "remove", "path": "/description" },
+        ])).unwrap();
+        let result = PatchTaskRequest::new(1, TaskPatchDocument::JsonPatch(operations), None, Actor::System, &task_repo).execute().await;
+
+        assert_eq!(409, result.unwrap_err().code);
+    }
+
+    #[actix_rt::test]
+    async fn test_should_reject_invalid_fields_after_patch() {
+        let mut task_repo = repo_with_current_task();
+        task_repo.expect_patch_task().times(0);
+
+        let document = TaskPatchDocument::MergePatch(json!({ "title": null, "status": "archived", "owner": "bob" }));
+        let result = PatchTaskRequest::new(1, document, None, Actor::System, &task_repo).execute().await;
+
+        let error = result.unwrap_err();
+        assert_eq!(422, error.code);
+        let fields = error.errors.iter().map(|e| e.field.as_str()).collect::<Vec<_>>();
+        assert_eq!(fields, vec!["title", "status", "owner"]);
+    }
+
+    #[actix_rt::test]
+    async fn test_should_patch_priority_and_dates() {
+        let mut task_repo = repo_with_current_task();
+        let due_at = "2026-11-01T12:00:00Z".parse::<DateTime<Utc>>().unwrap();
+        let expected_patch = TaskPatch {
+            priority: Some(TaskPriority::Urgent),
+            due_at: Some(Some(due_at)),
+            ..TaskPatch::default()
+        };
+        task_repo.expect_patch_task()
+            .with(eq(1), eq(expected_patch), eq(Some(1)))
+            .times(1)
+            .returning(|_, _, _| Ok(current_task()));
+
+        let document = TaskPatchDocument::MergePatch(json!({ "priority": "urgent", "due_at": "2026-11-01T14:00:00+02:00" }));
+        let result = PatchTaskRequest::new(1, document, None, Actor::System, &task_repo).execute().await;
+
+        assert!(result.is_ok());
+    }
+
+    #[actix_rt::test]
+    async fn test_should_reject_invalid_priority_and_dates() {
+        let mut task_repo = repo_with_current_task();
+        task_repo.expect_patch_task().times(0);
+
+        let document = TaskPatchDocument::MergePatch(json!({
+            "priority": "critical",
+            "start_at": "2026-11-02T00:00:00Z",
+            "due_at": "2026-11-01T00:00:00Z",
+        }));
+        let result = PatchTaskRequest::new(1, document, None, Actor::System, &task_repo).execute().await;
+
+        let error = result.unwrap_err();
+        let failures = error.errors.iter().map(|e| (e.field.as_str(), e.code.as_str())).collect::<Vec<_>>();
+        assert_eq!(failures, vec![("priority", "invalid_value"), ("due_at", "before_start")]);
+    }
+
+    #[actix_rt::test]
+    async fn test_should_not_write_when_nothing_changes() {
+        let mut task_repo = repo_with_current_task();
+        task_repo.expect_patch_task().times(0);
+
+        let document = TaskPatchDocument::MergePatch(json!({ "title": "Write report" }));
+        let result = PatchTaskRequest::new(1, document, None, Actor::System, &task_repo).execute().await;
+
+        assert_eq!(result.unwrap(), current_task());
+    }
+
+    #[actix_rt::test]
+    async fn test_should_return_not_found_when_task_does_not_exist() {
+        let mut task_repo = MockTaskRepositoryAbstract::new();
+        task_repo.expect_get_task_by_id()
+            .times(1)
+            .returning(|task_id| Err(DomainError::NotFound(format!("Task {} not found", task_id))));
+
+        let document = TaskPatchDocument::MergePatch(json!({ "title": "New" }));
+        let result = PatchTaskRequest::new(2, document, None, Actor::System, &task_repo).execute().await;
+
+        assert_eq!(404, result.unwrap_err().code);
+    }
+
+    #[actix_rt::test]
+    async fn test_should_return_precondition_failed_when_if_match_is_stale() {
+        let mut task_repo = repo_with_current_task();
+        task_repo.expect_patch_task().times(0);
+
+        let document = TaskPatchDocument::MergePatch(json!({ "title": "New" }));
+        let result = PatchTaskRequest::new(1, document, Some(7), Actor::System, &task_repo).execute().await;
+
+        assert_eq!(412, result.unwrap_err().code);
+    }
+}
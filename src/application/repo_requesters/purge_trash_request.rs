@@ -0,0 +1,74 @@
+use async_trait::async_trait;
+use chrono::{DateTime, Duration, Utc};
+use crate::application::helpers::error_handler::ErrorHandlingHelper;
+use crate::application::repo_requesters::abstract_request::AbstractRequest;
+use crate::application::repositories::task_abstract_repo::TaskRepositoryAbstract;
+use crate::domain::error::ApiError;
+
+/// Окончательно удаляет задачи, пролежавшие в корзине дольше `retention` к моменту `now`.
+///
+/// Выполняется фоновой задачей сервера, поэтому права клиента не проверяются.
+pub struct PurgeTrashRequest<'a> {
+    retention: Duration,
+    now: DateTime<Utc>,
+    repository: &'a dyn TaskRepositoryAbstract,
+}
+
+impl<'a> PurgeTrashRequest<'a> {
+    pub fn new(retention: Duration, now: DateTime<Utc>, repository: &'a dyn TaskRepositoryAbstract) -> Self {
+        PurgeTrashRequest { retention, now, repository }
+    }
+}
+
+#[async_trait(?Send)]
+impl<'a> AbstractRequest<usize> for PurgeTrashRequest<'a> {
+    async fn execute(&self) -> Result<usize, ApiError> {
+        let purged = self.repository.purge_trash(self.now - self.retention).await;
+
+        match purged {
+            Ok(purged) => Ok(purged),
+            Err(e) => Err(ErrorHandlingHelper::domain_error("Cannot purge trash", e)),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::TimeZone;
+    use mockall::predicate::eq;
+    use crate::application::repositories::task_abstract_repo::MockTaskRepositoryAbstract;
+    use crate::domain::error::DomainError;
+
+    fn now() -> DateTime<Utc> {
+        Utc.with_ymd_and_hms(2026, 10, 18, 12, 0, 0).unwrap()
+    }
+
+    #[actix_rt::test]
+    async fn test_should_purge_tasks_deleted_before_retention_period() {
+        let mut task_repo = MockTaskRepositoryAbstract::new();
+        task_repo
+            .expect_purge_trash()
+            .with(eq(Utc.with_ymd_and_hms(2026, 9, 18, 12, 0, 0).unwrap()))
+            .times(1)
+            .returning(|_| Ok(2));
+
+        let purged = PurgeTrashRequest::new(Duration::days(30), now(), &task_repo).execute().await.unwrap();
+
+        assert_eq!(purged, 2);
+    }
+
+    #[actix_rt::test]
+    async fn test_should_return_generic_message_when_unexpected_repo_error() {
+        let mut task_repo = MockTaskRepositoryAbstract::new();
+        task_repo
+            .expect_purge_trash()
+            .times(1)
+            .returning(|_| Err(DomainError::Internal("oh no!".to_string())));
+
+        let error = PurgeTrashRequest::new(Duration::days(30), now(), &task_repo).execute().await.unwrap_err();
+
+        assert_eq!(error.code, 500);
+        assert_eq!(error.message, "Cannot purge trash");
+    }
+}
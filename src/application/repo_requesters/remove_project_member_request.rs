@@ -0,0 +1,74 @@
+use async_trait::async_trait;
+use crate::application::helpers::access_control::AccessControl;
+use crate::application::helpers::error_handler::ErrorHandlingHelper;
+use crate::application::repo_requesters::abstract_request::AbstractRequest;
+use crate::application::repositories::project_abstract_repo::ProjectRepositoryAbstract;
+use crate::application::repositories::task_abstract_repo::TaskRepositoryAbstract;
+use crate::domain::access::actor::{AccessScope, Actor};
+use crate::domain::access::role::Permission;
+use crate::domain::error::ApiError;
+
+/// Исключает пользователя из проекта; доступно администраторам проекта.
+pub struct RemoveProjectMemberRequest<'a> {
+    project_id: i32,
+    user_id: i32,
+    actor: Actor,
+    access_repository: &'a dyn TaskRepositoryAbstract,
+    repository: &'a dyn ProjectRepositoryAbstract,
+}
+
+impl<'a> RemoveProjectMemberRequest<'a> {
+    pub fn new(project_id: i32, user_id: i32, actor: Actor, access_repository: &'a dyn TaskRepositoryAbstract, repository: &'a dyn ProjectRepositoryAbstract) -> Self {
+        RemoveProjectMemberRequest { project_id, user_id, actor, access_repository, repository }
+    }
+}
+
+#[async_trait(?Send)]
+impl<'a> AbstractRequest<()> for RemoveProjectMemberRequest<'a> {
+    async fn execute(&self) -> Result<(), ApiError> {
+        AccessControl::authorize(self.actor, AccessScope::Project(self.project_id), Permission::Manage, self.access_repository).await?;
+        let result = self.repository.remove_project_member(self.project_id, self.user_id).await;
+
+        match result {
+            Ok(_) => Ok(()),
+            Err(e) => Err(ErrorHandlingHelper::domain_error("Cannot remove project member", e)),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use mockall::predicate::eq;
+    use crate::application::repositories::project_abstract_repo::MockProjectRepositoryAbstract;
+    use crate::application::repositories::task_abstract_repo::MockTaskRepositoryAbstract;
+    use crate::domain::project::project_member::ProjectMember;
+
+    #[actix_rt::test]
+    async fn test_should_remove_project_member() {
+        let task_repo = MockTaskRepositoryAbstract::new();
+        let mut project_repo = MockProjectRepositoryAbstract::new();
+        project_repo.expect_remove_project_member()
+            .with(eq(5), eq(2))
+            .times(1)
+            .returning(|_, _| Ok(()));
+
+        let result = RemoveProjectMemberRequest::new(5, 2, Actor::System, &task_repo, &project_repo).execute().await;
+
+        assert!(result.is_ok());
+    }
+
+    #[actix_rt::test]
+    async fn test_should_return_not_found_for_non_member() {
+        let task_repo = MockTaskRepositoryAbstract::new();
+        let mut project_repo = MockProjectRepositoryAbstract::new();
+        project_repo.expect_remove_project_member()
+            .times(1)
+            .returning(|project_id, user_id| Err(ProjectMember::not_member(project_id, user_id)));
+
+        let error = RemoveProjectMemberRequest::new(5, 3, Actor::System, &task_repo, &project_repo).execute().await.unwrap_err();
+
+        assert_eq!(404, error.code);
+        assert_eq!("User 3 is not a member of project 5", error.message);
+    }
+}
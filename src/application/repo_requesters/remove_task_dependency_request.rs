@@ -0,0 +1,87 @@
+use async_trait::async_trait;
+use crate::application::helpers::access_control::AccessControl;
+use crate::application::helpers::error_handler::ErrorHandlingHelper;
+use crate::application::repo_requesters::abstract_request::AbstractRequest;
+use crate::application::repositories::dependency_abstract_repo::DependencyRepositoryAbstract;
+use crate::application::repositories::task_abstract_repo::TaskRepositoryAbstract;
+use crate::domain::access::actor::{AccessScope, Actor};
+use crate::domain::access::role::Permission;
+use crate::domain::error::ApiError;
+
+pub struct RemoveTaskDependencyRequest<'a> {
+    task_id: i32,
+    blocker_id: i32,
+    actor: Actor,
+    access_repository: &'a dyn TaskRepositoryAbstract,
+    repository: &'a dyn DependencyRepositoryAbstract,
+}
+
+impl<'a> RemoveTaskDependencyRequest<'a> {
+    pub fn new(task_id: i32, blocker_id: i32, actor: Actor, access_repository: &'a dyn TaskRepositoryAbstract, repository: &'a dyn DependencyRepositoryAbstract) -> Self {
+        RemoveTaskDependencyRequest { task_id, blocker_id, actor, access_repository, repository }
+    }
+}
+
+#[async_trait(?Send)]
+impl<'a> AbstractRequest<()> for RemoveTaskDependencyRequest<'a> {
+    async fn execute(&self) -> Result<(), ApiError> {
+        AccessControl::authorize(self.actor, AccessScope::Task(self.task_id), Permission::Write, self.access_repository).await?;
+        let result = self.repository.remove_dependency(self.task_id, self.blocker_id).await;
+
+        match result {
+            Ok(()) => Ok(()),
+            Err(e) => Err(ErrorHandlingHelper::domain_error("Cannot remove task dependency", e)),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use mockall::predicate::eq;
+    use crate::application::repositories::dependency_abstract_repo::MockDependencyRepositoryAbstract;
+    use crate::application::repositories::task_abstract_repo::MockTaskRepositoryAbstract;
+    use crate::domain::access::role::Role;
+    use crate::domain::error::DomainError;
+
+    #[actix_rt::test]
+    async fn test_should_remove_dependency() {
+        let mut dependency_repo = MockDependencyRepositoryAbstract::new();
+        dependency_repo.expect_remove_dependency()
+            .with(eq(3), eq(2))
+            .times(1)
+            .returning(|_, _| Ok(()));
+
+        let result = RemoveTaskDependencyRequest::new(3, 2, Actor::System, &MockTaskRepositoryAbstract::new(), &dependency_repo).execute().await;
+
+        assert!(result.is_ok());
+    }
+
+    #[actix_rt::test]
+    async fn test_should_return_not_found_when_task_is_not_blocked() {
+        let mut dependency_repo = MockDependencyRepositoryAbstract::new();
+        dependency_repo.expect_remove_dependency()
+            .times(1)
+            .returning(|task_id, blocker_id| Err(DomainError::NotFound(format!("Task {} is not blocked by task {}", task_id, blocker_id))));
+
+        let error = RemoveTaskDependencyRequest::new(3, 2, Actor::System, &MockTaskRepositoryAbstract::new(), &dependency_repo).execute().await.unwrap_err();
+
+        assert_eq!(404, error.code);
+    }
+
+    #[actix_rt::test]
+    async fn test_should_forbid_removing_dependency_for_viewer_and_guest() {
+        let mut task_repo = MockTaskRepositoryAbstract::new();
+        task_repo.expect_get_role()
+            .with(eq(3), eq(AccessScope::Task(3)))
+            .times(1)
+            .returning(|_, _| Ok(Some(Role::Viewer)));
+        let mut dependency_repo = MockDependencyRepositoryAbstract::new();
+        dependency_repo.expect_remove_dependency().times(0);
+
+        for actor in [Actor::User(3), Actor::Guest] {
+            let error = RemoveTaskDependencyRequest::new(3, 2, actor, &task_repo, &dependency_repo).execute().await.unwrap_err();
+            assert_eq!(403, error.code);
+        }
+    }
+}
@@ -0,0 +1,86 @@
+use async_trait::async_trait;
+use crate::application::helpers::access_control::AccessControl;
+use crate::application::helpers::error_handler::ErrorHandlingHelper;
+use crate::application::repo_requesters::abstract_request::AbstractRequest;
+use crate::application::repositories::task_abstract_repo::TaskRepositoryAbstract;
+use crate::domain::access::actor::{AccessScope, Actor};
+use crate::domain::access::role::Permission;
+use crate::domain::error::ApiError;
+use crate::domain::task::task::Task;
+
+/// Возвращает задачу из корзины; требует того же права, что и удаление.
+pub struct RestoreTaskRequest<'a> {
+    task_id: i32,
+    if_match: Option<i32>,
+    actor: Actor,
+    repository: &'a dyn TaskRepositoryAbstract,
+}
+
+impl<'a> RestoreTaskRequest<'a> {
+    pub fn new(task_id: i32, if_match: Option<i32>, actor: Actor, repository: &'a dyn TaskRepositoryAbstract) -> Self {
+        RestoreTaskRequest { task_id, if_match, actor, repository }
+    }
+}
+
+#[async_trait(?Send)]
+impl<'a> AbstractRequest<Task> for RestoreTaskRequest<'a> {
+    async fn execute(&self) -> Result<Task, ApiError> {
+        AccessControl::authorize(self.actor, AccessScope::Task(self.task_id), Permission::Delete, self.repository).await?;
+        let result = self.repository.restore_task(self.task_id, self.if_match).await;
+
+        match result {
+            Ok(task) => Ok(task),
+            Err(e) => Err(ErrorHandlingHelper::domain_error("Cannot restore task", e)),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use mockall::predicate::*;
+    use crate::application::repositories::task_abstract_repo::MockTaskRepositoryAbstract;
+    use crate::domain::access::role::Role;
+    use crate::domain::error::DomainError;
+
+    #[actix_rt::test]
+    async fn test_should_restore_task_from_trash() {
+        let mut task_repo = MockTaskRepositoryAbstract::new();
+        task_repo.expect_restore_task()
+            .with(eq(1), eq(Some(2)))
+            .times(1)
+            .returning(|task_id, _| Ok(Task { version: 3, ..Task::new(task_id, "Restored".to_string(), None) }));
+
+        let task = RestoreTaskRequest::new(1, Some(2), Actor::System, &task_repo).execute().await.unwrap();
+
+        assert_eq!(task.version, 3);
+        assert!(task.deleted_at.is_none());
+    }
+
+    #[actix_rt::test]
+    async fn test_should_return_not_found_when_task_is_not_in_trash() {
+        let mut task_repo = MockTaskRepositoryAbstract::new();
+        task_repo.expect_restore_task()
+            .times(1)
+            .returning(|task_id, _| Err(DomainError::NotFound(format!("Task {} is not in the trash", task_id))));
+
+        let error = RestoreTaskRequest::new(5, None, Actor::System, &task_repo).execute().await.unwrap_err();
+
+        assert_eq!(404, error.code);
+        assert_eq!("Task 5 is not in the trash", error.message);
+    }
+
+    #[actix_rt::test]
+    async fn test_should_forbid_restore_without_admin_role() {
+        let mut task_repo = MockTaskRepositoryAbstract::new();
+        task_repo.expect_get_role()
+            .with(eq(3), eq(AccessScope::Task(1)))
+            .times(1)
+            .returning(|_, _| Ok(Some(Role::Editor)));
+        task_repo.expect_restore_task().times(0);
+
+        let error = RestoreTaskRequest::new(1, None, Actor::User(3), &task_repo).execute().await.unwrap_err();
+
+        assert_eq!(403, error.code);
+    }
+}
@@ -0,0 +1,60 @@
+use async_trait::async_trait;
+use chrono::Utc;
+use crate::application::helpers::access_control::AccessControl;
+use crate::application::helpers::error_handler::ErrorHandlingHelper;
+use crate::application::repo_requesters::abstract_request::AbstractRequest;
+use crate::application::repositories::api_key_abstract_repo::ApiKeyRepositoryAbstract;
+use crate::application::repositories::task_abstract_repo::TaskRepositoryAbstract;
+use crate::domain::access::actor::{AccessScope, Actor};
+use crate::domain::access::api_key::ApiKey;
+use crate::domain::access::role::Permission;
+use crate::domain::error::ApiError;
+
+/// Отзывает ключ API; доступно глобальным администраторам. Отозванный ключ остаётся в списке.
+pub struct RevokeApiKeyRequest<'a> {
+    key_id: i32,
+    actor: Actor,
+    access_repository: &'a dyn TaskRepositoryAbstract,
+    repository: &'a dyn ApiKeyRepositoryAbstract,
+}
+
+impl<'a> RevokeApiKeyRequest<'a> {
+    pub fn new(key_id: i32, actor: Actor, access_repository: &'a dyn TaskRepositoryAbstract, repository: &'a dyn ApiKeyRepositoryAbstract) -> Self {
+        RevokeApiKeyRequest { key_id, actor, access_repository, repository }
+    }
+}
+
+#[async_trait(?Send)]
+impl<'a> AbstractRequest<ApiKey> for RevokeApiKeyRequest<'a> {
+    async fn execute(&self) -> Result<ApiKey, ApiError> {
+        AccessControl::authorize(self.actor, AccessScope::Global, Permission::Manage, self.access_repository).await?;
+        let api_key = self.repository.revoke_api_key(self.key_id, Utc::now()).await;
+
+        match api_key {
+            Ok(api_key) => Ok(api_key),
+            Err(e) => Err(ErrorHandlingHelper::domain_error("Cannot revoke API key", e)),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use mockall::predicate::{always, eq};
+    use crate::application::repositories::api_key_abstract_repo::MockApiKeyRepositoryAbstract;
+    use crate::application::repositories::task_abstract_repo::MockTaskRepositoryAbstract;
+
+    #[actix_rt::test]
+    async fn test_should_return_not_found_for_unknown_key() {
+        let task_repo = MockTaskRepositoryAbstract::new();
+        let mut api_key_repo = MockApiKeyRepositoryAbstract::new();
+        api_key_repo.expect_revoke_api_key()
+            .with(eq(9), always())
+            .times(1)
+            .returning(|key_id, _| Err(ApiKey::not_found(key_id)));
+
+        let error = RevokeApiKeyRequest::new(9, Actor::System, &task_repo, &api_key_repo).execute().await.unwrap_err();
+
+        assert_eq!(404, error.code);
+    }
+}
@@ -0,0 +1,102 @@
+use async_trait::async_trait;
+use crate::application::helpers::access_control::AccessControl;
+use crate::application::helpers::error_handler::ErrorHandlingHelper;
+use crate::application::repo_requesters::abstract_request::AbstractRequest;
+use crate::application::repo_requesters::get_tasks_request::{DEFAULT_PAGE_LIMIT, MAX_PAGE_LIMIT};
+use crate::application::repositories::task_abstract_repo::TaskRepositoryAbstract;
+use crate::domain::access::actor::{AccessScope, Actor};
+use crate::domain::access::role::Permission;
+use crate::domain::error::ApiError;
+use crate::domain::task::task_search::TaskSearchHit;
+
+pub struct SearchTasksRequest<'a> {
+    query: String,
+    limit: Option<i64>,
+    actor: Actor,
+    repository: &'a dyn TaskRepositoryAbstract,
+}
+
+impl<'a> SearchTasksRequest<'a> {
+    pub fn new(query: String, limit: Option<i64>, actor: Actor, repository: &'a dyn TaskRepositoryAbstract) -> Self {
+        SearchTasksRequest { query, limit, actor, repository }
+    }
+}
+
+#[async_trait(?Send)]
+impl<'a> AbstractRequest<Vec<TaskSearchHit>> for SearchTasksRequest<'a> {
+    async fn execute(&self) -> Result<Vec<TaskSearchHit>, ApiError> {
+        let query = self.query.trim();
+        if query.is_empty() {
+            return Err(ErrorHandlingHelper::application_error("Search query must not be empty", None));
+        }
+
+        let limit = self.limit.unwrap_or(DEFAULT_PAGE_LIMIT);
+        if !(1..=MAX_PAGE_LIMIT).contains(&limit) {
+            return Err(ErrorHandlingHelper::application_error(&format!("limit must be between 1 and {}", MAX_PAGE_LIMIT), None));
+        }
+
+        AccessControl::authorize(self.actor, AccessScope::Global, Permission::Read, self.repository).await?;
+        let hits = self.repository.search_tasks(query.to_string(), limit).await;
+
+        match hits {
+            Ok(hits) => Ok(hits),
+            Err(e) => Err(ErrorHandlingHelper::domain_error("Cannot search tasks", e)),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use mockall::predicate::*;
+    use crate::domain::error::DomainError;
+    use crate::{application::repositories::task_abstract_repo::MockTaskRepositoryAbstract, domain::task::task::Task};
+
+    #[actix_rt::test]
+    async fn test_should_return_hits_for_trimmed_query() {
+        let mut task_repo = MockTaskRepositoryAbstract::new();
+        let hit = TaskSearchHit {
+            task: Task::new(1, "Write quarterly report".to_string(), None),
+            rank: -1.5,
+            title_highlight: "Write quarterly <mark>report</mark>".to_string(),
+            description_snippet: None,
+        };
+        task_repo.expect_search_tasks()
+            .with(eq("report".to_string()), eq(DEFAULT_PAGE_LIMIT))
+            .times(1)
+            .returning(move |_, _| Ok(vec![hit.clone()]));
+
+        let search_request = SearchTasksRequest::new("  report ".to_string(), None, Actor::System, &task_repo);
+        let result = search_request.execute().await;
+
+        assert!(result.is_ok());
+        let hits = result.unwrap();
+        assert_eq!(hits.len(), 1);
+        assert_eq!(hits[0].task.id, 1);
+        assert_eq!(hits[0].title_highlight, "Write quarterly <mark>report</mark>");
+    }
+
+    #[actix_rt::test]
+    async fn test_should_reject_blank_query() {
+        let mut task_repo = MockTaskRepositoryAbstract::new();
+        task_repo.expect_search_tasks().times(0);
+
+        let search_request = SearchTasksRequest::new("   ".to_string(), None, Actor::System, &task_repo);
+        let result = search_request.execute().await;
+
+        assert_eq!("Search query must not be empty", result.unwrap_err().message);
+    }
+
+    #[actix_rt::test]
+    async fn test_should_return_error_when_repo_returns_error() {
+        let mut task_repo = MockTaskRepositoryAbstract::new();
+        task_repo.expect_search_tasks()
+            .times(1)
+            .returning(|_, _| Err(DomainError::Internal("Database error".to_string())));
+
+        let search_request = SearchTasksRequest::new("report".to_string(), Some(5), Actor::System, &task_repo);
+        let result = search_request.execute().await;
+
+        assert_eq!("Cannot search tasks", result.unwrap_err().message);
+    }
+}
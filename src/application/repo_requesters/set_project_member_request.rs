@@ -0,0 +1,79 @@
+use async_trait::async_trait;
+use crate::application::helpers::access_control::AccessControl;
+use crate::application::helpers::error_handler::ErrorHandlingHelper;
+use crate::application::repo_requesters::abstract_request::AbstractRequest;
+use crate::application::repositories::project_abstract_repo::ProjectRepositoryAbstract;
+use crate::application::repositories::task_abstract_repo::TaskRepositoryAbstract;
+use crate::domain::access::actor::{AccessScope, Actor};
+use crate::domain::access::role::{Permission, Role};
+use crate::domain::error::ApiError;
+use crate::domain::project::project_member::ProjectMember;
+
+/// Добавляет пользователя в проект или меняет его роль; доступно администраторам проекта.
+pub struct SetProjectMemberRequest<'a> {
+    project_id: i32,
+    user_id: i32,
+    role: Role,
+    actor: Actor,
+    access_repository: &'a dyn TaskRepositoryAbstract,
+    repository: &'a dyn ProjectRepositoryAbstract,
+}
+
+impl<'a> SetProjectMemberRequest<'a> {
+    pub fn new(project_id: i32, user_id: i32, role: Role, actor: Actor, access_repository: &'a dyn TaskRepositoryAbstract, repository: &'a dyn ProjectRepositoryAbstract) -> Self {
+        SetProjectMemberRequest { project_id, user_id, role, actor, access_repository, repository }
+    }
+}
+
+#[async_trait(?Send)]
+impl<'a> AbstractRequest<ProjectMember> for SetProjectMemberRequest<'a> {
+    async fn execute(&self) -> Result<ProjectMember, ApiError> {
+        AccessControl::authorize(self.actor, AccessScope::Project(self.project_id), Permission::Manage, self.access_repository).await?;
+        let member = self.repository.set_project_member(self.project_id, self.user_id, self.role).await;
+
+        match member {
+            Ok(member) => Ok(member),
+            Err(e) => Err(ErrorHandlingHelper::domain_error("Cannot change project member", e)),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use mockall::predicate::eq;
+    use crate::application::repositories::project_abstract_repo::MockProjectRepositoryAbstract;
+    use crate::application::repositories::task_abstract_repo::MockTaskRepositoryAbstract;
+
+    #[actix_rt::test]
+    async fn test_should_let_project_admin_change_member_role() {
+        let mut task_repo = MockTaskRepositoryAbstract::new();
+        task_repo.expect_get_role()
+            .with(eq(1), eq(AccessScope::Project(5)))
+            .times(1)
+            .returning(|_, _| Ok(Some(Role::Admin)));
+        let mut project_repo = MockProjectRepositoryAbstract::new();
+        project_repo.expect_set_project_member()
+            .with(eq(5), eq(2), eq(Role::Editor))
+            .times(1)
+            .returning(|project_id, user_id, role| Ok(ProjectMember::new(project_id, user_id, role)));
+
+        let member = SetProjectMemberRequest::new(5, 2, Role::Editor, Actor::User(1), &task_repo, &project_repo).execute().await.unwrap();
+
+        assert_eq!(member.role, Role::Editor);
+    }
+
+    #[actix_rt::test]
+    async fn test_should_forbid_editor_to_manage_members() {
+        let mut task_repo = MockTaskRepositoryAbstract::new();
+        task_repo.expect_get_role()
+            .times(1)
+            .returning(|_, _| Ok(Some(Role::Editor)));
+        let mut project_repo = MockProjectRepositoryAbstract::new();
+        project_repo.expect_set_project_member().times(0);
+
+        let error = SetProjectMemberRequest::new(5, 2, Role::Admin, Actor::User(2), &task_repo, &project_repo).execute().await.unwrap_err();
+
+        assert_eq!(403, error.code);
+    }
+}
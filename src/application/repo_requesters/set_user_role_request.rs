@@ -0,0 +1,75 @@
+use async_trait::async_trait;
+use crate::application::helpers::access_control::AccessControl;
+use crate::application::helpers::error_handler::ErrorHandlingHelper;
+use crate::application::repo_requesters::abstract_request::AbstractRequest;
+use crate::application::repositories::task_abstract_repo::TaskRepositoryAbstract;
+use crate::application::repositories::user_abstract_repo::UserRepositoryAbstract;
+use crate::domain::access::actor::{AccessScope, Actor};
+use crate::domain::access::role::{Permission, Role};
+use crate::domain::error::ApiError;
+use crate::domain::user::user::User;
+
+/// Меняет глобальную роль пользователя; доступно глобальным администраторам.
+pub struct SetUserRoleRequest<'a> {
+    user_id: i32,
+    role: Role,
+    actor: Actor,
+    access_repository: &'a dyn TaskRepositoryAbstract,
+    repository: &'a dyn UserRepositoryAbstract,
+}
+
+impl<'a> SetUserRoleRequest<'a> {
+    pub fn new(user_id: i32, role: Role, actor: Actor, access_repository: &'a dyn TaskRepositoryAbstract, repository: &'a dyn UserRepositoryAbstract) -> Self {
+        SetUserRoleRequest { user_id, role, actor, access_repository, repository }
+    }
+}
+
+#[async_trait(?Send)]
+impl<'a> AbstractRequest<User> for SetUserRoleRequest<'a> {
+    async fn execute(&self) -> Result<User, ApiError> {
+        AccessControl::authorize(self.actor, AccessScope::Global, Permission::Manage, self.access_repository).await?;
+        let user = self.repository.set_user_role(self.user_id, self.role).await;
+
+        match user {
+            Ok(user) => Ok(user),
+            Err(e) => Err(ErrorHandlingHelper::domain_error("Cannot change user role", e)),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use mockall::predicate::eq;
+    use crate::application::repositories::task_abstract_repo::MockTaskRepositoryAbstract;
+    use crate::application::repositories::user_abstract_repo::MockUserRepositoryAbstract;
+
+    #[actix_rt::test]
+    async fn test_should_change_role_for_global_admin() {
+        let mut task_repo = MockTaskRepositoryAbstract::new();
+        task_repo.expect_get_role()
+            .with(eq(1), eq(AccessScope::Global))
+            .times(1)
+            .returning(|_, _| Ok(Some(Role::Admin)));
+        let mut user_repo = MockUserRepositoryAbstract::new();
+        user_repo.expect_set_user_role()
+            .with(eq(2), eq(Role::Editor))
+            .times(1)
+            .returning(|user_id, role| Ok(User { role, ..User::new(user_id, "Grace".to_string(), "grace@example.com".to_string()) }));
+
+        let user = SetUserRoleRequest::new(2, Role::Editor, Actor::User(1), &task_repo, &user_repo).execute().await.unwrap();
+
+        assert_eq!(user.role, Role::Editor);
+    }
+
+    #[actix_rt::test]
+    async fn test_should_forbid_guest_to_change_roles() {
+        let task_repo = MockTaskRepositoryAbstract::new();
+        let mut user_repo = MockUserRepositoryAbstract::new();
+        user_repo.expect_set_user_role().times(0);
+
+        let error = SetUserRoleRequest::new(2, Role::Admin, Actor::Guest, &task_repo, &user_repo).execute().await.unwrap_err();
+
+        assert_eq!(403, error.code);
+    }
+}
@@ -0,0 +1,110 @@
+use async_trait::async_trait;
+use crate::application::helpers::access_control::AccessControl;
+use crate::application::helpers::error_handler::ErrorHandlingHelper;
+use crate::application::repo_requesters::abstract_request::AbstractRequest;
+use crate::application::repositories::task_abstract_repo::TaskRepositoryAbstract;
+use crate::domain::access::actor::{AccessScope, Actor};
+use crate::domain::access::role::Permission;
+use crate::domain::error::ApiError;
+use crate::domain::task::task::Task;
+use crate::domain::task::task_status::TaskStatus;
+
+pub struct TransitionTaskRequest<'a> {
+    task_id: i32,
+    status: TaskStatus,
+    actor: Actor,
+    repository: &'a dyn TaskRepositoryAbstract,
+}
+
+impl<'a> TransitionTaskRequest<'a> {
+    pub fn new(task_id: i32, status: TaskStatus, actor: Actor, repository: &'a dyn TaskRepositoryAbstract) -> Self {
+        TransitionTaskRequest { task_id, status, actor, repository }
+    }
+}
+
+#[async_trait(?Send)]
+impl<'a> AbstractRequest<Task> for TransitionTaskRequest<'a> {
+    async fn execute(&self) -> Result<Task, ApiError> {
+        AccessControl::authorize(self.actor, AccessScope::Task(self.task_id), Permission::Write, self.repository).await?;
+        let task = self.repository.get_task_by_id(self.task_id).await
+            .map_err(|e| ErrorHandlingHelper::domain_error("Cannot change task status", e))?;
+
+        let status = task.status.transition_to(self.status)
+            .map_err(|e| ErrorHandlingHelper::conflict_error(&e.to_string(), Some(Box::new(e))))?;
+
+        let task = self.repository.update_task_status(self.task_id, status, Some(task.version)).await;
+
+        match task {
+            Ok(task) => Ok(task),
+            Err(e) => Err(ErrorHandlingHelper::domain_error("Cannot change task status", e.precondition_as_conflict())),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use mockall::predicate::*;
+    use crate::domain::error::DomainError;
+    use crate::application::repositories::task_abstract_repo::MockTaskRepositoryAbstract;
+
+    fn task_with_status(status: TaskStatus) -> Task {
+        let mut task = Task::new(1, "Write report".to_string(), None);
+        task.status = status;
+        task
+    }
+
+    #[actix_rt::test]
+    async fn test_should_transition_task_when_allowed() {
+        let mut task_repo = MockTaskRepositoryAbstract::new();
+        task_repo.expect_get_task_by_id()
+            .with(eq(1))
+            .times(1)
+            .returning(|_| Ok(task_with_status(TaskStatus::Todo)));
+        task_repo.expect_update_task_status()
+            .with(eq(1), eq(TaskStatus::InProgress), eq(Some(1)))
+            .times(1)
+            .returning(|_, status, _| Ok(task_with_status(status)));
+
+        let transition_request = TransitionTaskRequest::new(1, TaskStatus::InProgress, Actor::System, &task_repo);
+        let result = transition_request.execute().await;
+
+        assert!(result.is_ok());
+        assert_eq!(result.unwrap().status, TaskStatus::InProgress);
+    }
+
+    #[actix_rt::test]
+    async fn test_should_return_conflict_when_done_task_is_blocked_without_reopening() {
+        let mut task_repo = MockTaskRepositoryAbstract::new();
+        task_repo.expect_get_task_by_id()
+            .with(eq(1))
+            .times(1)
+            .returning(|_| Ok(task_with_status(TaskStatus::Done)));
+        task_repo.expect_update_task_status().times(0);
+
+        let transition_request = TransitionTaskRequest::new(1, TaskStatus::Blocked, Actor::System, &task_repo);
+        let result = transition_request.execute().await;
+
+        assert!(result.is_err());
+        let error = result.unwrap_err();
+        assert_eq!(409, error.code);
+        assert_eq!("Cannot transition task from 'done' to 'blocked'", error.message);
+    }
+
+    #[actix_rt::test]
+    async fn test_should_return_error_when_task_does_not_exist() {
+        let mut task_repo = MockTaskRepositoryAbstract::new();
+        task_repo.expect_get_task_by_id()
+            .with(eq(2))
+            .times(1)
+            .returning(|_| Err(DomainError::NotFound("Task 2 not found".to_string())));
+
+        let transition_request = TransitionTaskRequest::new(2, TaskStatus::Done, Actor::System, &task_repo);
+        let result = transition_request.execute().await;
+
+        assert!(result.is_err());
+        let error = result.unwrap_err();
+        assert_eq!(404, error.code);
+        assert_eq!("Task 2 not found", error.message);
+    }
+}
@@ -0,0 +1,90 @@
+use async_trait::async_trait;
+use crate::application::helpers::access_control::AccessControl;
+use crate::application::helpers::error_handler::ErrorHandlingHelper;
+use crate::application::repo_requesters::abstract_request::AbstractRequest;
+use crate::application::repositories::user_abstract_repo::UserRepositoryAbstract;
+use crate::application::repositories::task_abstract_repo::TaskRepositoryAbstract;
+use crate::domain::access::actor::{AccessScope, Actor};
+use crate::domain::access::role::Permission;
+use crate::domain::error::ApiError;
+use crate::domain::task::task::Task;
+
+pub struct UnassignTaskRequest<'a> {
+    task_id: i32,
+    user_id: i32,
+    expected_version: Option<i32>,
+    actor: Actor,
+    access_repository: &'a dyn TaskRepositoryAbstract,
+    repository: &'a dyn UserRepositoryAbstract,
+}
+
+impl<'a> UnassignTaskRequest<'a> {
+    pub fn new(task_id: i32, user_id: i32, expected_version: Option<i32>, actor: Actor, access_repository: &'a dyn TaskRepositoryAbstract, repository: &'a dyn UserRepositoryAbstract) -> Self {
+        UnassignTaskRequest { task_id, user_id, expected_version, actor, access_repository, repository }
+    }
+}
+
+#[async_trait(?Send)]
+impl<'a> AbstractRequest<Task> for UnassignTaskRequest<'a> {
+    async fn execute(&self) -> Result<Task, ApiError> {
+        AccessControl::authorize(self.actor, AccessScope::Task(self.task_id), Permission::Write, self.access_repository).await?;
+        let task = self.repository.unassign_user(self.task_id, self.user_id, self.expected_version).await;
+
+        match task {
+            Ok(task) => Ok(task),
+            Err(e) => Err(ErrorHandlingHelper::domain_error("Cannot unassign task", e)),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use mockall::predicate::eq;
+    use crate::application::repositories::user_abstract_repo::MockUserRepositoryAbstract;
+    use crate::application::repositories::task_abstract_repo::MockTaskRepositoryAbstract;
+    use crate::domain::access::role::Role;
+    use crate::domain::user::user::User;
+
+    #[actix_rt::test]
+    async fn test_should_unassign_user_from_task() {
+        let mut user_repo = MockUserRepositoryAbstract::new();
+        user_repo.expect_unassign_user()
+            .with(eq(5), eq(2), eq(None))
+            .times(1)
+            .returning(|task_id, _, _| Ok(Task::new(task_id, "Review".to_string(), None)));
+
+        let task = UnassignTaskRequest::new(5, 2, None, Actor::System, &MockTaskRepositoryAbstract::new(), &user_repo).execute().await.unwrap();
+
+        assert_eq!(task.assignee_id, None);
+    }
+
+    #[actix_rt::test]
+    async fn test_should_return_not_found_when_user_is_not_assigned() {
+        let mut user_repo = MockUserRepositoryAbstract::new();
+        user_repo.expect_unassign_user()
+            .times(1)
+            .returning(|task_id, user_id, _| Err(User::not_assigned(task_id, user_id)));
+
+        let error = UnassignTaskRequest::new(5, 2, None, Actor::System, &MockTaskRepositoryAbstract::new(), &user_repo).execute().await.unwrap_err();
+
+        assert_eq!(404, error.code);
+        assert_eq!("User 2 is not assigned to task 5", error.message);
+    }
+
+    #[actix_rt::test]
+    async fn test_should_forbid_unassignment_for_viewer_and_guest() {
+        let mut task_repo = MockTaskRepositoryAbstract::new();
+        task_repo.expect_get_role()
+            .with(eq(3), eq(AccessScope::Task(5)))
+            .times(1)
+            .returning(|_, _| Ok(Some(Role::Viewer)));
+        let mut user_repo = MockUserRepositoryAbstract::new();
+        user_repo.expect_unassign_user().times(0);
+
+        for actor in [Actor::User(3), Actor::Guest] {
+            let error = UnassignTaskRequest::new(5, 2, None, actor, &task_repo, &user_repo).execute().await.unwrap_err();
+            assert_eq!(403, error.code);
+        }
+    }
+}
@@ -0,0 +1,89 @@
+use async_trait::async_trait;
+use crate::application::helpers::access_control::AccessControl;
+use crate::application::helpers::error_handler::ErrorHandlingHelper;
+use crate::application::repo_requesters::abstract_request::AbstractRequest;
+use crate::application::repositories::project_abstract_repo::ProjectRepositoryAbstract;
+use crate::application::repositories::task_abstract_repo::TaskRepositoryAbstract;
+use crate::domain::access::actor::{AccessScope, Actor};
+use crate::domain::access::role::Permission;
+use crate::domain::error::ApiError;
+use crate::domain::project::project::Project;
+
+pub struct UpdateProjectRequest<'a> {
+    project_id: i32,
+    name: String,
+    description: Option<String>,
+    actor: Actor,
+    access_repository: &'a dyn TaskRepositoryAbstract,
+    repository: &'a dyn ProjectRepositoryAbstract,
+}
+
+impl<'a> UpdateProjectRequest<'a> {
+    pub fn new(project_id: i32, name: String, description: Option<String>, actor: Actor, access_repository: &'a dyn TaskRepositoryAbstract, repository: &'a dyn ProjectRepositoryAbstract) -> Self {
+        UpdateProjectRequest { project_id, name, description, actor, access_repository, repository }
+    }
+}
+
+#[async_trait(?Send)]
+impl<'a> AbstractRequest<Project> for UpdateProjectRequest<'a> {
+    async fn execute(&self) -> Result<Project, ApiError> {
+        AccessControl::authorize(self.actor, AccessScope::Project(self.project_id), Permission::Write, self.access_repository).await?;
+        let project = self.repository.update_project(self.project_id, self.name.clone(), self.description.clone()).await;
+
+        match project {
+            Ok(project) => Ok(project),
+            Err(e) => Err(ErrorHandlingHelper::domain_error("Cannot update project", e)),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use mockall::predicate::eq;
+    use crate::application::repositories::project_abstract_repo::MockProjectRepositoryAbstract;
+    use crate::application::repositories::task_abstract_repo::MockTaskRepositoryAbstract;
+    use crate::domain::access::role::Role;
+
+    #[actix_rt::test]
+    async fn test_should_update_project_successfully() {
+        let mut project_repo = MockProjectRepositoryAbstract::new();
+        project_repo.expect_update_project()
+            .with(eq(2), eq("Frontend".to_string()), eq(Some("Web UI".to_string())))
+            .times(1)
+            .returning(|id, name, description| Ok(Project::new(id, name, description)));
+
+        let project = UpdateProjectRequest::new(2, "Frontend".to_string(), Some("Web UI".to_string()), Actor::System, &MockTaskRepositoryAbstract::new(), &project_repo)
+            .execute().await.unwrap();
+
+        assert_eq!(project.name, "Frontend");
+    }
+
+    #[actix_rt::test]
+    async fn test_should_return_not_found_when_project_does_not_exist() {
+        let mut project_repo = MockProjectRepositoryAbstract::new();
+        project_repo.expect_update_project()
+            .times(1)
+            .returning(|id, _, _| Err(Project::not_found(id)));
+
+        let error = UpdateProjectRequest::new(9, "Frontend".to_string(), None, Actor::System, &MockTaskRepositoryAbstract::new(), &project_repo).execute().await.unwrap_err();
+
+        assert_eq!(404, error.code);
+    }
+
+    #[actix_rt::test]
+    async fn test_should_forbid_project_update_for_viewer_and_guest() {
+        let mut task_repo = MockTaskRepositoryAbstract::new();
+        task_repo.expect_get_role()
+            .with(eq(3), eq(AccessScope::Project(2)))
+            .times(1)
+            .returning(|_, _| Ok(Some(Role::Viewer)));
+        let mut project_repo = MockProjectRepositoryAbstract::new();
+        project_repo.expect_update_project().times(0);
+
+        for actor in [Actor::User(3), Actor::Guest] {
+            let error = UpdateProjectRequest::new(2, "Frontend".to_string(), None, actor, &task_repo, &project_repo).execute().await.unwrap_err();
+            assert_eq!(403, error.code);
+        }
+    }
+}
@@ -0,0 +1,87 @@
+use async_trait::async_trait;
+use crate::application::helpers::access_control::AccessControl;
+use crate::application::helpers::error_handler::ErrorHandlingHelper;
+use crate::application::repo_requesters::abstract_request::AbstractRequest;
+use crate::application::repositories::tag_abstract_repo::TagRepositoryAbstract;
+use crate::application::repositories::task_abstract_repo::TaskRepositoryAbstract;
+use crate::domain::access::actor::{AccessScope, Actor};
+use crate::domain::access::role::Permission;
+use crate::domain::error::ApiError;
+use crate::domain::tag::tag::Tag;
+
+pub struct UpdateTagRequest<'a> {
+    tag_id: i32,
+    name: String,
+    actor: Actor,
+    access_repository: &'a dyn TaskRepositoryAbstract,
+    repository: &'a dyn TagRepositoryAbstract,
+}
+
+impl<'a> UpdateTagRequest<'a> {
+    pub fn new(tag_id: i32, name: String, actor: Actor, access_repository: &'a dyn TaskRepositoryAbstract, repository: &'a dyn TagRepositoryAbstract) -> Self {
+        UpdateTagRequest { tag_id, name, actor, access_repository, repository }
+    }
+}
+
+#[async_trait(?Send)]
+impl<'a> AbstractRequest<Tag> for UpdateTagRequest<'a> {
+    async fn execute(&self) -> Result<Tag, ApiError> {
+        AccessControl::authorize(self.actor, AccessScope::Global, Permission::Write, self.access_repository).await?;
+        let tag = self.repository.update_tag(self.tag_id, self.name.clone()).await;
+
+        match tag {
+            Ok(tag) => Ok(tag),
+            Err(e) => Err(ErrorHandlingHelper::domain_error("Cannot update tag", e)),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use mockall::predicate::eq;
+    use crate::application::repositories::tag_abstract_repo::MockTagRepositoryAbstract;
+    use crate::application::repositories::task_abstract_repo::MockTaskRepositoryAbstract;
+    use crate::domain::access::role::Role;
+
+    #[actix_rt::test]
+    async fn test_should_rename_tag_successfully() {
+        let mut tag_repo = MockTagRepositoryAbstract::new();
+        tag_repo.expect_update_tag()
+            .with(eq(1), eq("frontend".to_string()))
+            .times(1)
+            .returning(|tag_id, name| Ok(Tag::new(tag_id, name)));
+
+        let tag = UpdateTagRequest::new(1, "frontend".to_string(), Actor::System, &MockTaskRepositoryAbstract::new(), &tag_repo).execute().await.unwrap();
+
+        assert_eq!(tag.name, "frontend");
+    }
+
+    #[actix_rt::test]
+    async fn test_should_return_not_found_when_tag_does_not_exist() {
+        let mut tag_repo = MockTagRepositoryAbstract::new();
+        tag_repo.expect_update_tag()
+            .times(1)
+            .returning(|tag_id, _| Err(Tag::not_found(tag_id)));
+
+        let error = UpdateTagRequest::new(3, "frontend".to_string(), Actor::System, &MockTaskRepositoryAbstract::new(), &tag_repo).execute().await.unwrap_err();
+
+        assert_eq!(404, error.code);
+    }
+
+    #[actix_rt::test]
+    async fn test_should_forbid_tag_update_for_viewer_and_guest() {
+        let mut task_repo = MockTaskRepositoryAbstract::new();
+        task_repo.expect_get_role()
+            .with(eq(3), eq(AccessScope::Global))
+            .times(1)
+            .returning(|_, _| Ok(Some(Role::Viewer)));
+        let mut tag_repo = MockTagRepositoryAbstract::new();
+        tag_repo.expect_update_tag().times(0);
+
+        for actor in [Actor::User(3), Actor::Guest] {
+            let error = UpdateTagRequest::new(1, "frontend".to_string(), actor, &task_repo, &tag_repo).execute().await.unwrap_err();
+            assert_eq!(403, error.code);
+        }
+    }
+}
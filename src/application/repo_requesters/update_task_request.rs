@@ -1,31 +1,68 @@
 use async_trait::async_trait;
+use crate::application::helpers::access_control::AccessControl;
 use crate::application::helpers::error_handler::ErrorHandlingHelper;
 use crate::application::repo_requesters::abstract_request::AbstractRequest;
 use crate::application::repositories::task_abstract_repo::TaskRepositoryAbstract;
+use crate::domain::access::actor::{AccessScope, Actor};
+use crate::domain::access::role::Permission;
 use crate::domain::error::ApiError;
 use crate::domain::task::task::Task;
+use crate::domain::task::task_schedule::TaskSchedule;
+use crate::domain::task::task_status::TaskStatus;
 
 pub struct UpdateTaskRequest<'a> {
     task_id: i32,
     title: String,
     description: Option<String>,
+    status: Option<TaskStatus>,
+    schedule: TaskSchedule,
+    if_match: Option<i32>,
+    actor: Actor,
     repository: &'a dyn TaskRepositoryAbstract,
 }
 
 impl<'a> UpdateTaskRequest<'a> {
-    pub fn new(task_id: i32, title: String, description: Option<String>, repository: &'a dyn TaskRepositoryAbstract) -> Self {
-        UpdateTaskRequest { task_id, title, description, repository }
+    #[allow(clippy::too_many_arguments)]
+    pub fn new(task_id: i32, title: String, description: Option<String>, status: Option<TaskStatus>, schedule: TaskSchedule, if_match: Option<i32>, actor: Actor, repository: &'a dyn TaskRepositoryAbstract) -> Self {
+        UpdateTaskRequest { task_id, title, description, status, schedule, if_match, actor, repository }
+    }
+
+    /// Проверяет запрошенную смену статуса и возвращает статус, который нужно сохранить,
+    /// и версию задачи, для которой проверялся переход.
+    /// Статус `None` означает, что статус задачи не меняется.
+    async fn resolve_status(&self) -> Result<(Option<TaskStatus>, Option<i32>), ApiError> {
+        let target = match self.status {
+            Some(target) => target,
+            None => return Ok((None, None)),
+        };
+
+        let current = self.repository.get_task_by_id(self.task_id).await
+            .map_err(|e| ErrorHandlingHelper::domain_error("Cannot update task", e))?;
+        current.ensure_version(self.if_match)
+            .map_err(|e| ErrorHandlingHelper::domain_error("Cannot update task", e))?;
+
+        if current.status == target {
+            return Ok((None, Some(current.version)));
+        }
+
+        current.status.transition_to(target)
+            .map(|status| (Some(status), Some(current.version)))
+            .map_err(|e| ErrorHandlingHelper::conflict_error(&e.to_string(), Some(Box::new(e))))
     }
 }
 
 #[async_trait(?Send)]
 impl<'a> AbstractRequest<Task> for UpdateTaskRequest<'a> {
     async fn execute(&self) -> Result<Task, ApiError> {
-        let task = self.repository.update_task(self.task_id, self.title.clone(), self.description.clone()).await;
+        AccessControl::authorize(self.actor, AccessScope::Task(self.task_id), Permission::Write, self.repository).await?;
+        let (status, checked_version) = self.resolve_status().await?;
+        let expected_version = self.if_match.or(checked_version);
+        let task = self.repository.update_task(self.task_id, self.title.clone(), self.description.clone(), status, self.schedule, expected_version).await;
 
         match task {
             Ok(task) => Ok(task),
-            Err(e) => Err(ErrorHandlingHelper::application_error("Task not found", Some(e))),
+            Err(e) if self.if_match.is_none() => Err(ErrorHandlingHelper::domain_error("Cannot update task", e.precondition_as_conflict())),
+            Err(e) => Err(ErrorHandlingHelper::domain_error("Cannot update task", e)),
         }
     }
 }
@@ -34,7 +71,7 @@ impl<'a> AbstractRequest<Task> for UpdateTaskRequest<'a> {
 mod tests {
     use super::*;
     use mockall::predicate::*;
-    use std::io::{Error, ErrorKind};
+    use crate::domain::error::DomainError;
     use crate::{application::repositories::task_abstract_repo::MockTaskRepositoryAbstract, domain::task::task::Task};
 
     #[actix_rt::test]
@@ -45,11 +82,11 @@ mod tests {
         let description = Some("Updated description".to_string());
         let expected_task = Task::new(task_id, title.clone(), description.clone());
         task_repo.expect_update_task()
-            .with(eq(task_id), eq(title.clone()), eq(description.clone()))
+            .with(eq(task_id), eq(title.clone()), eq(description.clone()), eq(None), eq(TaskSchedule::default()), eq(None))
             .times(1)
-            .returning(move |_, _, _| Ok(expected_task.clone()));
+            .returning(move |_, _, _, _, _, _| Ok(expected_task.clone()));
 
-        let update_task_request = UpdateTaskRequest::new(task_id, title, description, &task_repo);
+        let update_task_request = UpdateTaskRequest::new(task_id, title, description, None, TaskSchedule::default(), None, Actor::System, &task_repo);
         let result = update_task_request.execute().await;
 
         assert!(result.is_ok());
@@ -66,17 +103,91 @@ mod tests {
         let title = "Nonexistent Task".to_string();
         let description = Some("This task does not exist".to_string());
         task_repo.expect_update_task()
-            .with(eq(task_id), eq(title.clone()), eq(description.clone()))
+            .with(eq(task_id), eq(title.clone()), eq(description.clone()), eq(None), eq(TaskSchedule::default()), eq(None))
             .times(1)
-            .returning(|_, _, _| Err(Box::new(Error::new(ErrorKind::NotFound, "Task not found"))));
+            .returning(|_, _, _, _, _, _| Err(DomainError::NotFound("Task 2 not found".to_string())));
 
-        let update_task_request = UpdateTaskRequest::new(task_id, title, description, &task_repo);
+        let update_task_request = UpdateTaskRequest::new(task_id, title, description, None, TaskSchedule::default(), None, Actor::System, &task_repo);
         let result = update_task_request.execute().await;
 
         assert!(result.is_err());
         let error = result.unwrap_err();
-        assert_eq!("Task not found", error.message);
+        assert_eq!(404, error.code);
+        assert_eq!("Task 2 not found", error.message);
         assert!(error.error.is_some());
     }
-}
 
+    #[actix_rt::test]
+    async fn test_should_update_status_when_transition_is_allowed() {
+        let mut task_repo = MockTaskRepositoryAbstract::new();
+        let current_task = Task::new(1, "Task".to_string(), None);
+        let mut expected_task = current_task.clone();
+        expected_task.status = TaskStatus::InProgress;
+        task_repo.expect_get_task_by_id()
+            .with(eq(1))
+            .times(1)
+            .returning(move |_| Ok(current_task.clone()));
+        task_repo.expect_update_task()
+            .with(eq(1), eq("Task".to_string()), eq(None), eq(Some(TaskStatus::InProgress)), eq(TaskSchedule::default()), eq(Some(1)))
+            .times(1)
+            .returning(move |_, _, _, _, _, _| Ok(expected_task.clone()));
+
+        let update_task_request = UpdateTaskRequest::new(1, "Task".to_string(), None, Some(TaskStatus::InProgress), TaskSchedule::default(), None, Actor::System, &task_repo);
+        let result = update_task_request.execute().await;
+
+        assert_eq!(result.unwrap().status, TaskStatus::InProgress);
+    }
+
+    #[actix_rt::test]
+    async fn test_should_return_conflict_when_transition_is_not_allowed() {
+        let mut task_repo = MockTaskRepositoryAbstract::new();
+        let mut current_task = Task::new(1, "Task".to_string(), None);
+        current_task.status = TaskStatus::Done;
+        task_repo.expect_get_task_by_id()
+            .with(eq(1))
+            .times(1)
+            .returning(move |_| Ok(current_task.clone()));
+        task_repo.expect_update_task().times(0);
+
+        let update_task_request = UpdateTaskRequest::new(1, "Task".to_string(), None, Some(TaskStatus::Blocked), TaskSchedule::default(), None, Actor::System, &task_repo);
+        let result = update_task_request.execute().await;
+
+        let error = result.unwrap_err();
+        assert_eq!(409, error.code);
+        assert_eq!("Cannot transition task from 'done' to 'blocked'", error.message);
+    }
+
+    #[actix_rt::test]
+    async fn test_should_pass_if_match_version_to_repository() {
+        let mut task_repo = MockTaskRepositoryAbstract::new();
+        task_repo.expect_update_task()
+            .with(eq(1), eq("Task".to_string()), eq(None), eq(None), eq(TaskSchedule::default()), eq(Some(3)))
+            .times(1)
+            .returning(|task_id, _, _, _, _, expected_version| Err(Task::version_mismatch(task_id, 4, expected_version.unwrap())));
+
+        let update_task_request = UpdateTaskRequest::new(1, "Task".to_string(), None, None, TaskSchedule::default(), Some(3), Actor::System, &task_repo);
+        let result = update_task_request.execute().await;
+
+        let error = result.unwrap_err();
+        assert_eq!(412, error.code);
+        assert_eq!("Task 1 has version 4, but version 3 was expected", error.message);
+    }
+
+    #[actix_rt::test]
+    async fn test_should_return_conflict_when_task_changes_after_status_check() {
+        let mut task_repo = MockTaskRepositoryAbstract::new();
+        task_repo.expect_get_task_by_id()
+            .with(eq(1))
+            .times(1)
+            .returning(|_| Ok(Task::new(1, "Task".to_string(), None)));
+        task_repo.expect_update_task()
+            .with(eq(1), eq("Task".to_string()), eq(None), eq(Some(TaskStatus::Done)), eq(TaskSchedule::default()), eq(Some(1)))
+            .times(1)
+            .returning(|task_id, _, _, _, _, _| Err(Task::version_mismatch(task_id, 2, 1)));
+
+        let update_task_request = UpdateTaskRequest::new(1, "Task".to_string(), None, Some(TaskStatus::Done), TaskSchedule::default(), None, Actor::System, &task_repo);
+        let result = update_task_request.execute().await;
+
+        assert_eq!(409, result.unwrap_err().code);
+    }
+}
@@ -0,0 +1,24 @@
+use std::sync::Arc;
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use crate::domain::access::api_key::{ApiKey, ApiKeyScope};
+use crate::domain::error::DomainError;
+use crate::domain::workspace::Workspace;
+
+#[cfg(test)]
+use mockall::{predicate::*, *};
+
+#[cfg_attr(test, automock)]
+#[async_trait]
+pub trait ApiKeyRepositoryAbstract: Send + Sync {
+    /// Тот же репозиторий, все запросы которого ограничены рабочим пространством `workspace`.
+    fn in_workspace(&self, workspace: Workspace) -> Arc<dyn ApiKeyRepositoryAbstract>;
+    async fn get_api_keys(&self) -> Result<Vec<ApiKey>, DomainError>;
+    /// Сохраняет ключ по его хешу `key_hash`; само значение ключа в хранилище не попадает.
+    async fn create_api_key(&self, name: String, prefix: String, key_hash: String, scopes: Vec<ApiKeyScope>) -> Result<ApiKey, DomainError>;
+    /// Отзывает ключ в момент `revoked_at`; уже отозванный ключ не меняется.
+    async fn revoke_api_key(&self, key_id: i32, revoked_at: DateTime<Utc>) -> Result<ApiKey, DomainError>;
+    /// Находит действующий ключ по хешу и отмечает его использование в момент `used_at`;
+    /// `None`, если ключ неизвестен или отозван.
+    async fn use_api_key(&self, key_hash: String, used_at: DateTime<Utc>) -> Result<Option<ApiKey>, DomainError>;
+}
@@ -0,0 +1,24 @@
+use std::sync::Arc;
+use async_trait::async_trait;
+use crate::domain::error::DomainError;
+use crate::domain::task::task::Task;
+use crate::domain::task::task_dependency::TaskDependency;
+use crate::domain::workspace::Workspace;
+
+#[cfg(test)]
+use mockall::{predicate::*, *};
+
+#[cfg_attr(test, automock)]
+#[async_trait]
+pub trait DependencyRepositoryAbstract: Send + Sync {
+    /// Тот же репозиторий, все запросы которого ограничены рабочим пространством `workspace`.
+    fn in_workspace(&self, workspace: Workspace) -> Arc<dyn DependencyRepositoryAbstract>;
+    /// Задачи, блокирующие `task_id` напрямую или, если `transitive`, через цепочку зависимостей.
+    async fn get_blockers(&self, task_id: i32, transitive: bool) -> Result<Vec<Task>, DomainError>;
+    /// Идентификаторы всех задач, прямо или транзитивно блокирующих `task_id`.
+    async fn get_blocker_ids(&self, task_id: i32) -> Result<Vec<i32>, DomainError>;
+    async fn add_dependency(&self, task_id: i32, blocker_id: i32) -> Result<(), DomainError>;
+    async fn remove_dependency(&self, task_id: i32, blocker_id: i32) -> Result<(), DomainError>;
+    /// Задачи проекта и зависимости между ними.
+    async fn get_project_dependency_graph(&self, project_id: i32) -> Result<(Vec<Task>, Vec<TaskDependency>), DomainError>;
+}
@@ -0,0 +1,25 @@
+use std::time::Duration;
+use async_trait::async_trait;
+use crate::domain::error::DomainError;
+use crate::domain::idempotency::{IdempotencyReservation, StoredResponse};
+
+#[cfg(test)]
+use mockall::{predicate::*, *};
+
+#[cfg_attr(test, automock)]
+#[async_trait]
+pub trait IdempotencyRepositoryAbstract: Send + Sync {
+    /// Занимает ключ за запросом с отпечатком `fingerprint` на время `ttl`. Ключ, занятый запросом,
+    /// который дольше `lease` так и не сохранил ответ, считается брошенным и занимается заново.
+    ///
+    /// Возвращает идентификатор резервирования, если ключ свободен и теперь занят, либо запись о ранее
+    /// использованном ключе.
+    async fn reserve(&self, key: String, fingerprint: String, ttl: Duration, lease: Duration) -> Result<IdempotencyReservation, DomainError>;
+    /// Сохраняет ответ на запрос, занявший ключ резервированием `reservation_id`.
+    ///
+    /// Возвращает `false`, если ключ за это время перехватил другой запрос и ответ не сохранён.
+    async fn complete(&self, key: String, reservation_id: String, response: StoredResponse) -> Result<bool, DomainError>;
+    /// Освобождает ключ, занятый резервированием `reservation_id`, чтобы запрос можно было повторить.
+    /// Перехваченный другим запросом ключ не освобождается.
+    async fn release(&self, key: String, reservation_id: String) -> Result<(), DomainError>;
+}
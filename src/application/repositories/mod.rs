@@ -1 +1,7 @@
-pub mod task_abstract_repo;
\ No newline at end of file
+pub mod task_abstract_repo;
+pub mod idempotency_abstract_repo;
+pub mod tag_abstract_repo;
+pub mod project_abstract_repo;
+pub mod dependency_abstract_repo;
+pub mod user_abstract_repo;
+pub mod api_key_abstract_repo;
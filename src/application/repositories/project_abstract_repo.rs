@@ -0,0 +1,33 @@
+use std::sync::Arc;
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use crate::domain::access::role::Role;
+use crate::domain::error::DomainError;
+use crate::domain::project::project::{Project, ProjectTasksPolicy};
+use crate::domain::project::project_member::ProjectMember;
+use crate::domain::task::task_event::EventActor;
+use crate::domain::workspace::Workspace;
+
+#[cfg(test)]
+use mockall::{predicate::*, *};
+
+#[cfg_attr(test, automock)]
+#[async_trait]
+pub trait ProjectRepositoryAbstract: Send + Sync {
+    /// Тот же репозиторий, все запросы которого ограничены рабочим пространством `workspace`.
+    fn in_workspace(&self, workspace: Workspace) -> Arc<dyn ProjectRepositoryAbstract>;
+    /// Тот же репозиторий, изменения задач которого записываются в их историю от имени `actor`.
+    fn on_behalf_of(&self, actor: EventActor) -> Arc<dyn ProjectRepositoryAbstract>;
+    async fn get_projects(&self, include_archived: bool) -> Result<Vec<Project>, DomainError>;
+    async fn get_project_by_id(&self, project_id: i32) -> Result<Project, DomainError>;
+    async fn create_project(&self, name: String, description: Option<String>) -> Result<Project, DomainError>;
+    async fn update_project(&self, project_id: i32, name: String, description: Option<String>) -> Result<Project, DomainError>;
+    /// Отправляет проект в архив в момент `archived_at` или, если `archived_at` равен `None`, возвращает из архива.
+    async fn set_project_archived(&self, project_id: i32, archived_at: Option<DateTime<Utc>>) -> Result<Project, DomainError>;
+    async fn delete_project(&self, project_id: i32, policy: ProjectTasksPolicy) -> Result<(), DomainError>;
+
+    async fn get_project_members(&self, project_id: i32) -> Result<Vec<ProjectMember>, DomainError>;
+    /// Добавляет пользователя в проект с ролью `role` или меняет роль участника.
+    async fn set_project_member(&self, project_id: i32, user_id: i32, role: Role) -> Result<ProjectMember, DomainError>;
+    async fn remove_project_member(&self, project_id: i32, user_id: i32) -> Result<(), DomainError>;
+}
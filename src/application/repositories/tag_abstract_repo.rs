@@ -0,0 +1,25 @@
+use std::sync::Arc;
+use async_trait::async_trait;
+use crate::domain::error::DomainError;
+use crate::domain::tag::tag::Tag;
+use crate::domain::workspace::Workspace;
+
+#[cfg(test)]
+use mockall::{predicate::*, *};
+
+#[cfg_attr(test, automock)]
+#[async_trait]
+pub trait TagRepositoryAbstract: Send + Sync {
+    /// Тот же репозиторий, все запросы которого ограничены рабочим пространством `workspace`.
+    fn in_workspace(&self, workspace: Workspace) -> Arc<dyn TagRepositoryAbstract>;
+    async fn get_tags(&self) -> Result<Vec<Tag>, DomainError>;
+    async fn get_tag_by_id(&self, tag_id: i32) -> Result<Tag, DomainError>;
+    async fn create_tag(&self, name: String) -> Result<Tag, DomainError>;
+    async fn update_tag(&self, tag_id: i32, name: String) -> Result<Tag, DomainError>;
+    async fn delete_tag(&self, tag_id: i32) -> Result<(), DomainError>;
+
+    async fn get_task_tags(&self, task_id: i32) -> Result<Vec<Tag>, DomainError>;
+    /// Отмечает задачу меткой; повторная отметка ничего не меняет.
+    async fn attach_tag(&self, task_id: i32, tag_id: i32) -> Result<(), DomainError>;
+    async fn detach_tag(&self, task_id: i32, tag_id: i32) -> Result<(), DomainError>;
+}
@@ -1,19 +1,59 @@
+use std::sync::Arc;
 use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use crate::domain::access::actor::AccessScope;
+use crate::domain::access::role::Role;
+use crate::domain::error::DomainError;
 use crate::domain::task::task::Task;
+use crate::domain::task::task_bulk::{BulkMode, BulkOperation, BulkOutcome};
+use crate::domain::task::task_event::{EventActor, TaskEventPage};
+use crate::domain::task::task_patch::TaskPatch;
+use crate::domain::task::task_placement::TaskPlacement;
+use crate::domain::task::task_schedule::TaskSchedule;
+use crate::domain::task::task_query::{TaskPage, TaskQuery};
+use crate::domain::task::task_search::TaskSearchHit;
+use crate::domain::task::task_status::TaskStatus;
+use crate::domain::task::task_trash::TrashPage;
+use crate::domain::workspace::Workspace;
 
 #[cfg(test)]
 use mockall::{predicate::*, *};
-use std::error::Error;
 
 #[cfg_attr(test, automock)]
-#[async_trait(?Send)]
-pub trait TaskRepositoryAbstract {
-    async fn get_all_tasks(&self) -> Result<Vec<Task>, Box<dyn Error>>;
-    async fn get_task_by_id(&self, task_id: i32) -> Result<Task, Box<dyn Error>>;
+#[async_trait]
+pub trait TaskRepositoryAbstract: Send + Sync {
+    /// Тот же репозиторий, все запросы которого ограничены рабочим пространством `workspace`.
+    fn in_workspace(&self, workspace: Workspace) -> Arc<dyn TaskRepositoryAbstract>;
+    /// Тот же репозиторий, изменения которого записываются в историю задач от имени `actor`.
+    fn on_behalf_of(&self, actor: EventActor) -> Arc<dyn TaskRepositoryAbstract>;
+    async fn get_tasks(&self, query: TaskQuery) -> Result<TaskPage, DomainError>;
+    async fn get_task_by_id(&self, task_id: i32) -> Result<Task, DomainError>;
+    async fn search_tasks(&self, query: String, limit: i64) -> Result<Vec<TaskSearchHit>, DomainError>;
+    async fn get_overdue_tasks(&self, now: DateTime<Utc>, limit: i64) -> Result<Vec<Task>, DomainError>;
+    async fn get_tasks_due_within(&self, now: DateTime<Utc>, until: DateTime<Utc>, limit: i64) -> Result<Vec<Task>, DomainError>;
+    async fn get_tasks_by_urgency(&self, now: DateTime<Utc>, limit: i64) -> Result<Vec<Task>, DomainError>;
+    /// Задача `task_id` и все её потомки, упорядоченные по идентификатору.
+    async fn get_task_subtree(&self, task_id: i32) -> Result<Vec<Task>, DomainError>;
+    /// Идентификаторы задачи `task_id` и всех её предков, от самой задачи к корню.
+    async fn get_task_lineage(&self, task_id: i32) -> Result<Vec<i32>, DomainError>;
+    /// Страница истории задачи `task_id`, от ранних изменений к поздним; история удалённой задачи сохраняется.
+    async fn get_task_events(&self, task_id: i32, limit: i64, offset: i64) -> Result<TaskEventPage, DomainError>;
+    /// Страница корзины, начиная с задач, удалённых последними.
+    async fn get_trash(&self, limit: i64, offset: i64) -> Result<TrashPage, DomainError>;
+    /// Роль пользователя `user_id` в области `scope`; `None`, если такого пользователя нет.
+    async fn get_role(&self, user_id: i32, scope: AccessScope) -> Result<Option<Role>, DomainError>;
 
-    async fn create_task(&self, title: String, description: Option<String>) -> Result<Task, Box<dyn Error>>;
-    async fn update_task(&self, task_id: i32, title: String, description: Option<String>) -> Result<Task, Box<dyn Error>>;
-    async fn delete_task(&self, task_id: i32) -> Result<(), Box<dyn Error>>;
+    async fn create_task(&self, title: String, description: Option<String>, status: TaskStatus, schedule: TaskSchedule, placement: TaskPlacement) -> Result<Task, DomainError>;
+    async fn update_task(&self, task_id: i32, title: String, description: Option<String>, status: Option<TaskStatus>, schedule: TaskSchedule, expected_version: Option<i32>) -> Result<Task, DomainError>;
+    async fn patch_task(&self, task_id: i32, patch: TaskPatch, expected_version: Option<i32>) -> Result<Task, DomainError>;
+    async fn update_task_status(&self, task_id: i32, status: TaskStatus, expected_version: Option<i32>) -> Result<Task, DomainError>;
+    async fn move_task(&self, task_id: i32, parent_id: Option<i32>, expected_version: Option<i32>) -> Result<Task, DomainError>;
+    /// Перемещает задачу в корзину.
+    async fn delete_task(&self, task_id: i32, expected_version: Option<i32>) -> Result<(), DomainError>;
+    async fn restore_task(&self, task_id: i32, expected_version: Option<i32>) -> Result<Task, DomainError>;
+    async fn bulk_write(&self, operations: Vec<BulkOperation>, mode: BulkMode) -> Result<Vec<Result<BulkOutcome, DomainError>>, DomainError>;
+    /// Окончательно удаляет задачи всех пространств, удалённые раньше `deleted_before`; возвращает их количество.
+    async fn purge_trash(&self, deleted_before: DateTime<Utc>) -> Result<usize, DomainError>;
 
 }
 
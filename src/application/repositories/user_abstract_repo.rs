@@ -0,0 +1,29 @@
+use std::sync::Arc;
+use async_trait::async_trait;
+use crate::domain::access::role::Role;
+use crate::domain::error::DomainError;
+use crate::domain::task::task::Task;
+use crate::domain::task::task_event::EventActor;
+use crate::domain::user::user::User;
+use crate::domain::workspace::Workspace;
+
+#[cfg(test)]
+use mockall::{predicate::*, *};
+
+#[cfg_attr(test, automock)]
+#[async_trait]
+pub trait UserRepositoryAbstract: Send + Sync {
+    /// Тот же репозиторий, все запросы которого ограничены рабочим пространством `workspace`.
+    fn in_workspace(&self, workspace: Workspace) -> Arc<dyn UserRepositoryAbstract>;
+    /// Тот же репозиторий, изменения задач которого записываются в их историю от имени `actor`.
+    fn on_behalf_of(&self, actor: EventActor) -> Arc<dyn UserRepositoryAbstract>;
+    async fn get_users(&self) -> Result<Vec<User>, DomainError>;
+    async fn get_user_by_id(&self, user_id: i32) -> Result<User, DomainError>;
+    async fn create_user(&self, name: String, email: String) -> Result<User, DomainError>;
+    async fn set_user_role(&self, user_id: i32, role: Role) -> Result<User, DomainError>;
+
+    async fn get_task_assignees(&self, task_id: i32) -> Result<Vec<User>, DomainError>;
+    /// Назначает пользователя на задачу; `primary` делает его ответственным исполнителем.
+    async fn assign_user(&self, task_id: i32, user_id: i32, primary: bool, expected_version: Option<i32>) -> Result<Task, DomainError>;
+    async fn unassign_user(&self, task_id: i32, user_id: i32, expected_version: Option<i32>) -> Result<Task, DomainError>;
+}
@@ -0,0 +1,4 @@
+use crate::application::validation::string_rules::StringRules;
+
+/// Правила проверки названия сервисной учётной записи, для которой выпускается ключ API.
+pub const API_KEY_NAME_RULES: StringRules = StringRules::new().trim().required().max_length(100).forbid_control_chars(&[]);
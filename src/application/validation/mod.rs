@@ -0,0 +1,6 @@
+pub mod project_rules;
+pub mod string_rules;
+pub mod tag_rules;
+pub mod user_rules;
+pub mod task_rules;
+pub mod validator;pub mod api_key_rules;
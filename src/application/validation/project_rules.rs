@@ -0,0 +1,4 @@
+use crate::application::validation::string_rules::StringRules;
+
+/// Правила проверки названия проекта.
+pub const PROJECT_NAME_RULES: StringRules = StringRules::new().trim().required().max_length(100).forbid_control_chars(&[]);
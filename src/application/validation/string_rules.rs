@@ -0,0 +1,136 @@
+use crate::domain::error::FieldError;
+
+/// Декларативные правила проверки строкового поля.
+///
+/// Правила задаются константами рядом с проверяемой структурой, например:
+///
+/// ```ignore
+/// const TITLE: StringRules = StringRules::new().trim().required().max_length(200);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct StringRules {
+    /// Удалять пробельные символы в начале и в конце строки перед проверкой.
+    pub trim: bool,
+    /// Строка не может быть пустой (после обрезки пробелов, если она включена).
+    pub required: bool,
+    /// Минимальная длина в символах.
+    pub min_length: usize,
+    /// Максимальная длина в символах.
+    pub max_length: Option<usize>,
+    /// Запрещены управляющие символы (кроме разрешённых в `allowed_control_chars`).
+    pub forbid_control_chars: bool,
+    /// Управляющие символы, допустимые несмотря на `forbid_control_chars`.
+    pub allowed_control_chars: &'static [char],
+    /// Дополнительно запрещённые символы.
+    pub forbidden_chars: &'static [char],
+}
+
+impl StringRules {
+    /// Правила без ограничений.
+    pub const fn new() -> Self {
+        StringRules {
+            trim: false,
+            required: false,
+            min_length: 0,
+            max_length: None,
+            forbid_control_chars: false,
+            allowed_control_chars: &[],
+            forbidden_chars: &[],
+        }
+    }
+
+    pub const fn trim(mut self) -> Self {
+        self.trim = true;
+        self
+    }
+
+    pub const fn required(mut self) -> Self {
+        self.required = true;
+        self
+    }
+
+    pub const fn min_length(mut self, min_length: usize) -> Self {
+        self.min_length = min_length;
+        self
+    }
+
+    pub const fn max_length(mut self, max_length: usize) -> Self {
+        self.max_length = Some(max_length);
+        self
+    }
+
+    /// Запрещает управляющие символы, кроме перечисленных в `allowed`.
+    pub const fn forbid_control_chars(mut self, allowed: &'static [char]) -> Self {
+        self.forbid_control_chars = true;
+        self.allowed_control_chars = allowed;
+        self
+    }
+
+    pub const fn forbid_chars(mut self, forbidden: &'static [char]) -> Self {
+        self.forbidden_chars = forbidden;
+        self
+    }
+
+    /// Нормализует и проверяет значение поля `field`.
+    ///
+    /// # Возвращает
+    /// - `Ok(String)` - значение после нормализации (обрезки пробелов).
+    /// - `Err(Vec<FieldError>)` - все нарушенные правила.
+    pub fn apply(&self, field: &str, value: String) -> Result<String, Vec<FieldError>> {
+        let value = if self.trim { value.trim().to_string() } else { value };
+        let length = value.chars().count();
+        let mut errors = Vec::new();
+
+        if value.is_empty() {
+            if self.required {
+                errors.push(FieldError::new(field, "required", &format!("{} must not be empty", field)));
+            }
+        } else if length < self.min_length {
+            errors.push(FieldError::new(field, "too_short", &format!("{} must be at least {} characters long", field, self.min_length)));
+        }
+        if let Some(max_length) = self.max_length.filter(|max_length| length > *max_length) {
+            errors.push(FieldError::new(field, "too_long", &format!("{} must be at most {} characters long", field, max_length)));
+        }
+        if self.forbid_control_chars && value.chars().any(|c| c.is_control() && !self.allowed_control_chars.contains(&c)) {
+            errors.push(FieldError::new(field, "control_characters", &format!("{} must not contain control characters", field)));
+        }
+        if let Some(c) = value.chars().find(|c| self.forbidden_chars.contains(c)) {
+            errors.push(FieldError::new(field, "forbidden_character", &format!("{} must not contain {:?}", field, c)));
+        }
+
+        if errors.is_empty() { Ok(value) } else { Err(errors) }
+    }
+}
+
+impl Default for StringRules {
+    fn default() -> Self {
+        StringRules::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const RULES: StringRules = StringRules::new().trim().required().max_length(5).forbid_control_chars(&['\n']).forbid_chars(&['<']);
+
+    #[test]
+    fn test_should_trim_value_before_checking_limits() {
+        assert_eq!(RULES.apply("title", "  abcde \t".to_string()), Ok("abcde".to_string()));
+    }
+
+    #[test]
+    fn test_should_report_every_violated_rule() {
+        let errors = RULES.apply("title", "a\u{7}<cdef".to_string()).unwrap_err();
+
+        let codes = errors.iter().map(|e| e.code.as_str()).collect::<Vec<_>>();
+        assert_eq!(codes, vec!["too_long", "control_characters", "forbidden_character"]);
+        assert!(errors.iter().all(|e| e.field == "title"));
+    }
+
+    #[test]
+    fn test_should_count_characters_not_bytes() {
+        assert!(RULES.apply("title", "ё\nжик".to_string()).is_ok());
+        assert_eq!(RULES.apply("title", "   ".to_string()).unwrap_err()[0].code, "required");
+    }
+}
@@ -0,0 +1,6 @@
+use crate::application::validation::string_rules::StringRules;
+
+/// Правила проверки имени метки.
+///
+/// Запятая запрещена, так как разделяет имена меток в фильтре `?tag=` списка задач.
+pub const TAG_NAME_RULES: StringRules = StringRules::new().trim().required().max_length(50).forbid_control_chars(&[]).forbid_chars(&[',']);
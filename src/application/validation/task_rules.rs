@@ -0,0 +1,30 @@
+use chrono::{DateTime, Utc};
+use crate::application::validation::string_rules::StringRules;
+use crate::application::validation::validator::Validator;
+use crate::domain::error::FieldError;
+use crate::domain::task::task_schedule::{TaskSchedule, MAX_ESTIMATE_MINUTES};
+
+/// Правила проверки заголовка задачи.
+///
+/// Ограничения продублированы в атрибутах `#[schema]` `TaskPayload`, соответствие проверяется тестом.
+pub const TITLE_RULES: StringRules = StringRules::new().trim().required().max_length(200).forbid_control_chars(&[]);
+/// Правила проверки описания задачи.
+pub const DESCRIPTION_RULES: StringRules = StringRules::new().trim().max_length(10_000).forbid_control_chars(&['\n', '\r', '\t']);
+
+/// Проверяет, что срок выполнения задачи не раньше даты её начала.
+pub fn check_schedule_dates(validator: &mut Validator, start_at: Option<DateTime<Utc>>, due_at: Option<DateTime<Utc>>) {
+    if !TaskSchedule::dates_are_ordered(start_at, due_at) {
+        validator.add_error(FieldError::new("due_at", "before_start", "due_at must not be earlier than start_at"));
+    }
+}
+
+/// Проверяет, что оценка трудоёмкости не отрицательна и не превышает `MAX_ESTIMATE_MINUTES`.
+pub fn check_estimate(validator: &mut Validator, estimate_minutes: Option<i32>) {
+    if !TaskSchedule::estimate_is_valid(estimate_minutes) {
+        validator.add_error(FieldError::new(
+            "estimate_minutes",
+            "out_of_range",
+            &format!("estimate_minutes must be between 0 and {}", MAX_ESTIMATE_MINUTES),
+        ));
+    }
+}
@@ -0,0 +1,28 @@
+use crate::application::validation::string_rules::StringRules;
+use crate::application::validation::validator::Validator;
+use crate::domain::error::FieldError;
+
+/// Правила проверки имени пользователя.
+pub const USER_NAME_RULES: StringRules = StringRules::new().trim().required().max_length(100).forbid_control_chars(&[]);
+
+/// Правила проверки адреса электронной почты; формат адреса проверяет `check_email`.
+pub const USER_EMAIL_RULES: StringRules = StringRules::new().trim().required().max_length(254).forbid_control_chars(&[]).forbid_chars(&[' ']);
+
+/// Проверяет, что адрес состоит из непустых имени и домена с точкой, разделённых одним `@`.
+///
+/// Пустой адрес уже отклонён правилом `required`, поэтому повторно не проверяется.
+pub fn check_email(validator: &mut Validator, email: &str) {
+    if email.is_empty() {
+        return;
+    }
+    let valid = match email.split_once('@') {
+        Some((local, domain)) => !local.is_empty()
+            && !domain.contains('@')
+            && domain.split('.').count() > 1
+            && domain.split('.').all(|part| !part.is_empty()),
+        None => false,
+    };
+    if !valid {
+        validator.add_error(FieldError::new("email", "invalid_format", "email must look like name@example.com"));
+    }
+}
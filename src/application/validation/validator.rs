@@ -0,0 +1,56 @@
+use crate::application::helpers::error_handler::ErrorHandlingHelper;
+use crate::application::validation::string_rules::StringRules;
+use crate::domain::error::{ApiError, FieldError};
+
+/// Входные данные, которые проверяются и нормализуются до передачи в `AbstractRequest`.
+pub trait Validate: Sized {
+    /// Возвращает нормализованные данные либо `ApiError` с кодом 422 и ошибками всех полей.
+    fn validate(self) -> Result<Self, ApiError>;
+}
+
+/// Собирает ошибки проверки нескольких полей, чтобы вернуть их клиенту одним ответом.
+#[derive(Debug, Default)]
+pub struct Validator {
+    errors: Vec<FieldError>,
+}
+
+impl Validator {
+    pub fn new() -> Self {
+        Validator::default()
+    }
+
+    /// Проверяет обязательное строковое поле и возвращает его нормализованное значение.
+    pub fn string(&mut self, field: &str, value: String, rules: &StringRules) -> String {
+        match rules.apply(field, value.clone()) {
+            Ok(value) => value,
+            Err(errors) => {
+                self.errors.extend(errors);
+                value
+            },
+        }
+    }
+
+    /// Проверяет необязательное строковое поле. Пустое после нормализации значение считается отсутствующим.
+    pub fn optional_string(&mut self, field: &str, value: Option<String>, rules: &StringRules) -> Option<String> {
+        value
+            .map(|value| self.string(field, value, rules))
+            .filter(|value| !value.is_empty())
+    }
+
+    /// Добавляет ошибку, найденную проверкой, не описываемой правилами.
+    pub fn add_error(&mut self, error: FieldError) {
+        self.errors.push(error);
+    }
+
+    /// Завершает проверку.
+    ///
+    /// # Ошибки
+    /// Возвращает `ApiError` с кодом 422, если хотя бы одно поле не прошло проверку.
+    pub fn finish<T>(self, value: T) -> Result<T, ApiError> {
+        if self.errors.is_empty() {
+            Ok(value)
+        } else {
+            Err(ErrorHandlingHelper::validation_error(self.errors))
+        }
+    }
+}
@@ -1,30 +1,223 @@
+use std::time::Duration;
+use diesel::connection::SimpleConnection;
 use diesel::prelude::*;
-use diesel::r2d2::{self, ConnectionManager};
+use diesel::r2d2::{self, ConnectionManager, CustomizeConnection, PooledConnection};
+use crate::domain::error::DomainError;
 
+type DbPool = r2d2::Pool<ConnectionManager<SqliteConnection>>;
+pub type DbPooledConnection = PooledConnection<ConnectionManager<SqliteConnection>>;
 
+const JOURNAL_MODES: [&str; 6] = ["DELETE", "TRUNCATE", "PERSIST", "MEMORY", "WAL", "OFF"];
+const SYNCHRONOUS_MODES: [&str; 4] = ["OFF", "NORMAL", "FULL", "EXTRA"];
 
-type DbPool = r2d2::Pool<ConnectionManager<SqliteConnection>>;
+/// Параметры пула соединений и SQLite, применяемые при запуске приложения.
+#[derive(Debug, Clone)]
+pub struct DbConfig {
+    pub database_url: String,
+    /// Максимальное количество соединений в пуле.
+    pub max_size: u32,
+    /// Минимальное количество простаивающих соединений; `None` - равно `max_size`.
+    pub min_idle: Option<u32>,
+    /// Сколько ждать свободного соединения, прежде чем вернуть ошибку недоступности.
+    pub connection_timeout: Duration,
+    /// Сколько SQLite ждёт снятия блокировки базы перед ошибкой `SQLITE_BUSY`.
+    pub busy_timeout: Duration,
+    pub journal_mode: String,
+    pub synchronous: String,
+}
+
+impl DbConfig {
+    /// Конфигурация по умолчанию для базы данных по адресу `database_url`.
+    pub fn new(database_url: &str) -> Self {
+        DbConfig {
+            database_url: database_url.to_string(),
+            max_size: 5,
+            min_idle: None,
+            connection_timeout: Duration::from_secs(5),
+            busy_timeout: Duration::from_millis(5000),
+            journal_mode: String::from("WAL"),
+            synchronous: String::from("NORMAL"),
+        }
+    }
+
+    /// Читает конфигурацию из переменных окружения.
+    ///
+    /// Обязательна `DATABASE_URL`; необязательные `DB_POOL_MAX_SIZE`, `DB_POOL_MIN_IDLE`,
+    /// `DB_POOL_CONNECTION_TIMEOUT_MS`, `DB_BUSY_TIMEOUT_MS`, `DB_JOURNAL_MODE` и `DB_SYNCHRONOUS`
+    /// переопределяют значения по умолчанию.
+    ///
+    /// # Паника
+    /// Функция паникует, если `DATABASE_URL` не установлена или если значение одной из переменных некорректно.
+    pub fn from_env() -> Self {
+        let database_url = dotenv::var("DATABASE_URL").expect("DATABASE_URL must be set");
+        let mut config = DbConfig::new(&database_url);
+
+        if let Ok(max_size) = dotenv::var("DB_POOL_MAX_SIZE") {
+            config.max_size = max_size.parse().expect("DB_POOL_MAX_SIZE must be a positive integer");
+        }
+        if let Ok(min_idle) = dotenv::var("DB_POOL_MIN_IDLE") {
+            config.min_idle = Some(min_idle.parse().expect("DB_POOL_MIN_IDLE must be a non-negative integer"));
+        }
+        if let Ok(timeout) = dotenv::var("DB_POOL_CONNECTION_TIMEOUT_MS") {
+            config.connection_timeout = Duration::from_millis(timeout.parse().expect("DB_POOL_CONNECTION_TIMEOUT_MS must be a number of milliseconds"));
+        }
+        if let Ok(timeout) = dotenv::var("DB_BUSY_TIMEOUT_MS") {
+            config.busy_timeout = Duration::from_millis(timeout.parse().expect("DB_BUSY_TIMEOUT_MS must be a number of milliseconds"));
+        }
+        if let Ok(journal_mode) = dotenv::var("DB_JOURNAL_MODE") {
+            config.journal_mode = journal_mode.to_uppercase();
+            assert!(JOURNAL_MODES.contains(&config.journal_mode.as_str()), "DB_JOURNAL_MODE must be one of {:?}", JOURNAL_MODES);
+        }
+        if let Ok(synchronous) = dotenv::var("DB_SYNCHRONOUS") {
+            config.synchronous = synchronous.to_uppercase();
+            assert!(SYNCHRONOUS_MODES.contains(&config.synchronous.as_str()), "DB_SYNCHRONOUS must be one of {:?}", SYNCHRONOUS_MODES);
+        }
+
+        config
+    }
+}
 
+/// Применяет PRAGMA-настройки SQLite к каждому новому соединению пула.
+#[derive(Debug)]
+struct SqlitePragmas {
+    busy_timeout: Duration,
+    journal_mode: String,
+    synchronous: String,
+}
+
+impl CustomizeConnection<SqliteConnection, r2d2::Error> for SqlitePragmas {
+    fn on_acquire(&self, conn: &mut SqliteConnection) -> Result<(), r2d2::Error> {
+        conn.batch_execute(&format!(
+            "PRAGMA busy_timeout = {}; PRAGMA journal_mode = {}; PRAGMA synchronous = {}; PRAGMA foreign_keys = ON;",
+            self.busy_timeout.as_millis(),
+            self.journal_mode,
+            self.synchronous,
+        ))
+        .map_err(r2d2::Error::QueryError)
+    }
+}
+
+/// Долгоживущий пул соединений к базе данных, создаваемый один раз при запуске приложения.
+///
+/// Клон разделяет тот же пул, поэтому несколько репозиториев могут работать через одно соединение.
+#[derive(Clone)]
 pub struct DbConnection {
     pub db_name: String,
+    pool: DbPool,
 }
-/// Создаёт и возвращает пул соединений к базе данных.
-///
-/// # Возвращаемое значение
-/// Возвращает `DbPool` - пул соединений, сконфигурированный с максимальным размером и соединением к базе данных.
-///
-/// # Паника
-/// Функция паникует, если переменная окружения `DATABASE_URL` не установлена или если пул соединений не может быть создан.
 
 impl DbConnection {
-    pub fn get_pool(&self) -> DbPool {
-        let database_url = dotenv::var("DATABASE_URL").expect("DATABASE_URL must be set");
-        let manager = ConnectionManager::<SqliteConnection>::new(&database_url);
+    /// Создаёт пул соединений согласно `config`.
+    ///
+    /// # Ошибки
+    /// Возвращает `r2d2::PoolError`, если не удалось установить начальные соединения с базой данных.
+    pub fn new(db_name: &str, config: &DbConfig) -> Result<Self, r2d2::PoolError> {
+        let manager = ConnectionManager::<SqliteConnection>::new(&config.database_url);
         let pool = r2d2::Pool::builder()
-            .max_size(5) // Пример максимального размера пула
-            .build(manager)
-            .expect("Failed to create pool.");
-        pool
+            .max_size(config.max_size)
+            .min_idle(config.min_idle)
+            .connection_timeout(config.connection_timeout)
+            .connection_customizer(Box::new(SqlitePragmas {
+                busy_timeout: config.busy_timeout,
+                journal_mode: config.journal_mode.clone(),
+                synchronous: config.synchronous.clone(),
+            }))
+            .build(manager)?;
+
+        Ok(DbConnection { db_name: db_name.to_string(), pool })
+    }
+
+    /// Берёт соединение из пула.
+    ///
+    /// # Ошибки
+    /// Возвращает `DomainError::Unavailable`, если за `connection_timeout` не освободилось ни одного соединения.
+    pub fn get_connection(&self) -> Result<DbPooledConnection, DomainError> {
+        self.pool.get().map_err(|e| DomainError::Unavailable(e.to_string()))
+    }
+
+    /// Выполняет `query` с соединением из пула в пуле блокирующих потоков,
+    /// не занимая поток обработки HTTP-запросов на время ожидания соединения и работы SQLite.
+    ///
+    /// # Ошибки
+    /// Возвращает ошибку `query`, либо `DomainError::Unavailable`, если соединение не удалось получить
+    /// или пул блокирующих потоков остановлен.
+    pub async fn run<F, T>(&self, query: F) -> Result<T, DomainError>
+    where
+        F: FnOnce(&mut SqliteConnection) -> Result<T, DomainError> + Send + 'static,
+        T: Send + 'static,
+    {
+        let pool = self.pool.clone();
+        let result = actix_rt::task::spawn_blocking(move || {
+            let mut conn = pool.get().map_err(|e| DomainError::Unavailable(e.to_string()))?;
+            query(&mut conn)
+        }).await;
+
+        match result {
+            Ok(result) => result,
+            Err(e) => Err(DomainError::Unavailable(e.to_string())),
+        }
     }
 }
 
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use diesel::sql_types::{BigInt, Text};
+
+    #[derive(QueryableByName)]
+    struct PragmaValue {
+        #[diesel(sql_type = BigInt)]
+        timeout: i64,
+    }
+
+    #[derive(QueryableByName)]
+    struct JournalMode {
+        #[diesel(sql_type = Text)]
+        journal_mode: String,
+    }
+
+    fn temp_database_url(name: &str) -> String {
+        let path = std::env::temp_dir().join(format!("task_api_{}_{}.db", name, std::process::id()));
+        let _ = std::fs::remove_file(&path);
+        path.to_string_lossy().into_owned()
+    }
+
+    #[test]
+    fn test_should_apply_pragmas_to_pooled_connections() {
+        let mut config = DbConfig::new(&temp_database_url("pragmas"));
+        config.max_size = 1;
+        config.busy_timeout = Duration::from_millis(1234);
+        let db_connection = DbConnection::new("test", &config).unwrap();
+
+        let mut conn = db_connection.get_connection().unwrap();
+        let timeout = diesel::sql_query("PRAGMA busy_timeout").get_result::<PragmaValue>(&mut conn).unwrap();
+        let journal = diesel::sql_query("PRAGMA journal_mode").get_result::<JournalMode>(&mut conn).unwrap();
+
+        assert_eq!(timeout.timeout, 1234);
+        assert_eq!(journal.journal_mode, "wal");
+    }
+
+    #[test]
+    fn test_should_return_storage_unavailable_when_pool_is_exhausted() {
+        let mut config = DbConfig::new(&temp_database_url("exhausted"));
+        config.max_size = 1;
+        config.connection_timeout = Duration::from_millis(50);
+        let db_connection = DbConnection::new("test", &config).unwrap();
+
+        let _held = db_connection.get_connection().unwrap();
+        let result = db_connection.get_connection();
+
+        assert!(matches!(result, Err(DomainError::Unavailable(_))));
+    }
+
+    #[actix_rt::test]
+    async fn test_should_run_queries_on_blocking_thread() {
+        let config = DbConfig::new(&temp_database_url("blocking"));
+        let db_connection = DbConnection::new("test", &config).unwrap();
+        let caller_thread = std::thread::current().id();
+
+        let query_thread = db_connection.run(|_conn| Ok(std::thread::current().id())).await.unwrap();
+
+        assert_ne!(caller_thread, query_thread);
+    }
+}
@@ -0,0 +1,26 @@
+use diesel::result::{DatabaseErrorKind, Error as DieselError};
+use crate::domain::error::DomainError;
+
+/// Переводит ошибки Diesel в доменные ошибки.
+///
+/// Нарушения уникальности и внешних ключей означают конфликт с существующими данными,
+/// нарушения `CHECK`/`NOT NULL` - некорректные данные, а `SQLITE_BUSY` - временную недоступность базы.
+impl From<DieselError> for DomainError {
+    fn from(error: DieselError) -> Self {
+        match error {
+            DieselError::NotFound => DomainError::NotFound(String::from("Record not found")),
+            DieselError::DatabaseError(DatabaseErrorKind::UniqueViolation, info)
+            | DieselError::DatabaseError(DatabaseErrorKind::ForeignKeyViolation, info) => {
+                DomainError::Conflict(info.message().to_string())
+            },
+            DieselError::DatabaseError(DatabaseErrorKind::CheckViolation, info)
+            | DieselError::DatabaseError(DatabaseErrorKind::NotNullViolation, info) => {
+                DomainError::Validation(info.message().to_string())
+            },
+            DieselError::DatabaseError(_, info) if info.message().contains("database is locked") => {
+                DomainError::Unavailable(info.message().to_string())
+            },
+            other => DomainError::Internal(other.to_string()),
+        }
+    }
+}
@@ -1,6 +1,19 @@
+use std::fmt::Display;
+use std::str::FromStr;
 use crate::application::mappers::db_mapper::DbMapper;
-use crate::dal::models::TaskEntity;
+use crate::dal::models::{ApiKeyEntity, IdempotencyKeyEntity, IdempotencyResponseChangeset, ProjectEntity, ProjectMemberEntity, TagEntity, TaskChangeset, TaskEntity, TaskEventEntity, TaskSearchEntity, UserEntity};
+use crate::domain::access::api_key::{ApiKey, ApiKeyScope};
+use crate::domain::error::DomainError;
+use crate::domain::idempotency::{IdempotencyRecord, StoredResponse};
+use crate::domain::project::project::Project;
+use crate::domain::project::project_member::ProjectMember;
+use crate::domain::tag::tag::Tag;
 use crate::domain::task::task::Task;
+use crate::domain::task::task_event::{FieldChange, TaskEvent};
+use crate::domain::task::task_patch::TaskPatch;
+use crate::domain::task::task_search::TaskSearchHit;
+use crate::domain::user::user::User;
+use crate::domain::workspace::Workspace;
 
 pub struct TaskDbMapper {}
 
@@ -9,15 +22,281 @@ impl DbMapper<Task, TaskEntity> for TaskDbMapper {
         TaskEntity {
             id: entity.id,
             title: entity.title,
-            description: entity.description
+            description: entity.description,
+            status: entity.status.to_string(),
+            version: entity.version,
+            priority: entity.priority.to_string(),
+            start_at: entity.start_at.map(|start_at| start_at.naive_utc()),
+            due_at: entity.due_at.map(|due_at| due_at.naive_utc()),
+            project_id: entity.project_id,
+            parent_id: entity.parent_id,
+            estimate_minutes: entity.estimate_minutes,
+            assignee_id: entity.assignee_id,
+            // Пространство не входит в доменную модель: при записи его задаёт репозиторий.
+            workspace_id: Workspace::DEFAULT.id,
+            deleted_at: entity.deleted_at.map(|deleted_at| deleted_at.naive_utc())
         }
     }
 
-    fn to_entity(model: TaskEntity) -> Task {
-        Task {
+    fn to_entity(model: TaskEntity) -> Result<Task, DomainError> {
+        Ok(Task {
             id: model.id,
             title: model.title,
-            description: model.description
+            description: model.description,
+            status: parse_column("tasks.status", &model.status)?,
+            version: model.version,
+            priority: parse_column("tasks.priority", &model.priority)?,
+            start_at: model.start_at.map(|start_at| start_at.and_utc()),
+            due_at: model.due_at.map(|due_at| due_at.and_utc()),
+            project_id: model.project_id,
+            parent_id: model.parent_id,
+            estimate_minutes: model.estimate_minutes,
+            assignee_id: model.assignee_id,
+            deleted_at: model.deleted_at.map(|deleted_at| deleted_at.and_utc())
+        })
+    }
+}
+
+pub struct TaskSearchDbMapper {}
+
+impl TaskSearchDbMapper {
+    pub fn to_entity(model: TaskSearchEntity) -> Result<TaskSearchHit, DomainError> {
+        Ok(TaskSearchHit {
+            task: TaskDbMapper::to_entity(model.task)?,
+            rank: model.rank,
+            title_highlight: model.title_highlight,
+            description_snippet: model.description_snippet.filter(|snippet| !snippet.is_empty())
+        })
+    }
+}
+
+pub struct TaskPatchDbMapper {}
+
+impl TaskPatchDbMapper {
+    pub fn to_db(patch: TaskPatch) -> TaskChangeset {
+        TaskChangeset {
+            title: patch.title,
+            description: patch.description,
+            status: patch.status.map(|status| status.to_string()),
+            priority: patch.priority.map(|priority| priority.to_string()),
+            start_at: patch.start_at.map(|start_at| start_at.map(|start_at| start_at.naive_utc())),
+            due_at: patch.due_at.map(|due_at| due_at.map(|due_at| due_at.naive_utc())),
+            project_id: patch.project_id,
+            estimate_minutes: patch.estimate_minutes
+        }
+    }
+}
+
+pub struct TaskEventDbMapper {}
+
+impl TaskEventDbMapper {
+    pub fn to_entity(model: TaskEventEntity) -> Result<TaskEvent, DomainError> {
+        Ok(TaskEvent {
+            id: model.id,
+            task_id: model.task_id,
+            action: parse_column("task_events.action", &model.action)?,
+            actor: model.actor,
+            occurred_at: model.occurred_at.and_utc(),
+            changes: serde_json::from_str(&model.changes).map_err(|e| corrupted("task_events.changes", e))?
+        })
+    }
+
+    pub fn changes_to_db(changes: &[FieldChange]) -> String {
+        serde_json::to_string(changes).unwrap_or_else(|_| "[]".to_string())
+    }
+}
+
+pub struct IdempotencyDbMapper {}
+
+impl IdempotencyDbMapper {
+    pub fn to_entity(model: IdempotencyKeyEntity) -> Result<IdempotencyRecord, DomainError> {
+        let headers = model.headers.as_deref()
+            .map(|headers| serde_json::from_str(headers).map_err(|e| corrupted("idempotency_keys.headers", e)))
+            .transpose()?;
+        let response = model.status_code.map(|status_code| StoredResponse {
+            status: status_code as u16,
+            headers: headers.unwrap_or_default(),
+            body: model.body.unwrap_or_default(),
+        });
+
+        Ok(IdempotencyRecord { fingerprint: model.fingerprint, response })
+    }
+
+    pub fn to_db(response: StoredResponse) -> IdempotencyResponseChangeset {
+        IdempotencyResponseChangeset {
+            status_code: i32::from(response.status),
+            headers: serde_json::to_string(&response.headers).unwrap_or_else(|_| String::from("[]")),
+            body: response.body,
+        }
+    }
+}
+
+pub struct TagDbMapper {}
+
+impl DbMapper<Tag, TagEntity> for TagDbMapper {
+    fn to_db(entity: Tag) -> TagEntity {
+        TagEntity {
+            id: entity.id,
+            name: entity.name,
+            workspace_id: Workspace::DEFAULT.id
+        }
+    }
+
+    fn to_entity(model: TagEntity) -> Result<Tag, DomainError> {
+        Ok(Tag {
+            id: model.id,
+            name: model.name
+        })
+    }
+}
+
+pub struct ProjectDbMapper {}
+
+impl DbMapper<Project, ProjectEntity> for ProjectDbMapper {
+    fn to_db(entity: Project) -> ProjectEntity {
+        ProjectEntity {
+            id: entity.id,
+            name: entity.name,
+            description: entity.description,
+            archived_at: entity.archived_at.map(|archived_at| archived_at.naive_utc()),
+            workspace_id: Workspace::DEFAULT.id
+        }
+    }
+
+    fn to_entity(model: ProjectEntity) -> Result<Project, DomainError> {
+        Ok(Project {
+            id: model.id,
+            name: model.name,
+            description: model.description,
+            archived_at: model.archived_at.map(|archived_at| archived_at.and_utc())
+        })
+    }
+}
+
+pub struct UserDbMapper {}
+
+impl DbMapper<User, UserEntity> for UserDbMapper {
+    fn to_db(entity: User) -> UserEntity {
+        UserEntity {
+            id: entity.id,
+            name: entity.name,
+            email: entity.email,
+            role: entity.role.to_string()
+        }
+    }
+
+    fn to_entity(model: UserEntity) -> Result<User, DomainError> {
+        Ok(User {
+            id: model.id,
+            name: model.name,
+            email: model.email,
+            role: parse_column("users.role", &model.role)?
+        })
+    }
+}
+
+pub struct ProjectMemberDbMapper {}
+
+impl DbMapper<ProjectMember, ProjectMemberEntity> for ProjectMemberDbMapper {
+    fn to_db(entity: ProjectMember) -> ProjectMemberEntity {
+        ProjectMemberEntity {
+            project_id: entity.project_id,
+            user_id: entity.user_id,
+            role: entity.role.to_string()
         }
     }
+
+    fn to_entity(model: ProjectMemberEntity) -> Result<ProjectMember, DomainError> {
+        Ok(ProjectMember {
+            project_id: model.project_id,
+            user_id: model.user_id,
+            role: parse_column("project_members.role", &model.role)?
+        })
+    }
+}
+
+pub struct ApiKeyDbMapper {}
+
+impl ApiKeyDbMapper {
+    pub fn to_entity(model: ApiKeyEntity) -> Result<ApiKey, DomainError> {
+        Ok(ApiKey {
+            id: model.id,
+            name: model.name,
+            prefix: model.prefix,
+            scopes: model.scopes.split(',')
+                .filter(|scope| !scope.is_empty())
+                .map(|scope| parse_column("api_keys.scopes", scope))
+                .collect::<Result<_, _>>()?,
+            created_at: model.created_at.and_utc(),
+            last_used_at: model.last_used_at.map(|last_used_at| last_used_at.and_utc()),
+            revoked_at: model.revoked_at.map(|revoked_at| revoked_at.and_utc()),
+            workspace_id: model.workspace_id
+        })
+    }
+
+    pub fn scopes_to_db(scopes: &[ApiKeyScope]) -> String {
+        scopes.iter().map(ApiKeyScope::as_str).collect::<Vec<_>>().join(",")
+    }
+}
+
+/// Разбирает значение столбца `column`, которое приложение записывает только в допустимом виде.
+///
+/// # Ошибки
+/// `DomainError::Internal`, если значение не разбирается: данные в базе повреждены, и подставлять
+/// значение по умолчанию нельзя.
+pub(crate) fn parse_column<T>(column: &str, value: &str) -> Result<T, DomainError>
+where
+    T: FromStr,
+    T::Err: Display,
+{
+    value.parse().map_err(|e| corrupted(column, e))
+}
+
+fn corrupted(column: &str, error: impl Display) -> DomainError {
+    DomainError::Internal(format!("Corrupted {} in the database: {}", column, error))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::access::role::Role;
+    use crate::domain::task::task_status::TaskStatus;
+
+    fn is_internal<T>(result: Result<T, DomainError>) -> bool {
+        matches!(result, Err(DomainError::Internal(_)))
+    }
+
+    #[test]
+    fn test_should_report_corrupted_values_instead_of_defaults() {
+        let mut task = TaskDbMapper::to_db(Task::new(1, "Buy milk".to_string(), None));
+        task.status = "in_review".to_string();
+        assert!(is_internal(TaskDbMapper::to_entity(task)));
+
+        let mut task = TaskDbMapper::to_db(Task::new(1, "Buy milk".to_string(), None));
+        task.priority = "critical".to_string();
+        assert!(is_internal(TaskDbMapper::to_entity(task)));
+
+        let user = UserEntity { id: 1, name: "Ada".to_string(), email: "ada@example.com".to_string(), role: "owner".to_string() };
+        assert!(is_internal(UserDbMapper::to_entity(user)));
+
+        let event = TaskEventEntity {
+            id: 1,
+            task_id: 1,
+            action: "updated".to_string(),
+            actor: "system".to_string(),
+            occurred_at: chrono::Utc::now().naive_utc(),
+            changes: "not json".to_string(),
+        };
+        assert!(is_internal(TaskEventDbMapper::to_entity(event)));
+    }
+
+    #[test]
+    fn test_should_map_valid_values() {
+        let mut task = TaskDbMapper::to_db(Task::new(1, "Buy milk".to_string(), None));
+        task.status = "done".to_string();
+        assert_eq!(TaskDbMapper::to_entity(task).unwrap().status, TaskStatus::Done);
+
+        let user = UserEntity { id: 1, name: "Ada".to_string(), email: "ada@example.com".to_string(), role: "admin".to_string() };
+        assert_eq!(UserDbMapper::to_entity(user).unwrap().role, Role::Admin);
+    }
 }
@@ -1,4 +1,5 @@
 pub mod db_connection;
+pub mod db_error;
 pub mod repositories;
 
 pub mod schema;
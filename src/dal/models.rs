@@ -1,11 +1,172 @@
-use diesel::{Insertable, Queryable, QueryableByName, Selectable};
+use chrono::NaiveDateTime;
+use diesel::{AsChangeset, Insertable, Queryable, QueryableByName, Selectable};
+use diesel::sql_types::{Double, Integer, Nullable, Text};
 use serde::{Deserialize, Serialize};
-use crate::dal::schema::tasks;
+use crate::dal::schema::{api_keys, idempotency_keys, project_members, projects, tags, task_assignees, task_dependencies, task_events, task_tags, tasks, users};
 
 #[derive(Insertable, Selectable,QueryableByName,Queryable, Serialize, Deserialize, Debug)]
 #[diesel(table_name = tasks)]
 pub struct TaskEntity {
     pub id: i32,
     pub title: String,
-    pub description: Option<String>
+    pub description: Option<String>,
+    pub status: String,
+    pub version: i32,
+    pub priority: String,
+    pub start_at: Option<NaiveDateTime>,
+    pub due_at: Option<NaiveDateTime>,
+    pub project_id: Option<i32>,
+    pub parent_id: Option<i32>,
+    pub estimate_minutes: Option<i32>,
+    pub assignee_id: Option<i32>,
+    pub workspace_id: i32,
+    /// Момент перемещения задачи в корзину; `None` - задача не удалена.
+    pub deleted_at: Option<NaiveDateTime>
+}
+
+/// Связь задачи с родителем, выбираемая рекурсивными запросами по дереву задач.
+#[derive(QueryableByName, Debug)]
+pub struct TaskLinkEntity {
+    #[diesel(sql_type = Integer)]
+    pub id: i32,
+    #[diesel(sql_type = Nullable<Integer>)]
+    pub parent_id: Option<i32>,
+}
+
+#[derive(QueryableByName, Debug)]
+pub struct TaskSearchEntity {
+    #[diesel(embed)]
+    pub task: TaskEntity,
+    #[diesel(sql_type = Double)]
+    pub rank: f64,
+    #[diesel(sql_type = Text)]
+    pub title_highlight: String,
+    #[diesel(sql_type = Nullable<Text>)]
+    pub description_snippet: Option<String>
+}
+
+/// Набор изменяемых столбцов задачи; столбцы со значением `None` не попадают в `UPDATE`.
+#[derive(AsChangeset, Debug, Default)]
+#[diesel(table_name = tasks)]
+pub struct TaskChangeset {
+    pub title: Option<String>,
+    pub description: Option<Option<String>>,
+    pub status: Option<String>,
+    pub priority: Option<String>,
+    pub start_at: Option<Option<NaiveDateTime>>,
+    pub due_at: Option<Option<NaiveDateTime>>,
+    pub project_id: Option<Option<i32>>,
+    pub estimate_minutes: Option<Option<i32>>
+}
+
+#[derive(Insertable, Selectable, Queryable, Debug)]
+#[diesel(table_name = idempotency_keys)]
+pub struct IdempotencyKeyEntity {
+    pub idempotency_key: String,
+    pub fingerprint: String,
+    pub status_code: Option<i32>,
+    /// Заголовки ответа, сериализованные в JSON-массив пар `[имя, значение]`.
+    pub headers: Option<String>,
+    pub body: Option<Vec<u8>>,
+    pub created_at: i64,
+    pub expires_at: i64,
+    /// Идентификатор последнего резервирования ключа; подтверждает, что запрос всё ещё владеет ключом.
+    pub reservation_id: String
+}
+
+/// Сохраняемый ответ на запрос с ключом идемпотентности.
+#[derive(AsChangeset, Debug)]
+#[diesel(table_name = idempotency_keys)]
+pub struct IdempotencyResponseChangeset {
+    pub status_code: i32,
+    pub headers: String,
+    pub body: Vec<u8>
+}
+
+#[derive(Selectable, Queryable, Debug)]
+#[diesel(table_name = tags)]
+pub struct TagEntity {
+    pub id: i32,
+    pub name: String,
+    pub workspace_id: i32
+}
+
+/// Связь задачи с меткой.
+#[derive(Insertable, Debug)]
+#[diesel(table_name = task_tags)]
+pub struct TaskTagEntity {
+    pub task_id: i32,
+    pub tag_id: i32,
+    pub workspace_id: i32
+}
+
+/// Зависимость задачи `task_id` от блокирующей задачи `blocker_id`.
+#[derive(Insertable, Selectable, Queryable, Debug)]
+#[diesel(table_name = task_dependencies)]
+pub struct TaskDependencyEntity {
+    pub task_id: i32,
+    pub blocker_id: i32,
+    pub workspace_id: i32
+}
+
+#[derive(Selectable, Queryable, Debug)]
+#[diesel(table_name = projects)]
+pub struct ProjectEntity {
+    pub id: i32,
+    pub name: String,
+    pub description: Option<String>,
+    pub archived_at: Option<NaiveDateTime>,
+    pub workspace_id: i32
+}
+
+#[derive(Insertable, Selectable, Queryable, Debug)]
+#[diesel(table_name = users)]
+pub struct UserEntity {
+    pub id: i32,
+    pub name: String,
+    pub email: String,
+    pub role: String
+}
+
+#[derive(Insertable, Selectable, Queryable, Debug)]
+#[diesel(table_name = task_assignees)]
+pub struct TaskAssigneeEntity {
+    pub task_id: i32,
+    pub user_id: i32,
+    pub workspace_id: i32
+}
+
+/// Участие пользователя `user_id` в проекте `project_id` с ролью `role`.
+#[derive(Insertable, Selectable, Queryable, Debug)]
+#[diesel(table_name = project_members)]
+pub struct ProjectMemberEntity {
+    pub project_id: i32,
+    pub user_id: i32,
+    pub role: String
+}
+
+/// Ключ API; `scopes` - права через запятую.
+#[derive(Selectable, Queryable, Debug)]
+#[diesel(table_name = api_keys)]
+pub struct ApiKeyEntity {
+    pub id: i32,
+    pub name: String,
+    pub prefix: String,
+    pub scopes: String,
+    pub created_at: NaiveDateTime,
+    pub last_used_at: Option<NaiveDateTime>,
+    pub revoked_at: Option<NaiveDateTime>,
+    pub workspace_id: i32
+}
+
+/// Запись истории задачи; `changes` - JSON-массив изменений полей.
+#[derive(Selectable, Queryable, Debug)]
+#[diesel(table_name = task_events)]
+pub struct TaskEventEntity {
+    pub id: i32,
+    pub task_id: i32,
+    pub action: String,
+    pub actor: String,
+    pub occurred_at: NaiveDateTime,
+    pub changes: String
 }
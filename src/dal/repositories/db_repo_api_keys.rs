@@ -0,0 +1,124 @@
+use std::sync::Arc;
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use diesel::prelude::*;
+use diesel::{insert_into, update};
+use crate::application::repositories::api_key_abstract_repo::ApiKeyRepositoryAbstract;
+use crate::dal::db_connection::DbConnection;
+use crate::dal::db_mapper::ApiKeyDbMapper;
+use crate::dal::models::ApiKeyEntity;
+use crate::dal::schema::api_keys;
+use crate::domain::access::api_key::{ApiKey, ApiKeyScope};
+use crate::domain::error::DomainError;
+use crate::domain::workspace::Workspace;
+
+/// Хранилище ключей API сервисных учётных записей.
+///
+/// Выпуск, список и отзыв ограничены рабочим пространством `workspace`; ключ, предъявленный
+/// в запросе, ищется во всех пространствах и сам определяет пространство запроса.
+pub struct ApiKeyRepository {
+    pub db_connection: DbConnection,
+    pub workspace: Workspace,
+}
+
+#[async_trait]
+impl ApiKeyRepositoryAbstract for ApiKeyRepository {
+    fn in_workspace(&self, workspace: Workspace) -> Arc<dyn ApiKeyRepositoryAbstract> {
+        Arc::new(ApiKeyRepository { db_connection: self.db_connection.clone(), workspace })
+    }
+    /// Получает все ключи, включая отозванные, в порядке выпуска.
+    ///
+    /// # Возвращает
+    /// - `Ok(Vec<ApiKey>)` если ключи успешно получены.
+    /// - `Err(DomainError)` если произошла ошибка при выполнении запроса.
+    async fn get_api_keys(&self) -> Result<Vec<ApiKey>, DomainError> {
+        let workspace = self.workspace;
+        self.db_connection.run(move |conn| {
+            let models = api_keys::table
+                .filter(api_keys::workspace_id.eq(workspace.id))
+                .order(api_keys::id.asc())
+                .select(ApiKeyEntity::as_select())
+                .load(conn)?;
+
+            models.into_iter().map(ApiKeyDbMapper::to_entity).collect::<Result<Vec<ApiKey>, DomainError>>()
+        }).await
+    }
+    /// Сохраняет новый ключ.
+    ///
+    /// # Аргументы
+    /// - `name` - Название сервисной учётной записи.
+    /// - `prefix` - Начало ключа для отображения в списке.
+    /// - `key_hash` - SHA-256 ключа.
+    /// - `scopes` - Права ключа.
+    ///
+    /// # Возвращает
+    /// - `Ok(ApiKey)` если ключ успешно сохранён.
+    /// - `Err(DomainError)` если произошла ошибка при выполнении запроса.
+    async fn create_api_key(&self, name: String, prefix: String, key_hash: String, scopes: Vec<ApiKeyScope>) -> Result<ApiKey, DomainError> {
+        let workspace = self.workspace;
+        self.db_connection.run(move |conn| {
+            let model = insert_into(api_keys::table)
+                .values((
+                    api_keys::name.eq(name),
+                    api_keys::prefix.eq(prefix),
+                    api_keys::key_hash.eq(key_hash),
+                    api_keys::scopes.eq(ApiKeyDbMapper::scopes_to_db(&scopes)),
+                    api_keys::workspace_id.eq(workspace.id),
+                ))
+                .returning(ApiKeyEntity::as_returning())
+                .get_result(conn)?;
+
+            ApiKeyDbMapper::to_entity(model)
+        }).await
+    }
+    /// Отзывает ключ.
+    ///
+    /// # Аргументы
+    /// - `key_id` - Идентификатор ключа.
+    /// - `revoked_at` - Момент отзыва; у уже отозванного ключа не меняется.
+    ///
+    /// # Возвращает
+    /// - `Ok(ApiKey)` ключ после отзыва.
+    /// - `Err(DomainError::NotFound)` если ключ не найден.
+    /// - `Err(DomainError)` если произошла ошибка при выполнении запроса.
+    async fn revoke_api_key(&self, key_id: i32, revoked_at: DateTime<Utc>) -> Result<ApiKey, DomainError> {
+        let workspace = self.workspace;
+        self.db_connection.run(move |conn| {
+            conn.immediate_transaction(|conn| {
+                let workspace_keys = api_keys::table.filter(api_keys::workspace_id.eq(workspace.id));
+                update(workspace_keys.find(key_id).filter(api_keys::revoked_at.is_null()))
+                    .set(api_keys::revoked_at.eq(revoked_at.naive_utc()))
+                    .execute(conn)?;
+
+                workspace_keys.find(key_id)
+                    .select(ApiKeyEntity::as_select())
+                    .first(conn)
+                    .optional()?
+                    .map(ApiKeyDbMapper::to_entity)
+                    .transpose()?
+                    .ok_or_else(|| ApiKey::not_found(key_id))
+            })
+        }).await
+    }
+    /// Находит действующий ключ по хешу и записывает время его использования.
+    ///
+    /// # Аргументы
+    /// - `key_hash` - SHA-256 предъявленного ключа.
+    /// - `used_at` - Момент использования.
+    ///
+    /// # Возвращает
+    /// - `Ok(Some(ApiKey))` если ключ существует и не отозван.
+    /// - `Ok(None)` если ключ неизвестен или отозван.
+    /// - `Err(DomainError)` если произошла ошибка при выполнении запроса.
+    async fn use_api_key(&self, key_hash: String, used_at: DateTime<Utc>) -> Result<Option<ApiKey>, DomainError> {
+        self.db_connection.run(move |conn| {
+            let model = update(api_keys::table.filter(api_keys::key_hash.eq(key_hash)).filter(api_keys::revoked_at.is_null()))
+                .set(api_keys::last_used_at.eq(used_at.naive_utc()))
+                .returning(ApiKeyEntity::as_returning())
+                .get_result(conn)
+                .optional()?;
+
+            model.map(ApiKeyDbMapper::to_entity).transpose()
+        }).await
+    }
+}
@@ -0,0 +1,173 @@
+use std::sync::Arc;
+use async_trait::async_trait;
+use diesel::prelude::*;
+use diesel::sql_query;
+use diesel::sql_types::Integer;
+use crate::application::mappers::db_mapper::DbMapper;
+use crate::application::repositories::dependency_abstract_repo::DependencyRepositoryAbstract;
+use crate::dal::db_connection::DbConnection;
+use crate::dal::db_mapper::TaskDbMapper;
+use crate::dal::models::{TaskDependencyEntity, TaskEntity};
+use crate::dal::repositories::db_repo_tasks::ensure_task_exists;
+use crate::dal::schema::{task_dependencies, tasks};
+use crate::domain::error::DomainError;
+use crate::domain::task::task::Task;
+use crate::domain::task::task_dependency::{check_new_dependency, TaskDependency};
+use crate::domain::workspace::Workspace;
+
+/// Хранилище зависимостей между задачами рабочего пространства `workspace`.
+pub struct DependencyRepository {
+    pub db_connection: DbConnection,
+    pub workspace: Workspace,
+}
+
+#[async_trait]
+impl DependencyRepositoryAbstract for DependencyRepository {
+    fn in_workspace(&self, workspace: Workspace) -> Arc<dyn DependencyRepositoryAbstract> {
+        Arc::new(DependencyRepository { db_connection: self.db_connection.clone(), workspace })
+    }
+    /// Получает задачи, блокирующие задачу `task_id`, упорядоченные по идентификатору.
+    ///
+    /// # Аргументы
+    /// - `task_id` - Идентификатор блокируемой задачи.
+    /// - `transitive` - `true` - все блокирующие задачи, включая блокирующие их задачи, `false` - только прямые.
+    ///
+    /// # Возвращает
+    /// - `Ok(Vec<Task>)` если задачи успешно получены.
+    /// - `Err(DomainError::NotFound)` если задача не найдена.
+    /// - `Err(DomainError)` если произошла ошибка при выполнении запроса.
+    async fn get_blockers(&self, task_id: i32, transitive: bool) -> Result<Vec<Task>, DomainError> {
+        let workspace = self.workspace;
+        self.db_connection.run(move |conn| {
+            ensure_task_exists(conn, workspace, task_id)?;
+            let models = match transitive {
+                true => transitive_blockers(conn, workspace, task_id)?,
+                false => tasks::table
+                    .filter(tasks::workspace_id.eq(workspace.id))
+                    .filter(tasks::id.eq_any(
+                        task_dependencies::table.filter(task_dependencies::task_id.eq(task_id)).select(task_dependencies::blocker_id),
+                    ))
+                    .order(tasks::id.asc())
+                    .load::<TaskEntity>(conn)?,
+            };
+
+            models.into_iter().map(TaskDbMapper::to_entity).collect::<Result<Vec<Task>, DomainError>>()
+        }).await
+    }
+    /// Получает идентификаторы всех задач, прямо или транзитивно блокирующих задачу `task_id`.
+    ///
+    /// # Аргументы
+    /// - `task_id` - Идентификатор блокируемой задачи.
+    ///
+    /// # Возвращает
+    /// - `Ok(Vec<i32>)` идентификаторы в порядке возрастания.
+    /// - `Err(DomainError::NotFound)` если задача не найдена.
+    /// - `Err(DomainError)` если произошла ошибка при выполнении запроса.
+    async fn get_blocker_ids(&self, task_id: i32) -> Result<Vec<i32>, DomainError> {
+        let workspace = self.workspace;
+        self.db_connection.run(move |conn| {
+            ensure_task_exists(conn, workspace, task_id)?;
+            Ok(transitive_blockers(conn, workspace, task_id)?.into_iter().map(|model| model.id).collect())
+        }).await
+    }
+    /// Делает задачу `blocker_id` блокирующей для задачи `task_id`. Повторное добавление ничего не меняет.
+    ///
+    /// Отсутствие цикла перепроверяется внутри транзакции, поэтому параллельно добавляемые
+    /// зависимости не могут его образовать.
+    ///
+    /// # Аргументы
+    /// - `task_id` - Идентификатор блокируемой задачи.
+    /// - `blocker_id` - Идентификатор блокирующей задачи.
+    ///
+    /// # Возвращает
+    /// - `Ok(())` если зависимость существует.
+    /// - `Err(DomainError::NotFound)` если одна из задач не найдена.
+    /// - `Err(DomainError::Conflict)` если зависимость образовала бы цикл.
+    /// - `Err(DomainError)` если произошла ошибка при выполнении запроса.
+    async fn add_dependency(&self, task_id: i32, blocker_id: i32) -> Result<(), DomainError> {
+        let workspace = self.workspace;
+        self.db_connection.run(move |conn| {
+            conn.immediate_transaction(|conn| {
+                ensure_task_exists(conn, workspace, task_id)?;
+                ensure_task_exists(conn, workspace, blocker_id)?;
+                let blocker_blockers = transitive_blockers(conn, workspace, blocker_id)?.into_iter().map(|model| model.id).collect::<Vec<_>>();
+                check_new_dependency(task_id, blocker_id, &blocker_blockers)?;
+                diesel::insert_or_ignore_into(task_dependencies::table)
+                    .values(TaskDependencyEntity { task_id, blocker_id, workspace_id: workspace.id })
+                    .execute(conn)?;
+                Ok(())
+            })
+        }).await
+    }
+    /// Удаляет зависимость задачи `task_id` от задачи `blocker_id`.
+    ///
+    /// # Возвращает
+    /// - `Ok(())` если зависимость удалена.
+    /// - `Err(DomainError::NotFound)` если одна из задач не найдена или зависимости нет.
+    /// - `Err(DomainError)` если произошла ошибка при выполнении запроса.
+    async fn remove_dependency(&self, task_id: i32, blocker_id: i32) -> Result<(), DomainError> {
+        let workspace = self.workspace;
+        self.db_connection.run(move |conn| {
+            conn.immediate_transaction(|conn| {
+                ensure_task_exists(conn, workspace, task_id)?;
+                ensure_task_exists(conn, workspace, blocker_id)?;
+                let removed = diesel::delete(task_dependencies::table.find((task_id, blocker_id))).execute(conn)?;
+                match removed {
+                    0 => Err(DomainError::NotFound(format!("Task {} is not blocked by task {}", task_id, blocker_id))),
+                    _ => Ok(()),
+                }
+            })
+        }).await
+    }
+    /// Получает задачи проекта и зависимости, оба конца которых принадлежат проекту.
+    ///
+    /// # Аргументы
+    /// - `project_id` - Идентификатор проекта.
+    ///
+    /// # Возвращает
+    /// - `Ok((Vec<Task>, Vec<TaskDependency>))` задачи и зависимости.
+    /// - `Err(DomainError)` если произошла ошибка при выполнении запроса.
+    async fn get_project_dependency_graph(&self, project_id: i32) -> Result<(Vec<Task>, Vec<TaskDependency>), DomainError> {
+        let workspace = self.workspace;
+        self.db_connection.run(move |conn| {
+            conn.transaction(|conn| {
+                let project_tasks = tasks::table
+                    .filter(tasks::workspace_id.eq(workspace.id))
+                    .filter(tasks::deleted_at.is_null())
+                    .filter(tasks::project_id.eq(project_id));
+                let project_task_ids = project_tasks.select(tasks::id);
+                let models = project_tasks
+                    .order(tasks::id.asc())
+                    .load::<TaskEntity>(conn)?;
+                let dependencies = task_dependencies::table
+                    .filter(task_dependencies::task_id.eq_any(project_task_ids))
+                    .filter(task_dependencies::blocker_id.eq_any(project_task_ids))
+                    .load::<TaskDependencyEntity>(conn)?;
+
+                Ok((
+                    models.into_iter().map(TaskDbMapper::to_entity).collect::<Result<_, _>>()?,
+                    dependencies.into_iter().map(|model| TaskDependency { task_id: model.task_id, blocker_id: model.blocker_id }).collect(),
+                ))
+            })
+        }).await
+    }
+}
+
+/// Загружает все задачи пространства `workspace`, прямо или транзитивно блокирующие задачу `task_id`,
+/// одним рекурсивным запросом.
+fn transitive_blockers(conn: &mut SqliteConnection, workspace: Workspace, task_id: i32) -> Result<Vec<TaskEntity>, DomainError> {
+    // UNION, а не UNION ALL: общая блокирующая задача нескольких веток обходится один раз.
+    let models = sql_query(
+        "WITH RECURSIVE blockers(id) AS ( \
+             SELECT blocker_id FROM task_dependencies WHERE task_id = ? \
+             UNION \
+             SELECT task_dependencies.blocker_id FROM task_dependencies JOIN blockers ON task_dependencies.task_id = blockers.id \
+         ) \
+         SELECT tasks.* FROM tasks WHERE tasks.id IN (SELECT id FROM blockers) AND tasks.workspace_id = ? ORDER BY tasks.id",
+    )
+        .bind::<Integer, _>(task_id)
+        .bind::<Integer, _>(workspace.id)
+        .load::<TaskEntity>(conn)?;
+
+    Ok(models)
+}
@@ -0,0 +1,125 @@
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+use async_trait::async_trait;
+use diesel::prelude::*;
+use diesel::{delete, insert_into, update};
+use crate::application::repositories::idempotency_abstract_repo::IdempotencyRepositoryAbstract;
+use crate::dal::db_connection::DbConnection;
+use crate::dal::db_mapper::IdempotencyDbMapper;
+use crate::dal::models::IdempotencyKeyEntity;
+use crate::dal::schema::idempotency_keys::dsl::*;
+use crate::domain::error::DomainError;
+use crate::domain::idempotency::{IdempotencyReservation, StoredResponse};
+
+/// Хранилище ключей идемпотентности и ответов на запросы, выполненные с ними.
+pub struct IdempotencyRepository {
+    pub db_connection: DbConnection,
+}
+
+#[async_trait]
+impl IdempotencyRepositoryAbstract for IdempotencyRepository {
+    /// Занимает ключ за запросом, если он не использовался, срок его хранения истёк или занявший его
+    /// запрос не сохранил ответ дольше `lease` (например, процесс завершился во время его выполнения).
+    ///
+    /// Заодно удаляет все ключи с истёкшим сроком хранения. Проверка и вставка выполняются
+    /// в одной транзакции с блокировкой записи, поэтому ключ не может быть занят дважды.
+    ///
+    /// # Аргументы
+    /// - `key` - Ключ идемпотентности из заголовка `Idempotency-Key`.
+    /// - `request_fingerprint` - Отпечаток запроса.
+    /// - `ttl` - Срок хранения ключа и ответа.
+    /// - `lease` - Срок, на который ключ занимается за выполняющимся запросом.
+    ///
+    /// # Возвращает
+    /// - `Ok(IdempotencyReservation::Reserved)` с новым идентификатором резервирования, если ключ занят за этим запросом.
+    /// - `Ok(IdempotencyReservation::Used)` если ключ уже использован.
+    /// - `Err(DomainError)` если произошла ошибка при выполнении запроса.
+    async fn reserve(&self, key: String, request_fingerprint: String, ttl: Duration, lease: Duration) -> Result<IdempotencyReservation, DomainError> {
+        let new_reservation_id = generate_reservation_id()?;
+        self.db_connection.run(move |conn| {
+            conn.immediate_transaction(|conn| {
+                let now = unix_now();
+                delete(idempotency_keys.filter(expires_at.le(now))).execute(conn)?;
+
+                let existing = idempotency_keys.filter(idempotency_key.eq(&key))
+                    .select(IdempotencyKeyEntity::as_select())
+                    .first::<IdempotencyKeyEntity>(conn)
+                    .optional()?;
+                match existing {
+                    Some(existing) if existing.status_code.is_none() && existing.created_at.saturating_add(lease.as_secs() as i64) <= now => {
+                        update(idempotency_keys.filter(idempotency_key.eq(&key)))
+                            .set((
+                                fingerprint.eq(request_fingerprint),
+                                created_at.eq(now),
+                                expires_at.eq(now.saturating_add(ttl.as_secs() as i64)),
+                                reservation_id.eq(&new_reservation_id),
+                            ))
+                            .execute(conn)?;
+                        return Ok(IdempotencyReservation::Reserved(new_reservation_id));
+                    },
+                    Some(existing) => return Ok(IdempotencyReservation::Used(IdempotencyDbMapper::to_entity(existing)?)),
+                    None => {},
+                }
+
+                insert_into(idempotency_keys)
+                    .values(IdempotencyKeyEntity {
+                        idempotency_key: key,
+                        fingerprint: request_fingerprint,
+                        status_code: None,
+                        headers: None,
+                        body: None,
+                        created_at: now,
+                        expires_at: now.saturating_add(ttl.as_secs() as i64),
+                        reservation_id: new_reservation_id.clone(),
+                    })
+                    .execute(conn)?;
+                Ok(IdempotencyReservation::Reserved(new_reservation_id))
+            })
+        }).await
+    }
+    /// Сохраняет ответ на запрос, занявший ключ.
+    ///
+    /// # Аргументы
+    /// - `key` - Ключ идемпотентности.
+    /// - `owner` - Идентификатор резервирования, полученный от `reserve`.
+    /// - `response` - Ответ, который будет выдаваться на повторы запроса.
+    ///
+    /// # Возвращает
+    /// - `Ok(true)` если ответ сохранён.
+    /// - `Ok(false)` если аренда истекла и ключ перехватил другой запрос; его резервирование не изменяется.
+    /// - `Err(DomainError)` если произошла ошибка при выполнении запроса.
+    async fn complete(&self, key: String, owner: String, response: StoredResponse) -> Result<bool, DomainError> {
+        self.db_connection.run(move |conn| {
+            let updated = update(idempotency_keys.filter(idempotency_key.eq(key)).filter(reservation_id.eq(owner)))
+                .set(IdempotencyDbMapper::to_db(response))
+                .execute(conn)?;
+            Ok(updated > 0)
+        }).await
+    }
+    /// Удаляет ключ, например, если запрос завершился ошибкой сервера и его можно повторить.
+    ///
+    /// # Аргументы
+    /// - `key` - Ключ идемпотентности.
+    /// - `owner` - Идентификатор резервирования, полученный от `reserve`.
+    ///
+    /// # Возвращает
+    /// - `Ok(())` если ключ удалён, не существовал или перехвачен другим запросом (тогда он остаётся занятым).
+    /// - `Err(DomainError)` если произошла ошибка при выполнении запроса.
+    async fn release(&self, key: String, owner: String) -> Result<(), DomainError> {
+        self.db_connection.run(move |conn| {
+            delete(idempotency_keys.filter(idempotency_key.eq(key)).filter(reservation_id.eq(owner))).execute(conn)?;
+            Ok(())
+        }).await
+    }
+}
+
+/// Случайный идентификатор резервирования из 16 байт операционной системы в шестнадцатеричном виде.
+fn generate_reservation_id() -> Result<String, DomainError> {
+    let mut bytes = [0u8; 16];
+    getrandom::getrandom(&mut bytes).map_err(|e| DomainError::Internal(e.to_string()))?;
+    Ok(bytes.iter().map(|byte| format!("{:02x}", byte)).collect())
+}
+
+/// Текущее время в секундах от начала эпохи Unix.
+fn unix_now() -> i64 {
+    SystemTime::now().duration_since(UNIX_EPOCH).map(|now| now.as_secs() as i64).unwrap_or_default()
+}
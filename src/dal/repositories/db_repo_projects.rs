@@ -0,0 +1,317 @@
+use std::sync::Arc;
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use diesel::prelude::*;
+use diesel::{delete, insert_into, update};
+use crate::application::mappers::db_mapper::DbMapper;
+use crate::application::repositories::project_abstract_repo::ProjectRepositoryAbstract;
+use crate::dal::db_connection::DbConnection;
+use crate::dal::db_mapper::{ProjectDbMapper, ProjectMemberDbMapper, TaskDbMapper};
+use crate::dal::models::{ProjectEntity, ProjectMemberEntity, TaskEntity};
+use crate::dal::repositories::db_repo_tasks::{record_event, trash_tasks, WriteContext};
+use crate::dal::repositories::db_repo_users::find_user;
+use crate::dal::schema::{project_members, projects, tasks};
+use crate::domain::access::role::Role;
+use crate::domain::error::DomainError;
+use crate::domain::project::project::{Project, ProjectTasksPolicy};
+use crate::domain::project::project_member::ProjectMember;
+use crate::domain::task::task::Task;
+use crate::domain::task::task_event::{EventActor, TaskEventAction};
+use crate::domain::workspace::Workspace;
+
+/// Хранилище проектов рабочего пространства `workspace`.
+///
+/// Изменения задач при удалении проекта записываются в их историю от имени `actor`.
+pub struct ProjectRepository {
+    pub db_connection: DbConnection,
+    pub workspace: Workspace,
+    pub actor: EventActor,
+}
+
+impl ProjectRepository {
+    fn write_context(&self) -> WriteContext {
+        WriteContext { workspace: self.workspace, actor: self.actor.clone() }
+    }
+}
+
+#[async_trait]
+impl ProjectRepositoryAbstract for ProjectRepository {
+    fn in_workspace(&self, workspace: Workspace) -> Arc<dyn ProjectRepositoryAbstract> {
+        Arc::new(ProjectRepository { db_connection: self.db_connection.clone(), workspace, actor: self.actor.clone() })
+    }
+    fn on_behalf_of(&self, actor: EventActor) -> Arc<dyn ProjectRepositoryAbstract> {
+        Arc::new(ProjectRepository { db_connection: self.db_connection.clone(), workspace: self.workspace, actor })
+    }
+    /// Получает проекты, упорядоченные по имени.
+    ///
+    /// # Аргументы
+    /// - `include_archived` - Включать ли проекты из архива.
+    ///
+    /// # Возвращает
+    /// - `Ok(Vec<Project>)` если проекты успешно получены.
+    /// - `Err(DomainError)` если произошла ошибка при выполнении запроса.
+    async fn get_projects(&self, include_archived: bool) -> Result<Vec<Project>, DomainError> {
+        let workspace = self.workspace;
+        self.db_connection.run(move |conn| {
+            let mut query = projects::table.filter(projects::workspace_id.eq(workspace.id)).into_boxed();
+            if !include_archived {
+                query = query.filter(projects::archived_at.is_null());
+            }
+            let models = query
+                .order((projects::name.asc(), projects::id.asc()))
+                .load::<ProjectEntity>(conn)?;
+
+            models.into_iter().map(ProjectDbMapper::to_entity).collect::<Result<Vec<Project>, DomainError>>()
+        }).await
+    }
+    /// Получает проект по его идентификатору.
+    ///
+    /// # Аргументы
+    /// - `project_id` - Идентификатор проекта.
+    ///
+    /// # Возвращает
+    /// - `Ok(Project)` если проект найден.
+    /// - `Err(DomainError::NotFound)` если проект не найден.
+    /// - `Err(DomainError)` если произошла ошибка при выполнении запроса.
+    async fn get_project_by_id(&self, project_id: i32) -> Result<Project, DomainError> {
+        let workspace = self.workspace;
+        self.db_connection.run(move |conn| find_project(conn, workspace, project_id)).await
+    }
+    /// Создаёт новый проект.
+    ///
+    /// # Аргументы
+    /// - `name` - Название проекта.
+    /// - `description` - Описание проекта (необязательно).
+    ///
+    /// # Возвращает
+    /// - `Ok(Project)` если проект успешно создан.
+    /// - `Err(DomainError)` если произошла ошибка при выполнении запроса.
+    async fn create_project(&self, name: String, description: Option<String>) -> Result<Project, DomainError> {
+        let workspace = self.workspace;
+        self.db_connection.run(move |conn| {
+            let model = insert_into(projects::table)
+                .values((projects::name.eq(name), projects::description.eq(description), projects::workspace_id.eq(workspace.id)))
+                .returning(ProjectEntity::as_returning())
+                .get_result(conn)?;
+
+            ProjectDbMapper::to_entity(model)
+        }).await
+    }
+    /// Изменяет название и описание проекта.
+    ///
+    /// # Аргументы
+    /// - `project_id` - Идентификатор проекта.
+    /// - `name` - Новое название проекта.
+    /// - `description` - Новое описание проекта; `None` очищает описание.
+    ///
+    /// # Возвращает
+    /// - `Ok(Project)` если проект успешно обновлён.
+    /// - `Err(DomainError::NotFound)` если проект не найден.
+    /// - `Err(DomainError)` если произошла ошибка при выполнении запроса.
+    async fn update_project(&self, project_id: i32, name: String, description: Option<String>) -> Result<Project, DomainError> {
+        let workspace = self.workspace;
+        self.db_connection.run(move |conn| {
+            let model = update(projects::table.find(project_id).filter(projects::workspace_id.eq(workspace.id)))
+                .set((projects::name.eq(name), projects::description.eq(description)))
+                .returning(ProjectEntity::as_returning())
+                .get_result(conn)
+                .optional()?;
+
+            model.map(ProjectDbMapper::to_entity).transpose()?.ok_or_else(|| Project::not_found(project_id))
+        }).await
+    }
+    /// Отправляет проект в архив или возвращает его из архива.
+    ///
+    /// Повторная отправка в архив сохраняет момент первой архивации.
+    ///
+    /// # Аргументы
+    /// - `project_id` - Идентификатор проекта.
+    /// - `archived_at` - Момент архивации; `None` возвращает проект из архива.
+    ///
+    /// # Возвращает
+    /// - `Ok(Project)` проект после изменения.
+    /// - `Err(DomainError::NotFound)` если проект не найден.
+    /// - `Err(DomainError)` если произошла ошибка при выполнении запроса.
+    async fn set_project_archived(&self, project_id: i32, archived_at: Option<DateTime<Utc>>) -> Result<Project, DomainError> {
+        let workspace = self.workspace;
+        self.db_connection.run(move |conn| {
+            conn.immediate_transaction(|conn| {
+                let project = find_project(conn, workspace, project_id)?;
+                if project.is_archived() == archived_at.is_some() {
+                    return Ok(project);
+                }
+                let model = update(projects::table.find(project_id))
+                    .set(projects::archived_at.eq(archived_at.map(|archived_at| archived_at.naive_utc())))
+                    .returning(ProjectEntity::as_returning())
+                    .get_result(conn)?;
+
+                ProjectDbMapper::to_entity(model)
+            })
+        }).await
+    }
+    /// Удаляет проект, поступая с его задачами согласно `policy`.
+    ///
+    /// # Аргументы
+    /// - `project_id` - Идентификатор проекта.
+    /// - `policy` - `Restrict` - не удалять проект с задачами, `Cascade` - переместить задачи в корзину,
+    ///   `Detach` - оставить задачи без проекта (их версия увеличивается). Задачи корзины не считаются задачами
+    ///   проекта для `Restrict`; при любой политике задачи корзины, в том числе перемещённые туда `Cascade`,
+    ///   отсоединяются от проекта и восстанавливаются без него. Изменение каждой задачи записывается в её историю.
+    ///
+    /// # Возвращает
+    /// - `Ok(())` если проект удалён.
+    /// - `Err(DomainError::NotFound)` если проект не найден.
+    /// - `Err(DomainError::Conflict)` если у проекта есть задачи, а `policy` равна `Restrict`.
+    /// - `Err(DomainError)` если произошла ошибка при выполнении запроса.
+    async fn delete_project(&self, project_id: i32, policy: ProjectTasksPolicy) -> Result<(), DomainError> {
+        let ctx = self.write_context();
+        self.db_connection.run(move |conn| {
+            conn.immediate_transaction(|conn| {
+                find_project(conn, ctx.workspace, project_id)?;
+                let project_tasks = tasks::table.filter(tasks::project_id.eq(project_id));
+                match policy {
+                    ProjectTasksPolicy::Restrict => {
+                        let count = project_tasks.filter(tasks::deleted_at.is_null()).count().get_result::<i64>(conn)?;
+                        if count > 0 {
+                            return Err(DomainError::Conflict(format!(
+                                "Project {} still has {} tasks; delete them first or choose on_tasks=cascade or on_tasks=detach",
+                                project_id, count,
+                            )));
+                        }
+                        // Задачи корзины не мешают удалению проекта и восстанавливаются без проекта.
+                        detach_project_tasks(conn, &ctx, project_id)?;
+                    },
+                    ProjectTasksPolicy::Cascade => {
+                        let live = project_tasks.filter(tasks::deleted_at.is_null())
+                            .select(tasks::id)
+                            .load::<i32>(conn)?;
+                        trash_tasks(conn, &ctx, live)?;
+                        detach_project_tasks(conn, &ctx, project_id)?;
+                    },
+                    ProjectTasksPolicy::Detach => {
+                        detach_project_tasks(conn, &ctx, project_id)?;
+                    },
+                }
+                delete(projects::table.find(project_id)).execute(conn)?;
+                Ok(())
+            })
+        }).await
+    }
+    /// Получает участников проекта, упорядоченных по идентификатору пользователя.
+    ///
+    /// # Аргументы
+    /// - `project_id` - Идентификатор проекта.
+    ///
+    /// # Возвращает
+    /// - `Ok(Vec<ProjectMember>)` если участники успешно получены.
+    /// - `Err(DomainError::NotFound)` если проект не найден.
+    /// - `Err(DomainError)` если произошла ошибка при выполнении запроса.
+    async fn get_project_members(&self, project_id: i32) -> Result<Vec<ProjectMember>, DomainError> {
+        let workspace = self.workspace;
+        self.db_connection.run(move |conn| {
+            conn.transaction(|conn| {
+                find_project(conn, workspace, project_id)?;
+                let models = project_members::table
+                    .filter(project_members::project_id.eq(project_id))
+                    .order(project_members::user_id.asc())
+                    .load::<ProjectMemberEntity>(conn)?;
+
+                models.into_iter().map(ProjectMemberDbMapper::to_entity).collect::<Result<Vec<ProjectMember>, DomainError>>()
+            })
+        }).await
+    }
+    /// Добавляет пользователя в проект или меняет роль участника.
+    ///
+    /// # Аргументы
+    /// - `project_id` - Идентификатор проекта.
+    /// - `user_id` - Идентификатор пользователя.
+    /// - `role` - Роль пользователя в задачах проекта.
+    ///
+    /// # Возвращает
+    /// - `Ok(ProjectMember)` участника с новой ролью.
+    /// - `Err(DomainError::NotFound)` если проект или пользователь не найдены.
+    /// - `Err(DomainError)` если произошла ошибка при выполнении запроса.
+    async fn set_project_member(&self, project_id: i32, user_id: i32, role: Role) -> Result<ProjectMember, DomainError> {
+        let workspace = self.workspace;
+        self.db_connection.run(move |conn| {
+            conn.immediate_transaction(|conn| {
+                find_project(conn, workspace, project_id)?;
+                find_user(conn, user_id)?;
+                let member = ProjectMemberDbMapper::to_db(ProjectMember::new(project_id, user_id, role));
+                let model = insert_into(project_members::table)
+                    .values(&member)
+                    .on_conflict((project_members::project_id, project_members::user_id))
+                    .do_update()
+                    .set(project_members::role.eq(&member.role))
+                    .returning(ProjectMemberEntity::as_returning())
+                    .get_result(conn)?;
+
+                ProjectMemberDbMapper::to_entity(model)
+            })
+        }).await
+    }
+    /// Исключает пользователя из проекта; его глобальная роль при этом не меняется.
+    ///
+    /// # Аргументы
+    /// - `project_id` - Идентификатор проекта.
+    /// - `user_id` - Идентификатор пользователя.
+    ///
+    /// # Возвращает
+    /// - `Ok(())` если участник исключён.
+    /// - `Err(DomainError::NotFound)` если проект не найден или пользователь не участвует в нём.
+    /// - `Err(DomainError)` если произошла ошибка при выполнении запроса.
+    async fn remove_project_member(&self, project_id: i32, user_id: i32) -> Result<(), DomainError> {
+        let workspace = self.workspace;
+        self.db_connection.run(move |conn| {
+            conn.immediate_transaction(|conn| {
+                find_project(conn, workspace, project_id)?;
+                let removed = delete(project_members::table.find((project_id, user_id))).execute(conn)?;
+                if removed == 0 {
+                    return Err(ProjectMember::not_member(project_id, user_id));
+                }
+                Ok(())
+            })
+        }).await
+    }
+}
+
+fn find_project(conn: &mut SqliteConnection, workspace: Workspace, project_id: i32) -> Result<Project, DomainError> {
+    projects::table.find(project_id)
+        .filter(projects::workspace_id.eq(workspace.id))
+        .first::<ProjectEntity>(conn)
+        .optional()?
+        .map(ProjectDbMapper::to_entity)
+        .transpose()?
+        .ok_or_else(|| Project::not_found(project_id))
+}
+
+/// Проверяет, что в проект `project_id` можно добавить задачу: он существует в пространстве `workspace`
+/// и не находится в архиве. Вызывается внутри транзакции, записывающей задачу.
+pub(crate) fn ensure_project_accepts_tasks(conn: &mut SqliteConnection, workspace: Workspace, project_id: i32) -> Result<(), DomainError> {
+    match find_project(conn, workspace, project_id)? {
+        project if project.is_archived() => Err(Project::archived(project_id)),
+        _ => Ok(()),
+    }
+}
+
+/// Загружает все задачи проекта `project_id`, включая задачи корзины.
+fn load_project_tasks(conn: &mut SqliteConnection, project_id: i32) -> Result<Vec<Task>, DomainError> {
+    let models = tasks::table.filter(tasks::project_id.eq(project_id))
+        .order(tasks::id.asc())
+        .load::<TaskEntity>(conn)?;
+    models.into_iter().map(TaskDbMapper::to_entity).collect()
+}
+
+/// Оставляет задачи проекта `project_id` без проекта, увеличивает их версию и записывает изменение
+/// в историю каждой задачи. Вызывается внутри транзакции, удаляющей проект.
+fn detach_project_tasks(conn: &mut SqliteConnection, ctx: &WriteContext, project_id: i32) -> Result<(), DomainError> {
+    let detached = load_project_tasks(conn, project_id)?;
+    update(tasks::table.filter(tasks::project_id.eq(project_id)))
+        .set((tasks::project_id.eq(None::<i32>), tasks::version.eq(tasks::version + 1)))
+        .execute(conn)?;
+    for task in detached {
+        let after = Task { project_id: None, version: task.version + 1, ..task.clone() };
+        record_event(conn, ctx, task.id, TaskEventAction::Updated, Some(&task), Some(&after))?;
+    }
+    Ok(())
+}
@@ -0,0 +1,212 @@
+use std::sync::Arc;
+use async_trait::async_trait;
+use diesel::prelude::*;
+use diesel::result::{DatabaseErrorKind, Error as DieselError};
+use diesel::{delete, insert_into, update};
+use crate::application::mappers::db_mapper::DbMapper;
+use crate::application::repositories::tag_abstract_repo::TagRepositoryAbstract;
+use crate::dal::db_connection::DbConnection;
+use crate::dal::db_mapper::TagDbMapper;
+use crate::dal::models::{TagEntity, TaskTagEntity};
+use crate::dal::repositories::db_repo_tasks::ensure_task_exists;
+use crate::dal::schema::{tags, task_tags};
+use crate::domain::error::DomainError;
+use crate::domain::tag::tag::Tag;
+use crate::domain::workspace::Workspace;
+
+/// Хранилище меток рабочего пространства `workspace` и их связей с задачами.
+pub struct TagRepository {
+    pub db_connection: DbConnection,
+    pub workspace: Workspace,
+}
+
+#[async_trait]
+impl TagRepositoryAbstract for TagRepository {
+    fn in_workspace(&self, workspace: Workspace) -> Arc<dyn TagRepositoryAbstract> {
+        Arc::new(TagRepository { db_connection: self.db_connection.clone(), workspace })
+    }
+    /// Получает все метки, упорядоченные по имени.
+    ///
+    /// # Возвращает
+    /// - `Ok(Vec<Tag>)` если метки успешно получены.
+    /// - `Err(DomainError)` если произошла ошибка при выполнении запроса.
+    async fn get_tags(&self) -> Result<Vec<Tag>, DomainError> {
+        let workspace = self.workspace;
+        self.db_connection.run(move |conn| {
+            let models = tags::table
+                .filter(tags::workspace_id.eq(workspace.id))
+                .order((tags::name.asc(), tags::id.asc()))
+                .load::<TagEntity>(conn)?;
+
+            models.into_iter().map(TagDbMapper::to_entity).collect::<Result<Vec<Tag>, DomainError>>()
+        }).await
+    }
+    /// Получает метку по её идентификатору.
+    ///
+    /// # Аргументы
+    /// - `tag_id` - Идентификатор метки.
+    ///
+    /// # Возвращает
+    /// - `Ok(Tag)` если метка найдена.
+    /// - `Err(DomainError::NotFound)` если метка не найдена.
+    /// - `Err(DomainError)` если произошла ошибка при выполнении запроса.
+    async fn get_tag_by_id(&self, tag_id: i32) -> Result<Tag, DomainError> {
+        let workspace = self.workspace;
+        self.db_connection.run(move |conn| find_tag(conn, workspace, tag_id)).await
+    }
+    /// Создаёт новую метку.
+    ///
+    /// # Аргументы
+    /// - `name` - Имя метки.
+    ///
+    /// # Возвращает
+    /// - `Ok(Tag)` если метка успешно создана.
+    /// - `Err(DomainError::Conflict)` если метка с таким именем (без учёта регистра) уже есть в пространстве.
+    /// - `Err(DomainError)` если произошла ошибка при выполнении запроса.
+    async fn create_tag(&self, name: String) -> Result<Tag, DomainError> {
+        let workspace = self.workspace;
+        self.db_connection.run(move |conn| {
+            let model = insert_into(tags::table)
+                .values((tags::name.eq(&name), tags::workspace_id.eq(workspace.id)))
+                .returning(TagEntity::as_returning())
+                .get_result(conn)
+                .map_err(|e| duplicate_name(e, &name))?;
+
+            TagDbMapper::to_entity(model)
+        }).await
+    }
+    /// Переименовывает метку.
+    ///
+    /// # Аргументы
+    /// - `tag_id` - Идентификатор метки.
+    /// - `name` - Новое имя метки.
+    ///
+    /// # Возвращает
+    /// - `Ok(Tag)` если метка успешно переименована.
+    /// - `Err(DomainError::NotFound)` если метка не найдена.
+    /// - `Err(DomainError::Conflict)` если другая метка уже носит это имя.
+    /// - `Err(DomainError)` если произошла ошибка при выполнении запроса.
+    async fn update_tag(&self, tag_id: i32, name: String) -> Result<Tag, DomainError> {
+        let workspace = self.workspace;
+        self.db_connection.run(move |conn| {
+            let model = update(tags::table.find(tag_id).filter(tags::workspace_id.eq(workspace.id)))
+                .set(tags::name.eq(&name))
+                .returning(TagEntity::as_returning())
+                .get_result(conn)
+                .optional()
+                .map_err(|e| duplicate_name(e, &name))?;
+
+            model.map(TagDbMapper::to_entity).transpose()?.ok_or_else(|| Tag::not_found(tag_id))
+        }).await
+    }
+    /// Удаляет метку; её связи с задачами удаляются каскадно.
+    ///
+    /// # Аргументы
+    /// - `tag_id` - Идентификатор метки.
+    ///
+    /// # Возвращает
+    /// - `Ok(())` если метка успешно удалена.
+    /// - `Err(DomainError::NotFound)` если метка не найдена.
+    /// - `Err(DomainError)` если произошла ошибка при выполнении запроса.
+    async fn delete_tag(&self, tag_id: i32) -> Result<(), DomainError> {
+        let workspace = self.workspace;
+        self.db_connection.run(move |conn| {
+            match delete(tags::table.find(tag_id).filter(tags::workspace_id.eq(workspace.id))).execute(conn)? {
+                0 => Err(Tag::not_found(tag_id)),
+                _ => Ok(()),
+            }
+        }).await
+    }
+    /// Получает метки задачи, упорядоченные по имени.
+    ///
+    /// # Аргументы
+    /// - `task_id` - Идентификатор задачи.
+    ///
+    /// # Возвращает
+    /// - `Ok(Vec<Tag>)` если метки успешно получены.
+    /// - `Err(DomainError::NotFound)` если задача не найдена.
+    /// - `Err(DomainError)` если произошла ошибка при выполнении запроса.
+    async fn get_task_tags(&self, task_id: i32) -> Result<Vec<Tag>, DomainError> {
+        let workspace = self.workspace;
+        self.db_connection.run(move |conn| {
+            conn.transaction(|conn| {
+                ensure_task_exists(conn, workspace, task_id)?;
+                let models = task_tags::table
+                    .inner_join(tags::table)
+                    .filter(task_tags::task_id.eq(task_id))
+                    .order((tags::name.asc(), tags::id.asc()))
+                    .select(TagEntity::as_select())
+                    .load::<TagEntity>(conn)?;
+
+                models.into_iter().map(TagDbMapper::to_entity).collect::<Result<Vec<Tag>, DomainError>>()
+            })
+        }).await
+    }
+    /// Отмечает задачу меткой. Если задача уже отмечена, ничего не меняется.
+    ///
+    /// # Аргументы
+    /// - `task_id` - Идентификатор задачи.
+    /// - `tag_id` - Идентификатор метки.
+    ///
+    /// # Возвращает
+    /// - `Ok(())` если задача отмечена меткой.
+    /// - `Err(DomainError::NotFound)` если задача или метка не найдены.
+    /// - `Err(DomainError)` если произошла ошибка при выполнении запроса.
+    async fn attach_tag(&self, task_id: i32, tag_id: i32) -> Result<(), DomainError> {
+        let workspace = self.workspace;
+        self.db_connection.run(move |conn| {
+            conn.immediate_transaction(|conn| {
+                ensure_task_exists(conn, workspace, task_id)?;
+                find_tag(conn, workspace, tag_id)?;
+                diesel::insert_or_ignore_into(task_tags::table)
+                    .values(TaskTagEntity { task_id, tag_id, workspace_id: workspace.id })
+                    .execute(conn)?;
+                Ok(())
+            })
+        }).await
+    }
+    /// Снимает метку с задачи.
+    ///
+    /// # Аргументы
+    /// - `task_id` - Идентификатор задачи.
+    /// - `tag_id` - Идентификатор метки.
+    ///
+    /// # Возвращает
+    /// - `Ok(())` если метка снята.
+    /// - `Err(DomainError::NotFound)` если задача или метка не найдены, либо задача не отмечена этой меткой.
+    /// - `Err(DomainError)` если произошла ошибка при выполнении запроса.
+    async fn detach_tag(&self, task_id: i32, tag_id: i32) -> Result<(), DomainError> {
+        let workspace = self.workspace;
+        self.db_connection.run(move |conn| {
+            conn.immediate_transaction(|conn| {
+                ensure_task_exists(conn, workspace, task_id)?;
+                find_tag(conn, workspace, tag_id)?;
+                let removed = delete(task_tags::table.find((task_id, tag_id))).execute(conn)?;
+                match removed {
+                    0 => Err(DomainError::NotFound(format!("Task {} is not tagged with tag {}", task_id, tag_id))),
+                    _ => Ok(()),
+                }
+            })
+        }).await
+    }
+}
+
+fn find_tag(conn: &mut SqliteConnection, workspace: Workspace, tag_id: i32) -> Result<Tag, DomainError> {
+    tags::table.find(tag_id)
+        .filter(tags::workspace_id.eq(workspace.id))
+        .first::<TagEntity>(conn)
+        .optional()?
+        .map(TagDbMapper::to_entity)
+        .transpose()?
+        .ok_or_else(|| Tag::not_found(tag_id))
+}
+
+/// Переводит нарушение уникальности имени в понятный клиенту конфликт.
+fn duplicate_name(error: DieselError, name: &str) -> DomainError {
+    match error {
+        DieselError::DatabaseError(DatabaseErrorKind::UniqueViolation, _) => {
+            DomainError::Conflict(format!("Tag '{}' already exists", name))
+        },
+        error => DomainError::from(error),
+    }
+}
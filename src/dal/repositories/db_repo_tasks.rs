@@ -1,62 +1,165 @@
+use std::collections::HashMap;
+use std::sync::Arc;
 use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use diesel::dsl::sql;
 use diesel::prelude::*;
-use std::error::Error;
-use diesel::{delete, update};
+use diesel::{delete, sql_query, update};
+use diesel::sql_types::{BigInt, Integer, Text, Timestamp};
+use diesel::sqlite::Sqlite;
 use crate::application::mappers::db_mapper::DbMapper;
 use crate::dal::db_connection::DbConnection;
 use crate::application::repositories::task_abstract_repo::TaskRepositoryAbstract;
-use crate::dal::db_mapper::TaskDbMapper;
-use crate::dal::models::{TaskEntity};
+use crate::dal::db_mapper::{parse_column, TaskDbMapper, TaskEventDbMapper, TaskPatchDbMapper, TaskSearchDbMapper};
+use crate::dal::repositories::db_repo_projects::ensure_project_accepts_tasks;
+use crate::dal::models::{TaskEntity, TaskEventEntity, TaskLinkEntity, TaskSearchEntity};
+use crate::domain::access::actor::AccessScope;
+use crate::domain::access::role::Role;
+use crate::domain::error::DomainError;
 use crate::domain::task::task::Task;
+use crate::domain::task::task_bulk::{BulkMode, BulkOperation, BulkOutcome};
+use crate::domain::task::task_event::{EventActor, TaskEvent, TaskEventAction, TaskEventPage};
+use crate::domain::task::task_trash::TrashPage;
+use crate::domain::task::task_patch::TaskPatch;
+use crate::domain::task::task_placement::TaskPlacement;
+use crate::domain::task::task_priority::TaskPriority;
+use crate::domain::task::task_schedule::TaskSchedule;
+use crate::domain::task::task_query::{CursorDirection, Pagination, SortOrder, TagMatch, TaskCursor, TaskFilter, TaskPage, TaskQuery, TaskSortField};
+use crate::domain::task::task_search::TaskSearchHit;
+use crate::domain::task::task_status::TaskStatus;
+use crate::domain::task::task_tree::check_new_parent;
+use crate::domain::workspace::Workspace;
+use crate::dal::schema::{project_members, tags, task_assignees, task_dependencies, task_events, task_tags, users};
 use crate::dal::schema::tasks::dsl::*;
+
+type TaskBoxedQuery<'a> = crate::dal::schema::tasks::BoxedQuery<'a, Sqlite>;
 /// Структура `TaskRepository` предназначена для взаимодействия с базой данных для управления задачами.
+///
+/// Все запросы ограничены рабочим пространством `workspace`: задачи других пространств для него не существуют.
+/// Каждое создание, изменение и удаление задачи записывается в её историю от имени `actor`
+/// в той же транзакции, что и само изменение.
 pub struct TaskRepository {
     pub db_connection: DbConnection,
+    pub workspace: Workspace,
+    pub actor: EventActor,
 }
 
-#[async_trait(?Send)]
-impl TaskRepositoryAbstract for TaskRepository {
-
+impl TaskRepository {
+    fn write_context(&self) -> WriteContext {
+        WriteContext { workspace: self.workspace, actor: self.actor.clone() }
+    }
+}
 
-    /// Получает все задачи из базы данных.
+#[async_trait]
+impl TaskRepositoryAbstract for TaskRepository {
+    fn in_workspace(&self, workspace: Workspace) -> Arc<dyn TaskRepositoryAbstract> {
+        Arc::new(TaskRepository { db_connection: self.db_connection.clone(), workspace, actor: self.actor.clone() })
+    }
+    fn on_behalf_of(&self, actor: EventActor) -> Arc<dyn TaskRepositoryAbstract> {
+        Arc::new(TaskRepository { db_connection: self.db_connection.clone(), workspace: self.workspace, actor })
+    }
+    /// Получает страницу задач с учётом фильтров, сортировки и пагинации.
+    ///
+    /// Фильтры, сортировка и ограничение выборки выполняются на стороне базы данных.
+    /// Для определения наличия соседних страниц запрашивается на одну запись больше `limit`.
+    ///
+    /// # Аргументы
+    /// - `query` - Параметры выборки.
     ///
     /// # Возвращает
-    /// - `Ok(Vec<Task>)` если задачи успешно получены.
-    /// - `Err(Box<dyn Error>)` если произошла ошибка при выполнении запроса.
-    async fn get_all_tasks(&self) -> Result<Vec<Task>, Box<dyn Error>> {
-        let mut conn = self.db_connection.get_pool().get().expect("couldn't get db connection from pool");
+    /// - `Ok(TaskPage)` если страница успешно получена.
+    /// - `Err(DomainError)` если произошла ошибка при выполнении запроса.
+    async fn get_tasks(&self, query: TaskQuery) -> Result<TaskPage, DomainError> {
+        let workspace = self.workspace;
+        self.db_connection.run(move |conn| {
+            let total = filtered_tasks(workspace, &query.filter).count().get_result::<i64>(conn)?;
 
-        let results = tasks.load::<TaskEntity>(&mut conn);
+            let (ascending, page_query) = match &query.pagination {
+                Pagination::Offset(offset) => {
+                    let ascending = query.order == SortOrder::Asc;
+                    (ascending, filtered_tasks(workspace, &query.filter).offset(*offset))
+                },
+                Pagination::Cursor(cursor) => {
+                    let ascending = (query.order == SortOrder::Asc) == (cursor.direction == CursorDirection::Next);
+                    (ascending, after_cursor(filtered_tasks(workspace, &query.filter), cursor, ascending))
+                },
+            };
 
-        match results {
-            Ok(models) => Ok(models.into_iter().map(TaskDbMapper::to_entity).collect::<Vec<Task>>()),
-        Err(e) => Err(Box::new(e)),
-        }
+            let mut models = ordered(page_query, query.sort_by, ascending)
+                .limit(query.limit + 1)
+                .load::<TaskEntity>(conn)?;
+
+            let has_more = models.len() as i64 > query.limit;
+            models.truncate(query.limit as usize);
+
+            let (has_prev, has_next) = match &query.pagination {
+                Pagination::Offset(offset) => (*offset > 0, has_more),
+                Pagination::Cursor(cursor) if cursor.direction == CursorDirection::Prev => {
+                    models.reverse();
+                    (has_more, true)
+                },
+                Pagination::Cursor(_) => (true, has_more),
+            };
+
+            let items = models.into_iter().map(TaskDbMapper::to_entity).collect::<Result<Vec<Task>, DomainError>>()?;
+            Ok(TaskPage::new(items, total, &query, has_prev, has_next))
+        }).await
     }
-    /// Удаляет задачу по её идентификатору.
+    /// Перемещает задачу в корзину; задача перестаёт быть видна, пока её не восстановят.
     ///
     /// # Аргументы
     /// - `task_id` - Идентификатор задачи, которую нужно удалить.
+    /// - `expected_version` - Версия, которую ожидает клиент; `None` - удалить без проверки версии.
     ///
     /// # Возвращает
-    /// - `Ok(())` если задача успешно удалена.
-    /// - `Err(Box<dyn Error>)` если задача не найдена или произошла ошибка при выполнении запроса.
-    async fn delete_task(&self, task_id: i32) -> Result<(), Box<dyn Error>> {
-        let mut conn = self.db_connection.get_pool().get().map_err(|e| Box::new(e) as Box<dyn Error>)?;
-
-        let task = tasks.filter(id.eq(task_id)).first::<TaskEntity>(&mut conn).optional();
-
-        match task {
-            Ok(Some(_)) => {
-                let result = delete(tasks.filter(id.eq(task_id))).execute(&mut conn);
-                match result {
-                    Ok(_) => Ok(()),
-                    Err(e) => Err(Box::new(e)),
+    /// - `Ok(())` если задача перемещена в корзину.
+    /// - `Err(DomainError::NotFound)` если задача не найдена.
+    /// - `Err(DomainError::PreconditionFailed)` если версия задачи не совпала с ожидаемой.
+    /// - `Err(DomainError)` если произошла ошибка при выполнении запроса.
+    async fn delete_task(&self, task_id: i32, expected_version: Option<i32>) -> Result<(), DomainError> {
+        let ctx = self.write_context();
+        self.db_connection.run(move |conn| {
+            conn.immediate_transaction(|conn| remove_task(conn, &ctx, task_id, expected_version))
+        }).await
+    }
+    /// Возвращает задачу из корзины и увеличивает её версию.
+    ///
+    /// Задача возвращается в прежнее место дерева, если её родитель не удалён, иначе становится корнем.
+    ///
+    /// # Аргументы
+    /// - `task_id` - Идентификатор задачи в корзине.
+    /// - `expected_version` - Версия, которую ожидает клиент; `None` - без проверки версии.
+    ///
+    /// # Возвращает
+    /// - `Ok(Task)` восстановленную задачу.
+    /// - `Err(DomainError::NotFound)` если задачи нет в корзине.
+    /// - `Err(DomainError::Conflict)` если проект задачи в архиве.
+    /// - `Err(DomainError::PreconditionFailed)` если версия задачи не совпала с ожидаемой.
+    /// - `Err(DomainError)` если произошла ошибка при выполнении запроса.
+    async fn restore_task(&self, task_id: i32, expected_version: Option<i32>) -> Result<Task, DomainError> {
+        let ctx = self.write_context();
+        self.db_connection.run(move |conn| {
+            conn.immediate_transaction(|conn| {
+                let workspace = ctx.workspace;
+                let current = find_trashed_task(conn, workspace, task_id)?;
+                current.ensure_version(expected_version)?;
+                if let Some(current_project_id) = current.project_id {
+                    ensure_project_accepts_tasks(conn, workspace, current_project_id)?;
                 }
-            },
-            Ok(None) => Err(Box::new(diesel::result::Error::NotFound)),
-            Err(e) => Err(Box::new(e)),
-        }
+                let restored_parent_id = match current.parent_id {
+                    Some(current_parent_id) if ensure_task_exists(conn, workspace, current_parent_id).is_ok() => Some(current_parent_id),
+                    _ => None,
+                };
+
+                let restored = update(tasks.filter(workspace_id.eq(workspace.id)).filter(id.eq(task_id)))
+                    .set((deleted_at.eq(None::<chrono::NaiveDateTime>), parent_id.eq(restored_parent_id), version.eq(version + 1)))
+                    .returning(TaskEntity::as_returning())
+                    .get_result::<TaskEntity>(conn)
+                    .map(TaskDbMapper::to_entity)??;
+                record_event(conn, &ctx, task_id, TaskEventAction::Restored, Some(&current), Some(&restored))?;
+                Ok(restored)
+            })
+        }).await
     }
     /// Получает задачу по её идентификатору.
     ///
@@ -65,44 +168,292 @@ impl TaskRepositoryAbstract for TaskRepository {
     ///
     /// # Возвращает
     /// - `Ok(Task)` если задача успешно найдена.
-    /// - `Err(Box<dyn Error>)` если задача не найдена или произошла ошибка при выполнении запроса.
-    async fn get_task_by_id(&self, task_id: i32) -> Result<Task, Box<dyn Error>> {
-        let mut  conn = self.db_connection.get_pool().get().expect("couldn't get db connection from pool");
+    /// - `Err(DomainError::NotFound)` если задача не найдена.
+    /// - `Err(DomainError)` если произошла ошибка при выполнении запроса.
+    async fn get_task_by_id(&self, task_id: i32) -> Result<Task, DomainError> {
+        let workspace = self.workspace;
+        self.db_connection.run(move |conn| find_task(conn, workspace, task_id)).await
+    }
+    /// Выполняет полнотекстовый поиск по заголовкам и описаниям задач.
+    ///
+    /// Использует FTS5-индекс `tasks_fts`, который поддерживается триггерами на таблице `tasks`.
+    /// Результаты упорядочены по релевантности (BM25), совпадения выделены тегами `<mark>`.
+    ///
+    /// # Аргументы
+    /// - `search_query` - Строка поиска; каждое слово ищется как отдельный термин.
+    /// - `limit` - Максимальное количество результатов.
+    ///
+    /// # Возвращает
+    /// - `Ok(Vec<TaskSearchHit>)` если поиск выполнен успешно.
+    /// - `Err(DomainError)` если произошла ошибка при выполнении запроса.
+    async fn search_tasks(&self, search_query: String, limit: i64) -> Result<Vec<TaskSearchHit>, DomainError> {
+        let workspace = self.workspace;
+        self.db_connection.run(move |conn| {
+            let models = sql_query(
+                "SELECT tasks.id, tasks.title, tasks.description, tasks.status, tasks.version, \
+                        tasks.priority, tasks.start_at, tasks.due_at, tasks.project_id, tasks.parent_id, tasks.estimate_minutes, tasks.assignee_id, tasks.workspace_id, tasks.deleted_at, \
+                        bm25(tasks_fts) AS rank, \
+                        highlight(tasks_fts, 0, '<mark>', '</mark>') AS title_highlight, \
+                        snippet(tasks_fts, 1, '<mark>', '</mark>', '…', 16) AS description_snippet \
+                 FROM tasks_fts JOIN tasks ON tasks.id = tasks_fts.rowid \
+                 WHERE tasks_fts MATCH ? AND tasks.workspace_id = ? AND tasks.deleted_at IS NULL \
+                 ORDER BY rank \
+                 LIMIT ?",
+            )
+                .bind::<Text, _>(fts_match_expression(&search_query))
+                .bind::<Integer, _>(workspace.id)
+                .bind::<BigInt, _>(limit)
+                .load::<TaskSearchEntity>(conn)?;
 
-        let result = tasks.filter(id.eq(task_id)).get_result::<TaskEntity>(&mut conn);
+            models.into_iter().map(TaskSearchDbMapper::to_entity).collect::<Result<Vec<TaskSearchHit>, DomainError>>()
+        }).await
+    }
+    /// Получает незавершённые задачи, срок выполнения которых уже прошёл.
+    ///
+    /// # Аргументы
+    /// - `now` - Текущий момент времени.
+    /// - `limit` - Максимальное количество задач.
+    ///
+    /// # Возвращает
+    /// - `Ok(Vec<Task>)` задачи, упорядоченные от самых просроченных.
+    /// - `Err(DomainError)` если произошла ошибка при выполнении запроса.
+    async fn get_overdue_tasks(&self, now: DateTime<Utc>, limit: i64) -> Result<Vec<Task>, DomainError> {
+        let workspace = self.workspace;
+        self.db_connection.run(move |conn| {
+            let models = open_tasks(workspace)
+                .filter(due_at.lt(now.naive_utc()))
+                .order((due_at.asc(), id.asc()))
+                .limit(limit)
+                .load::<TaskEntity>(conn)?;
 
-        match result {
-            Ok(model) => Ok(TaskDbMapper::to_entity(model)),
-            Err(e) => Err(Box::new(e)),
-        }
+            models.into_iter().map(TaskDbMapper::to_entity).collect::<Result<Vec<Task>, DomainError>>()
+        }).await
+    }
+    /// Получает незавершённые задачи, срок выполнения которых наступает в промежутке `[now, until]`.
+    ///
+    /// # Аргументы
+    /// - `now` - Текущий момент времени.
+    /// - `until` - Граница промежутка включительно.
+    /// - `limit` - Максимальное количество задач.
+    ///
+    /// # Возвращает
+    /// - `Ok(Vec<Task>)` задачи, упорядоченные от ближайшего срока.
+    /// - `Err(DomainError)` если произошла ошибка при выполнении запроса.
+    async fn get_tasks_due_within(&self, now: DateTime<Utc>, until: DateTime<Utc>, limit: i64) -> Result<Vec<Task>, DomainError> {
+        let workspace = self.workspace;
+        self.db_connection.run(move |conn| {
+            let models = open_tasks(workspace)
+                .filter(due_at.between(now.naive_utc(), until.naive_utc()))
+                .order((due_at.asc(), id.asc()))
+                .limit(limit)
+                .load::<TaskEntity>(conn)?;
+
+            models.into_iter().map(TaskDbMapper::to_entity).collect::<Result<Vec<Task>, DomainError>>()
+        }).await
+    }
+    /// Получает незавершённые задачи, упорядоченные по срочности.
+    ///
+    /// Первыми идут просроченные задачи, внутри групп - по убыванию приоритета,
+    /// затем по сроку выполнения; задачи без срока идут последними.
+    ///
+    /// # Аргументы
+    /// - `now` - Текущий момент времени.
+    /// - `limit` - Максимальное количество задач.
+    ///
+    /// # Возвращает
+    /// - `Ok(Vec<Task>)` если задачи успешно получены.
+    /// - `Err(DomainError)` если произошла ошибка при выполнении запроса.
+    async fn get_tasks_by_urgency(&self, now: DateTime<Utc>, limit: i64) -> Result<Vec<Task>, DomainError> {
+        let workspace = self.workspace;
+        self.db_connection.run(move |conn| {
+            let overdue = sql::<Integer>("CASE WHEN due_at < ")
+                .bind::<Timestamp, _>(now.naive_utc())
+                .sql(" THEN 1 ELSE 0 END");
+            let priority_rank = sql::<Integer>(
+                "CASE priority WHEN 'urgent' THEN 3 WHEN 'high' THEN 2 WHEN 'medium' THEN 1 ELSE 0 END",
+            );
+            let models = open_tasks(workspace)
+                .order((overdue.desc(), priority_rank.desc(), due_at.is_null().asc(), due_at.asc(), id.asc()))
+                .limit(limit)
+                .load::<TaskEntity>(conn)?;
 
+            models.into_iter().map(TaskDbMapper::to_entity).collect::<Result<Vec<Task>, DomainError>>()
+        }).await
+    }
+    /// Получает задачу и всех её потомков одним рекурсивным запросом.
+    ///
+    /// # Аргументы
+    /// - `task_id` - Идентификатор корня поддерева.
+    ///
+    /// # Возвращает
+    /// - `Ok(Vec<Task>)` задачи поддерева, упорядоченные по идентификатору.
+    /// - `Err(DomainError::NotFound)` если задача не найдена.
+    /// - `Err(DomainError)` если произошла ошибка при выполнении запроса.
+    async fn get_task_subtree(&self, task_id: i32) -> Result<Vec<Task>, DomainError> {
+        let workspace = self.workspace;
+        self.db_connection.run(move |conn| {
+            // UNION, а не UNION ALL: повторно встреченная задача не продолжает обход.
+            let models = sql_query(
+                "WITH RECURSIVE subtree(id) AS ( \
+                     SELECT id FROM tasks WHERE id = ? AND workspace_id = ? AND deleted_at IS NULL \
+                     UNION \
+                     SELECT tasks.id FROM tasks JOIN subtree ON tasks.parent_id = subtree.id WHERE tasks.workspace_id = ? AND tasks.deleted_at IS NULL \
+                 ) \
+                 SELECT tasks.* FROM tasks WHERE tasks.id IN (SELECT id FROM subtree) ORDER BY tasks.id",
+            )
+                .bind::<Integer, _>(task_id)
+                .bind::<Integer, _>(workspace.id)
+                .bind::<Integer, _>(workspace.id)
+                .load::<TaskEntity>(conn)?;
+            if models.is_empty() {
+                return Err(Task::not_found(task_id));
+            }
+
+            models.into_iter().map(TaskDbMapper::to_entity).collect::<Result<Vec<Task>, DomainError>>()
+        }).await
+    }
+    /// Получает цепочку предков задачи.
+    ///
+    /// # Аргументы
+    /// - `task_id` - Идентификатор задачи.
+    ///
+    /// # Возвращает
+    /// - `Ok(Vec<i32>)` идентификаторы задачи и её предков, от самой задачи к корню.
+    /// - `Err(DomainError::NotFound)` если задача не найдена.
+    /// - `Err(DomainError)` если произошла ошибка при выполнении запроса.
+    async fn get_task_lineage(&self, task_id: i32) -> Result<Vec<i32>, DomainError> {
+        let workspace = self.workspace;
+        self.db_connection.run(move |conn| task_lineage(conn, workspace, task_id)).await
+    }
+    /// Получает страницу истории задачи, от ранних изменений к поздним.
+    ///
+    /// История удалённой задачи сохраняется, поэтому задача считается найденной, если у неё есть история.
+    ///
+    /// # Аргументы
+    /// - `task_id` - Идентификатор задачи.
+    /// - `limit` - Максимальное количество записей.
+    /// - `offset` - Количество пропускаемых записей.
+    ///
+    /// # Возвращает
+    /// - `Ok(TaskEventPage)` если страница успешно получена.
+    /// - `Err(DomainError::NotFound)` если задачи нет и у неё нет истории.
+    /// - `Err(DomainError)` если произошла ошибка при выполнении запроса.
+    async fn get_task_events(&self, task_id: i32, limit: i64, offset: i64) -> Result<TaskEventPage, DomainError> {
+        let workspace = self.workspace;
+        self.db_connection.run(move |conn| {
+            let events = || task_events::table
+                .filter(task_events::workspace_id.eq(workspace.id))
+                .filter(task_events::task_id.eq(task_id));
+            let total = events().count().get_result::<i64>(conn)?;
+            if total == 0 {
+                ensure_task_exists(conn, workspace, task_id)?;
+            }
+
+            let items = events()
+                .select(TaskEventEntity::as_select())
+                .order(task_events::id.asc())
+                .limit(limit)
+                .offset(offset)
+                .load::<TaskEventEntity>(conn)?
+                .into_iter()
+                .map(TaskEventDbMapper::to_entity)
+                .collect::<Result<Vec<TaskEvent>, DomainError>>()?;
+            Ok(TaskEventPage { items, total, limit, offset })
+        }).await
+    }
+    /// Получает страницу корзины: удалённые задачи, начиная с удалённых последними.
+    ///
+    /// # Аргументы
+    /// - `limit` - Максимальное количество задач.
+    /// - `offset` - Количество пропускаемых задач.
+    ///
+    /// # Возвращает
+    /// - `Ok(TrashPage)` если страница успешно получена.
+    /// - `Err(DomainError)` если произошла ошибка при выполнении запроса.
+    async fn get_trash(&self, limit: i64, offset: i64) -> Result<TrashPage, DomainError> {
+        let workspace = self.workspace;
+        self.db_connection.run(move |conn| {
+            let total = trashed_tasks(workspace).count().get_result::<i64>(conn)?;
+            let items = trashed_tasks(workspace)
+                .order((deleted_at.desc(), id.desc()))
+                .limit(limit)
+                .offset(offset)
+                .load::<TaskEntity>(conn)?
+                .into_iter()
+                .map(TaskDbMapper::to_entity)
+                .collect::<Result<Vec<Task>, DomainError>>()?;
+            Ok(TrashPage { items, total, limit, offset })
+        }).await
+    }
+    /// Определяет роль пользователя в области `scope`.
+    ///
+    /// Действует глобальная роль пользователя или, для задач проекта, его роль участника проекта,
+    /// если она выше. Область задачи - область её проекта или глобальная, если задача вне проекта.
+    ///
+    /// # Аргументы
+    /// - `user_id` - Идентификатор пользователя.
+    /// - `scope` - Проверяемая область.
+    ///
+    /// # Возвращает
+    /// - `Ok(Some(Role))` действующую роль пользователя.
+    /// - `Ok(None)` если пользователь не найден.
+    /// - `Err(DomainError::NotFound)` если область - несуществующая задача; задача в корзине существует.
+    /// - `Err(DomainError)` если произошла ошибка при выполнении запроса.
+    async fn get_role(&self, user_id: i32, scope: AccessScope) -> Result<Option<Role>, DomainError> {
+        let workspace = self.workspace;
+        self.db_connection.run(move |conn| {
+            conn.transaction(|conn| {
+                let global_role = users::table.find(user_id)
+                    .select(users::role)
+                    .first::<String>(conn)
+                    .optional()?;
+                let Some(global_role) = global_role.map(|role| parse_column::<Role>("users.role", &role)).transpose()? else {
+                    return Ok(None);
+                };
+
+                let scope_project_id = match scope {
+                    AccessScope::Global => None,
+                    AccessScope::Project(scope_project_id) => Some(scope_project_id),
+                    // Права на задачу в корзине определяются так же, как до её удаления.
+                    AccessScope::Task(task_id) => tasks.filter(workspace_id.eq(workspace.id)).filter(id.eq(task_id))
+                        .select(project_id)
+                        .first::<Option<i32>>(conn)
+                        .optional()?
+                        .ok_or_else(|| Task::not_found(task_id))?,
+                };
+                let member_role = match scope_project_id {
+                    Some(scope_project_id) => project_members::table.find((scope_project_id, user_id))
+                        .select(project_members::role)
+                        .first::<String>(conn)
+                        .optional()?
+                        .map(|role| parse_column::<Role>("project_members.role", &role))
+                        .transpose()?,
+                    None => None,
+                };
+
+                Ok(Some(member_role.map_or(global_role, |member_role| member_role.max(global_role))))
+            })
+        }).await
     }
     /// Создаёт новую задачу.
     ///
     /// # Аргументы
     /// - `new_title` - Заголовок новой задачи.
     /// - `new_description` - Описание новой задачи (необязательно).
+    /// - `new_status` - Начальный статус задачи.
+    /// - `schedule` - Приоритет и сроки задачи.
+    /// - `placement` - Проект и родительская задача (необязательно); подзадача без проекта попадает в проект родителя.
     ///
     /// # Возвращает
     /// - `Ok(Task)` если задача успешно создана.
-    /// - `Err(Box<dyn Error>)` если произошла ошибка при выполнении запроса.
-    async fn create_task(&self, new_title: String, new_description: Option<String>) -> Result<Task, Box<dyn Error>> {
-        let mut conn = self.db_connection.get_pool().get().map_err(|e| Box::new(e) as Box<dyn Error>)?;
-
-        let result = diesel::insert_into(tasks)
-            .values((title.eq(new_title), description.eq(new_description)))
-            .execute(&mut conn);
-
-        match result {
-            Ok(_) => {
-                let inserted_task = tasks.order(id.desc()).first::<TaskEntity>(&mut conn);
-                match inserted_task {
-                    Ok(model) => Ok(TaskDbMapper::to_entity(model)),
-                    Err(e) => Err(Box::new(e)),
-                }
-            },
-            Err(e) => Err(Box::new(e)),
-        }
+    /// - `Err(DomainError::NotFound)` если проект или родительская задача не найдены.
+    /// - `Err(DomainError::Conflict)` если проект в архиве.
+    /// - `Err(DomainError)` если произошла ошибка при выполнении запроса.
+    async fn create_task(&self, new_title: String, new_description: Option<String>, new_status: TaskStatus, schedule: TaskSchedule, placement: TaskPlacement) -> Result<Task, DomainError> {
+        let ctx = self.write_context();
+        self.db_connection.run(move |conn| {
+            conn.immediate_transaction(|conn| insert_task(conn, &ctx, new_title, new_description, new_status, schedule, placement))
+        }).await
     }
     /// Обновляет существующую задачу.
     ///
@@ -110,29 +461,579 @@ impl TaskRepositoryAbstract for TaskRepository {
     /// - `task_id` - Идентификатор задачи, которую нужно обновить.
     /// - `new_title` - Новый заголовок задачи.
     /// - `new_description` - Новое описание задачи (необязательно).
+    /// - `new_status` - Новый статус задачи; `None` оставляет статус без изменений.
+    /// - `schedule` - Новые приоритет и сроки; приоритет `None` остаётся прежним, отсутствующие даты очищаются.
+    /// - `expected_version` - Версия, которую ожидает клиент; `None` - обновить без проверки версии.
     ///
     /// # Возвращает
     /// - `Ok(Task)` если задача успешно обновлена.
-    /// - `Err(Box<dyn Error>)` если произошла ошибка при выполнении запроса.
-    async fn update_task(&self, task_id: i32, new_title: String, new_description: Option<String>) -> Result<Task, Box<dyn Error>> {
-        let mut conn = self.db_connection.get_pool().get().map_err(|e| Box::new(e) as Box<dyn Error>)?;
-
-        let result = update(tasks.filter(id.eq(task_id)))
-            .set((
-                title.eq(new_title),
-                description.eq(new_description),
-            ))
-            .execute(&mut conn);
-
-        match result {
-            Ok(_) => {
-                let updated_task = tasks.filter(id.eq(task_id)).first::<TaskEntity>(&mut conn);
-                match updated_task {
-                    Ok(model) => Ok(TaskDbMapper::to_entity(model)),
-                    Err(e) => Err(Box::new(e)),
+    /// - `Err(DomainError::NotFound)` если задача не найдена.
+    /// - `Err(DomainError::PreconditionFailed)` если версия задачи не совпала с ожидаемой.
+    /// - `Err(DomainError)` если произошла ошибка при выполнении запроса.
+    async fn update_task(&self, task_id: i32, new_title: String, new_description: Option<String>, new_status: Option<TaskStatus>, schedule: TaskSchedule, expected_version: Option<i32>) -> Result<Task, DomainError> {
+        let patch = TaskPatch::replacement(new_title, new_description, new_status, schedule);
+        let ctx = self.write_context();
+        self.db_connection.run(move |conn| write_patch(conn, &ctx, task_id, patch, expected_version)).await
+    }
+    /// Частично обновляет задачу: в `UPDATE` попадают только затронутые изменением столбцы.
+    ///
+    /// # Аргументы
+    /// - `task_id` - Идентификатор задачи.
+    /// - `patch` - Изменяемые поля; `description: Some(None)` очищает описание.
+    /// - `expected_version` - Версия, которую ожидает клиент; `None` - обновить без проверки версии.
+    ///
+    /// # Возвращает
+    /// - `Ok(Task)` задачу после изменения.
+    /// - `Err(DomainError::NotFound)` если задача не найдена.
+    /// - `Err(DomainError::PreconditionFailed)` если версия задачи не совпала с ожидаемой.
+    /// - `Err(DomainError)` если произошла ошибка при выполнении запроса.
+    async fn patch_task(&self, task_id: i32, patch: TaskPatch, expected_version: Option<i32>) -> Result<Task, DomainError> {
+        let ctx = self.write_context();
+        self.db_connection.run(move |conn| write_patch(conn, &ctx, task_id, patch, expected_version)).await
+    }
+    /// Изменяет статус существующей задачи.
+    ///
+    /// Проверка допустимости перехода выполняется на уровне приложения,
+    /// репозиторий лишь сохраняет новое значение.
+    ///
+    /// # Аргументы
+    /// - `task_id` - Идентификатор задачи.
+    /// - `new_status` - Новый статус задачи.
+    /// - `expected_version` - Версия, для которой проверялся переход; `None` - без проверки версии.
+    ///
+    /// # Возвращает
+    /// - `Ok(Task)` если статус успешно изменён.
+    /// - `Err(DomainError::NotFound)` если задача не найдена.
+    /// - `Err(DomainError::PreconditionFailed)` если версия задачи не совпала с ожидаемой.
+    /// - `Err(DomainError)` если произошла ошибка при выполнении запроса.
+    async fn update_task_status(&self, task_id: i32, new_status: TaskStatus, expected_version: Option<i32>) -> Result<Task, DomainError> {
+        let patch = TaskPatch { status: Some(new_status), ..TaskPatch::default() };
+        let ctx = self.write_context();
+        self.db_connection.run(move |conn| write_patch(conn, &ctx, task_id, patch, expected_version)).await
+    }
+    /// Делает задачу подзадачей `new_parent_id` или, если он `None`, корнем нового дерева.
+    /// Подзадачи перемещаемой задачи перемещаются вместе с ней, проект задачи не меняется.
+    ///
+    /// Цепочка предков нового родителя перепроверяется внутри транзакции, поэтому параллельные
+    /// перемещения не могут образовать цикл.
+    ///
+    /// # Аргументы
+    /// - `task_id` - Идентификатор перемещаемой задачи.
+    /// - `new_parent_id` - Новый родитель.
+    /// - `expected_version` - Версия, которую ожидает клиент; `None` - без проверки версии.
+    ///
+    /// # Возвращает
+    /// - `Ok(Task)` задачу после перемещения; если родитель не изменился, версия остаётся прежней.
+    /// - `Err(DomainError::NotFound)` если задача или новый родитель не найдены.
+    /// - `Err(DomainError::Conflict)` если новый родитель - сама задача или её потомок.
+    /// - `Err(DomainError::PreconditionFailed)` если версия задачи не совпала с ожидаемой.
+    /// - `Err(DomainError)` если произошла ошибка при выполнении запроса.
+    async fn move_task(&self, task_id: i32, new_parent_id: Option<i32>, expected_version: Option<i32>) -> Result<Task, DomainError> {
+        let ctx = self.write_context();
+        self.db_connection.run(move |conn| {
+            conn.immediate_transaction(|conn| {
+                let workspace = ctx.workspace;
+                let current = find_task(conn, workspace, task_id)?;
+                current.ensure_version(expected_version)?;
+                if let Some(new_parent_id) = new_parent_id {
+                    check_new_parent(task_id, &task_lineage(conn, workspace, new_parent_id)?)?;
+                }
+                if current.parent_id == new_parent_id {
+                    return Ok(current);
                 }
+
+                update(tasks.filter(workspace_id.eq(workspace.id)).filter(id.eq(task_id)))
+                    .set((parent_id.eq(new_parent_id), version.eq(version + 1)))
+                    .execute(conn)?;
+                let moved = find_task(conn, workspace, task_id)?;
+                record_event(conn, &ctx, task_id, TaskEventAction::Updated, Some(&current), Some(&moved))?;
+                Ok(moved)
+            })
+        }).await
+    }
+    /// Выполняет пакет операций создания, обновления и удаления задач в одной транзакции.
+    ///
+    /// Операции выполняются по порядку. В режиме `AllOrNothing` первая неудачная операция
+    /// откатывает всю транзакцию, и её результат становится последним в списке; результаты
+    /// предшествующих операций в этом случае не сохранены. В режиме `BestEffort` каждая операция
+    /// выполняется в своей точке сохранения, и неудачная операция откатывается отдельно от остальных.
+    ///
+    /// Допустимость смены статуса при обновлении проверяется внутри транзакции,
+    /// поэтому она не может устареть до записи.
+    ///
+    /// # Аргументы
+    /// - `operations` - Операции в порядке выполнения.
+    /// - `mode` - Режим обработки неудачных операций.
+    ///
+    /// # Возвращает
+    /// - `Ok(Vec<Result<BulkOutcome, DomainError>>)` результаты операций в порядке выполнения.
+    /// - `Err(DomainError)` если не удалось выполнить или зафиксировать саму транзакцию.
+    async fn bulk_write(&self, operations: Vec<BulkOperation>, mode: BulkMode) -> Result<Vec<Result<BulkOutcome, DomainError>>, DomainError> {
+        let ctx = self.write_context();
+        self.db_connection.run(move |conn| {
+            let written = conn.immediate_transaction(|conn| {
+                let mut results = Vec::with_capacity(operations.len());
+                for operation in operations {
+                    let result = match mode {
+                        BulkMode::AllOrNothing => apply_bulk_operation(conn, &ctx, operation),
+                        BulkMode::BestEffort => conn.transaction(|conn| apply_bulk_operation(conn, &ctx, operation)),
+                    };
+                    let failed = result.is_err();
+                    results.push(result);
+                    if failed && mode == BulkMode::AllOrNothing {
+                        return Err(BulkAbort::Rollback(results));
+                    }
+                }
+                Ok(results)
+            });
+
+            match written {
+                Ok(results) | Err(BulkAbort::Rollback(results)) => Ok(results),
+                Err(BulkAbort::Storage(e)) => Err(e),
+            }
+        }).await
+    }
+    /// Окончательно удаляет задачи всех пространств, находящиеся в корзине с момента раньше `deleted_before`.
+    ///
+    /// Связи с метками, зависимости и назначения удаляются вместе с задачами, история задач сохраняется.
+    ///
+    /// # Аргументы
+    /// - `deleted_before` - Задачи, удалённые раньше этого момента, удаляются окончательно.
+    ///
+    /// # Возвращает
+    /// - `Ok(usize)` количество окончательно удалённых задач.
+    /// - `Err(DomainError)` если произошла ошибка при выполнении запроса.
+    async fn purge_trash(&self, deleted_before: DateTime<Utc>) -> Result<usize, DomainError> {
+        let actor = self.actor.clone();
+        self.db_connection.run(move |conn| {
+            conn.immediate_transaction(|conn| {
+                let expired = tasks.filter(deleted_at.lt(deleted_before.naive_utc())).load::<TaskEntity>(conn)?;
+                let purged_count = expired.len();
+                for model in expired {
+                    let ctx = WriteContext { workspace: Workspace::new(model.workspace_id), actor: actor.clone() };
+                    let purged = TaskDbMapper::to_entity(model)?;
+                    delete(tasks.filter(id.eq(purged.id))).execute(conn)?;
+                    record_event(conn, &ctx, purged.id, TaskEventAction::Purged, Some(&purged), None)?;
+                }
+                Ok(purged_count)
+            })
+        }).await
+    }
+}
+
+/// Пространство и автор записи, от имени которого изменения попадают в историю задач.
+pub(crate) struct WriteContext {
+    pub(crate) workspace: Workspace,
+    pub(crate) actor: EventActor,
+}
+
+/// Причина отката транзакции пакетной записи.
+enum BulkAbort {
+    /// Операция пакета завершилась ошибкой; содержит результаты до неё включительно.
+    Rollback(Vec<Result<BulkOutcome, DomainError>>),
+    /// Ошибка самой транзакции.
+    Storage(DomainError),
+}
+
+impl From<diesel::result::Error> for BulkAbort {
+    fn from(e: diesel::result::Error) -> Self {
+        BulkAbort::Storage(e.into())
+    }
+}
+
+/// Проверяет, что задача существует и, если задана `expected_version`, имеет эту версию.
+///
+/// Вызывается внутри транзакции, захватившей блокировку записи, поэтому версия не может
+/// измениться между проверкой и последующей записью.
+pub(crate) fn ensure_version(conn: &mut SqliteConnection, workspace: Workspace, task_id: i32, expected_version: Option<i32>) -> Result<(), DomainError> {
+    let current_version = workspace_tasks(workspace).filter(id.eq(task_id))
+        .select(version)
+        .first::<i32>(conn)
+        .optional()?
+        .ok_or_else(|| Task::not_found(task_id))?;
+
+    match expected_version {
+        Some(expected_version) if expected_version != current_version => Err(Task::version_mismatch(task_id, current_version, expected_version)),
+        _ => Ok(()),
+    }
+}
+
+/// Вставляет задачу и возвращает именно вставленную строку.
+///
+/// `RETURNING` не зависит от других вставок, поэтому параллельно создаваемые задачи не перепутываются.
+/// Вызывается внутри транзакции, чтобы проект не был отправлен в архив, а родитель не был удалён
+/// между проверкой и вставкой.
+fn insert_task(conn: &mut SqliteConnection, ctx: &WriteContext, new_title: String, new_description: Option<String>, new_status: TaskStatus, schedule: TaskSchedule, placement: TaskPlacement) -> Result<Task, DomainError> {
+    let workspace = ctx.workspace;
+    let mut new_project_id = placement.project_id;
+    if let Some(new_parent_id) = placement.parent_id {
+        new_project_id = new_project_id.or(find_task(conn, workspace, new_parent_id)?.project_id);
+    }
+    if let Some(new_project_id) = new_project_id {
+        ensure_project_accepts_tasks(conn, workspace, new_project_id)?;
+    }
+    let model = diesel::insert_into(tasks)
+        .values((
+            title.eq(new_title),
+            description.eq(new_description),
+            status.eq(new_status.to_string()),
+            priority.eq(schedule.priority.unwrap_or_default().to_string()),
+            start_at.eq(schedule.start_at.map(|value| value.naive_utc())),
+            due_at.eq(schedule.due_at.map(|value| value.naive_utc())),
+            project_id.eq(new_project_id),
+            parent_id.eq(placement.parent_id),
+            estimate_minutes.eq(schedule.estimate_minutes),
+            workspace_id.eq(workspace.id),
+        ))
+        .returning(TaskEntity::as_returning())
+        .get_result::<TaskEntity>(conn)?;
+
+    let created = TaskDbMapper::to_entity(model)?;
+    record_event(conn, ctx, created.id, TaskEventAction::Created, None, Some(&created))?;
+    Ok(created)
+}
+
+/// Записывает изменение задачи в отдельной транзакции, захватывающей блокировку записи.
+fn write_patch(conn: &mut SqliteConnection, ctx: &WriteContext, task_id: i32, patch: TaskPatch, expected_version: Option<i32>) -> Result<Task, DomainError> {
+    conn.immediate_transaction(|conn| apply_patch(conn, ctx, task_id, patch, expected_version))
+}
+
+/// Записывает затронутые изменением столбцы и увеличивает версию задачи.
+/// Пустое изменение не меняет ни данные, ни версию и не попадает в историю. Вызывается внутри транзакции.
+fn apply_patch(conn: &mut SqliteConnection, ctx: &WriteContext, task_id: i32, patch: TaskPatch, expected_version: Option<i32>) -> Result<Task, DomainError> {
+    let workspace = ctx.workspace;
+    let current = find_task(conn, workspace, task_id)?;
+    current.ensure_version(expected_version)?;
+    if let Some(Some(new_project_id)) = patch.project_id {
+        ensure_project_accepts_tasks(conn, workspace, new_project_id)?;
+    }
+    if patch.is_empty() {
+        return Ok(current);
+    }
+
+    update(tasks.filter(workspace_id.eq(workspace.id)).filter(id.eq(task_id)))
+        .set((TaskPatchDbMapper::to_db(patch), version.eq(version + 1)))
+        .execute(conn)?;
+    let updated = find_task(conn, workspace, task_id)?;
+    record_event(conn, ctx, task_id, TaskEventAction::Updated, Some(&current), Some(&updated))?;
+    Ok(updated)
+}
+
+/// Перемещает задачу в корзину, предварительно проверив её версию. Вызывается внутри транзакции.
+fn remove_task(conn: &mut SqliteConnection, ctx: &WriteContext, task_id: i32, expected_version: Option<i32>) -> Result<(), DomainError> {
+    find_task(conn, ctx.workspace, task_id)?.ensure_version(expected_version)?;
+    trash_tasks(conn, ctx, vec![task_id])
+}
+
+/// Перемещает задачи `task_ids` в корзину и увеличивает их версию. Связи родитель - подзадача внутри
+/// набора сохраняются, остальные подзадачи становятся корнями своих деревьев, их версия увеличивается,
+/// а отсоединение записывается в их историю. Зависимости задач удаляются, чтобы задачи из корзины
+/// не блокировали другие задачи; метки и исполнители сохраняются. Вызывается внутри транзакции.
+pub(crate) fn trash_tasks(conn: &mut SqliteConnection, ctx: &WriteContext, task_ids: Vec<i32>) -> Result<(), DomainError> {
+    let workspace = ctx.workspace;
+    let removed = workspace_tasks(workspace).filter(id.eq_any(task_ids.clone()))
+        .order(id.asc())
+        .load::<TaskEntity>(conn)?
+        .into_iter()
+        .map(TaskDbMapper::to_entity)
+        .collect::<Result<Vec<Task>, DomainError>>()?;
+    let children = workspace_tasks(workspace).filter(parent_id.eq_any(task_ids.clone())).filter(id.ne_all(task_ids.clone()))
+        .load::<TaskEntity>(conn)?
+        .into_iter()
+        .map(TaskDbMapper::to_entity)
+        .collect::<Result<Vec<Task>, DomainError>>()?;
+
+    update(tasks.filter(id.eq_any(children.iter().map(|child| child.id).collect::<Vec<i32>>())))
+        .set((parent_id.eq(None::<i32>), version.eq(version + 1)))
+        .execute(conn)?;
+    delete(task_dependencies::table.filter(task_dependencies::task_id.eq_any(task_ids.clone()).or(task_dependencies::blocker_id.eq_any(task_ids.clone()))))
+        .execute(conn)?;
+    let trashed = update(tasks.filter(id.eq_any(removed.iter().map(|task| task.id).collect::<Vec<i32>>())))
+        .set((deleted_at.eq(Utc::now().naive_utc()), version.eq(version + 1)))
+        .returning(TaskEntity::as_returning())
+        .get_results::<TaskEntity>(conn)?
+        .into_iter()
+        .map(|model| Ok((model.id, TaskDbMapper::to_entity(model)?)))
+        .collect::<Result<HashMap<i32, Task>, DomainError>>()?;
+
+    for child in children {
+        let detached = Task { parent_id: None, version: child.version + 1, ..child.clone() };
+        record_event(conn, ctx, child.id, TaskEventAction::Updated, Some(&child), Some(&detached))?;
+    }
+    for task in removed {
+        record_event(conn, ctx, task.id, TaskEventAction::Deleted, Some(&task), trashed.get(&task.id))?;
+    }
+    Ok(())
+}
+
+/// Загружает задачу корзины пространства `workspace`, возвращая `DomainError::NotFound`, если её там нет.
+fn find_trashed_task(conn: &mut SqliteConnection, workspace: Workspace, task_id: i32) -> Result<Task, DomainError> {
+    trashed_tasks(workspace).filter(id.eq(task_id))
+        .first::<TaskEntity>(conn)
+        .optional()?
+        .map(TaskDbMapper::to_entity)
+        .transpose()?
+        .ok_or_else(|| DomainError::NotFound(format!("Task {} is not in the trash", task_id)))
+}
+
+/// Записывает в историю задачи `task_id` изменение от `before` к `after` от имени автора записи.
+/// Изменение без различий в полях не записывается. Вызывается внутри транзакции, изменившей задачу.
+pub(crate) fn record_event(conn: &mut SqliteConnection, ctx: &WriteContext, task_id: i32, action: TaskEventAction, before: Option<&Task>, after: Option<&Task>) -> Result<(), DomainError> {
+    let changes = TaskEvent::diff(before, after);
+    if changes.is_empty() && action == TaskEventAction::Updated {
+        return Ok(());
+    }
+
+    diesel::insert_into(task_events::table)
+        .values((
+            task_events::task_id.eq(task_id),
+            task_events::workspace_id.eq(ctx.workspace.id),
+            task_events::action.eq(action.as_str()),
+            task_events::actor.eq(ctx.actor.as_str()),
+            task_events::occurred_at.eq(Utc::now().naive_utc()),
+            task_events::changes.eq(TaskEventDbMapper::changes_to_db(&changes)),
+        ))
+        .execute(conn)?;
+    Ok(())
+}
+
+/// Выполняет одну операцию пакета внутри уже открытой транзакции.
+fn apply_bulk_operation(conn: &mut SqliteConnection, ctx: &WriteContext, operation: BulkOperation) -> Result<BulkOutcome, DomainError> {
+    match operation {
+        BulkOperation::Create { title: new_title, description: new_description, status: new_status, schedule } => {
+            insert_task(conn, ctx, new_title, new_description, new_status, schedule, TaskPlacement::default()).map(BulkOutcome::Created)
+        },
+        BulkOperation::Update { task_id, title: new_title, description: new_description, status: new_status, schedule, expected_version } => {
+            let current = find_task(conn, ctx.workspace, task_id)?;
+            current.ensure_version(expected_version)?;
+            let new_status = match new_status {
+                Some(target) if target != current.status => Some(
+                    current.status.transition_to(target).map_err(|e| DomainError::Conflict(e.to_string()))?,
+                ),
+                _ => None,
+            };
+
+            let patch = TaskPatch::replacement(new_title, new_description, new_status, schedule);
+            apply_patch(conn, ctx, task_id, patch, None).map(BulkOutcome::Updated)
+        },
+        BulkOperation::Delete { task_id, expected_version } => {
+            remove_task(conn, ctx, task_id, expected_version).map(|_| BulkOutcome::Deleted(task_id))
+        },
+    }
+}
+
+/// Загружает идентификаторы задачи и её предков, от самой задачи к корню.
+///
+/// Рекурсивный запрос выбирает пары «задача - родитель», а цепочка восстанавливается обходом от `task_id`;
+/// так результат не зависит от порядка строк и конечен даже при повреждённых данных с циклом.
+fn task_lineage(conn: &mut SqliteConnection, workspace: Workspace, task_id: i32) -> Result<Vec<i32>, DomainError> {
+    let links = sql_query(
+        "WITH RECURSIVE lineage(id, parent_id) AS ( \
+             SELECT id, parent_id FROM tasks WHERE id = ? AND workspace_id = ? AND deleted_at IS NULL \
+             UNION \
+             SELECT tasks.id, tasks.parent_id FROM tasks JOIN lineage ON tasks.id = lineage.parent_id WHERE tasks.workspace_id = ? AND tasks.deleted_at IS NULL \
+         ) \
+         SELECT id, parent_id FROM lineage",
+    )
+        .bind::<Integer, _>(task_id)
+        .bind::<Integer, _>(workspace.id)
+        .bind::<Integer, _>(workspace.id)
+        .load::<TaskLinkEntity>(conn)?;
+    let parents = links.into_iter().map(|link| (link.id, link.parent_id)).collect::<HashMap<_, _>>();
+    if !parents.contains_key(&task_id) {
+        return Err(Task::not_found(task_id));
+    }
+
+    let mut lineage = vec![task_id];
+    while let Some(&Some(next)) = lineage.last().and_then(|last| parents.get(last)) {
+        if lineage.contains(&next) {
+            break;
+        }
+        lineage.push(next);
+    }
+    Ok(lineage)
+}
+
+/// Проверяет, что задача `task_id` существует в пространстве `workspace`.
+pub(crate) fn ensure_task_exists(conn: &mut SqliteConnection, workspace: Workspace, task_id: i32) -> Result<(), DomainError> {
+    let exists = diesel::select(diesel::dsl::exists(workspace_tasks(workspace).filter(id.eq(task_id)))).get_result::<bool>(conn)?;
+    if exists { Ok(()) } else { Err(Task::not_found(task_id)) }
+}
+
+/// Загружает задачу пространства `workspace` по идентификатору, возвращая `DomainError::NotFound`, если её нет.
+pub(crate) fn find_task(conn: &mut SqliteConnection, workspace: Workspace, task_id: i32) -> Result<Task, DomainError> {
+    workspace_tasks(workspace).filter(id.eq(task_id))
+        .first::<TaskEntity>(conn)
+        .optional()?
+        .map(TaskDbMapper::to_entity)
+        .transpose()?
+        .ok_or_else(|| Task::not_found(task_id))
+}
+
+/// Строит запрос к задачам пространства `workspace`, не находящимся в корзине; с него начинается
+/// любой запрос к таблице задач, кроме запросов к самой корзине.
+pub(crate) fn workspace_tasks(workspace: Workspace) -> TaskBoxedQuery<'static> {
+    tasks.into_boxed().filter(workspace_id.eq(workspace.id)).filter(deleted_at.is_null())
+}
+
+/// Строит запрос к задачам корзины пространства `workspace`.
+fn trashed_tasks(workspace: Workspace) -> TaskBoxedQuery<'static> {
+    tasks.into_boxed().filter(workspace_id.eq(workspace.id)).filter(deleted_at.is_not_null())
+}
+
+/// Строит запрос к незавершённым задачам.
+fn open_tasks(workspace: Workspace) -> TaskBoxedQuery<'static> {
+    workspace_tasks(workspace).filter(status.ne(TaskStatus::Done.to_string()))
+}
+
+/// Строит запрос к таблице задач с применёнными фильтрами.
+fn filtered_tasks(workspace: Workspace, filter: &TaskFilter) -> TaskBoxedQuery<'static> {
+    let mut query = workspace_tasks(workspace);
+
+    if let Some(project) = filter.project_id {
+        query = query.filter(project_id.eq(project));
+    }
+    if let Some(user) = filter.assignee_id {
+        query = query.filter(id.eq_any(task_assignees::table.filter(task_assignees::user_id.eq(user)).select(task_assignees::task_id)));
+    }
+    if let Some(needle) = &filter.title_contains {
+        let escaped = needle.replace('\\', "\\\\").replace('%', "\\%").replace('_', "\\_");
+        query = query.filter(title.like(format!("%{}%", escaped)).escape('\\'));
+    }
+    match filter.has_description {
+        Some(true) => query = query.filter(description.is_not_null()),
+        Some(false) => query = query.filter(description.is_null()),
+        None => {},
+    }
+    if let Some(from) = filter.id_from {
+        query = query.filter(id.ge(from));
+    }
+    if let Some(to) = filter.id_to {
+        query = query.filter(id.le(to));
+    }
+    if !filter.tags.is_empty() {
+        let tagged = |names: Vec<String>| task_tags::table
+            .inner_join(tags::table)
+            .filter(tags::workspace_id.eq(workspace.id))
+            .filter(tags::name.eq_any(names))
+            .select(task_tags::task_id);
+        match filter.tag_match {
+            TagMatch::Any => query = query.filter(id.eq_any(tagged(filter.tags.clone()))),
+            TagMatch::All => for tag in &filter.tags {
+                query = query.filter(id.eq_any(tagged(vec![tag.clone()])));
             },
-            Err(e) => Err(Box::new(e)),
         }
     }
+
+    query
+}
+
+/// Важность приоритета для сортировки: `low` - 0, ..., `urgent` - 3, как в `TaskPriority`.
+const PRIORITY_RANK: &str = "CASE tasks.priority WHEN 'low' THEN 0 WHEN 'medium' THEN 1 WHEN 'high' THEN 2 ELSE 3 END";
+
+/// Упорядочивает выборку по полю сортировки, используя `id` для однозначности порядка.
+fn ordered(query: TaskBoxedQuery<'static>, sort_by: TaskSortField, ascending: bool) -> TaskBoxedQuery<'static> {
+    match (sort_by, ascending) {
+        (TaskSortField::Id, true) => query.order(id.asc()),
+        (TaskSortField::Id, false) => query.order(id.desc()),
+        (TaskSortField::Title, true) => query.order((title.asc(), id.asc())),
+        (TaskSortField::Title, false) => query.order((title.desc(), id.desc())),
+        (TaskSortField::Description, true) => query.order((description.asc(), id.asc())),
+        (TaskSortField::Description, false) => query.order((description.desc(), id.desc())),
+        (TaskSortField::Status, true) => query.order((status.asc(), id.asc())),
+        (TaskSortField::Status, false) => query.order((status.desc(), id.desc())),
+        (TaskSortField::Priority, true) => query.order((sql::<Integer>(PRIORITY_RANK).asc(), id.asc())),
+        (TaskSortField::Priority, false) => query.order((sql::<Integer>(PRIORITY_RANK).desc(), id.desc())),
+        (TaskSortField::StartAt, true) => query.order((start_at.asc(), id.asc())),
+        (TaskSortField::StartAt, false) => query.order((start_at.desc(), id.desc())),
+        (TaskSortField::DueAt, true) => query.order((due_at.asc(), id.asc())),
+        (TaskSortField::DueAt, false) => query.order((due_at.desc(), id.desc())),
+        (TaskSortField::Version, true) => query.order((version.asc(), id.asc())),
+        (TaskSortField::Version, false) => query.order((version.desc(), id.desc())),
+        (TaskSortField::ProjectId, true) => query.order((project_id.asc(), id.asc())),
+        (TaskSortField::ProjectId, false) => query.order((project_id.desc(), id.desc())),
+        (TaskSortField::EstimateMinutes, true) => query.order((estimate_minutes.asc(), id.asc())),
+        (TaskSortField::EstimateMinutes, false) => query.order((estimate_minutes.desc(), id.desc())),
+    }
+}
+
+/// Записи после курсора по столбцу без `NULL`: с большим (при убывании - меньшим) значением
+/// или с тем же значением и большим (меньшим) `id`.
+macro_rules! after_value {
+    ($query:ident, $column:expr, $value:expr, $cursor_id:expr, $ascending:expr) => {{
+        let value = $value;
+        if $ascending {
+            $query.filter($column.gt(value.clone()).or($column.eq(value).and(id.gt($cursor_id))))
+        } else {
+            $query.filter($column.lt(value.clone()).or($column.eq(value).and(id.lt($cursor_id))))
+        }
+    }};
+}
+
+/// То же для столбца, допускающего `NULL`: SQLite считает `NULL` меньше любого значения, поэтому
+/// записи без значения идут первыми по возрастанию и последними по убыванию.
+macro_rules! after_nullable_value {
+    ($query:ident, $column:expr, $value:expr, $cursor_id:expr, $ascending:expr) => {
+        match ($value, $ascending) {
+            (Some(value), true) => $query.filter(
+                $column.gt(value.clone()).or($column.eq(value).and(id.gt($cursor_id))),
+            ),
+            (None, true) => $query.filter(
+                $column.is_not_null().or($column.is_null().and(id.gt($cursor_id))),
+            ),
+            (Some(value), false) => $query.filter(
+                $column.lt(value.clone())
+                    .or($column.eq(value).and(id.lt($cursor_id)))
+                    .or($column.is_null()),
+            ),
+            (None, false) => $query.filter($column.is_null().and(id.lt($cursor_id))),
+        }
+    };
+}
+
+/// Оставляет в выборке только записи, следующие за курсором в направлении обхода.
+///
+/// Значение курсора проверено при его разборе (`TaskCursor::decode`), поэтому здесь оно
+/// только преобразуется к типу столбца.
+fn after_cursor(query: TaskBoxedQuery<'static>, cursor: &TaskCursor, ascending: bool) -> TaskBoxedQuery<'static> {
+    let cursor_id = cursor.id;
+    let value = cursor.value.clone();
+    let number = || value.as_deref().and_then(|value| value.parse::<i32>().ok());
+    let timestamp = || value.as_deref()
+        .and_then(|value| DateTime::parse_from_rfc3339(value).ok())
+        .map(|value| value.naive_utc());
+
+    match cursor.sort_by {
+        TaskSortField::Id if ascending => query.filter(id.gt(cursor_id)),
+        TaskSortField::Id => query.filter(id.lt(cursor_id)),
+        TaskSortField::Title => after_value!(query, title, value.unwrap_or_default(), cursor_id, ascending),
+        TaskSortField::Status => after_value!(query, status, value.unwrap_or_default(), cursor_id, ascending),
+        TaskSortField::Description => after_nullable_value!(query, description, value, cursor_id, ascending),
+        TaskSortField::Priority => {
+            let rank = value.and_then(|value| value.parse::<TaskPriority>().ok()).unwrap_or_default() as i32;
+            after_value!(query, sql::<Integer>(PRIORITY_RANK), rank, cursor_id, ascending)
+        },
+        TaskSortField::Version => after_value!(query, version, number().unwrap_or_default(), cursor_id, ascending),
+        TaskSortField::StartAt => after_nullable_value!(query, start_at, timestamp(), cursor_id, ascending),
+        TaskSortField::DueAt => after_nullable_value!(query, due_at, timestamp(), cursor_id, ascending),
+        TaskSortField::ProjectId => after_nullable_value!(query, project_id, number(), cursor_id, ascending),
+        TaskSortField::EstimateMinutes => after_nullable_value!(query, estimate_minutes, number(), cursor_id, ascending),
+    }
+}
+
+/// Преобразует пользовательскую строку поиска в безопасное выражение FTS5 `MATCH`.
+///
+/// Каждое слово заключается в кавычки, поэтому операторы и спецсимволы синтаксиса FTS5
+/// во вводе пользователя трактуются как обычный текст. Последнее слово ищется по префиксу.
+fn fts_match_expression(search_query: &str) -> String {
+    let mut expression = search_query
+        .split_whitespace()
+        .map(|term| format!("\"{}\"", term.replace('"', "\"\"")))
+        .collect::<Vec<String>>()
+        .join(" ");
+
+    if !expression.is_empty() {
+        expression.push('*');
+    }
+    expression
 }
@@ -0,0 +1,245 @@
+use std::sync::Arc;
+use async_trait::async_trait;
+use diesel::prelude::*;
+use diesel::result::{DatabaseErrorKind, Error as DieselError};
+use diesel::{delete, insert_into, update};
+use crate::application::mappers::db_mapper::DbMapper;
+use crate::application::repositories::user_abstract_repo::UserRepositoryAbstract;
+use crate::dal::db_connection::DbConnection;
+use crate::dal::db_mapper::UserDbMapper;
+use crate::dal::models::{TaskAssigneeEntity, UserEntity};
+use crate::dal::repositories::db_repo_tasks::{ensure_task_exists, ensure_version, find_task, record_event, WriteContext};
+use crate::dal::schema::{task_assignees, tasks, users};
+use crate::domain::access::role::Role;
+use crate::domain::error::DomainError;
+use crate::domain::task::task::Task;
+use crate::domain::task::task_event::{EventActor, TaskEventAction};
+use crate::domain::user::user::User;
+use crate::domain::workspace::Workspace;
+
+/// Хранилище пользователей и их назначений на задачи.
+///
+/// Пользователи общие для всех рабочих пространств, назначения ограничены задачами пространства `workspace`.
+/// Смена ответственного исполнителя записывается в историю задачи от имени `actor`.
+pub struct UserRepository {
+    pub db_connection: DbConnection,
+    pub workspace: Workspace,
+    pub actor: EventActor,
+}
+
+impl UserRepository {
+    fn write_context(&self) -> WriteContext {
+        WriteContext { workspace: self.workspace, actor: self.actor.clone() }
+    }
+}
+
+#[async_trait]
+impl UserRepositoryAbstract for UserRepository {
+    fn in_workspace(&self, workspace: Workspace) -> Arc<dyn UserRepositoryAbstract> {
+        Arc::new(UserRepository { db_connection: self.db_connection.clone(), workspace, actor: self.actor.clone() })
+    }
+    fn on_behalf_of(&self, actor: EventActor) -> Arc<dyn UserRepositoryAbstract> {
+        Arc::new(UserRepository { db_connection: self.db_connection.clone(), workspace: self.workspace, actor })
+    }
+    /// Получает всех пользователей, упорядоченных по имени.
+    ///
+    /// # Возвращает
+    /// - `Ok(Vec<User>)` если пользователи успешно получены.
+    /// - `Err(DomainError)` если произошла ошибка при выполнении запроса.
+    async fn get_users(&self) -> Result<Vec<User>, DomainError> {
+        self.db_connection.run(move |conn| {
+            let models = users::table
+                .order((users::name.asc(), users::id.asc()))
+                .load::<UserEntity>(conn)?;
+
+            models.into_iter().map(UserDbMapper::to_entity).collect::<Result<Vec<User>, DomainError>>()
+        }).await
+    }
+    /// Получает пользователя по его идентификатору.
+    ///
+    /// # Аргументы
+    /// - `user_id` - Идентификатор пользователя.
+    ///
+    /// # Возвращает
+    /// - `Ok(User)` если пользователь найден.
+    /// - `Err(DomainError::NotFound)` если пользователь не найден.
+    /// - `Err(DomainError)` если произошла ошибка при выполнении запроса.
+    async fn get_user_by_id(&self, user_id: i32) -> Result<User, DomainError> {
+        self.db_connection.run(move |conn| find_user(conn, user_id)).await
+    }
+    /// Создаёт нового пользователя.
+    ///
+    /// # Аргументы
+    /// - `name` - Имя пользователя.
+    /// - `email` - Адрес электронной почты.
+    ///
+    /// # Возвращает
+    /// - `Ok(User)` если пользователь успешно создан.
+    /// - `Err(DomainError::Conflict)` если адрес (без учёта регистра) уже занят.
+    /// - `Err(DomainError)` если произошла ошибка при выполнении запроса.
+    async fn create_user(&self, name: String, email: String) -> Result<User, DomainError> {
+        self.db_connection.run(move |conn| {
+            let model = insert_into(users::table)
+                .values((users::name.eq(name), users::email.eq(&email)))
+                .returning(UserEntity::as_returning())
+                .get_result(conn)
+                .map_err(|e| duplicate_email(e, &email))?;
+
+            UserDbMapper::to_entity(model)
+        }).await
+    }
+    /// Меняет глобальную роль пользователя.
+    ///
+    /// # Аргументы
+    /// - `user_id` - Идентификатор пользователя.
+    /// - `role` - Новая роль.
+    ///
+    /// # Возвращает
+    /// - `Ok(User)` пользователя с новой ролью.
+    /// - `Err(DomainError::NotFound)` если пользователь не найден.
+    /// - `Err(DomainError)` если произошла ошибка при выполнении запроса.
+    async fn set_user_role(&self, user_id: i32, role: Role) -> Result<User, DomainError> {
+        self.db_connection.run(move |conn| {
+            update(users::table.find(user_id))
+                .set(users::role.eq(role.to_string()))
+                .returning(UserEntity::as_returning())
+                .get_result(conn)
+                .optional()?
+                .map(UserDbMapper::to_entity)
+                .transpose()?
+                .ok_or_else(|| User::not_found(user_id))
+        }).await
+    }
+    /// Получает исполнителей задачи, упорядоченных по имени.
+    ///
+    /// # Аргументы
+    /// - `task_id` - Идентификатор задачи.
+    ///
+    /// # Возвращает
+    /// - `Ok(Vec<User>)` если исполнители успешно получены.
+    /// - `Err(DomainError::NotFound)` если задача не найдена.
+    /// - `Err(DomainError)` если произошла ошибка при выполнении запроса.
+    async fn get_task_assignees(&self, task_id: i32) -> Result<Vec<User>, DomainError> {
+        let workspace = self.workspace;
+        self.db_connection.run(move |conn| {
+            conn.transaction(|conn| {
+                ensure_task_exists(conn, workspace, task_id)?;
+                let models = task_assignees::table
+                    .inner_join(users::table)
+                    .filter(task_assignees::task_id.eq(task_id))
+                    .order((users::name.asc(), users::id.asc()))
+                    .select(UserEntity::as_select())
+                    .load::<UserEntity>(conn)?;
+
+                models.into_iter().map(UserDbMapper::to_entity).collect::<Result<Vec<User>, DomainError>>()
+            })
+        }).await
+    }
+    /// Назначает пользователя на задачу. Повторное назначение ничего не меняет.
+    ///
+    /// Первый назначенный пользователь становится ответственным исполнителем (`assignee_id`);
+    /// `primary` передаёт ему эту роль и при наличии других исполнителей. Версия задачи
+    /// увеличивается, только если меняется ответственный исполнитель.
+    ///
+    /// # Аргументы
+    /// - `task_id` - Идентификатор задачи.
+    /// - `user_id` - Идентификатор пользователя.
+    /// - `primary` - Сделать пользователя ответственным исполнителем.
+    /// - `expected_version` - Ожидаемая версия задачи; `None` - без проверки.
+    ///
+    /// # Возвращает
+    /// - `Ok(Task)` задачу после назначения.
+    /// - `Err(DomainError::NotFound)` если задача или пользователь не найдены.
+    /// - `Err(DomainError::PreconditionFailed)` если версия задачи не совпадает с ожидаемой.
+    /// - `Err(DomainError)` если произошла ошибка при выполнении запроса.
+    async fn assign_user(&self, task_id: i32, user_id: i32, primary: bool, expected_version: Option<i32>) -> Result<Task, DomainError> {
+        let ctx = self.write_context();
+        let workspace = ctx.workspace;
+        self.db_connection.run(move |conn| {
+            conn.immediate_transaction(|conn| {
+                ensure_version(conn, workspace, task_id, expected_version)?;
+                find_user(conn, user_id)?;
+                diesel::insert_or_ignore_into(task_assignees::table)
+                    .values(TaskAssigneeEntity { task_id, user_id, workspace_id: workspace.id })
+                    .execute(conn)?;
+
+                let task = find_task(conn, workspace, task_id)?;
+                match task.assignee_id {
+                    None => set_primary_assignee(conn, &ctx, task, Some(user_id)),
+                    Some(current) if primary && current != user_id => set_primary_assignee(conn, &ctx, task, Some(user_id)),
+                    Some(_) => Ok(task),
+                }
+            })
+        }).await
+    }
+    /// Снимает пользователя с задачи. Если он был ответственным исполнителем, эта роль переходит
+    /// к оставшемуся исполнителю с наименьшим идентификатором, а версия задачи увеличивается.
+    ///
+    /// # Аргументы
+    /// - `task_id` - Идентификатор задачи.
+    /// - `user_id` - Идентификатор пользователя.
+    /// - `expected_version` - Ожидаемая версия задачи; `None` - без проверки.
+    ///
+    /// # Возвращает
+    /// - `Ok(Task)` задачу после снятия исполнителя.
+    /// - `Err(DomainError::NotFound)` если задача или пользователь не найдены, либо пользователь не назначен на задачу.
+    /// - `Err(DomainError::PreconditionFailed)` если версия задачи не совпадает с ожидаемой.
+    /// - `Err(DomainError)` если произошла ошибка при выполнении запроса.
+    async fn unassign_user(&self, task_id: i32, user_id: i32, expected_version: Option<i32>) -> Result<Task, DomainError> {
+        let ctx = self.write_context();
+        let workspace = ctx.workspace;
+        self.db_connection.run(move |conn| {
+            conn.immediate_transaction(|conn| {
+                ensure_version(conn, workspace, task_id, expected_version)?;
+                find_user(conn, user_id)?;
+                let removed = delete(task_assignees::table.find((task_id, user_id))).execute(conn)?;
+                if removed == 0 {
+                    return Err(User::not_assigned(task_id, user_id));
+                }
+
+                let task = find_task(conn, workspace, task_id)?;
+                if task.assignee_id != Some(user_id) {
+                    return Ok(task);
+                }
+                let next = task_assignees::table
+                    .filter(task_assignees::task_id.eq(task_id))
+                    .select(task_assignees::user_id)
+                    .order(task_assignees::user_id.asc())
+                    .first::<i32>(conn)
+                    .optional()?;
+                set_primary_assignee(conn, &ctx, task, next)
+            })
+        }).await
+    }
+}
+
+/// Загружает пользователя по идентификатору, возвращая `DomainError::NotFound`, если его нет.
+pub(crate) fn find_user(conn: &mut SqliteConnection, user_id: i32) -> Result<User, DomainError> {
+    users::table.find(user_id)
+        .first::<UserEntity>(conn)
+        .optional()?
+        .map(UserDbMapper::to_entity)
+        .transpose()?
+        .ok_or_else(|| User::not_found(user_id))
+}
+
+/// Меняет ответственного исполнителя задачи `current`, увеличивает её версию и записывает изменение
+/// в историю задачи. Вызывается внутри транзакции.
+fn set_primary_assignee(conn: &mut SqliteConnection, ctx: &WriteContext, current: Task, user_id: Option<i32>) -> Result<Task, DomainError> {
+    update(tasks::table.find(current.id).filter(tasks::workspace_id.eq(ctx.workspace.id)))
+        .set((tasks::assignee_id.eq(user_id), tasks::version.eq(tasks::version + 1)))
+        .execute(conn)?;
+    let task = find_task(conn, ctx.workspace, current.id)?;
+    record_event(conn, ctx, current.id, TaskEventAction::Updated, Some(&current), Some(&task))?;
+    Ok(task)
+}
+
+/// Переводит нарушение уникальности адреса почты в понятный клиенту конфликт.
+fn duplicate_email(error: DieselError, email: &str) -> DomainError {
+    match error {
+        DieselError::DatabaseError(DatabaseErrorKind::UniqueViolation, _) => {
+            DomainError::Conflict(format!("User with email '{}' already exists", email))
+        },
+        error => DomainError::from(error),
+    }
+}
@@ -1 +1,7 @@
-pub mod db_repo_tasks;
\ No newline at end of file
+pub mod db_repo_tasks;
+pub mod db_repo_idempotency;
+pub mod db_repo_tags;
+pub mod db_repo_projects;
+pub mod db_repo_dependencies;
+pub mod db_repo_users;
+pub mod db_repo_api_keys;
@@ -5,5 +5,135 @@ diesel::table! {
         id -> Integer,
         title -> Text,
         description -> Nullable<Text>,
+        status -> Text,
+        version -> Integer,
+        priority -> Text,
+        start_at -> Nullable<Timestamp>,
+        due_at -> Nullable<Timestamp>,
+        project_id -> Nullable<Integer>,
+        parent_id -> Nullable<Integer>,
+        estimate_minutes -> Nullable<Integer>,
+        assignee_id -> Nullable<Integer>,
+        workspace_id -> Integer,
+        deleted_at -> Nullable<Timestamp>,
     }
 }
+
+diesel::table! {
+    idempotency_keys (idempotency_key) {
+        idempotency_key -> Text,
+        fingerprint -> Text,
+        status_code -> Nullable<Integer>,
+        headers -> Nullable<Text>,
+        body -> Nullable<Binary>,
+        created_at -> BigInt,
+        expires_at -> BigInt,
+        reservation_id -> Text,
+    }
+}
+
+diesel::table! {
+    project_members (project_id, user_id) {
+        project_id -> Integer,
+        user_id -> Integer,
+        role -> Text,
+    }
+}
+
+diesel::table! {
+    projects (id) {
+        id -> Integer,
+        name -> Text,
+        description -> Nullable<Text>,
+        archived_at -> Nullable<Timestamp>,
+        workspace_id -> Integer,
+    }
+}
+
+diesel::table! {
+    tags (id) {
+        id -> Integer,
+        name -> Text,
+        workspace_id -> Integer,
+    }
+}
+
+diesel::table! {
+    task_dependencies (task_id, blocker_id) {
+        task_id -> Integer,
+        blocker_id -> Integer,
+        workspace_id -> Integer,
+    }
+}
+
+diesel::table! {
+    task_assignees (task_id, user_id) {
+        task_id -> Integer,
+        user_id -> Integer,
+        workspace_id -> Integer,
+    }
+}
+
+diesel::table! {
+    task_tags (task_id, tag_id) {
+        task_id -> Integer,
+        tag_id -> Integer,
+        workspace_id -> Integer,
+    }
+}
+
+diesel::table! {
+    users (id) {
+        id -> Integer,
+        name -> Text,
+        email -> Text,
+        role -> Text,
+    }
+}
+
+diesel::table! {
+    api_keys (id) {
+        id -> Integer,
+        name -> Text,
+        prefix -> Text,
+        key_hash -> Text,
+        scopes -> Text,
+        created_at -> Timestamp,
+        last_used_at -> Nullable<Timestamp>,
+        revoked_at -> Nullable<Timestamp>,
+        workspace_id -> Integer,
+    }
+}
+
+diesel::table! {
+    task_events (id) {
+        id -> Integer,
+        task_id -> Integer,
+        workspace_id -> Integer,
+        action -> Text,
+        actor -> Text,
+        occurred_at -> Timestamp,
+        changes -> Text,
+    }
+}
+
+diesel::joinable!(project_members -> projects (project_id));
+diesel::joinable!(project_members -> users (user_id));
+diesel::joinable!(task_assignees -> users (user_id));
+diesel::joinable!(task_tags -> tags (tag_id));
+diesel::joinable!(task_tags -> tasks (task_id));
+diesel::joinable!(tasks -> projects (project_id));
+
+diesel::allow_tables_to_appear_in_same_query!(
+    api_keys,
+    idempotency_keys,
+    project_members,
+    projects,
+    tags,
+    task_assignees,
+    task_dependencies,
+    task_events,
+    task_tags,
+    tasks,
+    users,
+);
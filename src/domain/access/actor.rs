@@ -0,0 +1,49 @@
+use crate::domain::access::api_key::ApiKeyScopes;
+use crate::domain::principal::Principal;
+use crate::domain::task::task_placement::TaskPlacement;
+
+/// Тот, от чьего имени выполняется операция.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Actor {
+    /// Аутентификация отключена: права не проверяются.
+    System,
+    /// Пользователь TaskManager с идентификатором из токена.
+    User(i32),
+    /// Сервисная учётная запись с ключом API: права определяются правами ключа, а не ролью.
+    Service(ApiKeyScopes),
+    /// Аутентифицированный клиент, не сопоставленный ни одному пользователю; прав не имеет.
+    Guest,
+}
+
+impl From<&Principal> for Actor {
+    fn from(principal: &Principal) -> Self {
+        principal.user_id().map(Actor::User).unwrap_or(Actor::Guest)
+    }
+}
+
+/// Область, для которой определяется роль пользователя.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AccessScope {
+    /// Задачи вне проектов и списки задач: действует глобальная роль.
+    Global,
+    /// Проект: глобальная роль или роль участника проекта, если она выше.
+    Project(i32),
+    /// Существующая задача: область её проекта или `Global`, если задача вне проекта.
+    Task(i32),
+}
+
+impl AccessScope {
+    /// Область, в которую попадёт новая задача: подзадача - в область родителя, иначе - в область проекта.
+    pub fn for_placement(placement: TaskPlacement) -> Self {
+        match placement {
+            TaskPlacement { parent_id: Some(parent_id), .. } => AccessScope::Task(parent_id),
+            TaskPlacement { project_id: Some(project_id), .. } => AccessScope::Project(project_id),
+            _ => AccessScope::Global,
+        }
+    }
+
+    /// Область задач проекта `project_id` или, без проекта, всех задач.
+    pub fn for_project(project_id: Option<i32>) -> Self {
+        project_id.map(AccessScope::Project).unwrap_or(AccessScope::Global)
+    }
+}
@@ -0,0 +1,164 @@
+use std::fmt;
+use std::str::FromStr;
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use thiserror::Error;
+use utoipa::ToSchema;
+use crate::domain::access::role::Permission;
+use crate::domain::error::DomainError;
+
+/// Право, выданное ключу API сервисной учётной записи.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize, ToSchema)]
+pub enum ApiKeyScope {
+    /// Чтение задач.
+    #[serde(rename = "tasks:read")]
+    ReadTasks,
+    /// Создание, изменение и удаление задач, а также проектов, меток, зависимостей и назначений исполнителей.
+    #[serde(rename = "tasks:write")]
+    WriteTasks,
+}
+
+/// Ошибка разбора права ключа из строкового представления.
+#[derive(Error, Debug, Clone, PartialEq, Eq)]
+#[error("Unknown API key scope '{0}'")]
+pub struct UnknownApiKeyScope(pub String);
+
+impl ApiKeyScope {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            ApiKeyScope::ReadTasks => "tasks:read",
+            ApiKeyScope::WriteTasks => "tasks:write",
+        }
+    }
+
+    /// Разрешает ли право действие `permission`; управлять ролями, пользователями и архивом проектов ключи не могут.
+    pub fn grants(&self, permission: Permission) -> bool {
+        match self {
+            ApiKeyScope::ReadTasks => permission == Permission::Read,
+            ApiKeyScope::WriteTasks => matches!(permission, Permission::Write | Permission::Delete),
+        }
+    }
+}
+
+impl fmt::Display for ApiKeyScope {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl FromStr for ApiKeyScope {
+    type Err = UnknownApiKeyScope;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "tasks:read" => Ok(ApiKeyScope::ReadTasks),
+            "tasks:write" => Ok(ApiKeyScope::WriteTasks),
+            other => Err(UnknownApiKeyScope(other.to_string())),
+        }
+    }
+}
+
+/// Набор прав ключа API; копируется вместе с `Actor`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct ApiKeyScopes {
+    read_tasks: bool,
+    write_tasks: bool,
+}
+
+impl ApiKeyScopes {
+    pub fn contains(&self, scope: ApiKeyScope) -> bool {
+        match scope {
+            ApiKeyScope::ReadTasks => self.read_tasks,
+            ApiKeyScope::WriteTasks => self.write_tasks,
+        }
+    }
+
+    /// Разрешает ли хотя бы одно из прав действие `permission`.
+    pub fn grants(&self, permission: Permission) -> bool {
+        [ApiKeyScope::ReadTasks, ApiKeyScope::WriteTasks].into_iter()
+            .any(|scope| self.contains(scope) && scope.grants(permission))
+    }
+}
+
+impl FromIterator<ApiKeyScope> for ApiKeyScopes {
+    fn from_iter<I: IntoIterator<Item = ApiKeyScope>>(scopes: I) -> Self {
+        scopes.into_iter().fold(ApiKeyScopes::default(), |mut set, scope| {
+            match scope {
+                ApiKeyScope::ReadTasks => set.read_tasks = true,
+                ApiKeyScope::WriteTasks => set.write_tasks = true,
+            }
+            set
+        })
+    }
+}
+
+/// Ключ API сервисной учётной записи; сам ключ не хранится, только его хеш.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, ToSchema)]
+pub struct ApiKey {
+    pub id: i32,
+    /// Название учётной записи, например `ci-bot`.
+    #[schema(example = "ci-bot")]
+    pub name: String,
+    /// Начало ключа, по которому его можно узнать в списке.
+    #[schema(example = "tm_3q2x9Lk")]
+    pub prefix: String,
+    pub scopes: Vec<ApiKeyScope>,
+    pub created_at: DateTime<Utc>,
+    /// Момент последнего успешного запроса с ключом.
+    pub last_used_at: Option<DateTime<Utc>>,
+    /// Момент отзыва; отозванный ключ больше не принимается.
+    pub revoked_at: Option<DateTime<Utc>>,
+    /// Рабочее пространство, в котором выпущен ключ и к данным которого он даёт доступ.
+    #[schema(example = 1)]
+    pub workspace_id: i32,
+}
+
+impl ApiKey {
+    pub fn scope_set(&self) -> ApiKeyScopes {
+        self.scopes.iter().copied().collect()
+    }
+
+    /// Ошибка обращения к несуществующему ключу `key_id`.
+    pub fn not_found(key_id: i32) -> DomainError {
+        DomainError::NotFound(format!("API key {} not found", key_id))
+    }
+}
+
+/// Только что выпущенный ключ: значение `key` возвращается один раз и больше нигде не хранится.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, ToSchema)]
+pub struct MintedApiKey {
+    #[serde(flatten)]
+    pub api_key: ApiKey,
+    /// Значение для заголовка `X-Api-Key`.
+    #[schema(example = "tm_3q2x9LkVb0mZ8WcJ1nQe4RtYuI7oPaSdFgHjKl5zXc")]
+    pub key: String,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_should_round_trip_scope_through_string() {
+        for scope in [ApiKeyScope::ReadTasks, ApiKeyScope::WriteTasks] {
+            assert_eq!(scope.to_string().parse::<ApiKeyScope>(), Ok(scope));
+        }
+        assert!("tasks:admin".parse::<ApiKeyScope>().is_err());
+    }
+
+    #[test]
+    fn test_should_grant_permissions_by_scopes() {
+        let granted = |scopes: &[ApiKeyScope]| {
+            let set = scopes.iter().copied().collect::<ApiKeyScopes>();
+            [Permission::Read, Permission::Write, Permission::Delete, Permission::Manage]
+                .into_iter()
+                .map(|permission| set.grants(permission))
+                .collect::<Vec<_>>()
+        };
+
+        assert_eq!(granted(&[]), vec![false, false, false, false]);
+        assert_eq!(granted(&[ApiKeyScope::ReadTasks]), vec![true, false, false, false]);
+        assert_eq!(granted(&[ApiKeyScope::WriteTasks]), vec![false, true, true, false]);
+        assert_eq!(granted(&[ApiKeyScope::ReadTasks, ApiKeyScope::WriteTasks]), vec![true, true, true, false]);
+    }
+}
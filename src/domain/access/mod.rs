@@ -0,0 +1,3 @@
+pub mod role;
+pub mod actor;
+pub mod api_key;
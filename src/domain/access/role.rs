@@ -0,0 +1,102 @@
+use std::fmt;
+use std::str::FromStr;
+use serde::{Deserialize, Serialize};
+use thiserror::Error;
+use utoipa::ToSchema;
+
+/// Роль пользователя, от наименьших прав к наибольшим.
+///
+/// Глобальная роль пользователя действует для задач вне проектов и как минимальная роль в любом проекте;
+/// участие в проекте может её повысить, но не понизить.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Default, Serialize, Deserialize, ToSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum Role {
+    /// Просмотр задач.
+    #[default]
+    Viewer,
+    /// Просмотр, создание и изменение задач.
+    Editor,
+    /// Любые действия, включая удаление задач и управление участниками.
+    Admin,
+}
+
+/// Действие, которое проверяется перед выполнением операции.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Permission {
+    /// Чтение задач.
+    Read,
+    /// Создание, изменение, смена статуса и перемещение задач.
+    Write,
+    /// Удаление задач.
+    Delete,
+    /// Назначение ролей и участников проекта.
+    Manage,
+}
+
+/// Ошибка разбора роли из строкового представления.
+#[derive(Error, Debug, Clone, PartialEq, Eq)]
+#[error("Unknown role '{0}'")]
+pub struct UnknownRole(pub String);
+
+impl Role {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Role::Viewer => "viewer",
+            Role::Editor => "editor",
+            Role::Admin => "admin",
+        }
+    }
+
+    /// Разрешает ли роль действие `permission`.
+    pub fn grants(&self, permission: Permission) -> bool {
+        match permission {
+            Permission::Read => true,
+            Permission::Write => *self >= Role::Editor,
+            Permission::Delete | Permission::Manage => *self == Role::Admin,
+        }
+    }
+}
+
+impl fmt::Display for Role {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl FromStr for Role {
+    type Err = UnknownRole;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "viewer" => Ok(Role::Viewer),
+            "editor" => Ok(Role::Editor),
+            "admin" => Ok(Role::Admin),
+            other => Err(UnknownRole(other.to_string())),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_should_round_trip_role_through_string() {
+        for role in [Role::Viewer, Role::Editor, Role::Admin] {
+            assert_eq!(role.to_string().parse::<Role>(), Ok(role));
+        }
+        assert!("owner".parse::<Role>().is_err());
+    }
+
+    #[test]
+    fn test_should_grant_permissions_by_role() {
+        let granted = |role: Role| [Permission::Read, Permission::Write, Permission::Delete, Permission::Manage]
+            .into_iter()
+            .map(|permission| role.grants(permission))
+            .collect::<Vec<_>>();
+
+        assert_eq!(granted(Role::Viewer), vec![true, false, false, false]);
+        assert_eq!(granted(Role::Editor), vec![true, true, false, false]);
+        assert_eq!(granted(Role::Admin), vec![true, true, true, true]);
+    }
+}
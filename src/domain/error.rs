@@ -1,10 +1,31 @@
 use std::{error::Error, fmt};
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
 
 #[derive(Debug)]
 pub struct ApiError {
     pub code: u16,
     pub message: String,
     pub error: Option<Box<dyn Error>>,
+    /// Ошибки отдельных полей запроса; пусто, если ошибка не связана с конкретными полями.
+    pub errors: Vec<FieldError>,
+}
+
+/// Ошибка проверки одного поля входных данных.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, ToSchema)]
+pub struct FieldError {
+    /// Имя поля, например `title`.
+    pub field: String,
+    /// Машиночитаемый код нарушенного правила, например `too_long`.
+    pub code: String,
+    /// Описание ошибки для человека.
+    pub message: String,
+}
+
+impl FieldError {
+    pub fn new(field: &str, code: &str, message: &str) -> Self {
+        FieldError { field: field.to_string(), code: code.to_string(), message: message.to_string() }
+    }
 }
 
 impl fmt::Display for ApiError {
@@ -22,3 +43,42 @@ impl ApiError {
         self.code
     }
 }
+
+/// Ошибка доменного уровня, которую возвращают репозитории.
+///
+/// Каждый вариант соответствует своему HTTP-статусу: `NotFound` - 404, `Conflict` - 409,
+/// `PreconditionFailed` - 412, `Validation` - 422, `Unavailable` - 503, `Internal` - 500.
+#[derive(thiserror::Error, Debug, Clone, PartialEq, Eq)]
+pub enum DomainError {
+    /// Запрошенная сущность не существует.
+    #[error("{0}")]
+    NotFound(String),
+    /// Операция противоречит текущему состоянию данных.
+    #[error("{0}")]
+    Conflict(String),
+    /// Версия сущности не совпала с ожидаемой клиентом.
+    #[error("{0}")]
+    PreconditionFailed(String),
+    /// Данные не удовлетворяют ограничениям предметной области.
+    #[error("{0}")]
+    Validation(String),
+    /// Хранилище временно не может обслужить запрос (например, исчерпан пул соединений).
+    #[error("Storage is temporarily unavailable: {0}")]
+    Unavailable(String),
+    /// Непредвиденная ошибка; подробности не показываются клиенту.
+    #[error("Internal error: {0}")]
+    Internal(String),
+}
+
+impl DomainError {
+    /// Превращает `PreconditionFailed` в `Conflict`.
+    ///
+    /// Используется, когда версию для проверки выбрало само приложение, а не клиент заголовком `If-Match`:
+    /// несовпадение означает, что задачу параллельно изменил другой запрос.
+    pub fn precondition_as_conflict(self) -> DomainError {
+        match self {
+            DomainError::PreconditionFailed(message) => DomainError::Conflict(format!("{}; the task was modified concurrently, please retry", message)),
+            error => error,
+        }
+    }
+}
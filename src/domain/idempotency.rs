@@ -0,0 +1,27 @@
+/// Ответ, сохранённый для повторной выдачи на запрос с тем же ключом идемпотентности.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct StoredResponse {
+    pub status: u16,
+    /// Заголовки ответа в исходном порядке.
+    pub headers: Vec<(String, String)>,
+    pub body: Vec<u8>,
+}
+
+/// Сведения о ранее использованном ключе идемпотентности.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct IdempotencyRecord {
+    /// Отпечаток запроса, впервые выполненного с этим ключом.
+    pub fingerprint: String,
+    /// Сохранённый ответ; `None`, пока первый запрос ещё выполняется.
+    pub response: Option<StoredResponse>,
+}
+
+/// Результат попытки занять ключ идемпотентности.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum IdempotencyReservation {
+    /// Ключ занят за этим запросом; идентификатор резервирования подтверждает владение ключом
+    /// при сохранении ответа и освобождении.
+    Reserved(String),
+    /// Ключ уже использован другим запросом.
+    Used(IdempotencyRecord),
+}
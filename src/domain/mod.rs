@@ -1,3 +1,10 @@
 pub mod task;
+pub mod tag;
+pub mod project;
+pub mod user;
 pub mod error;
+pub mod idempotency;
+pub mod principal;
+pub mod access;
+pub mod workspace;
 
@@ -0,0 +1,28 @@
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+
+/// Аутентифицированный клиент, от имени которого выполняется запрос.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, ToSchema)]
+pub struct Principal {
+    /// Идентификатор клиента из утверждения `sub` токена.
+    #[schema(example = "42")]
+    pub subject: String,
+    /// Отображаемое имя из утверждения `name`, если оно есть в токене.
+    #[schema(example = "Ada Lovelace")]
+    pub name: Option<String>,
+    /// Рабочее пространство из утверждения `workspace_id`; без него клиент работает в пространстве по умолчанию.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[schema(example = 2)]
+    pub workspace_id: Option<i32>,
+}
+
+impl Principal {
+    pub fn new(subject: &str) -> Self {
+        Principal { subject: subject.to_string(), name: None, workspace_id: None }
+    }
+
+    /// Идентификатор пользователя TaskManager, если `subject` - его числовой идентификатор.
+    pub fn user_id(&self) -> Option<i32> {
+        self.subject.parse().ok()
+    }
+}
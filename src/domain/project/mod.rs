@@ -0,0 +1,3 @@
+#[allow(clippy::module_inception)]
+pub mod project;
+pub mod project_member;
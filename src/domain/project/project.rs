@@ -0,0 +1,47 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+use crate::domain::error::DomainError;
+
+/// Проект, объединяющий задачи.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, ToSchema)]
+pub struct Project {
+    pub id: i32,
+    pub name: String,
+    pub description: Option<String>,
+    /// Момент отправки проекта в архив; в архивный проект нельзя добавлять задачи.
+    pub archived_at: Option<DateTime<Utc>>,
+}
+
+impl Project {
+    pub fn new(id: i32, name: String, description: Option<String>) -> Self {
+        Project { id, name, description, archived_at: None }
+    }
+
+    pub fn is_archived(&self) -> bool {
+        self.archived_at.is_some()
+    }
+
+    /// Ошибка обращения к несуществующему проекту `project_id`.
+    pub fn not_found(project_id: i32) -> DomainError {
+        DomainError::NotFound(format!("Project {} not found", project_id))
+    }
+
+    /// Ошибка добавления задачи в архивный проект `project_id`.
+    pub fn archived(project_id: i32) -> DomainError {
+        DomainError::Conflict(format!("Project {} is archived", project_id))
+    }
+}
+
+/// Что происходит с задачами проекта при его удалении.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize, ToSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum ProjectTasksPolicy {
+    /// Проект с задачами не удаляется.
+    #[default]
+    Restrict,
+    /// Задачи перемещаются в корзину вместе с удалением проекта.
+    Cascade,
+    /// Задачи остаются без проекта.
+    Detach,
+}
@@ -0,0 +1,23 @@
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+use crate::domain::access::role::Role;
+use crate::domain::error::DomainError;
+
+/// Участник проекта и его роль в задачах этого проекта.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, ToSchema)]
+pub struct ProjectMember {
+    pub project_id: i32,
+    pub user_id: i32,
+    pub role: Role,
+}
+
+impl ProjectMember {
+    pub fn new(project_id: i32, user_id: i32, role: Role) -> Self {
+        ProjectMember { project_id, user_id, role }
+    }
+
+    /// Ошибка обращения к пользователю `user_id`, который не участвует в проекте `project_id`.
+    pub fn not_member(project_id: i32, user_id: i32) -> DomainError {
+        DomainError::NotFound(format!("User {} is not a member of project {}", user_id, project_id))
+    }
+}
@@ -0,0 +1,23 @@
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+use crate::domain::error::DomainError;
+
+/// Метка, которой можно отметить любое количество задач.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, ToSchema)]
+pub struct Tag {
+    pub id: i32,
+    /// Имя метки, уникальное без учёта регистра.
+    #[schema(example = "backend")]
+    pub name: String,
+}
+
+impl Tag {
+    pub fn new(id: i32, name: String) -> Self {
+        Tag { id, name }
+    }
+
+    /// Ошибка обращения к несуществующей метке `tag_id`.
+    pub fn not_found(tag_id: i32) -> DomainError {
+        DomainError::NotFound(format!("Tag {} not found", tag_id))
+    }
+}
@@ -1 +1,15 @@
+#[allow(clippy::module_inception)]
 pub mod task;
+pub mod task_status;
+pub mod task_query;
+pub mod task_search;
+pub mod task_patch;
+pub mod task_bulk;
+pub mod task_priority;
+pub mod task_schedule;
+pub mod task_due;
+pub mod task_placement;
+pub mod task_tree;
+pub mod task_dependency;
+pub mod task_event;
+pub mod task_trash;
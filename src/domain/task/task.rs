@@ -1,18 +1,71 @@
+use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
 use utoipa::ToSchema;
+use crate::domain::error::DomainError;
+use crate::domain::task::task_priority::TaskPriority;
+use crate::domain::task::task_status::TaskStatus;
 
-#[derive(Debug, Clone,Serialize,Deserialize, ToSchema)]
+#[derive(Debug, Clone, PartialEq, Serialize,Deserialize, ToSchema)]
 pub struct Task
 {
     pub id: i32,
     pub title: String,
-    pub description: Option<String>
+    pub description: Option<String>,
+    pub status: TaskStatus,
+    /// Версия задачи, увеличивается при каждом изменении. Передаётся клиентам в заголовке `ETag`.
+    pub version: i32,
+    pub priority: TaskPriority,
+    /// Момент, не раньше которого планируется начать работу над задачей.
+    pub start_at: Option<DateTime<Utc>>,
+    /// Срок выполнения задачи.
+    pub due_at: Option<DateTime<Utc>>,
+    /// Проект, к которому относится задача.
+    pub project_id: Option<i32>,
+    /// Родительская задача; задачи без родителя - корни деревьев задач.
+    pub parent_id: Option<i32>,
+    /// Оценка трудоёмкости задачи в минутах.
+    pub estimate_minutes: Option<i32>,
+    /// Ответственный исполнитель задачи; остальные исполнители доступны через `GET /api/tasks/{id}/assignees`.
+    pub assignee_id: Option<i32>,
+    /// Момент перемещения задачи в корзину; задаётся только у задач из корзины.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub deleted_at: Option<DateTime<Utc>>
 }
 
 impl Task
 {
     pub fn new(id: i32, title: String, description: Option<String>) -> Self
     {
-        Task {id,title,description}
+        Task {id,title,description,status: TaskStatus::default(),version: 1,priority: TaskPriority::default(),start_at: None,due_at: None,project_id: None,parent_id: None,estimate_minutes: None,assignee_id: None,deleted_at: None}
+    }
+
+    /// Возвращает `true`, если срок задачи истёк к моменту `now`, а задача не выполнена.
+    pub fn is_overdue(&self, now: DateTime<Utc>) -> bool
+    {
+        self.status != TaskStatus::Done && self.due_at.is_some_and(|due_at| due_at < now)
+    }
+
+    /// Проверяет, что задача имеет версию `expected_version`; `None` означает отсутствие условия.
+    ///
+    /// # Ошибки
+    /// Возвращает `DomainError::PreconditionFailed`, если версии не совпадают.
+    pub fn ensure_version(&self, expected_version: Option<i32>) -> Result<(), DomainError>
+    {
+        match expected_version {
+            Some(expected_version) if expected_version != self.version => Err(Task::version_mismatch(self.id, self.version, expected_version)),
+            _ => Ok(()),
+        }
+    }
+
+    /// Ошибка обращения к несуществующей задаче `task_id`.
+    pub fn not_found(task_id: i32) -> DomainError
+    {
+        DomainError::NotFound(format!("Task {} not found", task_id))
+    }
+
+    /// Ошибка несовпадения версии задачи `task_id` с ожидаемой клиентом.
+    pub fn version_mismatch(task_id: i32, current_version: i32, expected_version: i32) -> DomainError
+    {
+        DomainError::PreconditionFailed(format!("Task {} has version {}, but version {} was expected", task_id, current_version, expected_version))
     }
 }
\ No newline at end of file
@@ -0,0 +1,51 @@
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+use crate::domain::task::task::Task;
+use crate::domain::task::task_schedule::TaskSchedule;
+use crate::domain::task::task_status::TaskStatus;
+
+/// Максимальное количество операций в одном пакете.
+pub const MAX_BULK_OPERATIONS: usize = 5000;
+
+/// Режим выполнения пакета операций над задачами.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize, ToSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum BulkMode {
+    /// Первая неудачная операция отменяет весь пакет.
+    #[default]
+    AllOrNothing,
+    /// Неудачные операции пропускаются, остальные сохраняются.
+    BestEffort,
+}
+
+/// Операция пакетного изменения задач.
+#[derive(Debug, Clone, PartialEq)]
+pub enum BulkOperation {
+    Create {
+        title: String,
+        description: Option<String>,
+        status: TaskStatus,
+        schedule: TaskSchedule,
+    },
+    /// Полная замена заголовка и описания; `status: None` оставляет статус без изменений.
+    Update {
+        task_id: i32,
+        title: String,
+        description: Option<String>,
+        status: Option<TaskStatus>,
+        schedule: TaskSchedule,
+        expected_version: Option<i32>,
+    },
+    Delete {
+        task_id: i32,
+        expected_version: Option<i32>,
+    },
+}
+
+/// Результат успешно выполненной операции пакета.
+#[derive(Debug, Clone, PartialEq)]
+pub enum BulkOutcome {
+    Created(Task),
+    Updated(Task),
+    Deleted(i32),
+}
@@ -0,0 +1,158 @@
+use std::cmp::Reverse;
+use std::collections::{BTreeSet, HashMap};
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+use crate::domain::error::DomainError;
+use crate::domain::task::task::Task;
+
+/// Зависимость между задачами: задача `task_id` заблокирована задачей `blocker_id`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, ToSchema)]
+pub struct TaskDependency {
+    pub task_id: i32,
+    pub blocker_id: i32,
+}
+
+/// Критический путь проекта - самая трудоёмкая цепочка задач, каждая из которых блокирует следующую.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, ToSchema)]
+pub struct CriticalPath {
+    /// Задачи пути в порядке выполнения; пусто, если в проекте нет задач.
+    pub tasks: Vec<Task>,
+    /// Суммарная оценка задач пути в минутах.
+    pub total_estimate_minutes: i64,
+    /// Задачи пути без оценки; они учитываются с нулевой трудоёмкостью.
+    pub unestimated_task_ids: Vec<i32>,
+}
+
+impl CriticalPath {
+    /// Находит критический путь в графе зависимостей задач `tasks`.
+    ///
+    /// Зависимости, один из концов которых не входит в `tasks`, не учитываются. Из путей одинаковой
+    /// трудоёмкости выбирается заканчивающийся задачей с меньшим идентификатором, а среди блокирующих
+    /// задач одинаковой трудоёмкости - задача с меньшим идентификатором.
+    ///
+    /// # Ошибки
+    /// `DomainError::Internal`, если граф содержит цикл; добавление таких зависимостей запрещено.
+    pub fn compute(mut tasks: Vec<Task>, dependencies: &[TaskDependency]) -> Result<CriticalPath, DomainError> {
+        tasks.sort_by_key(|task| task.id);
+        let index = tasks.iter().enumerate().map(|(i, task)| (task.id, i)).collect::<HashMap<_, _>>();
+        let mut blockers = vec![Vec::new(); tasks.len()];
+        let mut dependents = vec![Vec::new(); tasks.len()];
+        for dependency in dependencies {
+            if let (Some(&task), Some(&blocker)) = (index.get(&dependency.task_id), index.get(&dependency.blocker_id)) {
+                blockers[task].push(blocker);
+                dependents[blocker].push(task);
+            }
+        }
+
+        // Алгоритм Кана: задача обрабатывается после всех своих блокирующих задач.
+        let mut pending = blockers.iter().map(Vec::len).collect::<Vec<_>>();
+        let mut ready = (0..tasks.len()).filter(|&i| pending[i] == 0).collect::<BTreeSet<_>>();
+        let mut finish = vec![0i64; tasks.len()];
+        let mut previous = vec![None; tasks.len()];
+        let mut processed = 0;
+        while let Some(current) = ready.pop_first() {
+            processed += 1;
+            let longest_blocker = blockers[current].iter().copied().max_by_key(|&blocker| (finish[blocker], Reverse(blocker)));
+            previous[current] = longest_blocker;
+            finish[current] = longest_blocker.map_or(0, |blocker| finish[blocker])
+                + i64::from(tasks[current].estimate_minutes.unwrap_or(0));
+            for &dependent in &dependents[current] {
+                pending[dependent] -= 1;
+                if pending[dependent] == 0 {
+                    ready.insert(dependent);
+                }
+            }
+        }
+        if processed < tasks.len() {
+            return Err(DomainError::Internal("Task dependency graph contains a cycle".to_string()));
+        }
+
+        let end = (0..tasks.len()).max_by_key(|&i| (finish[i], Reverse(i)));
+        let mut path = Vec::new();
+        let mut step = end;
+        while let Some(current) = step {
+            path.push(current);
+            step = previous[current];
+        }
+        path.reverse();
+
+        let path_tasks = path.into_iter().map(|i| tasks[i].clone()).collect::<Vec<_>>();
+        Ok(CriticalPath {
+            total_estimate_minutes: end.map_or(0, |end| finish[end]),
+            unestimated_task_ids: path_tasks.iter().filter(|task| task.estimate_minutes.is_none()).map(|task| task.id).collect(),
+            tasks: path_tasks,
+        })
+    }
+}
+
+/// Проверяет, что задачу `blocker_id` можно сделать блокирующей для задачи `task_id`.
+///
+/// # Аргументы
+/// - `task_id` - Блокируемая задача.
+/// - `blocker_id` - Блокирующая задача.
+/// - `blocker_blockers` - Все задачи, прямо или транзитивно блокирующие `blocker_id`.
+///
+/// # Ошибки
+/// `DomainError::Conflict`, если задача блокировала бы саму себя напрямую или через цепочку зависимостей.
+pub fn check_new_dependency(task_id: i32, blocker_id: i32, blocker_blockers: &[i32]) -> Result<(), DomainError> {
+    if task_id == blocker_id {
+        return Err(DomainError::Conflict(format!("Task {} cannot block itself", task_id)));
+    }
+    if blocker_blockers.contains(&task_id) {
+        return Err(DomainError::Conflict(format!(
+            "Task {} cannot be blocked by task {}: task {} already blocks it, directly or transitively",
+            task_id, blocker_id, task_id,
+        )));
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn task(id: i32, estimate_minutes: Option<i32>) -> Task {
+        Task { estimate_minutes, ..Task::new(id, format!("Task {}", id), None) }
+    }
+
+    fn blocked_by(task_id: i32, blocker_id: i32) -> TaskDependency {
+        TaskDependency { task_id, blocker_id }
+    }
+
+    #[test]
+    fn test_should_find_longest_chain_by_estimates() {
+        // 1 -> 2 -> 4 занимает 30 + 10 + 5 минут, 1 -> 3 -> 4 - 30 + 60 + 5 минут.
+        let tasks = vec![task(4, Some(5)), task(1, Some(30)), task(2, Some(10)), task(3, Some(60)), task(5, None)];
+        let dependencies = [blocked_by(2, 1), blocked_by(3, 1), blocked_by(4, 2), blocked_by(4, 3), blocked_by(4, 99)];
+
+        let path = CriticalPath::compute(tasks, &dependencies).unwrap();
+
+        assert_eq!(path.tasks.iter().map(|task| task.id).collect::<Vec<_>>(), vec![1, 3, 4]);
+        assert_eq!(path.total_estimate_minutes, 95);
+        assert!(path.unestimated_task_ids.is_empty());
+    }
+
+    #[test]
+    fn test_should_report_unestimated_tasks_and_empty_projects() {
+        let path = CriticalPath::compute(vec![task(1, None), task(2, Some(15))], &[blocked_by(2, 1)]).unwrap();
+        let empty = CriticalPath::compute(vec![], &[]).unwrap();
+
+        assert_eq!(path.unestimated_task_ids, vec![1]);
+        assert_eq!(path.total_estimate_minutes, 15);
+        assert!(empty.tasks.is_empty());
+    }
+
+    #[test]
+    fn test_should_fail_on_cycle() {
+        let result = CriticalPath::compute(vec![task(1, Some(1)), task(2, Some(1))], &[blocked_by(1, 2), blocked_by(2, 1)]);
+
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn test_should_reject_dependencies_that_close_a_cycle() {
+        assert!(check_new_dependency(1, 2, &[3]).is_ok());
+        assert!(check_new_dependency(1, 1, &[]).is_err());
+        assert!(check_new_dependency(1, 2, &[3, 1]).is_err());
+    }
+}
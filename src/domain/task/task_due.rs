@@ -0,0 +1,15 @@
+/// Горизонт выборки задач со сроком в ближайшие дни по умолчанию.
+pub const DEFAULT_DUE_WITHIN_DAYS: i64 = 7;
+/// Максимальный горизонт выборки задач со сроком в ближайшие дни.
+pub const MAX_DUE_WITHIN_DAYS: i64 = 365;
+
+/// Выборка открытых задач по срокам и приоритету.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TaskDueView {
+    /// Задачи, срок которых уже прошёл, начиная с самых просроченных.
+    Overdue,
+    /// Задачи со сроком в ближайшие `days` дней, начиная с ближайших.
+    DueWithin { days: i64 },
+    /// Все открытые задачи по срочности: просроченные, затем по убыванию приоритета и по сроку.
+    ByUrgency,
+}
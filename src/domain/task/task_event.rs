@@ -0,0 +1,204 @@
+use std::fmt;
+use std::str::FromStr;
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use thiserror::Error;
+use utoipa::ToSchema;
+use crate::domain::access::api_key::ApiKey;
+use crate::domain::principal::Principal;
+use crate::domain::task::task::Task;
+
+/// Поля, которые не попадают в историю: идентификатор не меняется, а версия меняется при каждой записи.
+const UNTRACKED_FIELDS: [&str; 2] = ["id", "version"];
+
+/// Вид изменения задачи.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, ToSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum TaskEventAction {
+    Created,
+    Updated,
+    /// Задача перемещена в корзину.
+    Deleted,
+    /// Задача возвращена из корзины.
+    Restored,
+    /// Задача окончательно удалена из корзины.
+    Purged,
+}
+
+/// Ошибка разбора вида изменения из строкового представления.
+#[derive(Error, Debug, Clone, PartialEq, Eq)]
+#[error("Unknown task event action '{0}'")]
+pub struct UnknownTaskEventAction(pub String);
+
+impl TaskEventAction {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            TaskEventAction::Created => "created",
+            TaskEventAction::Updated => "updated",
+            TaskEventAction::Deleted => "deleted",
+            TaskEventAction::Restored => "restored",
+            TaskEventAction::Purged => "purged",
+        }
+    }
+}
+
+impl fmt::Display for TaskEventAction {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl FromStr for TaskEventAction {
+    type Err = UnknownTaskEventAction;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "created" => Ok(TaskEventAction::Created),
+            "updated" => Ok(TaskEventAction::Updated),
+            "deleted" => Ok(TaskEventAction::Deleted),
+            "restored" => Ok(TaskEventAction::Restored),
+            "purged" => Ok(TaskEventAction::Purged),
+            other => Err(UnknownTaskEventAction(other.to_string())),
+        }
+    }
+}
+
+/// Автор изменения задачи: `user:<sub>` - клиент с токеном, `api_key:<id>` - сервисная учётная запись,
+/// `system` - запрос без аутентификации.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct EventActor(String);
+
+impl EventActor {
+    pub fn system() -> Self {
+        EventActor("system".to_string())
+    }
+
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+}
+
+impl Default for EventActor {
+    fn default() -> Self {
+        EventActor::system()
+    }
+}
+
+impl From<&Principal> for EventActor {
+    fn from(principal: &Principal) -> Self {
+        EventActor(format!("user:{}", principal.subject))
+    }
+}
+
+impl From<&ApiKey> for EventActor {
+    fn from(api_key: &ApiKey) -> Self {
+        EventActor(format!("api_key:{}", api_key.id))
+    }
+}
+
+/// Изменение одного поля задачи; `null` - поле не было или не стало заданным.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, ToSchema)]
+pub struct FieldChange {
+    /// Имя поля, как в представлении задачи.
+    #[schema(example = "status")]
+    pub field: String,
+    #[schema(value_type = Object, example = "todo")]
+    pub before: Value,
+    #[schema(value_type = Object, example = "in_progress")]
+    pub after: Value,
+}
+
+/// Запись истории задачи: кто, когда и как её изменил.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, ToSchema)]
+pub struct TaskEvent {
+    pub id: i32,
+    pub task_id: i32,
+    pub action: TaskEventAction,
+    /// Автор изменения, например `user:42` или `api_key:3`.
+    #[schema(example = "user:42")]
+    pub actor: String,
+    pub occurred_at: DateTime<Utc>,
+    /// Изменённые поля; при создании - заданные поля, при окончательном удалении - поля удалённой задачи.
+    pub changes: Vec<FieldChange>,
+}
+
+impl TaskEvent {
+    /// Сравнивает состояния задачи до и после изменения; `None` - задачи ещё нет или уже нет.
+    ///
+    /// # Возвращает
+    /// Изменения полей в порядке имён, без идентификатора и версии.
+    pub fn diff(before: Option<&Task>, after: Option<&Task>) -> Vec<FieldChange> {
+        let fields = |task: Option<&Task>| match task.map(serde_json::to_value) {
+            Some(Ok(Value::Object(fields))) => fields,
+            _ => serde_json::Map::new(),
+        };
+        let (before, after) = (fields(before), fields(after));
+
+        let mut names = before.keys().chain(after.keys())
+            .filter(|name| !UNTRACKED_FIELDS.contains(&name.as_str()))
+            .cloned()
+            .collect::<Vec<String>>();
+        names.sort();
+        names.dedup();
+
+        names.into_iter()
+            .map(|field| FieldChange {
+                before: before.get(&field).cloned().unwrap_or(Value::Null),
+                after: after.get(&field).cloned().unwrap_or(Value::Null),
+                field,
+            })
+            .filter(|change| change.before != change.after)
+            .collect()
+    }
+}
+
+/// Страница истории задачи, от ранних изменений к поздним.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, ToSchema)]
+pub struct TaskEventPage {
+    pub items: Vec<TaskEvent>,
+    /// Общее количество записей истории задачи.
+    pub total: i64,
+    pub limit: i64,
+    pub offset: i64,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+    use crate::domain::task::task_status::TaskStatus;
+
+    #[test]
+    fn test_should_round_trip_action_through_string() {
+        for action in [TaskEventAction::Created, TaskEventAction::Updated, TaskEventAction::Deleted, TaskEventAction::Restored, TaskEventAction::Purged] {
+            assert_eq!(action.to_string().parse::<TaskEventAction>(), Ok(action));
+        }
+        assert!("renamed".parse::<TaskEventAction>().is_err());
+    }
+
+    #[test]
+    fn test_should_diff_changed_fields_only() {
+        let before = Task::new(1, "Draft".to_string(), None);
+        let after = Task { title: "Final".to_string(), status: TaskStatus::InProgress, version: 2, ..before.clone() };
+
+        assert_eq!(TaskEvent::diff(Some(&before), Some(&after)), vec![
+            FieldChange { field: "status".to_string(), before: json!("todo"), after: json!("in_progress") },
+            FieldChange { field: "title".to_string(), before: json!("Draft"), after: json!("Final") },
+        ]);
+        assert!(TaskEvent::diff(Some(&before), Some(&before)).is_empty());
+    }
+
+    #[test]
+    fn test_should_diff_set_fields_on_creation_and_deletion() {
+        let task = Task::new(1, "Draft".to_string(), None);
+
+        let created = TaskEvent::diff(None, Some(&task));
+        assert_eq!(created.iter().map(|change| change.field.as_str()).collect::<Vec<_>>(), vec!["priority", "status", "title"]);
+        assert!(created.iter().all(|change| change.before.is_null()));
+
+        let deleted = TaskEvent::diff(Some(&task), None);
+        assert_eq!(deleted.len(), 3);
+        assert!(deleted.iter().all(|change| change.after.is_null()));
+    }
+}
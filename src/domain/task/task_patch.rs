@@ -0,0 +1,46 @@
+use chrono::{DateTime, Utc};
+use crate::domain::task::task_priority::TaskPriority;
+use crate::domain::task::task_schedule::TaskSchedule;
+use crate::domain::task::task_status::TaskStatus;
+
+/// Частичное изменение задачи. Поле со значением `None` не затрагивается.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct TaskPatch {
+    pub title: Option<String>,
+    /// `Some(None)` очищает описание, `None` оставляет его без изменений.
+    pub description: Option<Option<String>>,
+    pub status: Option<TaskStatus>,
+    pub priority: Option<TaskPriority>,
+    /// `Some(None)` очищает дату начала.
+    pub start_at: Option<Option<DateTime<Utc>>>,
+    /// `Some(None)` очищает срок выполнения.
+    pub due_at: Option<Option<DateTime<Utc>>>,
+    /// `Some(None)` убирает задачу из проекта.
+    pub project_id: Option<Option<i32>>,
+    /// `Some(None)` очищает оценку трудоёмкости.
+    pub estimate_minutes: Option<Option<i32>>,
+}
+
+impl TaskPatch {
+    /// Изменение, полностью заменяющее редактируемые поля задачи.
+    /// Статус и приоритет со значением `None` остаются прежними, отсутствующие даты и оценка очищаются.
+    pub fn replacement(title: String, description: Option<String>, status: Option<TaskStatus>, schedule: TaskSchedule) -> Self {
+        TaskPatch {
+            title: Some(title),
+            description: Some(description),
+            status,
+            priority: schedule.priority,
+            start_at: Some(schedule.start_at),
+            due_at: Some(schedule.due_at),
+            project_id: None,
+            estimate_minutes: Some(schedule.estimate_minutes),
+        }
+    }
+
+    /// Возвращает `true`, если изменение не затрагивает ни одного поля.
+    pub fn is_empty(&self) -> bool {
+        self.title.is_none() && self.description.is_none() && self.status.is_none()
+            && self.priority.is_none() && self.start_at.is_none() && self.due_at.is_none()
+            && self.project_id.is_none() && self.estimate_minutes.is_none()
+    }
+}
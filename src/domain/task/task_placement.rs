@@ -0,0 +1,18 @@
+/// Место новой задачи среди проектов и деревьев задач.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct TaskPlacement {
+    /// Проект задачи; подзадача без явно указанного проекта попадает в проект родительской задачи.
+    pub project_id: Option<i32>,
+    /// Родительская задача.
+    pub parent_id: Option<i32>,
+}
+
+impl TaskPlacement {
+    pub fn in_project(project_id: i32) -> Self {
+        TaskPlacement { project_id: Some(project_id), parent_id: None }
+    }
+
+    pub fn under(parent_id: i32) -> Self {
+        TaskPlacement { project_id: None, parent_id: Some(parent_id) }
+    }
+}
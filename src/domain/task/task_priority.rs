@@ -0,0 +1,71 @@
+use std::fmt;
+use std::str::FromStr;
+use serde::{Deserialize, Serialize};
+use thiserror::Error;
+use utoipa::ToSchema;
+
+/// Приоритет задачи, от наименьшего к наибольшему.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Default, Serialize, Deserialize, ToSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum TaskPriority {
+    Low,
+    #[default]
+    Medium,
+    High,
+    Urgent,
+}
+
+/// Ошибка разбора приоритета из строкового представления.
+#[derive(Error, Debug, Clone, PartialEq, Eq)]
+#[error("Unknown task priority '{0}'")]
+pub struct UnknownPriority(pub String);
+
+impl TaskPriority {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            TaskPriority::Low => "low",
+            TaskPriority::Medium => "medium",
+            TaskPriority::High => "high",
+            TaskPriority::Urgent => "urgent",
+        }
+    }
+}
+
+impl fmt::Display for TaskPriority {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl FromStr for TaskPriority {
+    type Err = UnknownPriority;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "low" => Ok(TaskPriority::Low),
+            "medium" => Ok(TaskPriority::Medium),
+            "high" => Ok(TaskPriority::High),
+            "urgent" => Ok(TaskPriority::Urgent),
+            other => Err(UnknownPriority(other.to_string())),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_should_round_trip_priority_through_string() {
+        for priority in [TaskPriority::Low, TaskPriority::Medium, TaskPriority::High, TaskPriority::Urgent] {
+            assert_eq!(priority.to_string().parse::<TaskPriority>(), Ok(priority));
+        }
+        assert!("critical".parse::<TaskPriority>().is_err());
+    }
+
+    #[test]
+    fn test_should_order_priorities_by_urgency() {
+        assert!(TaskPriority::Urgent > TaskPriority::High);
+        assert!(TaskPriority::Medium > TaskPriority::Low);
+    }
+}
@@ -0,0 +1,237 @@
+use base64::engine::general_purpose::URL_SAFE_NO_PAD;
+use base64::Engine;
+use serde::{Deserialize, Serialize};
+use thiserror::Error;
+use chrono::DateTime;
+use utoipa::ToSchema;
+use crate::domain::task::task::Task;
+use crate::domain::task::task_priority::TaskPriority;
+
+/// Поле, по которому сортируется список задач.
+///
+/// Задачи без значения (`NULL`) идут первыми по возрастанию и последними по убыванию.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize, ToSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum TaskSortField {
+    #[default]
+    Id,
+    Title,
+    Description,
+    Status,
+    /// По важности, от `low` к `urgent`, а не по алфавиту.
+    Priority,
+    StartAt,
+    DueAt,
+    Version,
+    ProjectId,
+    EstimateMinutes,
+}
+
+/// Направление сортировки.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize, ToSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum SortOrder {
+    #[default]
+    Asc,
+    Desc,
+}
+
+/// Как сочетаются несколько меток в фильтре списка задач.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize, ToSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum TagMatch {
+    /// Задача отмечена всеми перечисленными метками.
+    #[default]
+    All,
+    /// Задача отмечена хотя бы одной из перечисленных меток.
+    Any,
+}
+
+/// Фильтры списка задач. Незаполненные поля не ограничивают выборку.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct TaskFilter {
+    pub title_contains: Option<String>,
+    pub has_description: Option<bool>,
+    pub id_from: Option<i32>,
+    pub id_to: Option<i32>,
+    /// Имена меток; пустой список не ограничивает выборку.
+    pub tags: Vec<String>,
+    pub tag_match: TagMatch,
+    /// Только задачи проекта с этим идентификатором.
+    pub project_id: Option<i32>,
+    /// Только задачи, среди исполнителей которых есть пользователь с этим идентификатором.
+    pub assignee_id: Option<i32>,
+}
+
+/// Направление перехода по курсору относительно текущей страницы.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum CursorDirection {
+    Next,
+    Prev,
+}
+
+/// Курсор для постраничной навигации по ключу сортировки.
+///
+/// Хранит значение поля сортировки и идентификатор граничной задачи,
+/// а также параметры сортировки, для которых курсор был выдан.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct TaskCursor {
+    pub direction: CursorDirection,
+    pub sort_by: TaskSortField,
+    pub order: SortOrder,
+    pub value: Option<String>,
+    pub id: i32,
+}
+
+/// Ошибка разбора курсора, переданного клиентом.
+#[derive(Error, Debug, Clone, PartialEq, Eq)]
+#[error("Invalid pagination cursor")]
+pub struct InvalidCursor;
+
+impl TaskCursor {
+    /// Создаёт курсор, указывающий на задачу `task` в заданной сортировке.
+    pub fn for_task(task: &Task, direction: CursorDirection, sort_by: TaskSortField, order: SortOrder) -> Self {
+        let value = match sort_by {
+            TaskSortField::Id => None,
+            TaskSortField::Title => Some(task.title.clone()),
+            TaskSortField::Description => task.description.clone(),
+            TaskSortField::Status => Some(task.status.to_string()),
+            TaskSortField::Priority => Some(task.priority.to_string()),
+            TaskSortField::StartAt => task.start_at.map(|start_at| start_at.to_rfc3339()),
+            TaskSortField::DueAt => task.due_at.map(|due_at| due_at.to_rfc3339()),
+            TaskSortField::Version => Some(task.version.to_string()),
+            TaskSortField::ProjectId => task.project_id.map(|project_id| project_id.to_string()),
+            TaskSortField::EstimateMinutes => task.estimate_minutes.map(|estimate| estimate.to_string()),
+        };
+        TaskCursor { direction, sort_by, order, value, id: task.id }
+    }
+
+    /// Кодирует курсор в непрозрачную строку, пригодную для передачи в URL.
+    pub fn encode(&self) -> String {
+        let json = serde_json::to_vec(self).expect("cursor is always serializable");
+        URL_SAFE_NO_PAD.encode(json)
+    }
+
+    /// Восстанавливает курсор из строки, полученной от `encode`.
+    ///
+    /// # Ошибки
+    /// Возвращает `InvalidCursor`, если строка повреждена, не является курсором или значение поля
+    /// сортировки в нём не соответствует типу поля.
+    pub fn decode(token: &str) -> Result<Self, InvalidCursor> {
+        let json = URL_SAFE_NO_PAD.decode(token).map_err(|_| InvalidCursor)?;
+        let cursor: TaskCursor = serde_json::from_slice(&json).map_err(|_| InvalidCursor)?;
+        cursor.has_valid_value().then_some(cursor).ok_or(InvalidCursor)
+    }
+
+    /// Проверяет, что значение курсора можно сравнить с полем сортировки.
+    fn has_valid_value(&self) -> bool {
+        match (self.sort_by, self.value.as_deref()) {
+            (TaskSortField::Priority, value) => value.is_some_and(|value| value.parse::<TaskPriority>().is_ok()),
+            (TaskSortField::Version, value) => value.is_some_and(|value| value.parse::<i32>().is_ok()),
+            (TaskSortField::ProjectId | TaskSortField::EstimateMinutes, Some(value)) => value.parse::<i32>().is_ok(),
+            (TaskSortField::StartAt | TaskSortField::DueAt, Some(value)) => DateTime::parse_from_rfc3339(value).is_ok(),
+            _ => true,
+        }
+    }
+}
+
+/// Способ постраничной выборки.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Pagination {
+    Offset(i64),
+    Cursor(TaskCursor),
+}
+
+/// Параметры выборки списка задач, передаваемые в репозиторий.
+#[derive(Debug, Clone, PartialEq)]
+pub struct TaskQuery {
+    pub filter: TaskFilter,
+    pub sort_by: TaskSortField,
+    pub order: SortOrder,
+    pub pagination: Pagination,
+    pub limit: i64,
+}
+
+/// Страница списка задач.
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct TaskPage {
+    pub items: Vec<Task>,
+    /// Общее количество задач, удовлетворяющих фильтрам.
+    pub total: i64,
+    pub limit: i64,
+    /// Смещение страницы; заполняется только при постраничной выборке по смещению.
+    pub offset: Option<i64>,
+    /// Курсор следующей страницы, если она существует.
+    pub next_cursor: Option<String>,
+    /// Курсор предыдущей страницы, если она существует.
+    pub prev_cursor: Option<String>,
+}
+
+impl TaskPage {
+    /// Собирает страницу и выдаёт курсоры по первой и последней задаче.
+    pub fn new(items: Vec<Task>, total: i64, query: &TaskQuery, has_prev: bool, has_next: bool) -> Self {
+        let cursor = |task: &Task, direction| TaskCursor::for_task(task, direction, query.sort_by, query.order).encode();
+        let next_cursor = items.last().filter(|_| has_next).map(|task| cursor(task, CursorDirection::Next));
+        let prev_cursor = items.first().filter(|_| has_prev).map(|task| cursor(task, CursorDirection::Prev));
+        let offset = match query.pagination {
+            Pagination::Offset(offset) => Some(offset),
+            Pagination::Cursor(_) => None,
+        };
+
+        TaskPage { items, total, limit: query.limit, offset, next_cursor, prev_cursor }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_should_round_trip_cursor_through_token() {
+        let task = Task::new(7, "Write report".to_string(), Some("Quarterly".to_string()));
+        let cursor = TaskCursor::for_task(&task, CursorDirection::Next, TaskSortField::Description, SortOrder::Desc);
+
+        let decoded = TaskCursor::decode(&cursor.encode());
+
+        assert_eq!(decoded, Ok(cursor));
+        assert_eq!(decoded.unwrap().value, Some("Quarterly".to_string()));
+    }
+
+    #[test]
+    fn test_should_reject_malformed_cursor() {
+        assert_eq!(TaskCursor::decode("not a cursor"), Err(InvalidCursor));
+        assert_eq!(TaskCursor::decode(&URL_SAFE_NO_PAD.encode("{}")), Err(InvalidCursor));
+        let cursor = |sort_by, value: Option<&str>| TaskCursor {
+            direction: CursorDirection::Next,
+            sort_by,
+            order: SortOrder::Asc,
+            value: value.map(str::to_string),
+            id: 1,
+        }.encode();
+        assert_eq!(TaskCursor::decode(&cursor(TaskSortField::Priority, Some("critical"))), Err(InvalidCursor));
+        assert_eq!(TaskCursor::decode(&cursor(TaskSortField::Version, None)), Err(InvalidCursor));
+        assert_eq!(TaskCursor::decode(&cursor(TaskSortField::DueAt, Some("tomorrow"))), Err(InvalidCursor));
+        assert!(TaskCursor::decode(&cursor(TaskSortField::DueAt, None)).is_ok());
+    }
+
+    #[test]
+    fn test_should_only_emit_cursors_for_existing_neighbour_pages() {
+        let query = TaskQuery {
+            filter: TaskFilter::default(),
+            sort_by: TaskSortField::Id,
+            order: SortOrder::Asc,
+            pagination: Pagination::Offset(0),
+            limit: 2,
+        };
+        let items = vec![Task::new(1, "a".to_string(), None), Task::new(2, "b".to_string(), None)];
+
+        let page = TaskPage::new(items, 5, &query, false, true);
+
+        assert!(page.prev_cursor.is_none());
+        let next = TaskCursor::decode(page.next_cursor.as_deref().unwrap()).unwrap();
+        assert_eq!(next.id, 2);
+        assert_eq!(next.direction, CursorDirection::Next);
+        assert_eq!(page.offset, Some(0));
+    }
+}
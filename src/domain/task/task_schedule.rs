@@ -0,0 +1,31 @@
+use chrono::{DateTime, Utc};
+use crate::domain::task::task_priority::TaskPriority;
+
+/// Наибольшая оценка трудоёмкости задачи в минутах (один год).
+pub const MAX_ESTIMATE_MINUTES: i32 = 525_600;
+
+/// Приоритет, сроки и оценка задачи, передаваемые при создании и полном обновлении.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct TaskSchedule {
+    /// `None` - приоритет по умолчанию при создании и прежний приоритет при обновлении.
+    pub priority: Option<TaskPriority>,
+    pub start_at: Option<DateTime<Utc>>,
+    pub due_at: Option<DateTime<Utc>>,
+    /// Оценка трудоёмкости в минутах; отсутствие оценки при обновлении очищает её.
+    pub estimate_minutes: Option<i32>,
+}
+
+impl TaskSchedule {
+    /// Возвращает `true`, если срок выполнения не раньше даты начала.
+    pub fn dates_are_ordered(start_at: Option<DateTime<Utc>>, due_at: Option<DateTime<Utc>>) -> bool {
+        match (start_at, due_at) {
+            (Some(start_at), Some(due_at)) => start_at <= due_at,
+            _ => true,
+        }
+    }
+
+    /// Возвращает `true`, если оценка трудоёмкости отсутствует или лежит в пределах `0..=MAX_ESTIMATE_MINUTES`.
+    pub fn estimate_is_valid(estimate_minutes: Option<i32>) -> bool {
+        estimate_minutes.is_none_or(|estimate_minutes| (0..=MAX_ESTIMATE_MINUTES).contains(&estimate_minutes))
+    }
+}
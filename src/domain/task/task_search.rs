@@ -0,0 +1,15 @@
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+use crate::domain::task::task::Task;
+
+/// Задача, найденная полнотекстовым поиском.
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct TaskSearchHit {
+    pub task: Task,
+    /// Релевантность по BM25: чем меньше значение, тем выше задача в выдаче.
+    pub rank: f64,
+    /// Заголовок с совпадениями, обрамлёнными тегами `<mark>`.
+    pub title_highlight: String,
+    /// Фрагмент описания вокруг совпадений, если описание задано.
+    pub description_snippet: Option<String>,
+}
@@ -0,0 +1,120 @@
+use std::fmt;
+use std::str::FromStr;
+use serde::{Deserialize, Serialize};
+use thiserror::Error;
+use utoipa::ToSchema;
+
+/// Статус задачи в рабочем процессе.
+///
+/// Допустимые переходы:
+/// - `todo` -> `in_progress`, `blocked`, `done`
+/// - `in_progress` -> `todo`, `blocked`, `done`
+/// - `blocked` -> `todo`, `in_progress`
+/// - `done` -> `todo` (повторное открытие задачи)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize, ToSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum TaskStatus {
+    #[default]
+    Todo,
+    InProgress,
+    Blocked,
+    Done,
+}
+
+/// Ошибка, возникающая при попытке выполнить недопустимый переход статуса.
+#[derive(Error, Debug, Clone, PartialEq, Eq)]
+#[error("Cannot transition task from '{from}' to '{to}'")]
+pub struct InvalidTransition {
+    pub from: TaskStatus,
+    pub to: TaskStatus,
+}
+
+/// Ошибка разбора статуса из строкового представления.
+#[derive(Error, Debug, Clone, PartialEq, Eq)]
+#[error("Unknown task status '{0}'")]
+pub struct UnknownStatus(pub String);
+
+impl TaskStatus {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            TaskStatus::Todo => "todo",
+            TaskStatus::InProgress => "in_progress",
+            TaskStatus::Blocked => "blocked",
+            TaskStatus::Done => "done",
+        }
+    }
+
+    /// Проверяет, разрешён ли переход из текущего статуса в `target`.
+    pub fn can_transition_to(&self, target: TaskStatus) -> bool {
+        use TaskStatus::*;
+        matches!(
+            (self, target),
+            (Todo, InProgress) | (Todo, Blocked) | (Todo, Done)
+                | (InProgress, Todo) | (InProgress, Blocked) | (InProgress, Done)
+                | (Blocked, Todo) | (Blocked, InProgress)
+                | (Done, Todo)
+        )
+    }
+
+    /// Выполняет переход в статус `target`.
+    ///
+    /// # Ошибки
+    /// Возвращает `InvalidTransition`, если переход запрещён рабочим процессом.
+    pub fn transition_to(self, target: TaskStatus) -> Result<TaskStatus, InvalidTransition> {
+        if self.can_transition_to(target) {
+            Ok(target)
+        } else {
+            Err(InvalidTransition { from: self, to: target })
+        }
+    }
+}
+
+impl fmt::Display for TaskStatus {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl FromStr for TaskStatus {
+    type Err = UnknownStatus;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "todo" => Ok(TaskStatus::Todo),
+            "in_progress" => Ok(TaskStatus::InProgress),
+            "blocked" => Ok(TaskStatus::Blocked),
+            "done" => Ok(TaskStatus::Done),
+            other => Err(UnknownStatus(other.to_string())),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_should_allow_reopening_done_task() {
+        assert_eq!(TaskStatus::Done.transition_to(TaskStatus::Todo), Ok(TaskStatus::Todo));
+    }
+
+    #[test]
+    fn test_should_reject_blocking_done_task_without_reopening() {
+        let result = TaskStatus::Done.transition_to(TaskStatus::Blocked);
+
+        assert_eq!(result, Err(InvalidTransition { from: TaskStatus::Done, to: TaskStatus::Blocked }));
+    }
+
+    #[test]
+    fn test_should_reject_transition_to_same_status() {
+        assert!(!TaskStatus::InProgress.can_transition_to(TaskStatus::InProgress));
+    }
+
+    #[test]
+    fn test_should_round_trip_status_through_string() {
+        for status in [TaskStatus::Todo, TaskStatus::InProgress, TaskStatus::Blocked, TaskStatus::Done] {
+            assert_eq!(status.to_string().parse::<TaskStatus>(), Ok(status));
+        }
+        assert!("archived".parse::<TaskStatus>().is_err());
+    }
+}
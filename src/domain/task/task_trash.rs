@@ -0,0 +1,13 @@
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+use crate::domain::task::task::Task;
+
+/// Страница корзины: удалённые задачи, начиная с удалённых последними.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, ToSchema)]
+pub struct TrashPage {
+    pub items: Vec<Task>,
+    /// Общее количество задач в корзине.
+    pub total: i64,
+    pub limit: i64,
+    pub offset: i64,
+}
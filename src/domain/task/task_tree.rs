@@ -0,0 +1,120 @@
+use std::collections::HashMap;
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+use crate::domain::error::DomainError;
+use crate::domain::task::task::Task;
+use crate::domain::task::task_status::TaskStatus;
+
+/// Задача со всеми её подзадачами.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, ToSchema)]
+pub struct TaskTree {
+    #[serde(flatten)]
+    pub task: Task,
+    /// Процент выполнения (0..=100, с точностью до десятой): 100 для выполненной задачи, 0 для невыполненной
+    /// задачи без подзадач, для остальных - среднее по непосредственным подзадачам.
+    #[schema(example = 66.7)]
+    pub completion: f64,
+    /// Непосредственные подзадачи в порядке возрастания идентификатора.
+    pub children: Vec<TaskTree>,
+}
+
+impl TaskTree {
+    /// Собирает дерево с корнем `root_id` из плоского списка задач поддерева.
+    ///
+    /// Порядок подзадач совпадает с их порядком в `tasks`; задачи, не связанные с корнем, отбрасываются.
+    ///
+    /// # Возвращает
+    /// `None`, если в `tasks` нет задачи `root_id`.
+    pub fn build(root_id: i32, tasks: Vec<Task>) -> Option<TaskTree> {
+        let mut root = None;
+        let mut children: HashMap<i32, Vec<Task>> = HashMap::new();
+        for task in tasks {
+            match task.parent_id {
+                _ if task.id == root_id => root = Some(task),
+                Some(parent_id) => children.entry(parent_id).or_default().push(task),
+                None => {},
+            }
+        }
+
+        root.map(|root| TaskTree::grow(root, &mut children).0)
+    }
+
+    /// Строит поддерево задачи `task` и возвращает его вместе с неокруглённым процентом выполнения,
+    /// чтобы округление не накапливалось при подъёме к корню.
+    fn grow(task: Task, children: &mut HashMap<i32, Vec<Task>>) -> (TaskTree, f64) {
+        let subtrees = children.remove(&task.id).unwrap_or_default().into_iter()
+            .map(|child| TaskTree::grow(child, children))
+            .collect::<Vec<_>>();
+
+        let completion = match (task.status, subtrees.len()) {
+            (TaskStatus::Done, _) => 100.0,
+            (_, 0) => 0.0,
+            (_, count) => subtrees.iter().map(|(_, completion)| completion).sum::<f64>() / count as f64,
+        };
+        let tree = TaskTree {
+            task,
+            completion: (completion * 10.0).round() / 10.0,
+            children: subtrees.into_iter().map(|(subtree, _)| subtree).collect(),
+        };
+
+        (tree, completion)
+    }
+}
+
+/// Проверяет, что задачу `task_id` можно сделать подзадачей задачи `parent_lineage[0]`.
+///
+/// # Аргументы
+/// - `task_id` - Перемещаемая задача.
+/// - `parent_lineage` - Новый родитель и все его предки, от родителя к корню.
+///
+/// # Ошибки
+/// `DomainError::Conflict`, если задача сама входит в цепочку, то есть стала бы собственным потомком.
+pub fn check_new_parent(task_id: i32, parent_lineage: &[i32]) -> Result<(), DomainError> {
+    match parent_lineage.first() {
+        Some(&parent_id) if parent_lineage.contains(&task_id) => Err(DomainError::Conflict(format!(
+            "Task {} cannot be moved under task {}: a task cannot become its own descendant",
+            task_id, parent_id,
+        ))),
+        _ => Ok(()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn task(id: i32, parent_id: Option<i32>, status: TaskStatus) -> Task {
+        Task { parent_id, status, ..Task::new(id, format!("Task {}", id), None) }
+    }
+
+    #[test]
+    fn test_should_build_tree_and_roll_up_completion() {
+        let tasks = vec![
+            task(1, None, TaskStatus::Todo),
+            task(2, Some(1), TaskStatus::Done),
+            task(3, Some(1), TaskStatus::InProgress),
+            task(4, Some(3), TaskStatus::Done),
+            task(5, Some(3), TaskStatus::Todo),
+            task(6, Some(3), TaskStatus::Todo),
+        ];
+
+        let tree = TaskTree::build(1, tasks).unwrap();
+
+        assert_eq!(tree.children.iter().map(|child| child.task.id).collect::<Vec<_>>(), vec![2, 3]);
+        assert_eq!(tree.children[1].completion, 33.3);
+        assert_eq!(tree.completion, 66.7);
+        assert_eq!(tree.children[1].children[0].completion, 100.0);
+    }
+
+    #[test]
+    fn test_should_return_none_without_root() {
+        assert_eq!(TaskTree::build(1, vec![task(2, Some(1), TaskStatus::Todo)]), None);
+    }
+
+    #[test]
+    fn test_should_reject_moving_task_under_its_descendant() {
+        assert!(check_new_parent(3, &[5, 4, 1]).is_ok());
+        assert!(check_new_parent(1, &[5, 4, 1]).is_err());
+        assert!(check_new_parent(5, &[5, 4, 1]).is_err());
+    }
+}
@@ -0,0 +1,33 @@
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+use crate::domain::access::role::Role;
+use crate::domain::error::DomainError;
+
+/// Пользователь, которому можно назначать задачи.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, ToSchema)]
+pub struct User {
+    pub id: i32,
+    #[schema(example = "Ada Lovelace")]
+    pub name: String,
+    /// Адрес электронной почты, уникальный без учёта регистра.
+    #[schema(example = "ada@example.com")]
+    pub email: String,
+    /// Глобальная роль пользователя; в проектах, где он участник, может действовать более высокая роль.
+    pub role: Role,
+}
+
+impl User {
+    pub fn new(id: i32, name: String, email: String) -> Self {
+        User { id, name, email, role: Role::default() }
+    }
+
+    /// Ошибка обращения к несуществующему пользователю `user_id`.
+    pub fn not_found(user_id: i32) -> DomainError {
+        DomainError::NotFound(format!("User {} not found", user_id))
+    }
+
+    /// Ошибка снятия с задачи `task_id` пользователя `user_id`, который на неё не назначен.
+    pub fn not_assigned(task_id: i32, user_id: i32) -> DomainError {
+        DomainError::NotFound(format!("User {} is not assigned to task {}", user_id, task_id))
+    }
+}
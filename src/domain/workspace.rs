@@ -0,0 +1,55 @@
+/// Рабочее пространство команды: задачи, проекты и метки одного пространства не видны из другого.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Workspace {
+    pub id: i32,
+}
+
+impl Workspace {
+    /// Пространство запросов, в которых оно не выбрано, и данных, созданных до появления пространств.
+    pub const DEFAULT: Workspace = Workspace { id: 1 };
+
+    pub fn new(id: i32) -> Self {
+        Workspace { id }
+    }
+
+    /// Определяет пространство запроса.
+    ///
+    /// # Аргументы
+    /// - `claimed` - Пространство, закреплённое за клиентом токеном или ключом API.
+    /// - `requested` - Пространство, выбранное клиентом в запросе.
+    ///
+    /// # Возвращает
+    /// - `Some(Workspace)` закреплённое пространство, иначе выбранное, иначе `DEFAULT`.
+    /// - `None` если клиент выбрал не то пространство, которое за ним закреплено.
+    pub fn resolve(claimed: Option<i32>, requested: Option<i32>) -> Option<Self> {
+        match (claimed, requested) {
+            (Some(claimed), Some(requested)) if claimed != requested => None,
+            (Some(id), _) | (None, Some(id)) => Some(Workspace::new(id)),
+            (None, None) => Some(Workspace::DEFAULT),
+        }
+    }
+}
+
+impl Default for Workspace {
+    fn default() -> Self {
+        Workspace::DEFAULT
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_should_resolve_claimed_then_requested_then_default_workspace() {
+        assert_eq!(Workspace::resolve(Some(2), None), Some(Workspace::new(2)));
+        assert_eq!(Workspace::resolve(Some(2), Some(2)), Some(Workspace::new(2)));
+        assert_eq!(Workspace::resolve(None, Some(3)), Some(Workspace::new(3)));
+        assert_eq!(Workspace::resolve(None, None), Some(Workspace::DEFAULT));
+    }
+
+    #[test]
+    fn test_should_not_resolve_workspace_other_than_claimed() {
+        assert_eq!(Workspace::resolve(Some(2), Some(3)), None);
+    }
+}
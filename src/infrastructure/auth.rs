@@ -0,0 +1,312 @@
+use std::future::{ready, Future, Ready};
+use std::pin::Pin;
+use std::rc::Rc;
+use std::sync::Arc;
+use actix_web::body::{BoxBody, MessageBody};
+use actix_web::dev::{forward_ready, Service, ServiceRequest, ServiceResponse, Transform};
+use actix_web::http::header;
+use actix_web::{Error, FromRequest, HttpMessage};
+use jsonwebtoken::{decode, Algorithm, DecodingKey, Validation};
+use serde::Deserialize;
+use crate::adapters::api::auth::api_key_extractor::api_key_header;
+use crate::adapters::api::task::error::ErrorReponse;
+use crate::application::helpers::error_handler::ErrorHandlingHelper;
+use crate::domain::access::api_key::ApiKey;
+use crate::domain::principal::Principal;
+
+/// Префикс путей, доступных только с действующим токеном; документация API остаётся открытой.
+const PROTECTED_PREFIX: &str = "/api/";
+const DEFAULT_LEEWAY_SECONDS: u64 = 60;
+
+/// Утверждения токена, из которых строится `Principal`.
+#[derive(Debug, Deserialize)]
+struct Claims {
+    sub: String,
+    name: Option<String>,
+    workspace_id: Option<i32>,
+}
+
+/// Параметры проверки JWT: алгоритм подписи, ключ и ожидаемые издатель и получатель.
+#[derive(Clone)]
+pub struct JwtConfig {
+    algorithm: Algorithm,
+    key: DecodingKey,
+    issuer: Option<String>,
+    audience: Option<String>,
+    leeway_seconds: u64,
+}
+
+impl JwtConfig {
+    /// Проверка токенов, подписанных HMAC-SHA256 общим секретом.
+    pub fn hs256(secret: &[u8]) -> Self {
+        JwtConfig::new(Algorithm::HS256, DecodingKey::from_secret(secret))
+    }
+
+    /// Проверка токенов, подписанных RSA-SHA256; `public_key_pem` - открытый ключ в формате PEM.
+    ///
+    /// # Ошибки
+    /// Возвращает ошибку `jsonwebtoken`, если ключ не является открытым ключом RSA в формате PEM.
+    pub fn rs256(public_key_pem: &[u8]) -> Result<Self, jsonwebtoken::errors::Error> {
+        Ok(JwtConfig::new(Algorithm::RS256, DecodingKey::from_rsa_pem(public_key_pem)?))
+    }
+
+    fn new(algorithm: Algorithm, key: DecodingKey) -> Self {
+        JwtConfig { algorithm, key, issuer: None, audience: None, leeway_seconds: DEFAULT_LEEWAY_SECONDS }
+    }
+
+    /// Принимать только токены с утверждением `iss`, равным `issuer`.
+    pub fn with_issuer(mut self, issuer: &str) -> Self {
+        self.issuer = Some(issuer.to_string());
+        self
+    }
+
+    /// Принимать только токены, в утверждении `aud` которых есть `audience`.
+    pub fn with_audience(mut self, audience: &str) -> Self {
+        self.audience = Some(audience.to_string());
+        self
+    }
+
+    /// Читает параметры проверки из переменных окружения.
+    ///
+    /// `JWT_ALGORITHM` (`HS256` или `RS256`) включает проверку; без неё функция возвращает `None`.
+    /// Для HS256 секрет задаёт `JWT_SECRET` или файл `JWT_SECRET_FILE`, для RS256 - файл открытого
+    /// ключа `JWT_PUBLIC_KEY_FILE`. Необязательные `JWT_ISSUER`, `JWT_AUDIENCE` и `JWT_LEEWAY_SECONDS`
+    /// (по умолчанию 60) задают ожидаемые `iss`, `aud` и допуск расхождения часов.
+    ///
+    /// # Паника
+    /// Функция паникует, если алгоритм не поддерживается, ключ не задан или не читается, либо допуск не является числом.
+    pub fn from_env() -> Option<Self> {
+        let algorithm = dotenv::var("JWT_ALGORITHM").ok()?;
+        let mut config = match algorithm.to_uppercase().as_str() {
+            "HS256" => {
+                let secret = dotenv::var("JWT_SECRET").ok()
+                    .map(String::into_bytes)
+                    .or_else(|| dotenv::var("JWT_SECRET_FILE").ok().map(|path| read_key_file(&path)))
+                    .expect("JWT_SECRET or JWT_SECRET_FILE must be set for HS256");
+                assert!(!secret.is_empty(), "JWT secret must not be empty");
+                JwtConfig::hs256(&secret)
+            },
+            "RS256" => {
+                let path = dotenv::var("JWT_PUBLIC_KEY_FILE").expect("JWT_PUBLIC_KEY_FILE must be set for RS256");
+                JwtConfig::rs256(&read_key_file(&path)).expect("JWT_PUBLIC_KEY_FILE must contain an RSA public key in PEM format")
+            },
+            _ => panic!("JWT_ALGORITHM must be HS256 or RS256"),
+        };
+
+        if let Ok(issuer) = dotenv::var("JWT_ISSUER") {
+            config = config.with_issuer(&issuer);
+        }
+        if let Ok(audience) = dotenv::var("JWT_AUDIENCE") {
+            config = config.with_audience(&audience);
+        }
+        if let Ok(leeway) = dotenv::var("JWT_LEEWAY_SECONDS") {
+            config.leeway_seconds = leeway.parse().expect("JWT_LEEWAY_SECONDS must be a number of seconds");
+        }
+
+        Some(config)
+    }
+
+    /// Проверяет подпись и срок действия токена и извлекает из него клиента.
+    ///
+    /// Утверждения `sub` и `exp` обязательны; `iss` и `aud` проверяются, если заданы в конфигурации.
+    ///
+    /// # Ошибки
+    /// Возвращает ошибку `jsonwebtoken`, если токен повреждён, подписан другим ключом или алгоритмом,
+    /// истёк или выдан не для этого сервиса.
+    pub fn verify(&self, token: &str) -> Result<Principal, jsonwebtoken::errors::Error> {
+        let mut validation = Validation::new(self.algorithm);
+        validation.leeway = self.leeway_seconds;
+        validation.set_required_spec_claims(&["exp", "sub"]);
+        if let Some(issuer) = &self.issuer {
+            validation.set_issuer(&[issuer]);
+        }
+        match &self.audience {
+            Some(audience) => validation.set_audience(&[audience]),
+            None => validation.validate_aud = false,
+        }
+
+        let claims = decode::<Claims>(token, &self.key, &validation)?.claims;
+        Ok(Principal { subject: claims.sub, name: claims.name, workspace_id: claims.workspace_id })
+    }
+}
+
+/// Промежуточный слой, пропускающий к `/api/` только запросы с действующим токеном `Authorization: Bearer`
+/// или ключом API в заголовке `X-Api-Key`.
+///
+/// Клиент из токена или ключ помещается в расширения запроса, откуда их получают контроллеры. Без конфигурации
+/// (`JwtAuth::new(None)`) запросы без ключа пропускаются без проверки; предъявленный ключ проверяется всегда,
+/// чтобы сервисная учётная запись не получала больше прав, чем выдано ключу.
+#[derive(Clone)]
+pub struct JwtAuth {
+    config: Option<Arc<JwtConfig>>,
+}
+
+impl JwtAuth {
+    pub fn new(config: Option<JwtConfig>) -> Self {
+        JwtAuth { config: config.map(Arc::new) }
+    }
+
+    /// Проверка токенов с параметрами из окружения; см. `JwtConfig::from_env`.
+    ///
+    /// Без `JWT_ALGORITHM` запросы пропускаются без проверки только при явном `AUTH_DISABLED=true`,
+    /// чтобы забытая настройка не открывала API.
+    ///
+    /// # Ошибки
+    /// Возвращает ошибку, если `JWT_ALGORITHM` не задана, а `AUTH_DISABLED` не равна `true`.
+    pub fn from_env() -> Result<Self, std::io::Error> {
+        let auth_disabled = dotenv::var("AUTH_DISABLED").is_ok_and(|value| value.eq_ignore_ascii_case("true"));
+        JwtAuth::new(JwtConfig::from_env()).fail_closed(auth_disabled)
+    }
+
+    /// Отказывает в запуске без проверки токенов, если она не отключена явно.
+    fn fail_closed(self, auth_disabled: bool) -> Result<Self, std::io::Error> {
+        if self.is_enabled() {
+            return Ok(self);
+        }
+        if !auth_disabled {
+            return Err(std::io::Error::other("Authentication is not configured: set JWT_ALGORITHM (HS256 or RS256) to verify tokens, or AUTH_DISABLED=true to run without authentication"));
+        }
+        log::warn!("AUTH_DISABLED=true: API requests are not authenticated");
+        Ok(self)
+    }
+
+    pub fn is_enabled(&self) -> bool {
+        self.config.is_some()
+    }
+}
+
+impl<S, B> Transform<S, ServiceRequest> for JwtAuth
+where
+    S: Service<ServiceRequest, Response = ServiceResponse<B>, Error = Error> + 'static,
+    B: MessageBody + 'static,
+{
+    type Response = ServiceResponse<BoxBody>;
+    type Error = Error;
+    type Transform = JwtAuthMiddleware<S>;
+    type InitError = ();
+    type Future = Ready<Result<Self::Transform, Self::InitError>>;
+
+    fn new_transform(&self, service: S) -> Self::Future {
+        ready(Ok(JwtAuthMiddleware {
+            service: Rc::new(service),
+            config: self.config.clone(),
+        }))
+    }
+}
+
+pub struct JwtAuthMiddleware<S> {
+    service: Rc<S>,
+    config: Option<Arc<JwtConfig>>,
+}
+
+impl<S, B> Service<ServiceRequest> for JwtAuthMiddleware<S>
+where
+    S: Service<ServiceRequest, Response = ServiceResponse<B>, Error = Error> + 'static,
+    B: MessageBody + 'static,
+{
+    type Response = ServiceResponse<BoxBody>;
+    type Error = Error;
+    type Future = Pin<Box<dyn Future<Output = Result<Self::Response, Self::Error>>>>;
+
+    forward_ready!(service);
+
+    fn call(&self, req: ServiceRequest) -> Self::Future {
+        let service = self.service.clone();
+        let config = self.config.clone();
+
+        Box::pin(async move {
+            if req.path().starts_with(PROTECTED_PREFIX) && api_key_header(req.request()).is_some() {
+                return match ApiKey::extract(req.request()).await {
+                    Ok(_) => service.call(req).await.map(ServiceResponse::map_into_boxed_body),
+                    Err(error) => Ok(req.error_response(error)),
+                };
+            }
+
+            let config = match config {
+                Some(config) if req.path().starts_with(PROTECTED_PREFIX) => config,
+                _ => return service.call(req).await.map(ServiceResponse::map_into_boxed_body),
+            };
+
+            let principal = bearer_token(&req).and_then(|token| config.verify(token).ok());
+            match principal {
+                Some(principal) => {
+                    req.extensions_mut().insert(principal);
+                    service.call(req).await.map(ServiceResponse::map_into_boxed_body)
+                },
+                None => {
+                    let error = ErrorReponse::map_io_error(ErrorHandlingHelper::unauthorized_error()).with_instance(req.path());
+                    Ok(req.error_response(error))
+                },
+            }
+        })
+    }
+}
+
+/// Извлекает токен из заголовка `Authorization: Bearer <token>`; схема сравнивается без учёта регистра.
+fn bearer_token(req: &ServiceRequest) -> Option<&str> {
+    let value = req.headers().get(header::AUTHORIZATION)?.to_str().ok()?;
+    let (scheme, token) = value.split_once(' ')?;
+    let token = token.trim();
+    (scheme.eq_ignore_ascii_case("Bearer") && !token.is_empty()).then_some(token)
+}
+
+/// Читает ключ из файла.
+///
+/// # Паника
+/// Функция паникует, если файл не удаётся прочитать.
+fn read_key_file(path: &str) -> Vec<u8> {
+    std::fs::read(path).unwrap_or_else(|e| panic!("Cannot read JWT key file {}: {}", path, e))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use actix_web::test::TestRequest;
+    use jsonwebtoken::{encode, EncodingKey, Header};
+    use serde_json::json;
+
+    const SECRET: &[u8] = b"test-secret";
+
+    fn token(claims: serde_json::Value) -> String {
+        encode(&Header::default(), &claims, &EncodingKey::from_secret(SECRET)).unwrap()
+    }
+
+    fn expires_in(seconds: i64) -> i64 {
+        chrono::Utc::now().timestamp() + seconds
+    }
+
+    #[test]
+    fn test_should_read_bearer_token_from_authorization_header() {
+        let bearer = TestRequest::get().insert_header((header::AUTHORIZATION, "bearer abc.def.ghi")).to_srv_request();
+        let basic = TestRequest::get().insert_header((header::AUTHORIZATION, "Basic dXNlcjpwYXNz")).to_srv_request();
+        let empty = TestRequest::get().insert_header((header::AUTHORIZATION, "Bearer ")).to_srv_request();
+
+        assert_eq!(bearer_token(&bearer), Some("abc.def.ghi"));
+        assert_eq!(bearer_token(&basic), None);
+        assert_eq!(bearer_token(&empty), None);
+        assert_eq!(bearer_token(&TestRequest::get().to_srv_request()), None);
+    }
+
+    #[test]
+    fn test_should_verify_signature_expiry_issuer_and_audience() {
+        let config = JwtConfig::hs256(SECRET).with_issuer("auth.example.com").with_audience("task-manager");
+        let claims = |sub: Option<&str>, exp: i64, iss: &str| json!({ "sub": sub, "name": "Ada", "exp": exp, "iss": iss, "aud": "task-manager" });
+        let valid = token(claims(Some("42"), expires_in(600), "auth.example.com"));
+
+        let principal = config.verify(&valid).unwrap();
+        assert_eq!(principal, Principal { subject: "42".to_string(), name: Some("Ada".to_string()), workspace_id: None });
+        assert_eq!(principal.user_id(), Some(42));
+
+        assert!(config.verify(&token(claims(Some("42"), expires_in(-600), "auth.example.com"))).is_err());
+        assert!(config.verify(&token(claims(Some("42"), expires_in(600), "evil.example.com"))).is_err());
+        assert!(config.verify(&token(claims(None, expires_in(600), "auth.example.com"))).is_err());
+        assert!(JwtConfig::hs256(b"other-secret").verify(&valid).is_err());
+    }
+
+    #[test]
+    fn test_should_refuse_to_start_without_jwt_unless_auth_disabled() {
+        assert!(JwtAuth::new(None).fail_closed(false).is_err());
+        assert!(!JwtAuth::new(None).fail_closed(true).unwrap().is_enabled());
+        assert!(JwtAuth::new(Some(JwtConfig::hs256(SECRET))).fail_closed(false).unwrap().is_enabled());
+    }
+}
@@ -0,0 +1,353 @@
+use std::future::{ready, Future, Ready};
+use std::pin::Pin;
+use std::rc::Rc;
+use std::sync::Arc;
+use std::time::Duration;
+use actix_web::body::{to_bytes, BoxBody, MessageBody};
+use actix_web::dev::{forward_ready, Payload, Service, ServiceRequest, ServiceResponse, Transform};
+use actix_web::http::{Method, StatusCode};
+use actix_web::{web, Error, FromRequest, HttpResponse};
+use sha2::{Digest, Sha256};
+use crate::adapters::api::auth::workspace_extractor::event_actor;
+use crate::adapters::api::task::error::ErrorReponse;
+use crate::application::helpers::error_handler::ErrorHandlingHelper;
+use crate::application::repositories::idempotency_abstract_repo::IdempotencyRepositoryAbstract;
+use crate::domain::idempotency::{IdempotencyRecord, IdempotencyReservation, StoredResponse};
+use crate::domain::workspace::Workspace;
+
+pub const IDEMPOTENCY_KEY: &str = "Idempotency-Key";
+/// Заголовок, которым помечается ответ, выданный повторно из хранилища.
+pub const IDEMPOTENT_REPLAYED: &str = "Idempotent-Replayed";
+const MAX_KEY_LENGTH: usize = 255;
+const DEFAULT_TTL: Duration = Duration::from_secs(24 * 60 * 60);
+const DEFAULT_LEASE: Duration = Duration::from_secs(60);
+
+/// Промежуточный слой, делающий изменяющие запросы с заголовком `Idempotency-Key` идемпотентными.
+///
+/// Первый запрос с ключом выполняется, а его ответ сохраняется на время `ttl`. Повтор с тем же ключом
+/// и тем же запросом получает сохранённый ответ без повторного выполнения, повтор с другим запросом -
+/// 422, а повтор, пришедший до завершения первого запроса, - 409. Ответы с кодом 5xx не сохраняются,
+/// чтобы такой запрос можно было повторить. Ключи разных рабочих пространств и разных клиентов
+/// (пользователей токенов и ключей API) не пересекаются: чужой ответ по совпавшему ключу не выдаётся.
+///
+/// Выполняющийся запрос занимает ключ только на `lease`: если обработка прервана (клиент отключился
+/// и actix отбросил её) или процесс завершился, не сохранив ответ, повтор по истечении `lease`
+/// выполняется заново, а не получает 409 до конца `ttl`.
+#[derive(Clone)]
+pub struct Idempotency {
+    repository: Arc<dyn IdempotencyRepositoryAbstract>,
+    ttl: Duration,
+    lease: Duration,
+}
+
+impl Idempotency {
+    pub fn new(repository: Arc<dyn IdempotencyRepositoryAbstract>, ttl: Duration) -> Self {
+        Idempotency { repository, ttl, lease: DEFAULT_LEASE }
+    }
+
+    /// Тот же слой, занимающий ключ за выполняющимся запросом на `lease` (по умолчанию минута).
+    pub fn with_lease(self, lease: Duration) -> Self {
+        Idempotency { lease, ..self }
+    }
+
+    /// Срок хранения ключей из переменной окружения `IDEMPOTENCY_TTL_SECONDS`, по умолчанию 24 часа.
+    ///
+    /// # Паника
+    /// Функция паникует, если значение переменной не является числом секунд.
+    pub fn ttl_from_env() -> Duration {
+        dotenv::var("IDEMPOTENCY_TTL_SECONDS")
+            .map(|ttl| Duration::from_secs(ttl.parse().expect("IDEMPOTENCY_TTL_SECONDS must be a number of seconds")))
+            .unwrap_or(DEFAULT_TTL)
+    }
+
+    /// Срок занятия ключа выполняющимся запросом из переменной окружения `IDEMPOTENCY_LEASE_SECONDS`,
+    /// по умолчанию минута.
+    ///
+    /// # Паника
+    /// Функция паникует, если значение переменной не является числом секунд.
+    pub fn lease_from_env() -> Duration {
+        dotenv::var("IDEMPOTENCY_LEASE_SECONDS")
+            .map(|lease| Duration::from_secs(lease.parse().expect("IDEMPOTENCY_LEASE_SECONDS must be a number of seconds")))
+            .unwrap_or(DEFAULT_LEASE)
+    }
+}
+
+impl<S, B> Transform<S, ServiceRequest> for Idempotency
+where
+    S: Service<ServiceRequest, Response = ServiceResponse<B>, Error = Error> + 'static,
+    B: MessageBody + 'static,
+{
+    type Response = ServiceResponse<BoxBody>;
+    type Error = Error;
+    type Transform = IdempotencyMiddleware<S>;
+    type InitError = ();
+    type Future = Ready<Result<Self::Transform, Self::InitError>>;
+
+    fn new_transform(&self, service: S) -> Self::Future {
+        ready(Ok(IdempotencyMiddleware {
+            service: Rc::new(service),
+            repository: self.repository.clone(),
+            ttl: self.ttl,
+            lease: self.lease,
+        }))
+    }
+}
+
+pub struct IdempotencyMiddleware<S> {
+    service: Rc<S>,
+    repository: Arc<dyn IdempotencyRepositoryAbstract>,
+    ttl: Duration,
+    lease: Duration,
+}
+
+impl<S, B> Service<ServiceRequest> for IdempotencyMiddleware<S>
+where
+    S: Service<ServiceRequest, Response = ServiceResponse<B>, Error = Error> + 'static,
+    B: MessageBody + 'static,
+{
+    type Response = ServiceResponse<BoxBody>;
+    type Error = Error;
+    type Future = Pin<Box<dyn Future<Output = Result<Self::Response, Self::Error>>>>;
+
+    forward_ready!(service);
+
+    fn call(&self, mut req: ServiceRequest) -> Self::Future {
+        let service = self.service.clone();
+        let repository = self.repository.clone();
+        let ttl = self.ttl;
+        let lease = self.lease;
+
+        Box::pin(async move {
+            let key = match idempotency_key(&req) {
+                Ok(Some(key)) => key,
+                Ok(None) => return service.call(req).await.map(ServiceResponse::map_into_boxed_body),
+                Err(error) => return Ok(problem(req, error)),
+            };
+            // Запрос, для которого не определяется пространство, отклонит обработчик; сохранять такой ответ незачем.
+            let key = match Workspace::extract(req.request()).await {
+                Ok(workspace) => format!("{}:{}:{}", workspace.id, event_actor(req.request()).as_str(), key),
+                Err(_) => return service.call(req).await.map(ServiceResponse::map_into_boxed_body),
+            };
+
+            let body = req.extract::<web::Bytes>().await?;
+            let request_fingerprint = fingerprint(req.method(), &req.uri().to_string(), &body);
+            req.set_payload(Payload::from(body));
+
+            let reservation = match repository.reserve(key.clone(), request_fingerprint.clone(), ttl, lease).await {
+                Ok(IdempotencyReservation::Reserved(reservation_id)) => Reservation::new(repository.clone(), key, reservation_id),
+                Ok(IdempotencyReservation::Used(record)) if record.fingerprint != request_fingerprint => {
+                    let message = "Idempotency-Key has already been used for a different request";
+                    return Ok(problem(req, ErrorReponse::new(StatusCode::UNPROCESSABLE_ENTITY, message)));
+                },
+                Ok(IdempotencyReservation::Used(IdempotencyRecord { response: Some(response), .. })) => {
+                    return Ok(req.into_response(replay(response)));
+                },
+                Ok(IdempotencyReservation::Used(_)) => {
+                    let message = "A request with this Idempotency-Key is still being processed";
+                    return Ok(problem(req, ErrorReponse::new(StatusCode::CONFLICT, message)));
+                },
+                Err(e) => {
+                    let error = ErrorHandlingHelper::domain_error("Cannot check idempotency key", e);
+                    return Ok(problem(req, ErrorReponse::map_io_error(error)));
+                },
+            };
+
+            let response = match service.call(req).await {
+                Ok(response) if !response.status().is_server_error() => response,
+                result => {
+                    reservation.release().await;
+                    return result.map(ServiceResponse::map_into_boxed_body);
+                },
+            };
+
+            let (req, res) = response.into_parts();
+            let (res, body) = res.into_parts();
+            let body = match to_bytes(body).await {
+                Ok(body) => body,
+                Err(_) => {
+                    reservation.release().await;
+                    return Err(ErrorReponse::new(StatusCode::INTERNAL_SERVER_ERROR, "Error: an unknown error occured").into());
+                },
+            };
+
+            let stored = StoredResponse {
+                status: res.status().as_u16(),
+                headers: res.headers().iter()
+                    .filter_map(|(name, value)| value.to_str().ok().map(|value| (name.to_string(), value.to_string())))
+                    .collect(),
+                body: body.to_vec(),
+            };
+            match repository.complete(reservation.key.clone(), reservation.id.clone(), stored).await {
+                Ok(true) => reservation.keep(),
+                // Аренда истекла, и ключ перехватил повтор: его резервирование трогать нельзя.
+                Ok(false) => {
+                    log::warn!("Idempotency-Key lease expired before the response was stored; the key belongs to a retry");
+                    reservation.keep();
+                },
+                Err(e) => {
+                    ErrorHandlingHelper::domain_error("Cannot store idempotent response", e);
+                    reservation.release().await;
+                },
+            }
+
+            Ok(ServiceResponse::new(req, res.set_body(BoxBody::new(body))))
+        })
+    }
+}
+
+/// Извлекает ключ идемпотентности из изменяющего запроса.
+///
+/// # Возвращает
+/// - `Ok(None)` для безопасных методов и запросов без заголовка.
+/// - `Ok(Some(key))` если заголовок задан корректно.
+///
+/// # Ошибки
+/// 400, если ключ пуст, длиннее 255 символов или содержит символы вне печатного ASCII.
+fn idempotency_key(req: &ServiceRequest) -> Result<Option<String>, ErrorReponse> {
+    if ![Method::POST, Method::PUT, Method::PATCH, Method::DELETE].contains(req.method()) {
+        return Ok(None);
+    }
+    let value = match req.headers().get(IDEMPOTENCY_KEY) {
+        Some(value) => value,
+        None => return Ok(None),
+    };
+
+    value.to_str().ok()
+        .filter(|key| !key.is_empty() && key.len() <= MAX_KEY_LENGTH && key.chars().all(|c| c.is_ascii_graphic()))
+        .map(|key| Some(key.to_string()))
+        .ok_or_else(|| ErrorReponse::new(
+            StatusCode::BAD_REQUEST,
+            "Idempotency-Key must be 1 to 255 printable ASCII characters without spaces",
+        ))
+}
+
+/// Отпечаток запроса: SHA-256 от метода, пути со строкой запроса и тела.
+fn fingerprint(method: &Method, uri: &str, body: &[u8]) -> String {
+    let mut hasher = Sha256::new();
+    hasher.update(method.as_str().as_bytes());
+    hasher.update(b"\n");
+    hasher.update(uri.as_bytes());
+    hasher.update(b"\n");
+    hasher.update(body);
+
+    hasher.finalize().iter().map(|byte| format!("{:02x}", byte)).collect()
+}
+
+/// Восстанавливает сохранённый ответ, помечая его заголовком `Idempotent-Replayed`.
+fn replay(stored: StoredResponse) -> HttpResponse {
+    let status = StatusCode::from_u16(stored.status).unwrap_or(StatusCode::OK);
+    let mut response = HttpResponse::build(status);
+    for header in stored.headers {
+        response.append_header(header);
+    }
+    response
+        .insert_header((IDEMPOTENT_REPLAYED, "true"))
+        .body(stored.body)
+}
+
+/// Ответ с описанием ошибки, относящейся к запросу `req`.
+fn problem(req: ServiceRequest, error: ErrorReponse) -> ServiceResponse<BoxBody> {
+    let error = error.with_instance(req.path());
+    req.error_response(error)
+}
+
+/// Ключ, занятый выполняющимся запросом.
+///
+/// Если обработку запроса отбросили до `keep` или `release` (например, клиент отключился), ключ
+/// освобождается при удалении резервирования, чтобы повтор не получал 409.
+///
+/// Ключ освобождается только вместе с идентификатором резервирования `id`, поэтому запрос, у которого ключ
+/// перехватили по истечении аренды, не освобождает резервирование повтора.
+struct Reservation {
+    repository: Arc<dyn IdempotencyRepositoryAbstract>,
+    key: String,
+    id: String,
+    armed: bool,
+}
+
+impl Reservation {
+    fn new(repository: Arc<dyn IdempotencyRepositoryAbstract>, key: String, id: String) -> Self {
+        Reservation { repository, key, id, armed: true }
+    }
+
+    /// Оставляет ключ занятым: ответ на запрос сохранён.
+    fn keep(mut self) {
+        self.armed = false;
+    }
+
+    /// Освобождает ключ, чтобы запрос можно было повторить.
+    async fn release(mut self) {
+        self.armed = false;
+        release(self.repository.as_ref(), self.key.clone(), self.id.clone()).await;
+    }
+}
+
+impl Drop for Reservation {
+    fn drop(&mut self) {
+        let Some(arbiter) = actix_rt::Arbiter::try_current().filter(|_| self.armed) else {
+            return;
+        };
+        let (repository, key, id) = (self.repository.clone(), std::mem::take(&mut self.key), std::mem::take(&mut self.id));
+        arbiter.spawn(async move { release(repository.as_ref(), key, id).await });
+    }
+}
+
+/// Освобождает ключ; ошибка хранилища только записывается в журнал, так как ответ клиенту уже определён.
+async fn release(repository: &dyn IdempotencyRepositoryAbstract, key: String, reservation_id: String) {
+    if let Err(e) = repository.release(key, reservation_id).await {
+        ErrorHandlingHelper::domain_error("Cannot release idempotency key", e);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+    use actix_web::test::TestRequest;
+    use crate::application::repositories::idempotency_abstract_repo::MockIdempotencyRepositoryAbstract;
+    use mockall::predicate::eq;
+
+    #[test]
+    fn test_should_read_key_only_from_mutating_requests() {
+        let post = TestRequest::post().insert_header((IDEMPOTENCY_KEY, "a1-b2")).to_srv_request();
+        let get = TestRequest::get().insert_header((IDEMPOTENCY_KEY, "a1-b2")).to_srv_request();
+        let without_key = TestRequest::post().to_srv_request();
+        let with_space = TestRequest::post().insert_header((IDEMPOTENCY_KEY, "a1 b2")).to_srv_request();
+
+        assert_eq!(idempotency_key(&post).unwrap(), Some("a1-b2".to_string()));
+        assert_eq!(idempotency_key(&get).unwrap(), None);
+        assert_eq!(idempotency_key(&without_key).unwrap(), None);
+        assert!(idempotency_key(&with_space).is_err());
+    }
+
+    #[test]
+    fn test_should_fingerprint_method_uri_and_body() {
+        let original = fingerprint(&Method::POST, "/api/tasks", b"{\"title\":\"A\"}");
+
+        assert_eq!(original, fingerprint(&Method::POST, "/api/tasks", b"{\"title\":\"A\"}"));
+        assert_ne!(original, fingerprint(&Method::POST, "/api/tasks", b"{\"title\":\"B\"}"));
+        assert_ne!(original, fingerprint(&Method::PUT, "/api/tasks", b"{\"title\":\"A\"}"));
+        assert_eq!(original.len(), 64);
+    }
+
+    #[actix_rt::test]
+    async fn test_should_release_dropped_reservation_only() {
+        let released = Arc::new(AtomicUsize::new(0));
+        let mut repository = MockIdempotencyRepositoryAbstract::new();
+        let counter = released.clone();
+        repository.expect_release()
+            .with(eq("1:system:dropped".to_string()), eq("r2".to_string()))
+            .times(1)
+            .returning(move |_, _| {
+                counter.fetch_add(1, Ordering::SeqCst);
+                Ok(())
+            });
+        let repository: Arc<dyn IdempotencyRepositoryAbstract> = Arc::new(repository);
+
+        Reservation::new(repository.clone(), "1:system:kept".to_string(), "r1".to_string()).keep();
+        drop(Reservation::new(repository, "1:system:dropped".to_string(), "r2".to_string()));
+        actix_rt::time::sleep(Duration::from_millis(10)).await;
+
+        assert_eq!(released.load(Ordering::SeqCst), 1);
+    }
+}
@@ -1,29 +1,216 @@
-use std::{env, net::TcpListener};
+use std::{env, net::TcpListener, sync::Arc};
 use actix_web::{dev::Server};
 use actix_web::{web, App, HttpServer};
-use utoipa::OpenApi;
+use utoipa::{Modify, OpenApi};
+use utoipa::openapi::{ContentBuilder, PathItemType, Ref, ResponseBuilder};
+use utoipa::openapi::security::{ApiKey as ApiKeyScheme, ApiKeyValue, HttpAuthScheme, HttpBuilder, SecurityRequirement, SecurityScheme};
+use utoipa::openapi::path::{ParameterBuilder, ParameterIn};
 use utoipa_swagger_ui::SwaggerUi;
-use crate::dal::db_connection::DbConnection;
+
+pub mod auth;
+pub mod idempotency;
+pub mod trash_purge;
+
+use crate::dal::db_connection::{DbConfig, DbConnection};
+use crate::dal::repositories::db_repo_idempotency::IdempotencyRepository;
+use crate::dal::repositories::db_repo_api_keys::ApiKeyRepository;
+use crate::dal::repositories::db_repo_dependencies::DependencyRepository;
+use crate::dal::repositories::db_repo_projects::ProjectRepository;
+use crate::dal::repositories::db_repo_tags::TagRepository;
 use crate::dal::repositories::db_repo_tasks::TaskRepository;
+use crate::dal::repositories::db_repo_users::UserRepository;
+use crate::infrastructure::auth::JwtAuth;
+use crate::infrastructure::idempotency::{Idempotency, IDEMPOTENCY_KEY};
+use crate::infrastructure::trash_purge::TrashPurge;
 use crate::adapters::routes;
 use crate::adapters::shared::app_confs::AppConfigs;
-use crate::adapters::api::task::task_payload::TaskPayload;
-use crate::adapters::api::task::task_controller::{__path_get_tasks,__path_create_task,__path_get_task,__path_delete_task,__path_update_task};
+use crate::adapters::api::api_key::api_key_controller::{__path_get_api_keys,__path_mint_api_key,__path_revoke_api_key};
+use crate::adapters::api::api_key::api_key_payload::ApiKeyPayload;
+use crate::adapters::api::auth::api_key_extractor::API_KEY_HEADER;
+use crate::adapters::api::auth::workspace_extractor::WORKSPACE_HEADER;
+use crate::adapters::api::auth::auth_controller::__path_get_current_principal;
+use crate::adapters::api::dependency::dependency_controller::{__path_get_task_blockers,__path_add_task_dependency,__path_remove_task_dependency,__path_get_critical_path};
+use crate::adapters::api::project::project_payload::ProjectPayload;
+use crate::adapters::api::project::project_controller::{__path_get_projects,__path_get_project,__path_create_project,__path_update_project,__path_delete_project,__path_archive_project,__path_unarchive_project,__path_get_project_tasks,__path_create_project_task,__path_get_project_members,__path_set_project_member,__path_remove_project_member};
+use crate::adapters::api::tag::tag_payload::TagPayload;
+use crate::adapters::api::tag::tag_controller::{__path_get_tags,__path_get_tag,__path_create_tag,__path_update_tag,__path_delete_tag,__path_get_task_tags,__path_attach_tag,__path_detach_tag};
+use crate::adapters::api::user::user_payload::{RolePayload, UserPayload};
+use crate::adapters::api::user::user_controller::{__path_get_users,__path_get_user,__path_create_user,__path_set_user_role,__path_get_user_tasks,__path_get_task_assignees,__path_assign_task,__path_unassign_task};
+use crate::adapters::api::task::error::{json_error_handler, path_error_handler, query_error_handler, ProblemDetails, PROBLEM_JSON};
+use crate::adapters::api::task::task_bulk_response::{BulkItemResponse, BulkResponse};
+use crate::adapters::api::task::task_payload::{BulkOperationPayload, BulkPayload, JsonPatchOp, JsonPatchOperation, MovePayload, TaskMergePatch, TaskPayload, TransitionPayload, JSON_PATCH_JSON};
+use crate::adapters::api::task::task_controller::{__path_get_tasks,__path_create_task,__path_bulk_tasks,__path_get_task,__path_delete_task,__path_restore_task,__path_get_trash,__path_update_task,__path_patch_task,__path_transition_task,__path_search_tasks,__path_get_overdue_tasks,__path_get_tasks_due_soon,__path_get_urgent_tasks,__path_get_task_tree,__path_get_task_history,__path_create_subtask,__path_move_task};
+use crate::domain::access::api_key::{ApiKey, ApiKeyScope, MintedApiKey};
+use crate::domain::access::role::Role;
+use crate::domain::error::FieldError;
+use crate::domain::principal::Principal;
+use crate::domain::project::project::{Project, ProjectTasksPolicy};
+use crate::domain::project::project_member::ProjectMember;
+use crate::domain::tag::tag::Tag;
 use crate::domain::task::task::Task;
+use crate::domain::task::task_bulk::BulkMode;
+use crate::domain::task::task_query::{SortOrder, TagMatch, TaskPage, TaskSortField};
+use crate::domain::task::task_search::TaskSearchHit;
+use crate::domain::task::task_priority::TaskPriority;
+use crate::domain::task::task_status::TaskStatus;
+use crate::domain::task::task_dependency::{CriticalPath, TaskDependency};
+use crate::domain::task::task_tree::TaskTree;
+use crate::domain::task::task_trash::TrashPage;
+use crate::domain::task::task_event::{EventActor, FieldChange, TaskEvent, TaskEventAction, TaskEventPage};
+use crate::domain::user::user::User;
+use crate::domain::workspace::Workspace;
+/// Наибольший размер тела запроса; пакет `POST /api/tasks/bulk` может содержать тысячи задач.
+const MAX_JSON_PAYLOAD_SIZE: usize = 16 * 1024 * 1024;
+
 #[derive(OpenApi)]
 #[openapi(
 paths(
 get_tasks,
+search_tasks,
+get_overdue_tasks,
+get_tasks_due_soon,
+get_urgent_tasks,
 get_task,
 create_task,
+bulk_tasks,
 update_task,
+patch_task,
 delete_task,
+restore_task,
+get_trash,
+transition_task,
+get_task_tree,
+get_task_history,
+create_subtask,
+move_task,
+get_task_tags,
+attach_tag,
+detach_tag,
+get_tags,
+get_tag,
+create_tag,
+update_tag,
+delete_tag,
+get_projects,
+get_project,
+create_project,
+update_project,
+delete_project,
+archive_project,
+unarchive_project,
+get_project_tasks,
+create_project_task,
+get_project_members,
+set_project_member,
+remove_project_member,
+get_task_blockers,
+add_task_dependency,
+remove_task_dependency,
+get_critical_path,
+get_users,
+get_user,
+create_user,
+set_user_role,
+get_user_tasks,
+get_task_assignees,
+assign_task,
+unassign_task,
+get_api_keys,
+mint_api_key,
+revoke_api_key,
+get_current_principal,
 ),
-components(schemas(TaskPayload,Task))
+components(schemas(TaskPayload,TransitionPayload,Task,TaskStatus,TaskPriority,TaskPage,TaskSortField,SortOrder,TagMatch,TaskSearchHit,ProblemDetails,FieldError,TaskMergePatch,MovePayload,TaskTree,TaskEvent,TaskEventAction,FieldChange,TaskEventPage,TrashPage,JsonPatchOperation,JsonPatchOp,BulkPayload,BulkOperationPayload,BulkMode,BulkResponse,BulkItemResponse,Tag,TagPayload,Project,ProjectPayload,ProjectTasksPolicy,TaskDependency,CriticalPath,User,UserPayload,Principal,Role,RolePayload,ProjectMember,ApiKey,ApiKeyScope,ApiKeyPayload,MintedApiKey)),
+modifiers(&JsonPatchContent, &IdempotencyKeyHeader, &WorkspaceHeader, &BearerSecurity, &ApiKeySecurity)
 )]
+struct ApiDoc;
 
+/// Добавляет в описание `PATCH /api/tasks/{id}` второй формат тела - JSON Patch,
+/// так как `#[utoipa::path]` позволяет указать только один `content_type`.
+struct JsonPatchContent;
 
-struct ApiDoc;
+impl Modify for JsonPatchContent {
+    fn modify(&self, openapi: &mut utoipa::openapi::OpenApi) {
+        let request_body = openapi.paths.paths.get_mut("/api/tasks/{id}")
+            .and_then(|path| path.operations.get_mut(&utoipa::openapi::PathItemType::Patch))
+            .and_then(|operation| operation.request_body.as_mut());
+        if let Some(request_body) = request_body {
+            let operations = utoipa::openapi::schema::ArrayBuilder::new().items(Ref::from_schema_name("JsonPatchOperation"));
+            request_body.content.insert(JSON_PATCH_JSON.to_string(), ContentBuilder::new().schema(operations).build());
+        }
+    }
+}
+/// Описывает заголовок `Idempotency-Key`, который обрабатывает `Idempotency` для всех изменяющих запросов.
+struct IdempotencyKeyHeader;
+
+impl Modify for IdempotencyKeyHeader {
+    fn modify(&self, openapi: &mut utoipa::openapi::OpenApi) {
+        let mutating = [PathItemType::Post, PathItemType::Put, PathItemType::Patch, PathItemType::Delete];
+        let operations = openapi.paths.paths.values_mut()
+            .flat_map(|path| path.operations.iter_mut())
+            .filter(|(method, _)| mutating.contains(method))
+            .map(|(_, operation)| operation);
+        for operation in operations {
+            let parameter = ParameterBuilder::new()
+                .name(IDEMPOTENCY_KEY)
+                .parameter_in(ParameterIn::Header)
+                .description(Some("Client-generated key; a retry with the same key and body replays the stored response"))
+                .schema(Some(utoipa::openapi::ObjectBuilder::new().schema_type(utoipa::openapi::SchemaType::String).max_length(Some(255))))
+                .build();
+            operation.parameters.get_or_insert_with(Vec::new).push(parameter);
+        }
+    }
+}
+/// Описывает заголовок `X-Workspace-Id`, которым выбирается рабочее пространство всех операций с данными.
+struct WorkspaceHeader;
+
+impl Modify for WorkspaceHeader {
+    fn modify(&self, openapi: &mut utoipa::openapi::OpenApi) {
+        let operations = openapi.paths.paths.iter_mut()
+            .filter(|(path, _)| path.as_str() != "/api/me")
+            .flat_map(|(_, path)| path.operations.values_mut());
+        for operation in operations {
+            let parameter = ParameterBuilder::new()
+                .name(WORKSPACE_HEADER)
+                .parameter_in(ParameterIn::Header)
+                .description(Some("Workspace to work in; defaults to 1 and must match the workspace bound to the token or API key"))
+                .schema(Some(utoipa::openapi::ObjectBuilder::new().schema_type(utoipa::openapi::SchemaType::Integer).minimum(Some(1.0))))
+                .build();
+            operation.parameters.get_or_insert_with(Vec::new).push(parameter);
+        }
+    }
+}
+/// Описывает аутентификацию `Authorization: Bearer <JWT>`, которую `JwtAuth` требует для всех путей `/api/`,
+/// чтобы Swagger UI мог отправлять токен, и добавляет каждой операции ответ 401.
+struct BearerSecurity;
+
+impl Modify for BearerSecurity {
+    fn modify(&self, openapi: &mut utoipa::openapi::OpenApi) {
+        let scheme = HttpBuilder::new().scheme(HttpAuthScheme::Bearer).bearer_format("JWT").build();
+        openapi.components.get_or_insert_with(Default::default).add_security_scheme("bearer", SecurityScheme::Http(scheme));
+        openapi.security = Some(vec![SecurityRequirement::new("bearer", Vec::<String>::new())]);
+
+        for operation in openapi.paths.paths.values_mut().flat_map(|path| path.operations.values_mut()) {
+            let unauthorized = ResponseBuilder::new()
+                .description("Bearer token or API key is missing, invalid or expired")
+                .content(PROBLEM_JSON, ContentBuilder::new().schema(Ref::from_schema_name("ProblemDetails")).build())
+                .build();
+            operation.responses.responses.insert("401".to_string(), unauthorized.into());
+        }
+    }
+}
+/// Описывает ключ API сервисных учётных записей в заголовке `X-Api-Key` как альтернативу токену.
+///
+/// Должен применяться после `BearerSecurity`, который задаёт список требований документа.
+struct ApiKeySecurity;
+
+impl Modify for ApiKeySecurity {
+    fn modify(&self, openapi: &mut utoipa::openapi::OpenApi) {
+        let scheme = SecurityScheme::ApiKey(ApiKeyScheme::Header(ApiKeyValue::with_description(API_KEY_HEADER, "API key of a service account")));
+        openapi.components.get_or_insert_with(Default::default).add_security_scheme("apiKey", scheme);
+        openapi.security.get_or_insert_with(Vec::new).push(SecurityRequirement::new("apiKey", Vec::<String>::new()));
+    }
+}
 /// Инициализирует и запускает HTTP сервер на основе переданного слушателя сокетов.
 ///
 /// # Параметры
@@ -34,7 +221,7 @@ struct ApiDoc;
 /// Возвращает экземпляр `Server`, который может быть запущен асинхронно.
 ///
 /// # Ошибки
-/// Возвращает `std::io::Error`, если сервер не может быть запущен.
+/// Возвращает `std::io::Error`, если сервер не может быть запущен или не удалось создать пул соединений к базе данных.
 pub fn server(listener: TcpListener, db_name: &str) -> Result<Server, std::io::Error> {
     env::set_var("RUST_BACKTRACE", "1");
     env::set_var("RUST_LOG", "actix_web=debug");
@@ -42,11 +229,24 @@ pub fn server(listener: TcpListener, db_name: &str) -> Result<Server, std::io::E
 
     env_logger::try_init().expect("Logger not init");
 
-    let db_connection: DbConnection = DbConnection { db_name: db_name.to_string() };
+    let db_config = DbConfig::from_env();
+    let db_connection = DbConnection::new(db_name, &db_config).map_err(std::io::Error::other)?;
 
+    let idempotency = Idempotency::new(
+        Arc::new(IdempotencyRepository { db_connection: db_connection.clone() }),
+        Idempotency::ttl_from_env(),
+    )
+    .with_lease(Idempotency::lease_from_env());
+    let auth = JwtAuth::from_env()?;
     let data = web::Data::new(AppConfigs {
-        task_repository: TaskRepository { db_connection },
+        task_repository: Arc::new(TaskRepository { db_connection: db_connection.clone(), workspace: Workspace::DEFAULT, actor: EventActor::system() }),
+        tag_repository: Arc::new(TagRepository { db_connection: db_connection.clone(), workspace: Workspace::DEFAULT }),
+        project_repository: Arc::new(ProjectRepository { db_connection: db_connection.clone(), workspace: Workspace::DEFAULT, actor: EventActor::system() }),
+        dependency_repository: Arc::new(DependencyRepository { db_connection: db_connection.clone(), workspace: Workspace::DEFAULT }),
+        user_repository: Arc::new(UserRepository { db_connection: db_connection.clone(), workspace: Workspace::DEFAULT, actor: EventActor::system() }),
+        api_key_repository: Arc::new(ApiKeyRepository { db_connection, workspace: Workspace::DEFAULT }),
     });
+    TrashPurge::from_env(data.task_repository.clone()).start();
 
     let port = listener.local_addr().unwrap().port();
 
@@ -54,6 +254,12 @@ pub fn server(listener: TcpListener, db_name: &str) -> Result<Server, std::io::E
     let server = HttpServer::new(move || {
         App::new()
             .app_data(data.clone())
+            .app_data(web::JsonConfig::default().limit(MAX_JSON_PAYLOAD_SIZE).error_handler(json_error_handler))
+            .app_data(web::QueryConfig::default().error_handler(query_error_handler))
+            .app_data(web::PathConfig::default().error_handler(path_error_handler))
+            .app_data(web::PayloadConfig::new(MAX_JSON_PAYLOAD_SIZE))
+            .wrap(idempotency.clone())
+            .wrap(auth.clone())
             .service(
                 SwaggerUi::new("/docs/{_:.*}")
                     .url("/api-doc/openapi.json", ApiDoc::openapi()),
@@ -0,0 +1,65 @@
+use std::sync::Arc;
+use std::time::Duration;
+use chrono::Utc;
+use crate::application::repo_requesters::abstract_request::AbstractRequest;
+use crate::application::repo_requesters::purge_trash_request::PurgeTrashRequest;
+use crate::application::repositories::task_abstract_repo::TaskRepositoryAbstract;
+use crate::domain::error::ApiError;
+
+const DEFAULT_RETENTION_DAYS: i64 = 30;
+const DEFAULT_INTERVAL: Duration = Duration::from_secs(60 * 60);
+
+/// Фоновая задача, окончательно удаляющая задачи, пролежавшие в корзине дольше `retention`.
+///
+/// Очистка выполняется сразу после запуска и затем каждые `interval`, для задач всех рабочих пространств.
+#[derive(Clone)]
+pub struct TrashPurge {
+    repository: Arc<dyn TaskRepositoryAbstract>,
+    retention: chrono::Duration,
+    interval: Duration,
+}
+
+impl TrashPurge {
+    pub fn new(repository: Arc<dyn TaskRepositoryAbstract>, retention: chrono::Duration, interval: Duration) -> Self {
+        TrashPurge { repository, retention, interval }
+    }
+
+    /// Очистка с настройками из переменных окружения `TRASH_RETENTION_DAYS` (по умолчанию 30 дней)
+    /// и `TRASH_PURGE_INTERVAL_SECONDS` (по умолчанию час).
+    ///
+    /// # Паника
+    /// Функция паникует, если срок хранения не является неотрицательным числом дней или период
+    /// не является положительным числом секунд.
+    pub fn from_env(repository: Arc<dyn TaskRepositoryAbstract>) -> Self {
+        let retention_days = dotenv::var("TRASH_RETENTION_DAYS")
+            .map(|days| days.parse::<i64>().ok().filter(|days| *days >= 0).expect("TRASH_RETENTION_DAYS must be a non-negative number of days"))
+            .unwrap_or(DEFAULT_RETENTION_DAYS);
+        let interval = dotenv::var("TRASH_PURGE_INTERVAL_SECONDS")
+            .map(|seconds| seconds.parse::<u64>().ok().filter(|seconds| *seconds > 0).expect("TRASH_PURGE_INTERVAL_SECONDS must be a positive number of seconds"))
+            .map(Duration::from_secs)
+            .unwrap_or(DEFAULT_INTERVAL);
+
+        TrashPurge::new(repository, chrono::Duration::days(retention_days), interval)
+    }
+
+    /// Выполняет одну очистку корзины и возвращает количество окончательно удалённых задач.
+    pub async fn run_once(&self) -> Result<usize, ApiError> {
+        PurgeTrashRequest::new(self.retention, Utc::now(), self.repository.as_ref()).execute().await
+    }
+
+    /// Запускает периодическую очистку в текущей системе actix. Ошибка очистки не останавливает
+    /// задачу: она записывается в журнал, а очистка повторяется в следующий период.
+    pub fn start(self) {
+        actix_rt::spawn(async move {
+            let mut interval = actix_rt::time::interval(self.interval);
+            loop {
+                interval.tick().await;
+                if let Ok(purged) = self.run_once().await {
+                    if purged > 0 {
+                        println!("Purged {} tasks from the trash", purged);
+                    }
+                }
+            }
+        });
+    }
+}
@@ -0,0 +1,80 @@
+//! Роли viewer/editor/admin и участники проектов: проверка прав в сценариях работы с задачами.
+
+mod common;
+
+use actix_web::{http::{header, StatusCode}, test};
+use jsonwebtoken::{encode, Algorithm, EncodingKey, Header};
+use serde_json::{json, Value};
+use task_api::infrastructure::auth::{JwtAuth, JwtConfig};
+
+const SECRET: &[u8] = b"access-secret";
+
+fn bearer(subject: &str) -> (header::HeaderName, String) {
+    let claims = json!({ "sub": subject, "exp": chrono::Utc::now().timestamp() + 600 });
+    let token = encode(&Header::new(Algorithm::HS256), &claims, &EncodingKey::from_secret(SECRET)).unwrap();
+    (header::AUTHORIZATION, format!("Bearer {}", token))
+}
+
+#[actix_rt::test]
+async fn test_should_enforce_global_roles_and_project_membership() {
+    let config = common::prepare_database("access_control");
+
+    // Без аутентификации права не проверяются: так заводятся пользователи, роли и исходные данные.
+    let setup = test::init_service(common::test_app(common::app_data(&config))).await;
+    for (name, email) in [("Viewer", "viewer@example.com"), ("Editor", "editor@example.com"), ("Admin", "admin@example.com")] {
+        let req = test::TestRequest::post().uri("/api/users").set_json(json!({ "name": name, "email": email })).to_request();
+        assert_eq!(test::call_service(&setup, req).await.status(), StatusCode::CREATED);
+    }
+    for (user_id, role) in [(2, "editor"), (3, "admin")] {
+        let req = test::TestRequest::put().uri(&format!("/api/users/{}/role", user_id)).set_json(json!({ "role": role })).to_request();
+        let user: Value = test::call_and_read_body_json(&setup, req).await;
+        assert_eq!(user["role"], role);
+    }
+    let req = test::TestRequest::post().uri("/api/projects").set_json(json!({ "name": "Apollo" })).to_request();
+    assert_eq!(test::call_service(&setup, req).await.status(), StatusCode::CREATED);
+    let req = test::TestRequest::post().uri("/api/tasks").set_json(json!({ "title": "Loose task" })).to_request();
+    assert_eq!(test::call_service(&setup, req).await.status(), StatusCode::CREATED);
+
+    let app = test::init_service(common::test_app(common::app_data(&config)).wrap(JwtAuth::new(Some(JwtConfig::hs256(SECRET))))).await;
+    let call = |method: test::TestRequest, uri: &str, subject: &str| method.uri(uri).insert_header(bearer(subject)).to_request();
+
+    // Viewer читает, но не пишет; editor пишет, но не удаляет.
+    assert_eq!(test::call_service(&app, call(test::TestRequest::get(), "/api/tasks/1", "1")).await.status(), StatusCode::OK);
+    let req = test::TestRequest::post().uri("/api/tasks").insert_header(bearer("1")).set_json(json!({ "title": "Nope" })).to_request();
+    let resp = test::call_service(&app, req).await;
+    assert_eq!(resp.status(), StatusCode::FORBIDDEN);
+    assert_eq!(resp.headers().get(header::CONTENT_TYPE).unwrap(), "application/problem+json");
+    let req = test::TestRequest::post().uri("/api/tasks").insert_header(bearer("2")).set_json(json!({ "title": "Edited" })).to_request();
+    assert_eq!(test::call_service(&app, req).await.status(), StatusCode::CREATED);
+    assert_eq!(test::call_service(&app, call(test::TestRequest::delete(), "/api/tasks/2", "2")).await.status(), StatusCode::FORBIDDEN);
+    assert_eq!(test::call_service(&app, call(test::TestRequest::delete(), "/api/tasks/2", "3")).await.status(), StatusCode::OK);
+
+    // Неизвестный пользователь и клиент без числового `sub` не имеют прав даже на чтение.
+    assert_eq!(test::call_service(&app, call(test::TestRequest::get(), "/api/tasks", "99")).await.status(), StatusCode::FORBIDDEN);
+    assert_eq!(test::call_service(&app, call(test::TestRequest::get(), "/api/tasks", "robot")).await.status(), StatusCode::FORBIDDEN);
+
+    // Участие в проекте повышает роль только для задач этого проекта.
+    let req = test::TestRequest::put().uri("/api/projects/1/members/1").insert_header(bearer("2")).set_json(json!({ "role": "editor" })).to_request();
+    assert_eq!(test::call_service(&app, req).await.status(), StatusCode::FORBIDDEN);
+    let req = test::TestRequest::put().uri("/api/projects/1/members/1").insert_header(bearer("3")).set_json(json!({ "role": "editor" })).to_request();
+    let member: Value = test::call_and_read_body_json(&app, req).await;
+    assert_eq!(member, json!({ "project_id": 1, "user_id": 1, "role": "editor" }));
+    let members: Value = test::call_and_read_body_json(&app, call(test::TestRequest::get(), "/api/projects/1/members", "1")).await;
+    assert_eq!(members, json!([{ "project_id": 1, "user_id": 1, "role": "editor" }]));
+
+    let req = test::TestRequest::post().uri("/api/projects/1/tasks").insert_header(bearer("1")).set_json(json!({ "title": "Member task" })).to_request();
+    assert_eq!(test::call_service(&app, req).await.status(), StatusCode::CREATED);
+    let req = test::TestRequest::post().uri("/api/tasks").insert_header(bearer("1")).set_json(json!({ "title": "Still loose" })).to_request();
+    assert_eq!(test::call_service(&app, req).await.status(), StatusCode::FORBIDDEN);
+
+    assert_eq!(test::call_service(&app, call(test::TestRequest::delete(), "/api/projects/1/members/1", "3")).await.status(), StatusCode::NO_CONTENT);
+    assert_eq!(test::call_service(&app, call(test::TestRequest::delete(), "/api/projects/1/members/1", "3")).await.status(), StatusCode::NOT_FOUND);
+    let req = test::TestRequest::post().uri("/api/projects/1/tasks").insert_header(bearer("1")).set_json(json!({ "title": "Too late" })).to_request();
+    assert_eq!(test::call_service(&app, req).await.status(), StatusCode::FORBIDDEN);
+
+    // Глобальные роли меняет только администратор.
+    let req = test::TestRequest::put().uri("/api/users/1/role").insert_header(bearer("2")).set_json(json!({ "role": "admin" })).to_request();
+    assert_eq!(test::call_service(&app, req).await.status(), StatusCode::FORBIDDEN);
+    let req = test::TestRequest::put().uri("/api/users/1/role").insert_header(bearer("3")).set_json(json!({ "role": "admin" })).to_request();
+    assert_eq!(test::call_service(&app, req).await.status(), StatusCode::OK);
+}
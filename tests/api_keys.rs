@@ -0,0 +1,125 @@
+//! Ключи API сервисных учётных записей: выпуск, права, отметка использования и отзыв.
+
+mod common;
+
+use actix_web::{http::{header, StatusCode}, test};
+use jsonwebtoken::{encode, Algorithm, EncodingKey, Header};
+use serde_json::{json, Value};
+use task_api::infrastructure::auth::{JwtAuth, JwtConfig};
+
+const SECRET: &[u8] = b"api-key-secret";
+
+fn bearer(subject: &str) -> (header::HeaderName, String) {
+    let claims = json!({ "sub": subject, "exp": chrono::Utc::now().timestamp() + 600 });
+    let token = encode(&Header::new(Algorithm::HS256), &claims, &EncodingKey::from_secret(SECRET)).unwrap();
+    (header::AUTHORIZATION, format!("Bearer {}", token))
+}
+
+fn api_key(key: &Value) -> (&'static str, String) {
+    ("X-Api-Key", key.as_str().unwrap().to_string())
+}
+
+#[actix_rt::test]
+async fn test_should_authenticate_service_accounts_by_api_key_scopes() {
+    let config = common::prepare_database("api_keys");
+
+    // Без аутентификации права не проверяются: так выпускаются ключи и назначается администратор.
+    let setup = test::init_service(common::test_app(common::app_data(&config)).wrap(JwtAuth::new(None))).await;
+    let req = test::TestRequest::post().uri("/api/users").set_json(json!({ "name": "Admin", "email": "admin@example.com" })).to_request();
+    assert_eq!(test::call_service(&setup, req).await.status(), StatusCode::CREATED);
+    let req = test::TestRequest::put().uri("/api/users/1/role").set_json(json!({ "role": "admin" })).to_request();
+    assert_eq!(test::call_service(&setup, req).await.status(), StatusCode::OK);
+
+    let req = test::TestRequest::post().uri("/api/api-keys").set_json(json!({ "name": "reporter", "scopes": ["tasks:read"] })).to_request();
+    let resp = test::call_service(&setup, req).await;
+    assert_eq!(resp.status(), StatusCode::CREATED);
+    assert_eq!(resp.headers().get(header::LOCATION).unwrap(), "/api/api-keys/1");
+    let reader: Value = test::read_body_json(resp).await;
+    assert_eq!(reader["scopes"], json!(["tasks:read"]));
+    assert!(reader["key"].as_str().unwrap().starts_with(reader["prefix"].as_str().unwrap()));
+    let req = test::TestRequest::post().uri("/api/api-keys").set_json(json!({ "name": "ci-bot", "scopes": ["tasks:write", "tasks:read"] })).to_request();
+    let writer: Value = test::call_and_read_body_json(&setup, req).await;
+    let req = test::TestRequest::post().uri("/api/api-keys").set_json(json!({ "name": "", "scopes": [] })).to_request();
+    assert_eq!(test::call_service(&setup, req).await.status(), StatusCode::UNPROCESSABLE_ENTITY);
+
+    // Предъявленный ключ проверяется, даже если проверка токенов не настроена.
+    let req = test::TestRequest::get().uri("/api/tasks").insert_header(("X-Api-Key", "tm_unknown")).to_request();
+    assert_eq!(test::call_service(&setup, req).await.status(), StatusCode::UNAUTHORIZED);
+
+    let app = test::init_service(common::test_app(common::app_data(&config)).wrap(JwtAuth::new(Some(JwtConfig::hs256(SECRET))))).await;
+
+    let req = test::TestRequest::post().uri("/api/tasks").insert_header(api_key(&writer["key"])).set_json(json!({ "title": "Nightly build" })).to_request();
+    assert_eq!(test::call_service(&app, req).await.status(), StatusCode::CREATED);
+    let req = test::TestRequest::get().uri("/api/tasks/1").insert_header(api_key(&reader["key"])).to_request();
+    assert_eq!(test::call_service(&app, req).await.status(), StatusCode::OK);
+    let req = test::TestRequest::post().uri("/api/tasks").insert_header(api_key(&reader["key"])).set_json(json!({ "title": "Nope" })).to_request();
+    assert_eq!(test::call_service(&app, req).await.status(), StatusCode::FORBIDDEN);
+    let req = test::TestRequest::get().uri("/api/api-keys").insert_header(api_key(&writer["key"])).to_request();
+    assert_eq!(test::call_service(&app, req).await.status(), StatusCode::FORBIDDEN);
+    let req = test::TestRequest::get().uri("/api/tasks").insert_header(("X-Api-Key", "tm_unknown")).to_request();
+    let resp = test::call_service(&app, req).await;
+    assert_eq!(resp.status(), StatusCode::UNAUTHORIZED);
+    assert_eq!(resp.headers().get(header::CONTENT_TYPE).unwrap(), "application/problem+json");
+
+    // Список не раскрывает значения ключей, но показывает время последнего использования.
+    let req = test::TestRequest::get().uri("/api/api-keys").insert_header(bearer("1")).to_request();
+    let keys: Value = test::call_and_read_body_json(&app, req).await;
+    assert_eq!(keys.as_array().unwrap().len(), 2);
+    assert!(keys.as_array().unwrap().iter().all(|key| key.get("key").is_none() && !key["last_used_at"].is_null()));
+    assert_eq!(keys[1]["scopes"], json!(["tasks:read", "tasks:write"]));
+
+    let req = test::TestRequest::delete().uri("/api/api-keys/2").insert_header(bearer("1")).to_request();
+    assert_eq!(test::call_service(&app, req).await.status(), StatusCode::NO_CONTENT);
+    let req = test::TestRequest::delete().uri("/api/api-keys/9").insert_header(bearer("1")).to_request();
+    assert_eq!(test::call_service(&app, req).await.status(), StatusCode::NOT_FOUND);
+    let req = test::TestRequest::get().uri("/api/tasks").insert_header(api_key(&writer["key"])).to_request();
+    assert_eq!(test::call_service(&app, req).await.status(), StatusCode::UNAUTHORIZED);
+    let req = test::TestRequest::get().uri("/api/api-keys").insert_header(bearer("1")).to_request();
+    let keys: Value = test::call_and_read_body_json(&app, req).await;
+    assert!(keys[0]["revoked_at"].is_null());
+    assert!(!keys[1]["revoked_at"].is_null());
+}
+
+#[actix_rt::test]
+async fn test_should_reject_read_only_key_on_every_write() {
+    let config = common::prepare_database("api_keys_read_only");
+
+    let setup = test::init_service(common::test_app(common::app_data(&config)).wrap(JwtAuth::new(None))).await;
+    let req = test::TestRequest::post().uri("/api/users").set_json(json!({ "name": "Ada", "email": "ada@example.com" })).to_request();
+    assert_eq!(test::call_service(&setup, req).await.status(), StatusCode::CREATED);
+    let req = test::TestRequest::post().uri("/api/projects").set_json(json!({ "name": "Apollo" })).to_request();
+    assert_eq!(test::call_service(&setup, req).await.status(), StatusCode::CREATED);
+    for title in ["Launch", "Countdown"] {
+        let req = test::TestRequest::post().uri("/api/projects/1/tasks").set_json(json!({ "title": title })).to_request();
+        assert_eq!(test::call_service(&setup, req).await.status(), StatusCode::CREATED);
+    }
+    let req = test::TestRequest::post().uri("/api/tags").set_json(json!({ "name": "urgent" })).to_request();
+    assert_eq!(test::call_service(&setup, req).await.status(), StatusCode::CREATED);
+    let req = test::TestRequest::post().uri("/api/api-keys").set_json(json!({ "name": "reporter", "scopes": ["tasks:read"] })).to_request();
+    let reader: Value = test::call_and_read_body_json(&setup, req).await;
+
+    let app = test::init_service(common::test_app(common::app_data(&config)).wrap(JwtAuth::new(Some(JwtConfig::hs256(SECRET))))).await;
+    let forbidden = [
+        test::TestRequest::delete().uri("/api/projects/1?on_tasks=cascade"),
+        test::TestRequest::post().uri("/api/projects/1/archive"),
+        test::TestRequest::put().uri("/api/tasks/1/assignees/1"),
+        test::TestRequest::delete().uri("/api/tasks/1/assignees/1"),
+        test::TestRequest::put().uri("/api/tasks/1/blockers/2"),
+        test::TestRequest::put().uri("/api/tasks/1/tags/1"),
+        test::TestRequest::delete().uri("/api/tags/1"),
+        test::TestRequest::post().uri("/api/users").set_json(json!({ "name": "Eve", "email": "eve@example.com" })),
+    ];
+    for req in forbidden {
+        let req = req.insert_header(api_key(&reader["key"])).to_request();
+        let path = req.path().to_string();
+        assert_eq!(test::call_service(&app, req).await.status(), StatusCode::FORBIDDEN, "{}", path);
+    }
+
+    // Ни одна из отклонённых операций не изменила данные.
+    let req = test::TestRequest::get().uri("/api/projects/1/tasks").insert_header(api_key(&reader["key"])).to_request();
+    let page: Value = test::call_and_read_body_json(&app, req).await;
+    assert_eq!(page["total"], 2);
+    let req = test::TestRequest::get().uri("/api/tasks/1/assignees").insert_header(api_key(&reader["key"])).to_request();
+    let assignees: Value = test::call_and_read_body_json(&app, req).await;
+    assert_eq!(assignees, json!([]));
+}
@@ -0,0 +1,83 @@
+//! Аутентификация запросов к API токенами `Authorization: Bearer` с подписью HS256 и RS256.
+
+mod common;
+
+use actix_web::{http::{header, StatusCode}, test};
+use jsonwebtoken::{encode, Algorithm, EncodingKey, Header};
+use serde_json::{json, Value};
+use task_api::infrastructure::auth::{JwtAuth, JwtConfig};
+
+const SECRET: &[u8] = b"integration-secret";
+
+fn claims(sub: &str, exp_offset: i64) -> Value {
+    json!({ "sub": sub, "name": "Ada Lovelace", "exp": chrono::Utc::now().timestamp() + exp_offset })
+}
+
+fn hs256_token(claims: &Value) -> String {
+    encode(&Header::new(Algorithm::HS256), claims, &EncodingKey::from_secret(SECRET)).unwrap()
+}
+
+fn bearer(token: &str) -> (header::HeaderName, String) {
+    (header::AUTHORIZATION, format!("Bearer {}", token))
+}
+
+#[actix_rt::test]
+async fn test_should_reject_requests_without_valid_bearer_token() {
+    let config = common::prepare_database("auth_hs256");
+    let auth = JwtAuth::new(Some(JwtConfig::hs256(SECRET)));
+    let admin = test::init_service(common::test_app(common::app_data(&config))).await;
+    let req = test::TestRequest::post().uri("/api/users").set_json(json!({ "name": "Ada Lovelace", "email": "ada@example.com" })).to_request();
+    assert_eq!(test::call_service(&admin, req).await.status(), StatusCode::CREATED);
+    let req = test::TestRequest::put().uri("/api/users/1/role").set_json(json!({ "role": "editor" })).to_request();
+    assert_eq!(test::call_service(&admin, req).await.status(), StatusCode::OK);
+    let app = test::init_service(common::test_app(common::app_data(&config)).wrap(auth)).await;
+
+    let resp = test::call_service(&app, test::TestRequest::get().uri("/api/tasks").to_request()).await;
+    assert_eq!(resp.status(), StatusCode::UNAUTHORIZED);
+    assert_eq!(resp.headers().get(header::WWW_AUTHENTICATE).unwrap(), "Bearer");
+    assert_eq!(resp.headers().get(header::CONTENT_TYPE).unwrap(), "application/problem+json");
+    let problem: Value = test::read_body_json(resp).await;
+    assert_eq!(problem["status"], 401);
+    assert_eq!(problem["instance"], "/api/tasks");
+
+    let expired = hs256_token(&claims("1", -600));
+    let req = test::TestRequest::get().uri("/api/tasks").insert_header(bearer(&expired)).to_request();
+    assert_eq!(test::call_service(&app, req).await.status(), StatusCode::UNAUTHORIZED);
+    let forged = encode(&Header::new(Algorithm::HS256), &claims("1", 600), &EncodingKey::from_secret(b"forged")).unwrap();
+    let req = test::TestRequest::get().uri("/api/tasks").insert_header(bearer(&forged)).to_request();
+    assert_eq!(test::call_service(&app, req).await.status(), StatusCode::UNAUTHORIZED);
+
+    let token = hs256_token(&claims("1", 600));
+    let req = test::TestRequest::post().uri("/api/tasks").insert_header(bearer(&token)).set_json(json!({ "title": "Secured" })).to_request();
+    assert_eq!(test::call_service(&app, req).await.status(), StatusCode::CREATED);
+    let req = test::TestRequest::get().uri("/api/me").insert_header(bearer(&token)).to_request();
+    let principal: Value = test::call_and_read_body_json(&app, req).await;
+    assert_eq!(principal, json!({ "subject": "1", "name": "Ada Lovelace" }));
+}
+
+#[actix_rt::test]
+async fn test_should_accept_rs256_tokens_signed_with_matching_private_key() {
+    let config = common::prepare_database("auth_rs256");
+    let public_key = std::fs::read("tests/fixtures/jwt_rs256_public.pem").unwrap();
+    let private_key = std::fs::read("tests/fixtures/jwt_rs256_private.pem").unwrap();
+    let auth = JwtAuth::new(Some(JwtConfig::rs256(&public_key).unwrap().with_audience("task-manager")));
+    let app = test::init_service(common::test_app(common::app_data(&config)).wrap(auth)).await;
+    let encoding_key = EncodingKey::from_rsa_pem(&private_key).unwrap();
+
+    let mut valid = claims("7", 600);
+    valid["aud"] = json!("task-manager");
+    let token = encode(&Header::new(Algorithm::RS256), &valid, &encoding_key).unwrap();
+    let req = test::TestRequest::get().uri("/api/me").insert_header(bearer(&token)).to_request();
+    let principal: Value = test::call_and_read_body_json(&app, req).await;
+    assert_eq!(principal["subject"], "7");
+
+    let mut foreign = claims("7", 600);
+    foreign["aud"] = json!("billing");
+    let token = encode(&Header::new(Algorithm::RS256), &foreign, &encoding_key).unwrap();
+    let req = test::TestRequest::get().uri("/api/me").insert_header(bearer(&token)).to_request();
+    assert_eq!(test::call_service(&app, req).await.status(), StatusCode::UNAUTHORIZED);
+
+    // Токен HS256 не принимается сервером, настроенным на RS256.
+    let req = test::TestRequest::get().uri("/api/me").insert_header(bearer(&hs256_token(&valid))).to_request();
+    assert_eq!(test::call_service(&app, req).await.status(), StatusCode::UNAUTHORIZED);
+}
@@ -0,0 +1,58 @@
+//! Транзакционная семантика `POST /api/tasks/bulk` на реальной базе SQLite.
+
+mod common;
+
+use actix_web::{http::StatusCode, test};
+use serde_json::{json, Value};
+
+#[actix_rt::test]
+async fn test_should_roll_back_whole_batch_when_operation_fails() {
+    let config = common::prepare_database("bulk_all_or_nothing");
+    let app = test::init_service(common::test_app(common::app_data(&config))).await;
+
+    let req = test::TestRequest::post().uri("/api/tasks/bulk").set_json(json!({
+        "operations": [
+            { "op": "create", "title": "Imported 1" },
+            { "op": "create", "title": "Imported 2" },
+            { "op": "delete", "id": 999 },
+        ]
+    })).to_request();
+    let resp = test::call_service(&app, req).await;
+
+    assert_eq!(resp.status(), StatusCode::NOT_FOUND);
+    let problem: Value = test::read_body_json(resp).await;
+    assert_eq!(problem["errors"][0]["field"], "/operations/2");
+    let req = test::TestRequest::get().uri("/api/tasks").to_request();
+    let page: Value = test::call_and_read_body_json(&app, req).await;
+    assert_eq!(page["total"], 0);
+}
+
+#[actix_rt::test]
+async fn test_should_keep_successful_operations_in_best_effort_mode() {
+    let config = common::prepare_database("bulk_best_effort");
+    let app = test::init_service(common::test_app(common::app_data(&config))).await;
+
+    let req = test::TestRequest::post().uri("/api/tasks/bulk").set_json(json!({
+        "mode": "best_effort",
+        "operations": [
+            { "op": "create", "title": "Imported", "status": "done" },
+            { "op": "update", "id": 1, "title": "Reopened", "status": "blocked" },
+            { "op": "update", "id": 1, "title": "Renamed", "version": 1 },
+            { "op": "create", "title": "" },
+            { "op": "delete", "id": 1, "version": 1 },
+        ]
+    })).to_request();
+    let resp = test::call_service(&app, req).await;
+
+    assert_eq!(resp.status(), StatusCode::OK);
+    let body: Value = test::read_body_json(resp).await;
+    let statuses = body["results"].as_array().unwrap().iter().map(|item| item["status"].as_u64().unwrap()).collect::<Vec<_>>();
+    assert_eq!(statuses, vec![201, 409, 200, 422, 412]);
+    assert_eq!(body["results"][2]["task"]["title"], "Renamed");
+    assert_eq!(body["results"][2]["task"]["version"], 2);
+
+    let req = test::TestRequest::get().uri("/api/tasks/1").to_request();
+    let task: Value = test::call_and_read_body_json(&app, req).await;
+    assert_eq!(task["title"], "Renamed");
+    assert_eq!(task["status"], "done");
+}
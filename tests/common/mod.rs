@@ -0,0 +1,61 @@
+//! Общая подготовка интеграционных тестов: временная база с применёнными миграциями
+//! и приложение actix с реальными репозиториями.
+
+use std::sync::Arc;
+use actix_web::{web, App};
+use diesel::prelude::*;
+use diesel_migrations::{embed_migrations, EmbeddedMigrations, MigrationHarness};
+use task_api::adapters::api::task::error::json_error_handler;
+use task_api::adapters::routes::routes;
+use task_api::adapters::shared::app_confs::AppConfigs;
+use task_api::dal::db_connection::{DbConfig, DbConnection};
+use task_api::dal::repositories::db_repo_api_keys::ApiKeyRepository;
+use task_api::dal::repositories::db_repo_dependencies::DependencyRepository;
+use task_api::dal::repositories::db_repo_projects::ProjectRepository;
+use task_api::dal::repositories::db_repo_tags::TagRepository;
+use task_api::dal::repositories::db_repo_tasks::TaskRepository;
+use task_api::dal::repositories::db_repo_users::UserRepository;
+use task_api::domain::task::task_event::EventActor;
+use task_api::domain::workspace::Workspace;
+
+const MIGRATIONS: EmbeddedMigrations = embed_migrations!("migrations");
+
+/// Создаёт пустую базу `name` во временном каталоге и применяет к ней миграции.
+pub fn prepare_database(name: &str) -> DbConfig {
+    let path = std::env::temp_dir().join(format!("task_api_{}_{}.db", name, std::process::id()));
+    let _ = std::fs::remove_file(&path);
+    let mut config = DbConfig::new(&path.to_string_lossy());
+    config.max_size = 16;
+
+    let mut conn = SqliteConnection::establish(&config.database_url).expect("cannot open test database");
+    conn.run_pending_migrations(MIGRATIONS).expect("cannot run migrations");
+
+    config
+}
+
+/// Данные приложения с репозиториями поверх базы `config`.
+pub fn app_data(config: &DbConfig) -> web::Data<AppConfigs> {
+    let db_connection = DbConnection::new("test", config).unwrap();
+    web::Data::new(AppConfigs {
+        task_repository: Arc::new(TaskRepository { db_connection: db_connection.clone(), workspace: Workspace::DEFAULT, actor: EventActor::system() }),
+        tag_repository: Arc::new(TagRepository { db_connection: db_connection.clone(), workspace: Workspace::DEFAULT }),
+        project_repository: Arc::new(ProjectRepository { db_connection: db_connection.clone(), workspace: Workspace::DEFAULT, actor: EventActor::system() }),
+        dependency_repository: Arc::new(DependencyRepository { db_connection: db_connection.clone(), workspace: Workspace::DEFAULT }),
+        user_repository: Arc::new(UserRepository { db_connection: db_connection.clone(), workspace: Workspace::DEFAULT, actor: EventActor::system() }),
+        api_key_repository: Arc::new(ApiKeyRepository { db_connection, workspace: Workspace::DEFAULT }),
+    })
+}
+
+/// Приложение с маршрутами API, настроенное так же, как в `infrastructure::server`.
+pub fn test_app(data: web::Data<AppConfigs>) -> App<impl actix_web::dev::ServiceFactory<
+    actix_web::dev::ServiceRequest,
+    Config = (),
+    Response = actix_web::dev::ServiceResponse,
+    Error = actix_web::Error,
+    InitError = (),
+>> {
+    App::new()
+        .app_data(data)
+        .app_data(web::JsonConfig::default().error_handler(json_error_handler))
+        .configure(routes)
+}
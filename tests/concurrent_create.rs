@@ -0,0 +1,52 @@
+//! Параллельное создание задач через HTTP API: каждый ответ должен описывать
+//! именно ту строку, которую вставил этот запрос.
+
+mod common;
+
+use std::collections::HashMap;
+use std::rc::Rc;
+use actix_web::{http::{header, StatusCode}, test};
+use serde_json::{json, Value};
+
+const PARALLEL_REQUESTS: usize = 300;
+
+#[actix_rt::test]
+async fn test_should_return_own_row_for_each_parallel_create() {
+    let config = common::prepare_database("concurrent_create");
+    let app = Rc::new(test::init_service(common::test_app(common::app_data(&config))).await);
+
+    let handles = (0..PARALLEL_REQUESTS).map(|i| {
+        let app = app.clone();
+        actix_rt::spawn(async move {
+            let req = test::TestRequest::post()
+                .uri("/api/tasks")
+                .set_json(json!({ "title": format!("Task {}", i), "description": format!("Created by request {}", i) }))
+                .to_request();
+            let resp = test::call_service(app.as_ref(), req).await;
+            let status = resp.status();
+            let location = resp.headers().get(header::LOCATION).map(|value| value.to_str().unwrap().to_string());
+            let body: Value = test::read_body_json(resp).await;
+            (i, status, location, body)
+        })
+    }).collect::<Vec<_>>();
+
+    let mut created = HashMap::new();
+    for handle in handles {
+        let (i, status, location, body) = handle.await.unwrap();
+        assert_eq!(status, StatusCode::CREATED, "request {} failed: {}", i, body);
+        assert_eq!(body["title"], format!("Task {}", i));
+        assert_eq!(body["description"], format!("Created by request {}", i));
+
+        let task_id = body["id"].as_i64().unwrap();
+        assert_eq!(location, Some(format!("/api/tasks/{}", task_id)));
+        assert!(created.insert(task_id, i).is_none(), "task {} was returned twice", task_id);
+    }
+
+    // Каждый выданный идентификатор указывает на строку, созданную соответствующим запросом.
+    for (task_id, i) in created {
+        let req = test::TestRequest::get().uri(&format!("/api/tasks/{}", task_id)).to_request();
+        let stored: Value = test::call_and_read_body_json(app.as_ref(), req).await;
+        assert_eq!(stored["title"], format!("Task {}", i));
+        assert_eq!(stored["description"], format!("Created by request {}", i));
+    }
+}
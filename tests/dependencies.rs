@@ -0,0 +1,95 @@
+//! Зависимости задач: транзитивные блокирующие задачи, защита от циклов и критический путь проекта на реальной базе SQLite.
+
+mod common;
+
+use actix_web::{http::StatusCode, test};
+use serde_json::{json, Value};
+
+fn ids(tasks: &Value) -> Vec<i64> {
+    tasks.as_array().unwrap().iter().map(|task| task["id"].as_i64().unwrap()).collect()
+}
+
+#[actix_rt::test]
+async fn test_should_list_transitive_blockers_and_reject_cycles() {
+    let config = common::prepare_database("dependencies_blockers");
+    let app = test::init_service(common::test_app(common::app_data(&config))).await;
+
+    for title in ["Design", "Build", "Test", "Release"] {
+        let req = test::TestRequest::post().uri("/api/tasks").set_json(json!({ "title": title })).to_request();
+        assert_eq!(test::call_service(&app, req).await.status(), StatusCode::CREATED);
+    }
+    // 1 -> 2 -> 3 -> 4: каждая задача блокирует следующую.
+    for (task_id, blocker_id) in [(2, 1), (3, 2), (4, 3), (4, 3)] {
+        let req = test::TestRequest::put().uri(&format!("/api/tasks/{}/blockers/{}", task_id, blocker_id)).to_request();
+        assert_eq!(test::call_service(&app, req).await.status(), StatusCode::NO_CONTENT);
+    }
+
+    let req = test::TestRequest::get().uri("/api/tasks/4/blockers").to_request();
+    let blockers: Value = test::call_and_read_body_json(&app, req).await;
+    assert_eq!(ids(&blockers), vec![1, 2, 3]);
+    let req = test::TestRequest::get().uri("/api/tasks/4/blockers?transitive=false").to_request();
+    let blockers: Value = test::call_and_read_body_json(&app, req).await;
+    assert_eq!(ids(&blockers), vec![3]);
+
+    // Зависимость, замыкающая цепочку, и зависимость задачи от самой себя отклоняются.
+    let req = test::TestRequest::put().uri("/api/tasks/1/blockers/4").to_request();
+    let resp = test::call_service(&app, req).await;
+    assert_eq!(resp.status(), StatusCode::CONFLICT);
+    let problem: Value = test::read_body_json(resp).await;
+    assert_eq!(problem["detail"], "Task 1 cannot be blocked by task 4: task 1 already blocks it, directly or transitively");
+    let req = test::TestRequest::put().uri("/api/tasks/2/blockers/2").to_request();
+    assert_eq!(test::call_service(&app, req).await.status(), StatusCode::CONFLICT);
+    let req = test::TestRequest::put().uri("/api/tasks/2/blockers/9").to_request();
+    assert_eq!(test::call_service(&app, req).await.status(), StatusCode::NOT_FOUND);
+
+    // После удаления зависимости 3 -> 4 цепочка разрывается, и обратная зависимость становится допустимой.
+    let req = test::TestRequest::delete().uri("/api/tasks/4/blockers/3").to_request();
+    assert_eq!(test::call_service(&app, req).await.status(), StatusCode::NO_CONTENT);
+    let req = test::TestRequest::delete().uri("/api/tasks/4/blockers/3").to_request();
+    assert_eq!(test::call_service(&app, req).await.status(), StatusCode::NOT_FOUND);
+    let req = test::TestRequest::put().uri("/api/tasks/1/blockers/4").to_request();
+    assert_eq!(test::call_service(&app, req).await.status(), StatusCode::NO_CONTENT);
+
+    // Удаление задачи удаляет и её зависимости.
+    let req = test::TestRequest::delete().uri("/api/tasks/1").to_request();
+    assert_eq!(test::call_service(&app, req).await.status(), StatusCode::OK);
+    let req = test::TestRequest::get().uri("/api/tasks/3/blockers").to_request();
+    let blockers: Value = test::call_and_read_body_json(&app, req).await;
+    assert_eq!(ids(&blockers), vec![2]);
+}
+
+#[actix_rt::test]
+async fn test_should_compute_critical_path_of_project() {
+    let config = common::prepare_database("dependencies_critical_path");
+    let app = test::init_service(common::test_app(common::app_data(&config))).await;
+
+    let req = test::TestRequest::post().uri("/api/projects").set_json(json!({ "name": "Launch" })).to_request();
+    assert_eq!(test::call_service(&app, req).await.status(), StatusCode::CREATED);
+    let req = test::TestRequest::get().uri("/api/projects/1/critical-path").to_request();
+    let empty: Value = test::call_and_read_body_json(&app, req).await;
+    assert_eq!(empty["tasks"], json!([]));
+    assert_eq!(empty["total_estimate_minutes"], 0);
+
+    for (title, estimate) in [("Design", json!(60)), ("Backend", json!(240)), ("Frontend", json!(120)), ("Docs", Value::Null), ("Release", json!(30))] {
+        let req = test::TestRequest::post().uri("/api/projects/1/tasks")
+            .set_json(json!({ "title": title, "estimate_minutes": estimate }))
+            .to_request();
+        assert_eq!(test::call_service(&app, req).await.status(), StatusCode::CREATED);
+    }
+    // Задача вне проекта не влияет на его критический путь.
+    let req = test::TestRequest::post().uri("/api/tasks").set_json(json!({ "title": "Elsewhere", "estimate_minutes": 9000 })).to_request();
+    assert_eq!(test::call_service(&app, req).await.status(), StatusCode::CREATED);
+    for (task_id, blocker_id) in [(2, 1), (3, 1), (5, 2), (5, 3), (5, 4), (1, 6)] {
+        let req = test::TestRequest::put().uri(&format!("/api/tasks/{}/blockers/{}", task_id, blocker_id)).to_request();
+        assert_eq!(test::call_service(&app, req).await.status(), StatusCode::NO_CONTENT);
+    }
+
+    let req = test::TestRequest::get().uri("/api/projects/1/critical-path").to_request();
+    let path: Value = test::call_and_read_body_json(&app, req).await;
+    assert_eq!(ids(&path["tasks"]), vec![1, 2, 5]);
+    assert_eq!(path["total_estimate_minutes"], 330);
+    assert_eq!(path["unestimated_task_ids"], json!([]));
+
+    let req = test::TestRequest::get().uri("/api/projects/9/critical-path").to_request();
+    assert_eq!(test::call_service(&app, req).await.status(), StatusCode::NOT_FOUND);
+}
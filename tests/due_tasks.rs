@@ -0,0 +1,106 @@
+//! Выборки задач по срокам и приоритету на реальной базе SQLite.
+
+mod common;
+
+use actix_web::{http::StatusCode, test};
+use chrono::{Duration, SecondsFormat, Utc};
+use serde_json::{json, Value};
+
+fn in_days(days: i64) -> String {
+    (Utc::now() + Duration::days(days)).to_rfc3339_opts(SecondsFormat::Secs, true)
+}
+
+fn titles(tasks: &Value) -> Vec<&str> {
+    tasks.as_array().unwrap().iter().map(|task| task["title"].as_str().unwrap()).collect()
+}
+
+#[actix_rt::test]
+async fn test_should_select_overdue_due_soon_and_urgent_tasks() {
+    let config = common::prepare_database("due_tasks");
+    let app = test::init_service(common::test_app(common::app_data(&config))).await;
+
+    let tasks = [
+        json!({ "title": "Late low", "priority": "low", "due_at": in_days(-1) }),
+        json!({ "title": "Very late", "priority": "medium", "due_at": in_days(-3) }),
+        json!({ "title": "Late but done", "status": "done", "due_at": in_days(-2) }),
+        json!({ "title": "Tomorrow", "priority": "high", "due_at": in_days(1) }),
+        json!({ "title": "Next month", "priority": "urgent", "due_at": in_days(30) }),
+        json!({ "title": "Someday", "priority": "urgent" }),
+    ];
+    for task in tasks {
+        let req = test::TestRequest::post().uri("/api/tasks").set_json(task).to_request();
+        assert_eq!(test::call_service(&app, req).await.status(), StatusCode::CREATED);
+    }
+
+    let req = test::TestRequest::get().uri("/api/tasks/overdue").to_request();
+    let overdue: Value = test::call_and_read_body_json(&app, req).await;
+    assert_eq!(titles(&overdue), vec!["Very late", "Late low"]);
+
+    let req = test::TestRequest::get().uri("/api/tasks/due-soon?days=7").to_request();
+    let due_soon: Value = test::call_and_read_body_json(&app, req).await;
+    assert_eq!(titles(&due_soon), vec!["Tomorrow"]);
+
+    let req = test::TestRequest::get().uri("/api/tasks/urgent?limit=5").to_request();
+    let urgent: Value = test::call_and_read_body_json(&app, req).await;
+    assert_eq!(titles(&urgent), vec!["Very late", "Late low", "Next month", "Someday", "Tomorrow"]);
+}
+
+#[actix_rt::test]
+async fn test_should_reject_due_date_before_start_date() {
+    let config = common::prepare_database("due_before_start");
+    let app = test::init_service(common::test_app(common::app_data(&config))).await;
+
+    let req = test::TestRequest::post().uri("/api/tasks").set_json(json!({
+        "title": "Backwards",
+        "start_at": in_days(2),
+        "due_at": in_days(1),
+    })).to_request();
+    let resp = test::call_service(&app, req).await;
+
+    assert_eq!(resp.status(), StatusCode::UNPROCESSABLE_ENTITY);
+    let problem: Value = test::read_body_json(resp).await;
+    assert_eq!(problem["errors"][0]["field"], "due_at");
+}
+
+#[actix_rt::test]
+async fn test_should_page_tasks_sorted_by_priority_and_due_date() {
+    let config = common::prepare_database("sorted_by_priority");
+    let app = test::init_service(common::test_app(common::app_data(&config))).await;
+
+    let tasks = [
+        json!({ "title": "Late low", "priority": "low", "due_at": in_days(-1) }),
+        json!({ "title": "Very late", "priority": "medium", "due_at": in_days(-3) }),
+        json!({ "title": "Late medium", "due_at": in_days(-2) }),
+        json!({ "title": "Tomorrow", "priority": "high", "due_at": in_days(1) }),
+        json!({ "title": "Next month", "priority": "urgent", "due_at": in_days(30) }),
+        json!({ "title": "Someday", "priority": "urgent" }),
+    ];
+    for task in tasks {
+        let req = test::TestRequest::post().uri("/api/tasks").set_json(task).to_request();
+        assert_eq!(test::call_service(&app, req).await.status(), StatusCode::CREATED);
+    }
+
+    let mut pages = vec![];
+    let mut uri = "/api/tasks?sort_by=priority&order=desc&limit=2".to_string();
+    loop {
+        let page: Value = test::call_and_read_body_json(&app, test::TestRequest::get().uri(&uri).to_request()).await;
+        pages.push(page.clone());
+        match page["next_cursor"].as_str() {
+            Some(cursor) => uri = format!("/api/tasks?limit=2&cursor={}", cursor),
+            None => break,
+        }
+    }
+    let sorted = pages.iter().flat_map(|page| titles(&page["items"])).collect::<Vec<&str>>();
+    assert_eq!(sorted, vec!["Someday", "Next month", "Tomorrow", "Late medium", "Very late", "Late low"]);
+
+    let uri = format!("/api/tasks?limit=2&cursor={}", pages[2]["prev_cursor"].as_str().unwrap());
+    let previous: Value = test::call_and_read_body_json(&app, test::TestRequest::get().uri(&uri).to_request()).await;
+    assert_eq!(titles(&previous["items"]), vec!["Tomorrow", "Late medium"]);
+
+    let req = test::TestRequest::get().uri("/api/tasks?sort_by=due_at&limit=3").to_request();
+    let first: Value = test::call_and_read_body_json(&app, req).await;
+    assert_eq!(titles(&first["items"]), vec!["Someday", "Very late", "Late medium"]);
+    let uri = format!("/api/tasks?limit=3&cursor={}", first["next_cursor"].as_str().unwrap());
+    let second: Value = test::call_and_read_body_json(&app, test::TestRequest::get().uri(&uri).to_request()).await;
+    assert_eq!(titles(&second["items"]), vec!["Late low", "Tomorrow", "Next month"]);
+}
@@ -0,0 +1,156 @@
+//! Повторы изменяющих запросов с заголовком `Idempotency-Key`.
+
+mod common;
+
+use std::sync::Arc;
+use std::time::Duration;
+use actix_web::{http::{header, StatusCode}, test};
+use jsonwebtoken::{encode, Algorithm, EncodingKey, Header};
+use serde_json::{json, Value};
+use task_api::dal::db_connection::{DbConfig, DbConnection};
+use task_api::application::repositories::idempotency_abstract_repo::IdempotencyRepositoryAbstract;
+use task_api::dal::repositories::db_repo_idempotency::IdempotencyRepository;
+use task_api::domain::idempotency::{IdempotencyRecord, IdempotencyReservation, StoredResponse};
+use task_api::infrastructure::auth::{JwtAuth, JwtConfig};
+use task_api::infrastructure::idempotency::{Idempotency, IDEMPOTENCY_KEY, IDEMPOTENT_REPLAYED};
+
+const SECRET: &[u8] = b"idempotency-secret";
+
+fn idempotency(config: &DbConfig, ttl: Duration) -> Idempotency {
+    let repository = IdempotencyRepository { db_connection: DbConnection::new("test", config).unwrap() };
+    Idempotency::new(Arc::new(repository), ttl)
+}
+
+fn bearer(subject: &str) -> (header::HeaderName, String) {
+    let claims = json!({ "sub": subject, "exp": chrono::Utc::now().timestamp() + 600 });
+    let token = encode(&Header::new(Algorithm::HS256), &claims, &EncodingKey::from_secret(SECRET)).unwrap();
+    (header::AUTHORIZATION, format!("Bearer {}", token))
+}
+
+fn create_request(key: &str, title: &str) -> test::TestRequest {
+    test::TestRequest::post()
+        .uri("/api/tasks")
+        .insert_header((IDEMPOTENCY_KEY, key))
+        .set_json(json!({ "title": title }))
+}
+
+#[actix_rt::test]
+async fn test_should_replay_stored_response_for_repeated_key() {
+    let config = common::prepare_database("idempotency_replay");
+    let app = test::init_service(common::test_app(common::app_data(&config)).wrap(idempotency(&config, Duration::from_secs(60)))).await;
+
+    let first = test::call_service(&app, create_request("retry-1", "Buy milk").to_request()).await;
+    assert_eq!(first.status(), StatusCode::CREATED);
+    assert!(first.headers().get(IDEMPOTENT_REPLAYED).is_none());
+    let first: Value = test::read_body_json(first).await;
+
+    let retry = test::call_service(&app, create_request("retry-1", "Buy milk").to_request()).await;
+    assert_eq!(retry.status(), StatusCode::CREATED);
+    assert_eq!(retry.headers().get(IDEMPOTENT_REPLAYED).unwrap(), "true");
+    assert_eq!(retry.headers().get("Location").unwrap(), &format!("/api/tasks/{}", first["id"]));
+    let retry: Value = test::read_body_json(retry).await;
+    assert_eq!(retry, first);
+
+    let req = test::TestRequest::get().uri("/api/tasks").to_request();
+    let page: Value = test::call_and_read_body_json(&app, req).await;
+    assert_eq!(page["total"], 1);
+}
+
+#[actix_rt::test]
+async fn test_should_reject_key_reused_with_different_body() {
+    let config = common::prepare_database("idempotency_mismatch");
+    let app = test::init_service(common::test_app(common::app_data(&config)).wrap(idempotency(&config, Duration::from_secs(60)))).await;
+
+    let first = test::call_service(&app, create_request("retry-2", "Buy milk").to_request()).await;
+    assert_eq!(first.status(), StatusCode::CREATED);
+
+    let reused = test::call_service(&app, create_request("retry-2", "Buy bread").to_request()).await;
+    assert_eq!(reused.status(), StatusCode::UNPROCESSABLE_ENTITY);
+}
+
+#[actix_rt::test]
+async fn test_should_execute_request_again_after_key_expires() {
+    let config = common::prepare_database("idempotency_expired");
+    let app = test::init_service(common::test_app(common::app_data(&config)).wrap(idempotency(&config, Duration::ZERO))).await;
+
+    let first: Value = test::call_and_read_body_json(&app, create_request("retry-3", "Buy milk").to_request()).await;
+    let second: Value = test::call_and_read_body_json(&app, create_request("retry-3", "Buy milk").to_request()).await;
+
+    assert_ne!(first["id"], second["id"]);
+}
+
+#[actix_rt::test]
+async fn test_should_not_replay_response_of_another_caller() {
+    let config = common::prepare_database("idempotency_callers");
+
+    let setup = test::init_service(common::test_app(common::app_data(&config)).wrap(JwtAuth::new(None))).await;
+    for (id, email) in [(1, "ada@example.com"), (2, "eve@example.com")] {
+        let req = test::TestRequest::post().uri("/api/users").set_json(json!({ "name": email, "email": email })).to_request();
+        assert_eq!(test::call_service(&setup, req).await.status(), StatusCode::CREATED);
+        let req = test::TestRequest::put().uri(&format!("/api/users/{}/role", id)).set_json(json!({ "role": "editor" })).to_request();
+        assert_eq!(test::call_service(&setup, req).await.status(), StatusCode::OK);
+    }
+
+    let app = test::init_service(common::test_app(common::app_data(&config))
+        .wrap(idempotency(&config, Duration::from_secs(60)))
+        .wrap(JwtAuth::new(Some(JwtConfig::hs256(SECRET))))).await;
+    let first = test::call_service(&app, create_request("shared", "Buy milk").insert_header(bearer("1")).to_request()).await;
+    assert_eq!(first.status(), StatusCode::CREATED);
+    let first: Value = test::read_body_json(first).await;
+
+    // Тот же ключ и то же тело от другого пользователя выполняются заново, а не выдают чужой ответ.
+    let other = test::call_service(&app, create_request("shared", "Buy milk").insert_header(bearer("2")).to_request()).await;
+    assert_eq!(other.status(), StatusCode::CREATED);
+    assert!(other.headers().get(IDEMPOTENT_REPLAYED).is_none());
+    let other: Value = test::read_body_json(other).await;
+    assert_ne!(other["id"], first["id"]);
+
+    let retry = test::call_service(&app, create_request("shared", "Buy milk").insert_header(bearer("1")).to_request()).await;
+    assert_eq!(retry.headers().get(IDEMPOTENT_REPLAYED).unwrap(), "true");
+}
+
+#[actix_rt::test]
+async fn test_should_take_over_reservation_abandoned_longer_than_lease() {
+    let config = common::prepare_database("idempotency_lease");
+    let repository = IdempotencyRepository { db_connection: DbConnection::new("test", &config).unwrap() };
+    let ttl = Duration::from_secs(60);
+
+    // Запрос, занявший ключ и не сохранивший ответ, например из-за завершения процесса.
+    let reserved = repository.reserve("1:system:crashed".to_string(), "lost".to_string(), ttl, ttl).await.unwrap();
+    assert!(matches!(reserved, IdempotencyReservation::Reserved(_)));
+    let pending = repository.reserve("1:system:crashed".to_string(), "lost".to_string(), ttl, ttl).await.unwrap();
+    assert!(matches!(pending, IdempotencyReservation::Used(IdempotencyRecord { response: None, .. })));
+
+    let app = test::init_service(common::test_app(common::app_data(&config))
+        .wrap(idempotency(&config, ttl).with_lease(Duration::ZERO))).await;
+    let retry = test::call_service(&app, create_request("crashed", "Buy milk").to_request()).await;
+    assert_eq!(retry.status(), StatusCode::CREATED);
+    assert!(retry.headers().get(IDEMPOTENT_REPLAYED).is_none());
+
+    let replay = test::call_service(&app, create_request("crashed", "Buy milk").to_request()).await;
+    assert_eq!(replay.headers().get(IDEMPOTENT_REPLAYED).unwrap(), "true");
+}
+
+#[actix_rt::test]
+async fn test_should_keep_taken_over_reservation_from_stale_owner() {
+    let config = common::prepare_database("idempotency_stale_owner");
+    let repository = IdempotencyRepository { db_connection: DbConnection::new("test", &config).unwrap() };
+    let (ttl, key) = (Duration::from_secs(60), "1:system:slow".to_string());
+    let reserve = |lease| repository.reserve(key.clone(), "slow".to_string(), ttl, lease);
+
+    let IdempotencyReservation::Reserved(stale) = reserve(ttl).await.unwrap() else { panic!("key must be free") };
+    // Аренда медленного запроса истекла, и ключ перехватил повтор.
+    let IdempotencyReservation::Reserved(current) = reserve(Duration::ZERO).await.unwrap() else { panic!("lease must expire") };
+    assert_ne!(stale, current);
+
+    let response = StoredResponse { status: 201, headers: vec![], body: b"stale".to_vec() };
+    assert!(!repository.complete(key.clone(), stale.clone(), response).await.unwrap());
+    repository.release(key.clone(), stale).await.unwrap();
+    let pending = reserve(ttl).await.unwrap();
+    assert!(matches!(pending, IdempotencyReservation::Used(IdempotencyRecord { response: None, .. })));
+
+    let response = StoredResponse { status: 201, headers: vec![], body: b"retry".to_vec() };
+    assert!(repository.complete(key.clone(), current, response).await.unwrap());
+    let IdempotencyReservation::Used(record) = reserve(ttl).await.unwrap() else { panic!("key must stay used") };
+    assert_eq!(record.response.unwrap().body, b"retry".to_vec());
+}
@@ -0,0 +1,98 @@
+//! Проекты, вложенные маршруты задач и правила архивации и удаления проектов на реальной базе SQLite.
+
+mod common;
+
+use actix_web::{http::{header, StatusCode}, test};
+use serde_json::{json, Value};
+
+fn titles(page: &Value) -> Vec<&str> {
+    page["items"].as_array().unwrap().iter().map(|task| task["title"].as_str().unwrap()).collect()
+}
+
+#[actix_rt::test]
+async fn test_should_keep_tasks_inside_their_project_and_block_archived_projects() {
+    let config = common::prepare_database("projects_nested");
+    let app = test::init_service(common::test_app(common::app_data(&config))).await;
+
+    for name in ["Backend", "Frontend"] {
+        let req = test::TestRequest::post().uri("/api/projects").set_json(json!({ "name": name })).to_request();
+        assert_eq!(test::call_service(&app, req).await.status(), StatusCode::CREATED);
+    }
+    let req = test::TestRequest::post().uri("/api/projects/1/tasks").set_json(json!({ "title": "Design API" })).to_request();
+    let resp = test::call_service(&app, req).await;
+    assert_eq!(resp.status(), StatusCode::CREATED);
+    assert_eq!(resp.headers().get(header::LOCATION).unwrap(), "/api/tasks/1");
+    let task: Value = test::read_body_json(resp).await;
+    assert_eq!(task["project_id"], 1);
+
+    let req = test::TestRequest::post().uri("/api/tasks").set_json(json!({ "title": "Loose task" })).to_request();
+    assert_eq!(test::call_service(&app, req).await.status(), StatusCode::CREATED);
+    let req = test::TestRequest::patch().uri("/api/tasks/2")
+        .insert_header((header::CONTENT_TYPE, "application/merge-patch+json"))
+        .set_payload(r#"{"project_id": 2}"#)
+        .to_request();
+    assert_eq!(test::call_service(&app, req).await.status(), StatusCode::OK);
+
+    let req = test::TestRequest::get().uri("/api/projects/1/tasks").to_request();
+    let backend: Value = test::call_and_read_body_json(&app, req).await;
+    assert_eq!(titles(&backend), vec!["Design API"]);
+    let req = test::TestRequest::get().uri("/api/projects/9/tasks").to_request();
+    assert_eq!(test::call_service(&app, req).await.status(), StatusCode::NOT_FOUND);
+
+    // В архивный проект нельзя ни добавить задачу, ни перенести существующую.
+    let req = test::TestRequest::post().uri("/api/projects/1/archive").to_request();
+    let archived: Value = test::call_and_read_body_json(&app, req).await;
+    assert!(archived["archived_at"].is_string());
+    let req = test::TestRequest::post().uri("/api/projects/1/tasks").set_json(json!({ "title": "Too late" })).to_request();
+    assert_eq!(test::call_service(&app, req).await.status(), StatusCode::CONFLICT);
+    let req = test::TestRequest::patch().uri("/api/tasks/2")
+        .insert_header((header::CONTENT_TYPE, "application/merge-patch+json"))
+        .set_payload(r#"{"project_id": 1}"#)
+        .to_request();
+    assert_eq!(test::call_service(&app, req).await.status(), StatusCode::CONFLICT);
+
+    let req = test::TestRequest::get().uri("/api/projects").to_request();
+    let active: Value = test::call_and_read_body_json(&app, req).await;
+    assert_eq!(active.as_array().unwrap().len(), 1);
+    let req = test::TestRequest::get().uri("/api/projects?include_archived=true").to_request();
+    let all: Value = test::call_and_read_body_json(&app, req).await;
+    assert_eq!(all.as_array().unwrap().len(), 2);
+
+    let req = test::TestRequest::post().uri("/api/projects/1/unarchive").to_request();
+    let restored: Value = test::call_and_read_body_json(&app, req).await;
+    assert!(restored["archived_at"].is_null());
+}
+
+#[actix_rt::test]
+async fn test_should_apply_task_policy_when_deleting_project() {
+    let config = common::prepare_database("projects_delete");
+    let app = test::init_service(common::test_app(common::app_data(&config))).await;
+
+    for name in ["Keep tasks", "Drop tasks"] {
+        let req = test::TestRequest::post().uri("/api/projects").set_json(json!({ "name": name })).to_request();
+        assert_eq!(test::call_service(&app, req).await.status(), StatusCode::CREATED);
+    }
+    for (project_id, title) in [(1, "Kept"), (2, "Dropped")] {
+        let req = test::TestRequest::post().uri(&format!("/api/projects/{}/tasks", project_id)).set_json(json!({ "title": title })).to_request();
+        assert_eq!(test::call_service(&app, req).await.status(), StatusCode::CREATED);
+    }
+
+    let req = test::TestRequest::delete().uri("/api/projects/1").to_request();
+    assert_eq!(test::call_service(&app, req).await.status(), StatusCode::CONFLICT);
+    let req = test::TestRequest::delete().uri("/api/projects/1?on_tasks=bogus").to_request();
+    assert_eq!(test::call_service(&app, req).await.status(), StatusCode::BAD_REQUEST);
+
+    let req = test::TestRequest::delete().uri("/api/projects/1?on_tasks=detach").to_request();
+    assert_eq!(test::call_service(&app, req).await.status(), StatusCode::NO_CONTENT);
+    let req = test::TestRequest::get().uri("/api/tasks/1").to_request();
+    let kept: Value = test::call_and_read_body_json(&app, req).await;
+    assert!(kept["project_id"].is_null());
+    assert_eq!(kept["version"], 2);
+
+    let req = test::TestRequest::delete().uri("/api/projects/2?on_tasks=cascade").to_request();
+    assert_eq!(test::call_service(&app, req).await.status(), StatusCode::NO_CONTENT);
+    let req = test::TestRequest::get().uri("/api/tasks/2").to_request();
+    assert_eq!(test::call_service(&app, req).await.status(), StatusCode::NOT_FOUND);
+    let req = test::TestRequest::get().uri("/api/projects/2").to_request();
+    assert_eq!(test::call_service(&app, req).await.status(), StatusCode::NOT_FOUND);
+}
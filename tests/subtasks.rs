@@ -0,0 +1,93 @@
+//! Подзадачи: поддерево с процентом выполнения, перемещение поддеревьев и защита от циклов на реальной базе SQLite.
+
+mod common;
+
+use actix_web::{http::{header, StatusCode}, test};
+use serde_json::{json, Value};
+
+fn child_ids(tree: &Value) -> Vec<i64> {
+    tree["children"].as_array().unwrap().iter().map(|child| child["id"].as_i64().unwrap()).collect()
+}
+
+#[actix_rt::test]
+async fn test_should_build_subtree_and_move_it_without_cycles() {
+    let config = common::prepare_database("subtasks_tree");
+    let app = test::init_service(common::test_app(common::app_data(&config))).await;
+
+    // 1 - эпик, 2 и 3 - его подзадачи, 4 и 5 - подзадачи задачи 3, 6 - отдельная задача.
+    let req = test::TestRequest::post().uri("/api/tasks").set_json(json!({ "title": "Epic" })).to_request();
+    assert_eq!(test::call_service(&app, req).await.status(), StatusCode::CREATED);
+    for (parent_id, title, status) in [(1, "Spec", "done"), (1, "Build", "in_progress"), (3, "API", "done"), (3, "UI", "todo")] {
+        let req = test::TestRequest::post().uri(&format!("/api/tasks/{}/subtasks", parent_id))
+            .set_json(json!({ "title": title, "status": status }))
+            .to_request();
+        let resp = test::call_service(&app, req).await;
+        assert_eq!(resp.status(), StatusCode::CREATED);
+        let task: Value = test::read_body_json(resp).await;
+        assert_eq!(task["parent_id"], parent_id);
+    }
+    let req = test::TestRequest::post().uri("/api/tasks").set_json(json!({ "title": "Other epic" })).to_request();
+    assert_eq!(test::call_service(&app, req).await.status(), StatusCode::CREATED);
+
+    let req = test::TestRequest::get().uri("/api/tasks/1/tree").to_request();
+    let tree: Value = test::call_and_read_body_json(&app, req).await;
+    assert_eq!(tree["title"], "Epic");
+    assert_eq!(child_ids(&tree), vec![2, 3]);
+    assert_eq!(child_ids(&tree["children"][1]), vec![4, 5]);
+    assert_eq!(tree["children"][1]["completion"], 50.0);
+    assert_eq!(tree["completion"], 75.0);
+
+    // Эпик нельзя переместить в собственное поддерево, а задачу - под саму себя.
+    let req = test::TestRequest::post().uri("/api/tasks/1/move").set_json(json!({ "parent_id": 5 })).to_request();
+    assert_eq!(test::call_service(&app, req).await.status(), StatusCode::CONFLICT);
+    let req = test::TestRequest::post().uri("/api/tasks/3/move").set_json(json!({ "parent_id": 3 })).to_request();
+    assert_eq!(test::call_service(&app, req).await.status(), StatusCode::CONFLICT);
+    let req = test::TestRequest::post().uri("/api/tasks/3/move").set_json(json!({ "parent_id": 99 })).to_request();
+    assert_eq!(test::call_service(&app, req).await.status(), StatusCode::NOT_FOUND);
+    let req = test::TestRequest::post().uri("/api/tasks/3/move")
+        .insert_header((header::IF_MATCH, "\"7\""))
+        .set_json(json!({ "parent_id": 6 }))
+        .to_request();
+    assert_eq!(test::call_service(&app, req).await.status(), StatusCode::PRECONDITION_FAILED);
+
+    // Задача 3 переезжает под задачу 6 вместе со своими подзадачами.
+    let req = test::TestRequest::post().uri("/api/tasks/3/move").set_json(json!({ "parent_id": 6 })).to_request();
+    let moved: Value = test::call_and_read_body_json(&app, req).await;
+    assert_eq!(moved["parent_id"], 6);
+    assert_eq!(moved["version"], 2);
+    let req = test::TestRequest::get().uri("/api/tasks/6/tree").to_request();
+    let other: Value = test::call_and_read_body_json(&app, req).await;
+    assert_eq!(child_ids(&other), vec![3]);
+    assert_eq!(child_ids(&other["children"][0]), vec![4, 5]);
+    let req = test::TestRequest::get().uri("/api/tasks/1/tree").to_request();
+    let epic: Value = test::call_and_read_body_json(&app, req).await;
+    assert_eq!(child_ids(&epic), vec![2]);
+    assert_eq!(epic["completion"], 100.0);
+
+    let req = test::TestRequest::post().uri("/api/tasks/3/move").set_json(json!({ "parent_id": null })).to_request();
+    let root: Value = test::call_and_read_body_json(&app, req).await;
+    assert!(root["parent_id"].is_null());
+}
+
+#[actix_rt::test]
+async fn test_should_place_subtasks_in_parent_project_and_detach_them_on_delete() {
+    let config = common::prepare_database("subtasks_project");
+    let app = test::init_service(common::test_app(common::app_data(&config))).await;
+
+    let req = test::TestRequest::post().uri("/api/projects").set_json(json!({ "name": "Backend" })).to_request();
+    assert_eq!(test::call_service(&app, req).await.status(), StatusCode::CREATED);
+    let req = test::TestRequest::post().uri("/api/projects/1/tasks").set_json(json!({ "title": "Epic" })).to_request();
+    assert_eq!(test::call_service(&app, req).await.status(), StatusCode::CREATED);
+    let req = test::TestRequest::post().uri("/api/tasks/1/subtasks").set_json(json!({ "title": "Part" })).to_request();
+    let part: Value = test::call_and_read_body_json(&app, req).await;
+    assert_eq!(part["project_id"], 1);
+    let req = test::TestRequest::post().uri("/api/tasks/9/subtasks").set_json(json!({ "title": "Orphan" })).to_request();
+    assert_eq!(test::call_service(&app, req).await.status(), StatusCode::NOT_FOUND);
+
+    let req = test::TestRequest::delete().uri("/api/tasks/1").to_request();
+    assert_eq!(test::call_service(&app, req).await.status(), StatusCode::OK);
+    let req = test::TestRequest::get().uri("/api/tasks/2").to_request();
+    let detached: Value = test::call_and_read_body_json(&app, req).await;
+    assert!(detached["parent_id"].is_null());
+    assert_eq!(detached["version"], 2);
+}
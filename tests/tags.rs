@@ -0,0 +1,71 @@
+//! Метки задач, их связи и фильтр `?tag=` списка задач на реальной базе SQLite.
+
+mod common;
+
+use actix_web::{http::StatusCode, test};
+use serde_json::{json, Value};
+
+fn titles(page: &Value) -> Vec<&str> {
+    page["items"].as_array().unwrap().iter().map(|task| task["title"].as_str().unwrap()).collect()
+}
+
+#[actix_rt::test]
+async fn test_should_filter_tasks_by_tags_with_all_and_any() {
+    let config = common::prepare_database("tags_filter");
+    let app = test::init_service(common::test_app(common::app_data(&config))).await;
+
+    for name in ["backend", "bug"] {
+        let req = test::TestRequest::post().uri("/api/tags").set_json(json!({ "name": name })).to_request();
+        assert_eq!(test::call_service(&app, req).await.status(), StatusCode::CREATED);
+    }
+    for title in ["API crash", "Schema cleanup", "Button colour"] {
+        let req = test::TestRequest::post().uri("/api/tasks").set_json(json!({ "title": title })).to_request();
+        assert_eq!(test::call_service(&app, req).await.status(), StatusCode::CREATED);
+    }
+    for (task_id, tag_id) in [(1, 1), (1, 2), (2, 1), (3, 2), (3, 2)] {
+        let req = test::TestRequest::put().uri(&format!("/api/tasks/{}/tags/{}", task_id, tag_id)).to_request();
+        assert_eq!(test::call_service(&app, req).await.status(), StatusCode::NO_CONTENT);
+    }
+
+    let req = test::TestRequest::get().uri("/api/tasks?tag=backend,BUG").to_request();
+    let all: Value = test::call_and_read_body_json(&app, req).await;
+    assert_eq!(titles(&all), vec!["API crash"]);
+
+    let req = test::TestRequest::get().uri("/api/tasks?tag=backend,bug&tag_match=any").to_request();
+    let any: Value = test::call_and_read_body_json(&app, req).await;
+    assert_eq!(titles(&any), vec!["API crash", "Schema cleanup", "Button colour"]);
+
+    let req = test::TestRequest::delete().uri("/api/tasks/1/tags/2").to_request();
+    assert_eq!(test::call_service(&app, req).await.status(), StatusCode::NO_CONTENT);
+    let req = test::TestRequest::get().uri("/api/tasks/1/tags").to_request();
+    let tags: Value = test::call_and_read_body_json(&app, req).await;
+    assert_eq!(tags, json!([{ "id": 1, "name": "backend" }]));
+
+    // Удаление метки снимает её со всех задач.
+    let req = test::TestRequest::delete().uri("/api/tags/1").to_request();
+    assert_eq!(test::call_service(&app, req).await.status(), StatusCode::NO_CONTENT);
+    let req = test::TestRequest::get().uri("/api/tasks?tag=backend").to_request();
+    let none: Value = test::call_and_read_body_json(&app, req).await;
+    assert_eq!(none["total"], 0);
+}
+
+#[actix_rt::test]
+async fn test_should_reject_duplicate_names_and_unknown_tags() {
+    let config = common::prepare_database("tags_errors");
+    let app = test::init_service(common::test_app(common::app_data(&config))).await;
+
+    let req = test::TestRequest::post().uri("/api/tags").set_json(json!({ "name": "Backend" })).to_request();
+    assert_eq!(test::call_service(&app, req).await.status(), StatusCode::CREATED);
+    let req = test::TestRequest::post().uri("/api/tags").set_json(json!({ "name": " backend " })).to_request();
+    let resp = test::call_service(&app, req).await;
+    assert_eq!(resp.status(), StatusCode::CONFLICT);
+    let problem: Value = test::read_body_json(resp).await;
+    assert_eq!(problem["detail"], "Tag 'backend' already exists");
+
+    let req = test::TestRequest::post().uri("/api/tasks").set_json(json!({ "title": "Task" })).to_request();
+    test::call_service(&app, req).await;
+    let req = test::TestRequest::put().uri("/api/tasks/1/tags/42").to_request();
+    assert_eq!(test::call_service(&app, req).await.status(), StatusCode::NOT_FOUND);
+    let req = test::TestRequest::put().uri("/api/tasks/42/tags/1").to_request();
+    assert_eq!(test::call_service(&app, req).await.status(), StatusCode::NOT_FOUND);
+}
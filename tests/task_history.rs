@@ -0,0 +1,156 @@
+//! История изменений задач: каждое создание, изменение и удаление записывается вместе с автором
+//! и изменёнными полями и доступно через `GET /api/tasks/{id}/history`.
+
+mod common;
+
+use actix_web::{http::{header, StatusCode}, test};
+use jsonwebtoken::{encode, Algorithm, EncodingKey, Header};
+use serde_json::{json, Value};
+use task_api::infrastructure::auth::{JwtAuth, JwtConfig};
+
+const SECRET: &[u8] = b"history-secret";
+const MERGE_PATCH: (header::HeaderName, &str) = (header::CONTENT_TYPE, "application/merge-patch+json");
+
+fn bearer(subject: &str) -> (header::HeaderName, String) {
+    let claims = json!({ "sub": subject, "exp": chrono::Utc::now().timestamp() + 600 });
+    let token = encode(&Header::new(Algorithm::HS256), &claims, &EncodingKey::from_secret(SECRET)).unwrap();
+    (header::AUTHORIZATION, format!("Bearer {}", token))
+}
+
+fn actions(page: &Value) -> Vec<&str> {
+    page["items"].as_array().unwrap().iter().map(|event| event["action"].as_str().unwrap()).collect()
+}
+
+#[actix_rt::test]
+async fn test_should_record_field_changes_of_every_write() {
+    let config = common::prepare_database("task_history");
+    let app = test::init_service(common::test_app(common::app_data(&config))).await;
+
+    let req = test::TestRequest::post().uri("/api/tasks").set_json(json!({ "title": "Draft" })).to_request();
+    assert_eq!(test::call_service(&app, req).await.status(), StatusCode::CREATED);
+    let req = test::TestRequest::post().uri("/api/tasks/1/subtasks").set_json(json!({ "title": "Child" })).to_request();
+    assert_eq!(test::call_service(&app, req).await.status(), StatusCode::CREATED);
+    let req = test::TestRequest::patch().uri("/api/tasks/1").set_json(json!({ "title": "Final", "description": "Ready" })).insert_header(MERGE_PATCH).to_request();
+    assert_eq!(test::call_service(&app, req).await.status(), StatusCode::OK);
+    let req = test::TestRequest::post().uri("/api/tasks/1/transition").set_json(json!({ "status": "in_progress" })).to_request();
+    assert_eq!(test::call_service(&app, req).await.status(), StatusCode::OK);
+
+    // Отклонённое и пустое изменения в историю не попадают.
+    let req = test::TestRequest::put().uri("/api/tasks/1")
+        .insert_header((header::IF_MATCH, "\"1\""))
+        .set_json(json!({ "title": "Stale" }))
+        .to_request();
+    assert_eq!(test::call_service(&app, req).await.status(), StatusCode::PRECONDITION_FAILED);
+    let req = test::TestRequest::patch().uri("/api/tasks/1").set_json(json!({})).insert_header(MERGE_PATCH).to_request();
+    assert_eq!(test::call_service(&app, req).await.status(), StatusCode::OK);
+
+    let req = test::TestRequest::get().uri("/api/tasks/1/history").to_request();
+    let history: Value = test::call_and_read_body_json(&app, req).await;
+    assert_eq!(actions(&history), vec!["created", "updated", "updated"]);
+    assert_eq!(history["total"], 3);
+    assert_eq!(history["items"][0]["actor"], "system");
+    assert_eq!(history["items"][0]["changes"], json!([
+        { "field": "priority", "before": null, "after": "medium" },
+        { "field": "status", "before": null, "after": "todo" },
+        { "field": "title", "before": null, "after": "Draft" },
+    ]));
+    assert_eq!(history["items"][1]["changes"], json!([
+        { "field": "description", "before": null, "after": "Ready" },
+        { "field": "title", "before": "Draft", "after": "Final" },
+    ]));
+    assert_eq!(history["items"][2]["changes"], json!([{ "field": "status", "before": "todo", "after": "in_progress" }]));
+
+    // История удалённой задачи сохраняется; её подзадача отсоединяется, и это тоже записывается.
+    let req = test::TestRequest::delete().uri("/api/tasks/1").to_request();
+    assert_eq!(test::call_service(&app, req).await.status(), StatusCode::OK);
+    let req = test::TestRequest::get().uri("/api/tasks/1/history?offset=3").to_request();
+    let history: Value = test::call_and_read_body_json(&app, req).await;
+    assert_eq!(actions(&history), vec!["deleted"]);
+    assert_eq!(history["total"], 4);
+    let changes = history["items"][0]["changes"].as_array().unwrap();
+    assert_eq!(changes.len(), 1);
+    assert_eq!(changes[0]["field"], "deleted_at");
+    assert!(changes[0]["before"].is_null() && changes[0]["after"].is_string());
+
+    let req = test::TestRequest::get().uri("/api/tasks/2/history").to_request();
+    let history: Value = test::call_and_read_body_json(&app, req).await;
+    assert_eq!(actions(&history), vec!["created", "updated"]);
+    assert_eq!(history["items"][1]["changes"], json!([{ "field": "parent_id", "before": 1, "after": null }]));
+
+    let req = test::TestRequest::get().uri("/api/tasks/2/history?limit=1").to_request();
+    let history: Value = test::call_and_read_body_json(&app, req).await;
+    assert_eq!(actions(&history), vec!["created"]);
+    assert_eq!(history["limit"], 1);
+
+    let req = test::TestRequest::get().uri("/api/tasks/99/history").to_request();
+    assert_eq!(test::call_service(&app, req).await.status(), StatusCode::NOT_FOUND);
+    let req = test::TestRequest::get().uri("/api/tasks/2/history?limit=0").to_request();
+    assert_eq!(test::call_service(&app, req).await.status(), StatusCode::BAD_REQUEST);
+}
+
+#[actix_rt::test]
+async fn test_should_attribute_changes_to_token_subject_and_api_key() {
+    let config = common::prepare_database("task_history_actors");
+
+    let setup = test::init_service(common::test_app(common::app_data(&config)).wrap(JwtAuth::new(None))).await;
+    let req = test::TestRequest::post().uri("/api/users").set_json(json!({ "name": "Editor", "email": "editor@example.com" })).to_request();
+    assert_eq!(test::call_service(&setup, req).await.status(), StatusCode::CREATED);
+    let req = test::TestRequest::put().uri("/api/users/1/role").set_json(json!({ "role": "editor" })).to_request();
+    assert_eq!(test::call_service(&setup, req).await.status(), StatusCode::OK);
+    let req = test::TestRequest::post().uri("/api/api-keys").set_json(json!({ "name": "bot", "scopes": ["tasks:read", "tasks:write"] })).to_request();
+    let minted: Value = test::call_and_read_body_json(&setup, req).await;
+
+    let app = test::init_service(common::test_app(common::app_data(&config)).wrap(JwtAuth::new(Some(JwtConfig::hs256(SECRET))))).await;
+    let req = test::TestRequest::post().uri("/api/tasks").insert_header(bearer("1")).set_json(json!({ "title": "Owned" })).to_request();
+    assert_eq!(test::call_service(&app, req).await.status(), StatusCode::CREATED);
+    let key = ("X-Api-Key", minted["key"].as_str().unwrap().to_string());
+    let req = test::TestRequest::patch().uri("/api/tasks/1").insert_header(key.clone()).set_json(json!({ "title": "Automated" })).insert_header(MERGE_PATCH).to_request();
+    assert_eq!(test::call_service(&app, req).await.status(), StatusCode::OK);
+
+    let req = test::TestRequest::get().uri("/api/tasks/1/history").insert_header(key).to_request();
+    let history: Value = test::call_and_read_body_json(&app, req).await;
+    let actors = history["items"].as_array().unwrap().iter().map(|event| event["actor"].as_str().unwrap()).collect::<Vec<_>>();
+    assert_eq!(actors, vec!["user:1".to_string(), format!("api_key:{}", minted["id"])]);
+}
+
+#[actix_rt::test]
+async fn test_should_record_assignee_and_project_deletion_changes() {
+    let config = common::prepare_database("task_history_side_effects");
+    let app = test::init_service(common::test_app(common::app_data(&config))).await;
+
+    let req = test::TestRequest::post().uri("/api/users").set_json(json!({ "name": "Ada", "email": "ada@example.com" })).to_request();
+    assert_eq!(test::call_service(&app, req).await.status(), StatusCode::CREATED);
+    for name in ["Apollo", "Gemini"] {
+        let req = test::TestRequest::post().uri("/api/projects").set_json(json!({ "name": name })).to_request();
+        assert_eq!(test::call_service(&app, req).await.status(), StatusCode::CREATED);
+    }
+    for project in [1, 2] {
+        let req = test::TestRequest::post().uri(&format!("/api/projects/{}/tasks", project)).set_json(json!({ "title": "Launch" })).to_request();
+        assert_eq!(test::call_service(&app, req).await.status(), StatusCode::CREATED);
+    }
+
+    // Смена ответственного исполнителя меняет версию задачи и попадает в её историю.
+    let req = test::TestRequest::put().uri("/api/tasks/1/assignees/1").to_request();
+    assert_eq!(test::call_service(&app, req).await.status(), StatusCode::OK);
+    let req = test::TestRequest::delete().uri("/api/tasks/1/assignees/1").to_request();
+    assert_eq!(test::call_service(&app, req).await.status(), StatusCode::OK);
+
+    let req = test::TestRequest::delete().uri("/api/projects/1?on_tasks=detach").to_request();
+    assert_eq!(test::call_service(&app, req).await.status(), StatusCode::NO_CONTENT);
+    let req = test::TestRequest::get().uri("/api/tasks/1/history").to_request();
+    let history: Value = test::call_and_read_body_json(&app, req).await;
+    assert_eq!(actions(&history), vec!["created", "updated", "updated", "updated"]);
+    assert_eq!(history["items"][1]["changes"], json!([{ "field": "assignee_id", "before": null, "after": 1 }]));
+    assert_eq!(history["items"][2]["changes"], json!([{ "field": "assignee_id", "before": 1, "after": null }]));
+    assert_eq!(history["items"][3]["changes"], json!([{ "field": "project_id", "before": 1, "after": null }]));
+    let req = test::TestRequest::get().uri("/api/tasks/1").to_request();
+    let task: Value = test::call_and_read_body_json(&app, req).await;
+    assert_eq!(task["version"], 4);
+
+    let req = test::TestRequest::delete().uri("/api/projects/2?on_tasks=cascade").to_request();
+    assert_eq!(test::call_service(&app, req).await.status(), StatusCode::NO_CONTENT);
+    let req = test::TestRequest::get().uri("/api/tasks/2/history").to_request();
+    let history: Value = test::call_and_read_body_json(&app, req).await;
+    assert_eq!(actions(&history), vec!["created", "deleted", "updated"]);
+    assert_eq!(history["items"][2]["changes"], json!([{ "field": "project_id", "before": 2, "after": null }]));
+}
@@ -0,0 +1,165 @@
+//! Корзина: удалённые задачи скрыты от чтения, доступны через `GET /api/trash`, восстанавливаются
+//! через `POST /api/tasks/{id}/restore` и окончательно удаляются очисткой корзины.
+
+mod common;
+
+use std::time::Duration;
+use actix_web::{http::{header, StatusCode}, test};
+use serde_json::{json, Value};
+use task_api::infrastructure::trash_purge::TrashPurge;
+
+fn ids(page: &Value) -> Vec<i64> {
+    page["items"].as_array().unwrap().iter().map(|task| task["id"].as_i64().unwrap()).collect()
+}
+
+#[actix_rt::test]
+async fn test_should_hide_deleted_tasks_and_restore_them() {
+    let config = common::prepare_database("trash");
+    let app = test::init_service(common::test_app(common::app_data(&config))).await;
+
+    let req = test::TestRequest::post().uri("/api/tasks").set_json(json!({ "title": "Deleted by mistake" })).to_request();
+    assert_eq!(test::call_service(&app, req).await.status(), StatusCode::CREATED);
+    let req = test::TestRequest::post().uri("/api/tasks/1/subtasks").set_json(json!({ "title": "Child" })).to_request();
+    assert_eq!(test::call_service(&app, req).await.status(), StatusCode::CREATED);
+    let req = test::TestRequest::post().uri("/api/tags").set_json(json!({ "name": "urgent" })).to_request();
+    assert_eq!(test::call_service(&app, req).await.status(), StatusCode::CREATED);
+    let req = test::TestRequest::put().uri("/api/tasks/1/tags/1").to_request();
+    assert_eq!(test::call_service(&app, req).await.status(), StatusCode::NO_CONTENT);
+
+    let req = test::TestRequest::delete().uri("/api/tasks/1").to_request();
+    assert_eq!(test::call_service(&app, req).await.status(), StatusCode::OK);
+
+    // Задача из корзины не видна ни одним чтением.
+    let req = test::TestRequest::get().uri("/api/tasks/1").to_request();
+    assert_eq!(test::call_service(&app, req).await.status(), StatusCode::NOT_FOUND);
+    let req = test::TestRequest::get().uri("/api/tasks").to_request();
+    let page: Value = test::call_and_read_body_json(&app, req).await;
+    assert_eq!(ids(&page), vec![2]);
+    let req = test::TestRequest::get().uri("/api/tasks/search?q=mistake").to_request();
+    let hits: Value = test::call_and_read_body_json(&app, req).await;
+    assert_eq!(hits, json!([]));
+    let req = test::TestRequest::get().uri("/api/tasks?tag=urgent").to_request();
+    let page: Value = test::call_and_read_body_json(&app, req).await;
+    assert_eq!(page["total"], 0);
+    let req = test::TestRequest::delete().uri("/api/tasks/1").to_request();
+    assert_eq!(test::call_service(&app, req).await.status(), StatusCode::NOT_FOUND);
+
+    let req = test::TestRequest::get().uri("/api/trash").to_request();
+    let trash: Value = test::call_and_read_body_json(&app, req).await;
+    assert_eq!(ids(&trash), vec![1]);
+    assert_eq!(trash["total"], 1);
+    assert!(trash["items"][0]["deleted_at"].is_string());
+    assert_eq!(trash["items"][0]["version"], 2);
+    let req = test::TestRequest::get().uri("/api/trash").insert_header(("X-Workspace-Id", "2")).to_request();
+    let trash: Value = test::call_and_read_body_json(&app, req).await;
+    assert_eq!(trash["total"], 0);
+
+    // Восстановление проверяет версию и возвращает задачу вместе с её метками.
+    let req = test::TestRequest::post().uri("/api/tasks/1/restore").insert_header((header::IF_MATCH, "\"1\"")).to_request();
+    assert_eq!(test::call_service(&app, req).await.status(), StatusCode::PRECONDITION_FAILED);
+    let req = test::TestRequest::post().uri("/api/tasks/1/restore").insert_header((header::IF_MATCH, "\"2\"")).to_request();
+    let resp = test::call_service(&app, req).await;
+    assert_eq!(resp.status(), StatusCode::OK);
+    assert_eq!(resp.headers().get(header::ETAG).unwrap(), "\"3\"");
+    let restored: Value = test::read_body_json(resp).await;
+    assert!(restored.get("deleted_at").is_none());
+    let req = test::TestRequest::get().uri("/api/tasks?tag=urgent").to_request();
+    let page: Value = test::call_and_read_body_json(&app, req).await;
+    assert_eq!(ids(&page), vec![1]);
+    let req = test::TestRequest::post().uri("/api/tasks/1/restore").to_request();
+    assert_eq!(test::call_service(&app, req).await.status(), StatusCode::NOT_FOUND);
+
+    // Подзадача, чей родитель удалён после неё, восстанавливается корнем.
+    let req = test::TestRequest::post().uri("/api/tasks/1/subtasks").set_json(json!({ "title": "Orphan" })).to_request();
+    assert_eq!(test::call_service(&app, req).await.status(), StatusCode::CREATED);
+    let req = test::TestRequest::delete().uri("/api/tasks/3").to_request();
+    assert_eq!(test::call_service(&app, req).await.status(), StatusCode::OK);
+    let req = test::TestRequest::delete().uri("/api/tasks/1").to_request();
+    assert_eq!(test::call_service(&app, req).await.status(), StatusCode::OK);
+    let req = test::TestRequest::get().uri("/api/trash?limit=1").to_request();
+    let trash: Value = test::call_and_read_body_json(&app, req).await;
+    assert_eq!(ids(&trash), vec![1]);
+    assert_eq!(trash["total"], 2);
+    let req = test::TestRequest::post().uri("/api/tasks/3/restore").to_request();
+    let orphan: Value = test::call_and_read_body_json(&app, req).await;
+    assert_eq!(orphan["parent_id"], Value::Null);
+
+    let req = test::TestRequest::get().uri("/api/trash?limit=0").to_request();
+    assert_eq!(test::call_service(&app, req).await.status(), StatusCode::BAD_REQUEST);
+}
+
+#[actix_rt::test]
+async fn test_should_purge_tasks_after_retention_period() {
+    let config = common::prepare_database("trash_purge");
+    let data = common::app_data(&config);
+    let app = test::init_service(common::test_app(data.clone())).await;
+
+    let req = test::TestRequest::post().uri("/api/projects").set_json(json!({ "name": "Apollo" })).to_request();
+    assert_eq!(test::call_service(&app, req).await.status(), StatusCode::CREATED);
+    let req = test::TestRequest::post().uri("/api/projects/1/tasks").set_json(json!({ "title": "Obsolete" })).to_request();
+    assert_eq!(test::call_service(&app, req).await.status(), StatusCode::CREATED);
+    let req = test::TestRequest::delete().uri("/api/tasks/1").to_request();
+    assert_eq!(test::call_service(&app, req).await.status(), StatusCode::OK);
+
+    // Задачи корзины не мешают удалению проекта.
+    let req = test::TestRequest::delete().uri("/api/projects/1").to_request();
+    assert_eq!(test::call_service(&app, req).await.status(), StatusCode::NO_CONTENT);
+
+    let kept = TrashPurge::new(data.task_repository.clone(), chrono::Duration::days(30), Duration::from_secs(3600));
+    assert_eq!(kept.run_once().await.unwrap(), 0);
+    let purge = TrashPurge::new(data.task_repository.clone(), chrono::Duration::zero(), Duration::from_secs(3600));
+    assert_eq!(purge.run_once().await.unwrap(), 1);
+
+    let req = test::TestRequest::get().uri("/api/trash").to_request();
+    let trash: Value = test::call_and_read_body_json(&app, req).await;
+    assert_eq!(trash["total"], 0);
+    let req = test::TestRequest::post().uri("/api/tasks/1/restore").to_request();
+    assert_eq!(test::call_service(&app, req).await.status(), StatusCode::NOT_FOUND);
+
+    // История окончательно удалённой задачи сохраняется вместе с отсоединением от удалённого проекта.
+    let req = test::TestRequest::get().uri("/api/tasks/1/history").to_request();
+    let history: Value = test::call_and_read_body_json(&app, req).await;
+    let actions = history["items"].as_array().unwrap().iter().map(|event| event["action"].as_str().unwrap()).collect::<Vec<_>>();
+    assert_eq!(actions, vec!["created", "deleted", "updated", "purged"]);
+}
+
+#[actix_rt::test]
+async fn test_should_move_tasks_of_cascade_deleted_project_to_trash() {
+    let config = common::prepare_database("trash_cascade");
+    let app = test::init_service(common::test_app(common::app_data(&config))).await;
+
+    let req = test::TestRequest::post().uri("/api/projects").set_json(json!({ "name": "Apollo" })).to_request();
+    assert_eq!(test::call_service(&app, req).await.status(), StatusCode::CREATED);
+    let req = test::TestRequest::post().uri("/api/projects/1/tasks").set_json(json!({ "title": "Launch" })).to_request();
+    assert_eq!(test::call_service(&app, req).await.status(), StatusCode::CREATED);
+    let req = test::TestRequest::post().uri("/api/tasks/1/subtasks").set_json(json!({ "title": "Countdown" })).to_request();
+    assert_eq!(test::call_service(&app, req).await.status(), StatusCode::CREATED);
+    let req = test::TestRequest::post().uri("/api/tasks").set_json(json!({ "title": "Press release" })).to_request();
+    assert_eq!(test::call_service(&app, req).await.status(), StatusCode::CREATED);
+    let req = test::TestRequest::put().uri("/api/tasks/3/blockers/1").to_request();
+    assert_eq!(test::call_service(&app, req).await.status(), StatusCode::NO_CONTENT);
+
+    let req = test::TestRequest::delete().uri("/api/projects/1?on_tasks=cascade").to_request();
+    assert_eq!(test::call_service(&app, req).await.status(), StatusCode::NO_CONTENT);
+
+    // Задачи проекта не удалены окончательно, а лежат в корзине; задача вне проекта больше не заблокирована.
+    let req = test::TestRequest::get().uri("/api/tasks").to_request();
+    let page: Value = test::call_and_read_body_json(&app, req).await;
+    assert_eq!(ids(&page), vec![3]);
+    let req = test::TestRequest::get().uri("/api/tasks/3/blockers").to_request();
+    let blockers: Value = test::call_and_read_body_json(&app, req).await;
+    assert_eq!(blockers, json!([]));
+    let req = test::TestRequest::get().uri("/api/trash").to_request();
+    let trash: Value = test::call_and_read_body_json(&app, req).await;
+    assert_eq!(trash["total"], 2);
+    assert!(trash["items"].as_array().unwrap().iter().all(|task| task["project_id"].is_null()));
+
+    // Связь родитель - подзадача внутри проекта сохраняется, а задачи восстанавливаются без проекта.
+    let req = test::TestRequest::post().uri("/api/tasks/1/restore").to_request();
+    let parent: Value = test::call_and_read_body_json(&app, req).await;
+    assert_eq!(parent["project_id"], Value::Null);
+    let req = test::TestRequest::post().uri("/api/tasks/2/restore").to_request();
+    let child: Value = test::call_and_read_body_json(&app, req).await;
+    assert_eq!(child["parent_id"], 1);
+    assert_eq!(child["project_id"], Value::Null);
+}
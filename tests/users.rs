@@ -0,0 +1,95 @@
+//! Пользователи и исполнители задач: ответственный исполнитель, несколько исполнителей и список задач пользователя на реальной базе SQLite.
+
+mod common;
+
+use actix_web::{http::{header, StatusCode}, test};
+use serde_json::{json, Value};
+
+fn ids(values: &Value) -> Vec<i64> {
+    values.as_array().unwrap().iter().map(|value| value["id"].as_i64().unwrap()).collect()
+}
+
+#[actix_rt::test]
+async fn test_should_assign_several_users_and_hand_over_primary_role() {
+    let config = common::prepare_database("users_assignees");
+    let app = test::init_service(common::test_app(common::app_data(&config))).await;
+
+    for (name, email) in [("Ada", "ada@example.com"), ("Grace", "grace@example.com")] {
+        let req = test::TestRequest::post().uri("/api/users").set_json(json!({ "name": name, "email": email })).to_request();
+        let resp = test::call_service(&app, req).await;
+        assert_eq!(resp.status(), StatusCode::CREATED);
+        assert!(resp.headers().get(header::LOCATION).is_some());
+    }
+    let req = test::TestRequest::post().uri("/api/users").set_json(json!({ "name": "Ada 2", "email": "ADA@example.com" })).to_request();
+    assert_eq!(test::call_service(&app, req).await.status(), StatusCode::CONFLICT);
+    let req = test::TestRequest::post().uri("/api/users").set_json(json!({ "name": "Bob", "email": "bob" })).to_request();
+    assert_eq!(test::call_service(&app, req).await.status(), StatusCode::UNPROCESSABLE_ENTITY);
+
+    let req = test::TestRequest::post().uri("/api/tasks").set_json(json!({ "title": "Review" })).to_request();
+    assert_eq!(test::call_service(&app, req).await.status(), StatusCode::CREATED);
+
+    // Первый исполнитель становится ответственным, второй - нет, пока не передан `primary=true`.
+    let req = test::TestRequest::put().uri("/api/tasks/1/assignees/2").to_request();
+    let task: Value = test::call_and_read_body_json(&app, req).await;
+    assert_eq!(task["assignee_id"], 2);
+    assert_eq!(task["version"], 2);
+    let req = test::TestRequest::put().uri("/api/tasks/1/assignees/1").to_request();
+    let task: Value = test::call_and_read_body_json(&app, req).await;
+    assert_eq!(task["assignee_id"], 2);
+    assert_eq!(task["version"], 2);
+    let req = test::TestRequest::put().uri("/api/tasks/1/assignees/1?primary=true")
+        .insert_header((header::IF_MATCH, "\"1\""))
+        .to_request();
+    assert_eq!(test::call_service(&app, req).await.status(), StatusCode::PRECONDITION_FAILED);
+    let req = test::TestRequest::put().uri("/api/tasks/1/assignees/1?primary=true").to_request();
+    let task: Value = test::call_and_read_body_json(&app, req).await;
+    assert_eq!(task["assignee_id"], 1);
+    assert_eq!(task["version"], 3);
+
+    let req = test::TestRequest::get().uri("/api/tasks/1/assignees").to_request();
+    let assignees: Value = test::call_and_read_body_json(&app, req).await;
+    assert_eq!(ids(&assignees), vec![1, 2]);
+    let req = test::TestRequest::put().uri("/api/tasks/1/assignees/9").to_request();
+    assert_eq!(test::call_service(&app, req).await.status(), StatusCode::NOT_FOUND);
+
+    // Снятие ответственного передаёт роль оставшемуся исполнителю, снятие последнего очищает её.
+    let req = test::TestRequest::delete().uri("/api/tasks/1/assignees/1").to_request();
+    let task: Value = test::call_and_read_body_json(&app, req).await;
+    assert_eq!(task["assignee_id"], 2);
+    let req = test::TestRequest::delete().uri("/api/tasks/1/assignees/1").to_request();
+    assert_eq!(test::call_service(&app, req).await.status(), StatusCode::NOT_FOUND);
+    let req = test::TestRequest::delete().uri("/api/tasks/1/assignees/2").to_request();
+    let task: Value = test::call_and_read_body_json(&app, req).await;
+    assert!(task["assignee_id"].is_null());
+    assert_eq!(task["version"], 5);
+}
+
+#[actix_rt::test]
+async fn test_should_list_tasks_of_user_with_task_list_filters() {
+    let config = common::prepare_database("users_tasks");
+    let app = test::init_service(common::test_app(common::app_data(&config))).await;
+
+    let req = test::TestRequest::post().uri("/api/users").set_json(json!({ "name": "Ada", "email": "ada@example.com" })).to_request();
+    assert_eq!(test::call_service(&app, req).await.status(), StatusCode::CREATED);
+    for title in ["Write API", "Write docs", "Deploy API", "Not mine"] {
+        let req = test::TestRequest::post().uri("/api/tasks").set_json(json!({ "title": title })).to_request();
+        assert_eq!(test::call_service(&app, req).await.status(), StatusCode::CREATED);
+    }
+    for task_id in [1, 2, 3] {
+        let req = test::TestRequest::put().uri(&format!("/api/tasks/{}/assignees/1", task_id)).to_request();
+        assert_eq!(test::call_service(&app, req).await.status(), StatusCode::OK);
+    }
+
+    let req = test::TestRequest::get().uri("/api/users/1/tasks").to_request();
+    let page: Value = test::call_and_read_body_json(&app, req).await;
+    assert_eq!(ids(&page["items"]), vec![1, 2, 3]);
+    let req = test::TestRequest::get().uri("/api/users/1/tasks?title_contains=API&order=desc&limit=1").to_request();
+    let page: Value = test::call_and_read_body_json(&app, req).await;
+    assert_eq!(ids(&page["items"]), vec![3]);
+    assert_eq!(page["total"], 2);
+
+    let req = test::TestRequest::get().uri("/api/users/9/tasks").to_request();
+    assert_eq!(test::call_service(&app, req).await.status(), StatusCode::NOT_FOUND);
+    let req = test::TestRequest::get().uri("/api/users/1/tasks?limit=0").to_request();
+    assert_eq!(test::call_service(&app, req).await.status(), StatusCode::BAD_REQUEST);
+}
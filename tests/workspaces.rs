@@ -0,0 +1,157 @@
+//! Рабочие пространства: задачи, проекты и метки одного пространства не видны и не изменяются из другого.
+
+mod common;
+
+use actix_web::{http::{header, StatusCode}, test};
+use jsonwebtoken::{encode, Algorithm, EncodingKey, Header};
+use serde_json::{json, Value};
+use task_api::infrastructure::auth::{JwtAuth, JwtConfig};
+
+const SECRET: &[u8] = b"workspace-secret";
+
+fn bearer(subject: &str, workspace_id: i32) -> (header::HeaderName, String) {
+    let claims = json!({ "sub": subject, "workspace_id": workspace_id, "exp": chrono::Utc::now().timestamp() + 600 });
+    let token = encode(&Header::new(Algorithm::HS256), &claims, &EncodingKey::from_secret(SECRET)).unwrap();
+    (header::AUTHORIZATION, format!("Bearer {}", token))
+}
+
+fn workspace(id: &str) -> (&'static str, String) {
+    ("X-Workspace-Id", id.to_string())
+}
+
+fn titles(page: &Value) -> Vec<&str> {
+    page["items"].as_array().unwrap().iter().map(|task| task["title"].as_str().unwrap()).collect()
+}
+
+#[actix_rt::test]
+async fn test_should_isolate_tasks_projects_and_tags_between_workspaces() {
+    let config = common::prepare_database("workspaces");
+    let app = test::init_service(common::test_app(common::app_data(&config))).await;
+
+    // Без заголовка данные попадают в пространство по умолчанию 1.
+    let req = test::TestRequest::post().uri("/api/tasks").set_json(json!({ "title": "Alpha launch" })).to_request();
+    assert_eq!(test::call_service(&app, req).await.status(), StatusCode::CREATED);
+    let req = test::TestRequest::post().uri("/api/projects").set_json(json!({ "name": "Apollo" })).to_request();
+    assert_eq!(test::call_service(&app, req).await.status(), StatusCode::CREATED);
+    let req = test::TestRequest::post().uri("/api/tags").set_json(json!({ "name": "backend" })).to_request();
+    assert_eq!(test::call_service(&app, req).await.status(), StatusCode::CREATED);
+    let req = test::TestRequest::put().uri("/api/tasks/1/tags/1").to_request();
+    assert_eq!(test::call_service(&app, req).await.status(), StatusCode::NO_CONTENT);
+
+    let req = test::TestRequest::post().uri("/api/tasks").insert_header(workspace("2")).set_json(json!({ "title": "Beta launch" })).to_request();
+    let beta: Value = test::call_and_read_body_json(&app, req).await;
+    assert_eq!(beta["id"], 2);
+
+    // Пространство 2 не видит и не изменяет задачу пространства 1.
+    let req = test::TestRequest::get().uri("/api/tasks/1").insert_header(workspace("2")).to_request();
+    assert_eq!(test::call_service(&app, req).await.status(), StatusCode::NOT_FOUND);
+    let req = test::TestRequest::put().uri("/api/tasks/1").insert_header(workspace("2")).set_json(json!({ "title": "Hijacked" })).to_request();
+    assert_eq!(test::call_service(&app, req).await.status(), StatusCode::NOT_FOUND);
+    let req = test::TestRequest::delete().uri("/api/tasks/1").insert_header(workspace("2")).to_request();
+    assert_eq!(test::call_service(&app, req).await.status(), StatusCode::NOT_FOUND);
+    let req = test::TestRequest::post().uri("/api/tasks/1/subtasks").insert_header(workspace("2")).set_json(json!({ "title": "Child" })).to_request();
+    assert_eq!(test::call_service(&app, req).await.status(), StatusCode::NOT_FOUND);
+    let req = test::TestRequest::post().uri("/api/projects/1/tasks").insert_header(workspace("2")).set_json(json!({ "title": "Intruder" })).to_request();
+    assert_eq!(test::call_service(&app, req).await.status(), StatusCode::NOT_FOUND);
+    let req = test::TestRequest::put().uri("/api/tasks/2/tags/1").insert_header(workspace("2")).to_request();
+    assert_eq!(test::call_service(&app, req).await.status(), StatusCode::NOT_FOUND);
+
+    let req = test::TestRequest::get().uri("/api/tasks").insert_header(workspace("2")).to_request();
+    let page: Value = test::call_and_read_body_json(&app, req).await;
+    assert_eq!(titles(&page), vec!["Beta launch"]);
+    assert_eq!(page["total"], 1);
+    let req = test::TestRequest::get().uri("/api/tasks/search?q=launch").insert_header(workspace("2")).to_request();
+    let hits: Value = test::call_and_read_body_json(&app, req).await;
+    assert_eq!(hits.as_array().unwrap().len(), 1);
+    assert_eq!(hits[0]["task"]["id"], 2);
+    let req = test::TestRequest::get().uri("/api/tasks?tag=backend").insert_header(workspace("2")).to_request();
+    let page: Value = test::call_and_read_body_json(&app, req).await;
+    assert_eq!(page["total"], 0);
+    let req = test::TestRequest::get().uri("/api/projects").insert_header(workspace("2")).to_request();
+    let projects: Value = test::call_and_read_body_json(&app, req).await;
+    assert_eq!(projects, json!([]));
+
+    // Имена меток уникальны только в пределах пространства.
+    let req = test::TestRequest::post().uri("/api/tags").insert_header(workspace("2")).set_json(json!({ "name": "Backend" })).to_request();
+    assert_eq!(test::call_service(&app, req).await.status(), StatusCode::CREATED);
+    let req = test::TestRequest::post().uri("/api/tags").set_json(json!({ "name": "BACKEND" })).to_request();
+    assert_eq!(test::call_service(&app, req).await.status(), StatusCode::CONFLICT);
+    let req = test::TestRequest::get().uri("/api/tags").insert_header(workspace("2")).to_request();
+    let tags: Value = test::call_and_read_body_json(&app, req).await;
+    assert_eq!(tags, json!([{ "id": 2, "name": "Backend" }]));
+
+    // Пространство 1 по-прежнему видит только свою задачу, нетронутой.
+    let req = test::TestRequest::get().uri("/api/tasks").to_request();
+    let page: Value = test::call_and_read_body_json(&app, req).await;
+    assert_eq!(titles(&page), vec!["Alpha launch"]);
+    assert_eq!(page["items"][0]["version"], 1);
+
+    let req = test::TestRequest::get().uri("/api/tasks").insert_header(workspace("two")).to_request();
+    let resp = test::call_service(&app, req).await;
+    assert_eq!(resp.status(), StatusCode::BAD_REQUEST);
+    assert_eq!(resp.headers().get(header::CONTENT_TYPE).unwrap(), "application/problem+json");
+}
+
+#[actix_rt::test]
+async fn test_should_bind_tokens_and_api_keys_to_their_workspace() {
+    let config = common::prepare_database("workspace_principals");
+
+    // Без аутентификации права не проверяются: так заводятся пользователь, задачи и ключ пространства 2.
+    let setup = test::init_service(common::test_app(common::app_data(&config)).wrap(JwtAuth::new(None))).await;
+    let req = test::TestRequest::post().uri("/api/users").set_json(json!({ "name": "Editor", "email": "editor@example.com" })).to_request();
+    assert_eq!(test::call_service(&setup, req).await.status(), StatusCode::CREATED);
+    let req = test::TestRequest::put().uri("/api/users/1/role").set_json(json!({ "role": "editor" })).to_request();
+    assert_eq!(test::call_service(&setup, req).await.status(), StatusCode::OK);
+    let req = test::TestRequest::post().uri("/api/tasks").set_json(json!({ "title": "Default task" })).to_request();
+    assert_eq!(test::call_service(&setup, req).await.status(), StatusCode::CREATED);
+    let req = test::TestRequest::post().uri("/api/tasks").insert_header(workspace("2")).set_json(json!({ "title": "Team task" })).to_request();
+    assert_eq!(test::call_service(&setup, req).await.status(), StatusCode::CREATED);
+    let req = test::TestRequest::post().uri("/api/api-keys").insert_header(workspace("2")).set_json(json!({ "name": "team-bot", "scopes": ["tasks:read"] })).to_request();
+    let minted: Value = test::call_and_read_body_json(&setup, req).await;
+    assert_eq!(minted["workspace_id"], 2);
+    let req = test::TestRequest::get().uri("/api/api-keys").to_request();
+    let keys: Value = test::call_and_read_body_json(&setup, req).await;
+    assert_eq!(keys, json!([]));
+
+    let app = test::init_service(common::test_app(common::app_data(&config)).wrap(JwtAuth::new(Some(JwtConfig::hs256(SECRET))))).await;
+
+    // Пространство из токена действует без заголовка, а заголовок не может его подменить.
+    let req = test::TestRequest::get().uri("/api/tasks").insert_header(bearer("1", 2)).to_request();
+    let page: Value = test::call_and_read_body_json(&app, req).await;
+    assert_eq!(titles(&page), vec!["Team task"]);
+    let req = test::TestRequest::get().uri("/api/tasks/1").insert_header(bearer("1", 2)).insert_header(workspace("1")).to_request();
+    let resp = test::call_service(&app, req).await;
+    assert_eq!(resp.status(), StatusCode::FORBIDDEN);
+    assert_eq!(resp.headers().get(header::CONTENT_TYPE).unwrap(), "application/problem+json");
+    let req = test::TestRequest::delete().uri("/api/tasks/1").insert_header(bearer("1", 2)).to_request();
+    assert_eq!(test::call_service(&app, req).await.status(), StatusCode::NOT_FOUND);
+
+    // Ключ API действует только в пространстве, в котором выпущен.
+    let key = ("X-Api-Key", minted["key"].as_str().unwrap().to_string());
+    let req = test::TestRequest::get().uri("/api/tasks").insert_header(key.clone()).to_request();
+    let page: Value = test::call_and_read_body_json(&app, req).await;
+    assert_eq!(titles(&page), vec!["Team task"]);
+    let req = test::TestRequest::get().uri("/api/tasks/1").insert_header(key.clone()).to_request();
+    assert_eq!(test::call_service(&app, req).await.status(), StatusCode::NOT_FOUND);
+    let req = test::TestRequest::get().uri("/api/tasks").insert_header(key).insert_header(workspace("1")).to_request();
+    assert_eq!(test::call_service(&app, req).await.status(), StatusCode::FORBIDDEN);
+
+    // Токен без `workspace_id` закреплён за пространством по умолчанию и не выбирает чужое заголовком.
+    let unbound = {
+        let claims = json!({ "sub": "1", "exp": chrono::Utc::now().timestamp() + 600 });
+        let token = encode(&Header::new(Algorithm::HS256), &claims, &EncodingKey::from_secret(SECRET)).unwrap();
+        (header::AUTHORIZATION, format!("Bearer {}", token))
+    };
+    let req = test::TestRequest::get().uri("/api/tasks").insert_header(unbound.clone()).to_request();
+    let page: Value = test::call_and_read_body_json(&app, req).await;
+    assert_eq!(titles(&page), vec!["Default task"]);
+    let req = test::TestRequest::get().uri("/api/tasks").insert_header(unbound.clone()).insert_header(workspace("2")).to_request();
+    assert_eq!(test::call_service(&app, req).await.status(), StatusCode::FORBIDDEN);
+    let req = test::TestRequest::put().uri("/api/tasks/2").insert_header(unbound.clone()).set_json(json!({ "title": "Hijacked" })).to_request();
+    assert_eq!(test::call_service(&app, req).await.status(), StatusCode::NOT_FOUND);
+    let req = test::TestRequest::post().uri("/api/tasks").insert_header(unbound).insert_header(workspace("2")).set_json(json!({ "title": "Intruder" })).to_request();
+    assert_eq!(test::call_service(&app, req).await.status(), StatusCode::FORBIDDEN);
+    let req = test::TestRequest::get().uri("/api/tasks").insert_header(bearer("1", 2)).to_request();
+    let page: Value = test::call_and_read_body_json(&app, req).await;
+    assert_eq!(titles(&page), vec!["Team task"]);
+}